@@ -1,17 +1,35 @@
-#[cfg(all(not(feature = "raster-renderer"), feature = "fluffy-stuff-tile-sets"))]
+#[cfg(all(
+    not(feature = "raster-renderer"),
+    any(
+        feature = "fluffy-stuff-yellow",
+        feature = "fluffy-stuff-red",
+        feature = "fluffy-stuff-black"
+    )
+))]
 compile_error!("feature \"fluffy-stuff-tile-sets\" must be used with \"raster-renderer\"");
 
 #[cfg(all(not(feature = "raster-renderer"), feature = "martin-persson-tile-sets"))]
 compile_error!("feature \"martin-persson-tile-sets\" must be used with \"raster-renderer\"");
 
 fn main() {
-    #[cfg(feature = "fluffy-stuff-tile-sets")]
+    #[cfg(feature = "regenerate-tile-sets")]
     tile_set_render::render_tile_sets();
+    #[cfg(feature = "fluffy-stuff-blue")]
+    tile_set_recolor::recolor_back("Blue", 170.0);
+    #[cfg(feature = "fluffy-stuff-green")]
+    tile_set_recolor::recolor_back("Green", 90.0);
 
     println!("cargo:rerun-if-changed=build.rs");
 }
 
-#[cfg(feature = "fluffy-stuff-tile-sets")]
+#[cfg(feature = "regenerate-tile-sets")]
+// Renders the enabled FluffyStuff tile sets from the SVG files, instead of
+// using the pre-rasterized PNG files from the `tilesets/FluffyStuff`
+// directory.
+//
+// The rendered images are written to `$OUT_DIR/tilesets/FluffyStuff`. To
+// update the pre-rasterized images after modifying the SVG files, copy the
+// rendered images to the `tilesets/FluffyStuff` directory.
 mod tile_set_render {
     use std::path::Path;
     use std::{env, fs};
@@ -20,7 +38,11 @@ mod tile_set_render {
     use rayon::prelude::{IntoParallelIterator, ParallelIterator};
     use usvg::fontdb;
 
-    const TILE_SETS: [&str; 3] = ["Yellow", "Red", "Black"];
+    const TILE_SETS: [(&str, bool); 3] = [
+        ("Yellow", cfg!(feature = "fluffy-stuff-yellow")),
+        ("Red", cfg!(feature = "fluffy-stuff-red")),
+        ("Black", cfg!(feature = "fluffy-stuff-black")),
+    ];
     const TILE_NAMES: [&str; 37] = [
         "Man1",
         "Man2",
@@ -67,7 +89,11 @@ mod tile_set_render {
     pub fn render_tile_sets() {
         let out_dir = env::var_os("OUT_DIR").unwrap();
 
-        for tile_set in TILE_SETS {
+        for (tile_set, enabled) in TILE_SETS {
+            if !enabled {
+                continue;
+            }
+
             let tile_set_path = Path::new(&out_dir)
                 .join("tilesets")
                 .join("FluffyStuff")
@@ -122,3 +148,75 @@ mod tile_set_render {
         .expect("could not construct an image")
     }
 }
+
+#[cfg(any(feature = "fluffy-stuff-blue", feature = "fluffy-stuff-green"))]
+// Creates the backs of the additional FluffyStuff tile set colors by shifting
+// the hue of the Yellow tile back. The rest of the tile images is shared with
+// the Yellow tile set.
+//
+// The recolored backs are written to `$OUT_DIR/tilesets/FluffyStuff`.
+mod tile_set_recolor {
+    use std::path::Path;
+    use std::{env, fs};
+
+    use image::{Rgba, RgbaImage};
+
+    pub fn recolor_back(tile_set: &str, hue_shift: f32) {
+        let out_dir = env::var_os("OUT_DIR").unwrap();
+        let source_path = if cfg!(feature = "regenerate-tile-sets") {
+            Path::new(&out_dir).join("tilesets/FluffyStuff/Yellow/Back.png")
+        } else {
+            Path::new("tilesets/FluffyStuff/Yellow/Back.png").to_owned()
+        };
+        println!("cargo:rerun-if-changed={}", source_path.display());
+
+        let mut back: RgbaImage = image::open(&source_path)
+            .expect("could not load the tile back")
+            .to_rgba8();
+        back.pixels_mut()
+            .for_each(|pixel| shift_hue(pixel, hue_shift));
+
+        let tile_set_path = Path::new(&out_dir)
+            .join("tilesets")
+            .join("FluffyStuff")
+            .join(tile_set);
+        fs::create_dir_all(&tile_set_path).expect("could not create tile set output directory");
+        back.save(tile_set_path.join("Back.png"))
+            .expect("could not save file");
+    }
+
+    /// Rotates the hue of given pixel by given number of degrees, keeping its
+    /// saturation, value and alpha.
+    fn shift_hue(pixel: &mut Rgba<u8>, hue_shift: f32) {
+        let [r, g, b] = [pixel[0], pixel[1], pixel[2]].map(|channel| f32::from(channel) / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let chroma = max - min;
+        if chroma == 0.0 {
+            return;
+        }
+
+        let hue = if max == r {
+            (g - b) / chroma
+        } else if max == g {
+            (b - r) / chroma + 2.0
+        } else {
+            (r - g) / chroma + 4.0
+        };
+        let hue = (hue * 60.0 + hue_shift).rem_euclid(360.0) / 60.0;
+
+        let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+        let (r, g, b) = match hue as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = max - chroma;
+        for (channel, value) in pixel.0[..3].iter_mut().zip([r, g, b]) {
+            *channel = ((value + m) * 255.0).round() as u8;
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::tiles::{ALL_TILES, BLANK, UNKNOWN};
+use crate::TilePlacement::{FaceDown, Normal, Rotated, RotatedAndShifted};
+use crate::{Hand, HandTile, Tile, TilePlacement};
+
+/// Generates valid tiles only, including the blank and unknown tiles.
+impl<'a> Arbitrary<'a> for Tile {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let index = u.choose_index(ALL_TILES.len() + 2)?;
+
+        Ok(match index.checked_sub(ALL_TILES.len()) {
+            None => ALL_TILES[index],
+            Some(0) => BLANK,
+            Some(_) => UNKNOWN,
+        })
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u32::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for TilePlacement {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(*u.choose(&[Normal, Rotated, RotatedAndShifted, FaceDown])?)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u32::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for HandTile {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(HandTile::new(u.arbitrary()?, u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(Tile::size_hint(depth), TilePlacement::size_hint(depth))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Hand {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Hand::new(u.arbitrary()?))
+    }
+}
+
+#[cfg(feature = "raster-renderer")]
+mod raster_renderer {
+    use arbitrary::{Arbitrary, Result, Unstructured};
+    use image::imageops::FilterType;
+    use image::Rgba;
+
+    use crate::meld::AnkanStyle;
+    use crate::raster_renderer::{Gap, RenderOptions, Rotation, Scale, Shadow};
+    use crate::renderer::{VerticalAlign, WinningTile};
+
+    /// Largest gap or padding generated, in pixels.
+    const MAX_GAP: u32 = 32;
+    /// Largest width of a row of tiles generated, in pixels.
+    const MAX_WIDTH: u32 = 2048;
+
+    fn ratio(u: &mut Unstructured<'_>, max_percent: u32) -> Result<f32> {
+        Ok(u.int_in_range(0..=max_percent)? as f32 / 100.0)
+    }
+
+    fn gap(u: &mut Unstructured<'_>) -> Result<Gap> {
+        Ok(if u.arbitrary()? {
+            Gap::Pixels(u.int_in_range(0..=MAX_GAP)?)
+        } else {
+            Gap::Ratio(ratio(u, 100)?)
+        })
+    }
+
+    /// Generates render options with the dimensions small enough to render
+    /// the hands quickly (e.g. when fuzzing), and without a texture.
+    impl<'a> Arbitrary<'a> for RenderOptions {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            let mut options = RenderOptions::new(gap(u)?, gap(u)?);
+            options.background = Rgba(u.arbitrary()?);
+            options.padding = gap(u)?;
+            options.scale = if u.arbitrary()? {
+                Scale::Factor(ratio(u, 300)?.max(0.1))
+            } else {
+                Scale::TileHeight(u.int_in_range(1..=256)?)
+            };
+            options.filter = *u.choose(&[
+                FilterType::Nearest,
+                FilterType::Triangle,
+                FilterType::CatmullRom,
+                FilterType::Gaussian,
+                FilterType::Lanczos3,
+            ])?;
+            options.vertical_align = *u.choose(&[
+                VerticalAlign::Top,
+                VerticalAlign::Center,
+                VerticalAlign::Bottom,
+            ])?;
+            options.winning_tile = match u.int_in_range(0..=2)? {
+                0 => None,
+                1 => Some(WinningTile::Last),
+                _ => Some(WinningTile::Index(u.int_in_range(0..=20)?)),
+            };
+            options.shadow = if u.arbitrary()? {
+                Some(Shadow::new(
+                    u.int_in_range(-8..=8)?,
+                    u.int_in_range(-8..=8)?,
+                    ratio(u, 400)?,
+                    ratio(u, 100)?,
+                ))
+            } else {
+                None
+            };
+            options.rotation = *u.choose(&[
+                Rotation::Upright,
+                Rotation::Clockwise90,
+                Rotation::Clockwise180,
+                Rotation::Clockwise270,
+            ])?;
+            options.max_width = if u.arbitrary()? {
+                Some(u.int_in_range(1..=MAX_WIDTH)?)
+            } else {
+                None
+            };
+            options.face_down_closed_kans = u.arbitrary()?;
+            options.ankan_style = *u.choose(&[
+                None,
+                Some(AnkanStyle::FaceDownEnds),
+                Some(AnkanStyle::FaceDownMiddle),
+            ])?;
+
+            Ok(options)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    use crate::tiles::{ALL_TILES, BLANK, UNKNOWN};
+    use crate::{Hand, HandTile, Tile};
+
+    /// Returns deterministic pseudo-random bytes to generate the values from.
+    fn bytes(seed: u32, len: usize) -> Vec<u8> {
+        let mut state = seed;
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                (state >> 16) as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_generate_valid_tiles() {
+        for seed in 0..64 {
+            let data = bytes(seed, 256);
+            let mut u = Unstructured::new(&data);
+            while !u.is_empty() {
+                let tile = HandTile::arbitrary(&mut u).unwrap().tile;
+                assert!(
+                    ALL_TILES.contains(&tile) || tile == BLANK || tile == UNKNOWN,
+                    "invalid tile: {:?}",
+                    tile
+                );
+                assert!(Tile::new(tile.suite, tile.value).is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn should_generate_hands() {
+        let hands = (0..16)
+            .map(|seed| {
+                let data = bytes(seed, 1024);
+                Hand::arbitrary(&mut Unstructured::new(&data)).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        assert!(hands.iter().any(|hand| hand.tiles().count() > 1));
+    }
+
+    #[cfg(feature = "raster-renderer")]
+    #[test]
+    fn should_render_arbitrary_hands() {
+        use crate::raster_renderer::{GeneratedTileSet, RasterRenderer, RenderOptions};
+
+        let tile_set = GeneratedTileSet::new(8);
+
+        for seed in 0..32 {
+            let data = bytes(seed, 512);
+            let mut u = Unstructured::new(&data);
+            let options = RenderOptions::arbitrary(&mut u).unwrap();
+            let hand = Hand::arbitrary(&mut u).unwrap();
+
+            RasterRenderer::render(&hand, &tile_set, options).unwrap();
+        }
+    }
+}
@@ -0,0 +1,477 @@
+//! Command-line interface of the `riichi_hand` crate, allowing to use it from
+//! shell scripts and CI pipelines.
+//!
+//! # Usage
+//! ```text
+//! riichi-hand render <HAND> -o <FILE> [--tile-set <NAME>] [--scale <FACTOR>]
+//! riichi-hand score --han <HAN> --fu <FU> [--honbas <HONBAS>] [--mode <MODE>]
+//! ```
+
+use std::env;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::process;
+
+use riichi_hand::parser::{HandParseError, HandParser};
+use riichi_hand::points::{Fu, Han, Honbas, PointCalculationError, Points, PointsCalculationMode};
+use riichi_hand::raster_renderer::fluffy_stuff_tile_sets::{
+    BLACK_FLUFFY_STUFF_TILE_SET, RED_FLUFFY_STUFF_TILE_SET, YELLOW_FLUFFY_STUFF_TILE_SET,
+};
+use riichi_hand::raster_renderer::martin_persson_tile_sets::MARTIN_PERSSON_TILE_SET;
+use riichi_hand::raster_renderer::{
+    HandRenderError, RasterRenderer, RenderOptions, Scale, TileSet,
+};
+
+const USAGE: &str = "\
+Usage:
+    riichi-hand render <HAND> -o <FILE> [--tile-set <NAME>] [--scale <FACTOR>]
+    riichi-hand score --han <HAN> --fu <FU> [--honbas <HONBAS>] [--mode <MODE>]
+
+Render options:
+    -o, --output <FILE>    Path of the rendered image; the format is chosen
+                           by the file extension
+    --tile-set <NAME>      Tile set to use: yellow (default), red, black or
+                           martin-persson
+    --scale <FACTOR>       Scale of the image (e.g. 0.5); defaults to the
+                           scale recommended by the tile set
+
+Score options:
+    --han <HAN>            Number of han of the hand
+    --fu <FU>              Number of fu of the hand
+    --honbas <HONBAS>      Number of honbas (repeat counters); defaults to 0
+    --mode <MODE>          Calculation mode: default, loose (allows any fu
+                           value) or unlimited (no mangan and above limits)";
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum TileSetName {
+    Yellow,
+    Red,
+    Black,
+    MartinPersson,
+}
+
+impl TileSetName {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "yellow" => Some(Self::Yellow),
+            "red" => Some(Self::Red),
+            "black" => Some(Self::Black),
+            "martin-persson" => Some(Self::MartinPersson),
+            _ => None,
+        }
+    }
+
+    fn tile_set(self) -> &'static dyn TileSet {
+        match self {
+            Self::Yellow => &*YELLOW_FLUFFY_STUFF_TILE_SET,
+            Self::Red => &*RED_FLUFFY_STUFF_TILE_SET,
+            Self::Black => &*BLACK_FLUFFY_STUFF_TILE_SET,
+            Self::MartinPersson => &*MARTIN_PERSSON_TILE_SET,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Command {
+    Render {
+        hand: String,
+        output: String,
+        tile_set: TileSetName,
+        scale: Option<f32>,
+    },
+    Score {
+        han: Han,
+        fu: Fu,
+        honbas: Honbas,
+        mode: PointsCalculationMode,
+    },
+    Help,
+}
+
+#[derive(Debug)]
+enum CliError {
+    Usage(String),
+    Parse(HandParseError),
+    Render(HandRenderError),
+    Save(riichi_hand::image::ImageError),
+    Points(PointCalculationError),
+}
+
+impl Error for CliError {}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Usage(message) => write!(f, "{}", message),
+            Self::Parse(e) => write!(f, "could not parse the hand: {}", e),
+            Self::Render(e) => write!(f, "could not render the hand: {}", e),
+            Self::Save(e) => write!(f, "could not save the image: {}", e),
+            Self::Points(e) => write!(f, "could not calculate the points: {}", e),
+        }
+    }
+}
+
+impl From<HandParseError> for CliError {
+    fn from(e: HandParseError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+impl From<HandRenderError> for CliError {
+    fn from(e: HandRenderError) -> Self {
+        Self::Render(e)
+    }
+}
+
+impl From<riichi_hand::image::ImageError> for CliError {
+    fn from(e: riichi_hand::image::ImageError) -> Self {
+        Self::Save(e)
+    }
+}
+
+impl From<PointCalculationError> for CliError {
+    fn from(e: PointCalculationError) -> Self {
+        Self::Points(e)
+    }
+}
+
+fn usage_error<T>(message: &str) -> Result<T, CliError> {
+    Err(CliError::Usage(message.to_owned()))
+}
+
+/// Parses the command-line arguments, excluding the program name.
+fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Command, CliError> {
+    let mut args = args.into_iter();
+    match args.next().as_deref() {
+        Some("render") => parse_render_args(args),
+        Some("score") => parse_score_args(args),
+        Some("help" | "-h" | "--help") => Ok(Command::Help),
+        Some(command) => usage_error(&format!("unknown command: {}", command)),
+        None => usage_error("no command given"),
+    }
+}
+
+fn parse_render_args<I: Iterator<Item = String>>(mut args: I) -> Result<Command, CliError> {
+    let mut hand = None;
+    let mut output = None;
+    let mut tile_set = TileSetName::Yellow;
+    let mut scale = None;
+
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .ok_or_else(|| CliError::Usage(format!("missing value of {}", name)))
+        };
+        match arg.as_str() {
+            "-o" | "--output" => output = Some(value(&arg)?),
+            "--tile-set" => {
+                let name = value(&arg)?;
+                tile_set = TileSetName::parse(&name)
+                    .ok_or_else(|| CliError::Usage(format!("unknown tile set: {}", name)))?;
+            }
+            "--scale" => {
+                let factor = value(&arg)?;
+                match factor.parse::<f32>() {
+                    Ok(factor) if factor.is_finite() && factor > 0.0 => scale = Some(factor),
+                    _ => return usage_error(&format!("invalid scale: {}", factor)),
+                }
+            }
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                return usage_error(&format!("unknown option: {}", arg));
+            }
+            _ if hand.is_none() => hand = Some(arg),
+            _ => return usage_error(&format!("unexpected argument: {}", arg)),
+        }
+    }
+
+    match (hand, output) {
+        (Some(hand), Some(output)) => Ok(Command::Render {
+            hand,
+            output,
+            tile_set,
+            scale,
+        }),
+        (None, _) => usage_error("no hand given"),
+        (_, None) => usage_error("no output file given"),
+    }
+}
+
+/// Parses given option value as an integer.
+fn parse_number(name: &str, value: &str) -> Result<i32, CliError> {
+    value
+        .parse()
+        .map_err(|_| CliError::Usage(format!("invalid value of {}: {}", name, value)))
+}
+
+fn parse_score_args<I: Iterator<Item = String>>(mut args: I) -> Result<Command, CliError> {
+    let mut han = None;
+    let mut fu = None;
+    let mut honbas = Honbas::ZERO;
+    let mut mode = PointsCalculationMode::Default;
+
+    while let Some(arg) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| CliError::Usage(format!("missing value of {}", arg)));
+        match arg.as_str() {
+            "--han" => han = Some(Han::new(parse_number(&arg, &value?)?)),
+            "--fu" => fu = Some(Fu::new(parse_number(&arg, &value?)?)),
+            "--honbas" => honbas = Honbas::new(parse_number(&arg, &value?)?),
+            "--mode" => {
+                mode = match value?.as_str() {
+                    "default" => PointsCalculationMode::Default,
+                    "loose" => PointsCalculationMode::Loose,
+                    "unlimited" => PointsCalculationMode::Unlimited,
+                    other => return usage_error(&format!("unknown mode: {}", other)),
+                };
+            }
+            _ => return usage_error(&format!("unexpected argument: {}", arg)),
+        }
+    }
+
+    match (han, fu) {
+        (Some(han), Some(fu)) => Ok(Command::Score {
+            han,
+            fu,
+            honbas,
+            mode,
+        }),
+        (None, _) => usage_error("no han given"),
+        (_, None) => usage_error("no fu given"),
+    }
+}
+
+/// Formats given payment, or a dash if it is not possible.
+fn payment<T: Display>(value: Option<T>) -> String {
+    value.map_or_else(|| "-".to_owned(), |value| value.to_string())
+}
+
+/// Returns the payment table of given points.
+fn payment_table(points: &Points) -> String {
+    let oya_tsumo = payment(points.oya_tsumo().map(|value| format!("{} all", value)));
+    let ko_tsumo = payment(points.ko_tsumo().map(|(ko, oya)| format!("{}/{}", ko, oya)));
+
+    format!(
+        "{:<12}{:>10}  {}\n{:<12}{:>10}  {}",
+        "Dealer:",
+        payment(points.oya_ron()),
+        oya_tsumo,
+        "Non-dealer:",
+        payment(points.ko_ron()),
+        ko_tsumo,
+    )
+}
+
+fn score(han: Han, fu: Fu, honbas: Honbas, mode: PointsCalculationMode) -> Result<(), CliError> {
+    let points = Points::checked_from_calculated(mode, han, fu, honbas)?;
+
+    println!("{} {}, {}", han, fu, honbas);
+    if let Some(limit) = points.limit() {
+        println!("{}", limit);
+    }
+    println!("{:<12}{:>10}  Tsumo", "", "Ron");
+    println!("{}", payment_table(&points));
+    Ok(())
+}
+
+fn render(
+    hand: &str,
+    output: &str,
+    tile_set: TileSetName,
+    scale: Option<f32>,
+) -> Result<(), CliError> {
+    let hand = HandParser::parse(hand)?;
+    let tile_set = tile_set.tile_set();
+    let scale = scale.map(Scale::Factor).or_else(|| {
+        tile_set
+            .metadata()
+            .and_then(|metadata| metadata.recommended_scale)
+    });
+    let mut options = RenderOptions::default();
+    if let Some(scale) = scale {
+        options.scale = scale;
+    }
+
+    let image = RasterRenderer::render(&hand, &tile_set, options)?;
+    image.save(output)?;
+    Ok(())
+}
+
+fn run() -> Result<(), CliError> {
+    match parse_args(env::args().skip(1))? {
+        Command::Render {
+            hand,
+            output,
+            tile_set,
+            scale,
+        } => render(&hand, &output, tile_set, scale),
+        Command::Score {
+            han,
+            fu,
+            honbas,
+            mode,
+        } => score(han, fu, honbas, mode),
+        Command::Help => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+    }
+}
+
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("error: {}", e);
+        if let CliError::Usage(_) = e {
+            eprintln!("\n{}", USAGE);
+            process::exit(2);
+        }
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use riichi_hand::points::{Fu, Han, Honbas, Points, PointsCalculationMode};
+
+    use crate::{parse_args, payment_table, CliError, Command, TileSetName};
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|&arg| arg.to_owned()).collect()
+    }
+
+    #[test]
+    fn should_parse_render_args() {
+        let command = parse_args(args(&[
+            "render",
+            "123m456p789sEE",
+            "-o",
+            "hand.png",
+            "--tile-set",
+            "red",
+            "--scale",
+            "0.5",
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            command,
+            Command::Render {
+                hand: "123m456p789sEE".to_owned(),
+                output: "hand.png".to_owned(),
+                tile_set: TileSetName::Red,
+                scale: Some(0.5),
+            }
+        );
+    }
+
+    #[test]
+    fn should_use_render_defaults() {
+        let command = parse_args(args(&["render", "--output", "hand.png", "123m"])).unwrap();
+
+        assert_eq!(
+            command,
+            Command::Render {
+                hand: "123m".to_owned(),
+                output: "hand.png".to_owned(),
+                tile_set: TileSetName::Yellow,
+                scale: None,
+            }
+        );
+    }
+
+    #[test]
+    fn should_return_usage_errors() {
+        let invalid_args: &[&[&str]] = &[
+            &[],
+            &["draw", "123m"],
+            &["render", "123m"],
+            &["render", "-o", "hand.png"],
+            &["render", "123m", "-o"],
+            &["render", "123m", "-o", "hand.png", "--tile-set", "purple"],
+            &["render", "123m", "-o", "hand.png", "--scale", "-1"],
+            &["render", "123m", "-o", "hand.png", "--size", "2"],
+            &["render", "123m", "456p", "-o", "hand.png"],
+        ];
+
+        for &invalid in invalid_args {
+            assert!(
+                matches!(parse_args(args(invalid)), Err(CliError::Usage(_))),
+                "{:?}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn should_parse_score_args() {
+        let command = parse_args(args(&[
+            "score", "--han", "4", "--fu", "30", "--honbas", "2", "--mode", "loose",
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            command,
+            Command::Score {
+                han: Han::new(4),
+                fu: Fu::new(30),
+                honbas: Honbas::new(2),
+                mode: PointsCalculationMode::Loose,
+            }
+        );
+        let command = parse_args(args(&["score", "--fu", "40", "--han", "1"])).unwrap();
+        assert_eq!(
+            command,
+            Command::Score {
+                han: Han::new(1),
+                fu: Fu::new(40),
+                honbas: Honbas::ZERO,
+                mode: PointsCalculationMode::Default,
+            }
+        );
+    }
+
+    #[test]
+    fn should_return_score_usage_errors() {
+        let invalid_args: &[&[&str]] = &[
+            &["score", "--han", "4"],
+            &["score", "--fu", "30"],
+            &["score", "--han", "four", "--fu", "30"],
+            &["score", "--han", "4", "--fu", "30", "--mode", "strict"],
+            &["score", "--han", "4", "--fu", "30", "--honbas"],
+            &["score", "--han", "4", "--fu", "30", "--riichi"],
+        ];
+
+        for &invalid in invalid_args {
+            assert!(
+                matches!(parse_args(args(invalid)), Err(CliError::Usage(_))),
+                "{:?}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn should_format_payment_table() {
+        let points = Points::from_calculated(
+            PointsCalculationMode::Default,
+            Han::new(4),
+            Fu::new(30),
+            Honbas::new(2),
+        )
+        .unwrap();
+
+        assert_eq!(
+            payment_table(&points),
+            "Dealer:          12200  4100 all\nNon-dealer:       8300  2200/4100"
+        );
+
+        let points = Points::from_calculated(
+            PointsCalculationMode::Default,
+            Han::new(1),
+            Fu::new(20),
+            Honbas::ZERO,
+        )
+        .unwrap();
+        assert!(payment_table(&points).starts_with("Dealer:              -"));
+    }
+}
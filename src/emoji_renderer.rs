@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::renderer::{HandRenderer, Layout, LayoutOptions};
+use crate::tiles::{ALL_TILES, BLANK, UNKNOWN};
+use crate::TilePlacement::{FaceDown, Normal, Rotated, RotatedAndShifted};
+use crate::{HandTile, Tile};
+
+#[derive(Clone, Debug)]
+/// Renders a [Hand](crate::Hand) instance to a chat message made of custom
+/// emojis (e.g. `<:m1:123456789>` on Discord or `:m1:` on Slack), which is a
+/// lightweight alternative to uploading an image for every hand.
+///
+/// Each tile is mapped to its emoji markup. Rotated tiles (including the
+/// tiles added to a kan) use the rotated emojis if provided, and the normal
+/// ones otherwise. Face-down tiles use the emoji of
+/// [ANY](crate::tiles::ANY) (the tile back).
+///
+/// By default, the tiles are not separated, and the groups are separated by
+/// a single space.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::emoji_renderer::EmojiRenderer;
+/// use riichi_hand::parser::HandParser;
+/// use riichi_hand::renderer::HandRenderer;
+/// use riichi_hand::Suite;
+///
+/// let renderer = EmojiRenderer::from_fn(|tile| match tile.suite {
+///     Suite::Manzu => Some(format!(":m{}:", tile.value)),
+///     Suite::Honor => Some(format!(":z{}:", tile.value)),
+///     _ => None,
+/// });
+/// let hand = HandParser::parse("123m_E*EE").unwrap();
+///
+/// assert_eq!(
+///     renderer.render_hand(&hand).unwrap(),
+///     ":m1::m2::m3: :z1::z1::z1:"
+/// );
+/// ```
+pub struct EmojiRenderer {
+    emojis: HashMap<Tile, String>,
+    rotated_emojis: HashMap<Tile, String>,
+    tile_separator: String,
+    group_separator: String,
+}
+
+impl EmojiRenderer {
+    #[inline]
+    /// Creates a new renderer instance that uses given emoji markup for the
+    /// tiles. The instance implements [HandRenderer].
+    #[must_use]
+    pub fn new(emojis: HashMap<Tile, String>) -> Self {
+        Self {
+            emojis,
+            rotated_emojis: HashMap::new(),
+            tile_separator: String::new(),
+            group_separator: String::from(" "),
+        }
+    }
+
+    /// Creates a new renderer instance with the emoji markup returned by given
+    /// function for each tile (including [BLANK] and [UNKNOWN]), or no emoji
+    /// if it returns `None`.
+    #[must_use]
+    pub fn from_fn<F: Fn(Tile) -> Option<String>>(emoji: F) -> Self {
+        let emojis = ALL_TILES
+            .iter()
+            .chain(&[BLANK, UNKNOWN])
+            .filter_map(|&tile| emoji(tile).map(|markup| (tile, markup)))
+            .collect();
+
+        Self::new(emojis)
+    }
+
+    #[inline]
+    /// Sets the emoji markup used for the rotated tiles.
+    #[must_use]
+    pub fn with_rotated_emojis(mut self, rotated_emojis: HashMap<Tile, String>) -> Self {
+        self.rotated_emojis = rotated_emojis;
+        self
+    }
+
+    #[inline]
+    /// Sets the text inserted between the tiles of a group, and between the
+    /// groups.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::emoji_renderer::EmojiRenderer;
+    /// use riichi_hand::parser::HandParser;
+    /// use riichi_hand::renderer::HandRenderer;
+    ///
+    /// let renderer = EmojiRenderer::from_fn(|tile| Some(format!(":{}:", tile.value)))
+    ///     .with_separators(" ", " | ");
+    /// let hand = HandParser::parse("12m_3p").unwrap();
+    ///
+    /// assert_eq!(renderer.render_hand(&hand).unwrap(), ":1: :2: | :3:");
+    /// ```
+    #[must_use]
+    pub fn with_separators(mut self, tile_separator: &str, group_separator: &str) -> Self {
+        self.tile_separator = tile_separator.to_owned();
+        self.group_separator = group_separator.to_owned();
+        self
+    }
+
+    fn emoji(&self, hand_tile: &HandTile) -> Result<&str, MissingEmojiError> {
+        let displayed = hand_tile.displayed();
+        let emoji = match displayed.placement {
+            Rotated | RotatedAndShifted => self
+                .rotated_emojis
+                .get(&displayed.tile)
+                .or_else(|| self.emojis.get(&displayed.tile)),
+            Normal | FaceDown => self.emojis.get(&displayed.tile),
+        };
+
+        emoji
+            .map(String::as_str)
+            .ok_or(MissingEmojiError { tile: *hand_tile })
+    }
+}
+
+impl HandRenderer for EmojiRenderer {
+    type Output = String;
+    type Error = MissingEmojiError;
+
+    fn layout_options(&self) -> LayoutOptions {
+        LayoutOptions::new(1, 1, 0, 0)
+    }
+
+    fn render_layout(&self, layout: &Layout) -> Result<Self::Output, Self::Error> {
+        let mut text = String::new();
+        let mut last_group = None;
+        for position in &layout.tiles {
+            match last_group {
+                Some(group) if group != position.group => text.push_str(&self.group_separator),
+                Some(_) => text.push_str(&self.tile_separator),
+                None => {}
+            }
+            last_group = Some(position.group);
+
+            text.push_str(self.emoji(&position.tile)?);
+        }
+
+        Ok(text)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+/// Error that denotes that [EmojiRenderer] has no emoji for a tile.
+pub struct MissingEmojiError {
+    /// The tile without an emoji.
+    pub tile: HandTile,
+}
+
+impl Error for MissingEmojiError {}
+
+impl Display for MissingEmojiError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "no emoji for tile: {}", self.tile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::emoji_renderer::{EmojiRenderer, MissingEmojiError};
+    use crate::parser::HandParser;
+    use crate::renderer::HandRenderer;
+    use crate::tiles::*;
+    use crate::{HandTile, Tile, TilePlacement};
+
+    fn discord_emoji(tile: Tile) -> Option<String> {
+        Some(format!(
+            "<:{}{}:{}>",
+            tile.suite,
+            tile.value,
+            1000 + u32::from(tile.value.0)
+        ))
+    }
+
+    #[test]
+    fn should_render_custom_emojis() {
+        let renderer = EmojiRenderer::from_fn(discord_emoji);
+        let hand = HandParser::parse("10p_N*NN").unwrap();
+
+        assert_eq!(
+            renderer.render_hand(&hand).unwrap(),
+            format!(
+                "{}{} {}{}{}",
+                discord_emoji(II_PIN).unwrap(),
+                discord_emoji(AKADORA_PIN).unwrap(),
+                discord_emoji(PEI).unwrap(),
+                discord_emoji(PEI).unwrap(),
+                discord_emoji(PEI).unwrap(),
+            )
+        );
+    }
+
+    #[test]
+    fn should_render_rotated_and_face_down_tiles() {
+        let emojis = vec![
+            (CHUN, String::from(":chun:")),
+            (ANY, String::from(":back:")),
+        ]
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+        let rotated_emojis = vec![(CHUN, String::from(":chun_side:"))]
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+        let renderer = EmojiRenderer::new(emojis)
+            .with_rotated_emojis(rotated_emojis)
+            .with_separators("", "  ");
+        let hand = HandParser::parse("r*r**rr_rrrr")
+            .unwrap()
+            .with_face_down_closed_kans();
+
+        assert_eq!(
+            renderer.render_hand(&hand).unwrap(),
+            ":chun_side::chun_side::chun::chun:  :back::chun::chun::back:"
+        );
+    }
+
+    #[test]
+    fn should_fail_on_missing_emoji() {
+        let renderer = EmojiRenderer::new(HashMap::new());
+        let hand = HandParser::parse("1s").unwrap();
+
+        let error = renderer.render_hand(&hand).unwrap_err();
+
+        assert_eq!(
+            error,
+            MissingEmojiError {
+                tile: HandTile::new(II_SOU, TilePlacement::Normal)
+            }
+        );
+        assert_eq!(error.to_string(), "no emoji for tile: normal Ii sou");
+    }
+}
@@ -0,0 +1,206 @@
+use core::fmt::{Display, Formatter};
+
+use crate::parser::HandParseError;
+use crate::{HandValidationError, InvalidTileError};
+
+/// Defines the [Error] enum along with its [Display], [std::error::Error] and
+/// [From] implementations, given the variants, their wrapped error types and
+/// the context prepended to the messages of the wrapped errors.
+macro_rules! define_error {
+    ($(
+        $(#[cfg($cfg:meta)])?
+        #[doc = $doc:literal]
+        $variant:ident($error:ty) => $context:literal,
+    )*) => {
+        #[derive(Debug)]
+        #[non_exhaustive]
+        /// An error that can occur anywhere in this crate, from parsing a hand
+        /// to rendering it or calculating its points. Every other error type
+        /// of this crate can be converted into it with the `?` operator.
+        ///
+        /// The variants available depend on the features enabled.
+        ///
+        /// # Examples
+        /// ```
+        /// use riichi_hand::parser::HandParser;
+        /// use riichi_hand::{Error, Hand, ValidationOptions};
+        ///
+        /// fn parse_valid(hand: &str) -> Result<Hand, Error> {
+        ///     let hand = HandParser::parse(hand)?;
+        ///     Ok(Hand::try_new(hand.groups().clone(), ValidationOptions::default())?)
+        /// }
+        ///
+        /// assert!(parse_valid("123m").is_ok());
+        /// assert_eq!(
+        ///     parse_valid("123x").unwrap_err().to_string(),
+        ///     "could not parse hand: error when parsing hand at position 3: invalid character"
+        /// );
+        /// assert_eq!(
+        ///     parse_valid("11111m").unwrap_err().to_string(),
+        ///     "invalid hand: too many copies of tile: Ii man"
+        /// );
+        /// ```
+        pub enum Error {
+            $(
+                $(#[cfg($cfg)])?
+                #[doc = $doc]
+                $variant($error),
+            )*
+        }
+
+        impl Display for Error {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                match self {
+                    $(
+                        $(#[cfg($cfg)])?
+                        Self::$variant(error) => write!(f, concat!($context, ": {}"), error),
+                    )*
+                }
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl std::error::Error for Error {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                match self {
+                    $(
+                        $(#[cfg($cfg)])?
+                        Self::$variant(error) => Some(error),
+                    )*
+                }
+            }
+        }
+
+        $(
+            $(#[cfg($cfg)])?
+            impl From<$error> for Error {
+                fn from(error: $error) -> Self {
+                    Self::$variant(error)
+                }
+            }
+        )*
+    };
+}
+
+define_error! {
+    /// Error occurred when creating a tile.
+    InvalidTile(InvalidTileError) => "invalid tile",
+    /// Error occurred when parsing a hand.
+    HandParse(HandParseError) => "could not parse hand",
+    /// Error occurred when validating a hand.
+    HandValidation(HandValidationError) => "invalid hand",
+    #[cfg(feature = "points")]
+    /// Error occurred when calculating points.
+    PointCalculation(crate::points::PointCalculationError) => "could not calculate points",
+    #[cfg(feature = "points")]
+    /// Error occurred when applying an action to a game.
+    Game(crate::game::GameError) => "invalid game action",
+    #[cfg(feature = "std")]
+    /// Error occurred when rendering a hand as emojis.
+    MissingEmoji(crate::emoji_renderer::MissingEmojiError) => "could not render hand as emojis",
+    #[cfg(feature = "raster-renderer")]
+    /// Error occurred when rendering a hand with the raster renderer.
+    HandRender(crate::raster_renderer::HandRenderError) => "could not render hand",
+    #[cfg(feature = "raster-renderer")]
+    /// Error occurred when retrieving a tile image from a tile set.
+    TileImageRetrieve(crate::raster_renderer::TileImageRetrieveError) => "could not retrieve tile image",
+    #[cfg(feature = "raster-renderer")]
+    /// Error occurred when creating a raster tile set.
+    TileSetCreation(crate::raster_renderer::TileSetCreationError) => "could not create tile set",
+    #[cfg(feature = "raster-renderer")]
+    /// Error occurred when creating an atlas tile set.
+    AtlasTileSetCreation(crate::raster_renderer::AtlasTileSetCreationError) => "could not create atlas tile set",
+    #[cfg(feature = "raster-renderer")]
+    /// Error occurred when building the render options.
+    RenderOptions(crate::raster_renderer::RenderOptionsError) => "invalid render options",
+    #[cfg(feature = "tile-set-loader")]
+    /// Error occurred when loading a tile set at runtime.
+    TileSetLoad(crate::raster_renderer::TileSetLoadError) => "could not load tile set",
+    #[cfg(feature = "vector-tile-sets")]
+    /// Error occurred when creating a vector tile set.
+    VectorTileSetCreation(crate::raster_renderer::VectorTileSetCreationError) => "could not create vector tile set",
+    #[cfg(feature = "extra-formats")]
+    /// Error occurred when encoding a rendered image.
+    Encode(crate::raster_renderer::EncodeError) => "could not encode image",
+    #[cfg(feature = "extra-formats")]
+    /// Error occurred when rendering a hand to encoded image bytes.
+    RenderToBytes(crate::raster_renderer::RenderToBytesError) => "could not render hand to bytes",
+    #[cfg(feature = "animation")]
+    /// Error occurred when rendering an animation of a hand.
+    AnimationRender(crate::raster_renderer::AnimationRenderError) => "could not render animation",
+    #[cfg(feature = "svg-renderer")]
+    /// Error occurred when creating an SVG tile set.
+    SvgTileSetCreation(crate::svg_renderer::SvgTileSetCreationError) => "could not create SVG tile set",
+    #[cfg(feature = "html-renderer")]
+    /// Error occurred when rendering a hand as HTML.
+    HtmlRender(crate::html_renderer::HtmlRenderError) => "could not render hand as HTML",
+    #[cfg(feature = "tenhou")]
+    /// Error occurred when parsing a Tenhou game log.
+    TenhouParse(crate::tenhou::TenhouParseError) => "could not parse Tenhou log",
+    #[cfg(feature = "majsoul")]
+    /// Error occurred when converting a Mahjong Soul game record.
+    MajsoulConversion(crate::majsoul::MajsoulConversionError) => "could not convert Mahjong Soul record",
+    #[cfg(feature = "riichi-tools")]
+    /// Error occurred when converting from or to `riichi-tools-rs` types.
+    RiichiToolsConversion(crate::riichi_tools::RiichiToolsConversionError) => "could not convert riichi-tools-rs types",
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::HandParser;
+    use crate::Error;
+
+    #[test]
+    fn should_convert_errors() {
+        let error: Error = HandParser::parse("9z").unwrap_err().into();
+
+        assert!(matches!(error, Error::HandParse(_)));
+        assert_eq!(
+            error.to_string(),
+            "could not parse hand: error when parsing hand at position 1: invalid tile value"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn should_return_error_sources() {
+        use std::error::Error as _;
+
+        use crate::tiles::CHUN;
+        use crate::{HandTile, TilePlacement};
+
+        let error = Error::from(crate::emoji_renderer::MissingEmojiError {
+            tile: HandTile::new(CHUN, TilePlacement::Normal),
+        });
+
+        assert_eq!(
+            error.source().unwrap().to_string(),
+            "no emoji for tile: normal Chun"
+        );
+    }
+
+    #[cfg(feature = "raster-renderer")]
+    #[test]
+    fn should_propagate_errors_across_pipeline() {
+        use std::collections::HashMap;
+
+        use crate::raster_renderer::{RasterRenderer, RenderOptions, SimpleTileSet};
+
+        fn render(hand: &str) -> Result<(), Error> {
+            let tile_set = SimpleTileSet::new(HashMap::new())?;
+            RasterRenderer::render(
+                &HandParser::parse(hand)?,
+                &tile_set,
+                RenderOptions::default(),
+            )?;
+            Ok(())
+        }
+
+        let error = render("123m").unwrap_err();
+
+        assert!(matches!(error, Error::TileSetCreation(_)));
+        assert!(error
+            .to_string()
+            .starts_with("could not create tile set: tile foreground missing:"));
+    }
+}
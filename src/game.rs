@@ -0,0 +1,691 @@
+use core::fmt::{Display, Formatter};
+
+use crate::points::{Honbas, Points, WinMethod, Winner, RIICHI_STICK_VALUE};
+use crate::tiles::{NAN, PEI, SHAA, TON};
+use crate::Tile;
+
+/// A wind, used both as the round wind and to identify the players.
+///
+/// Players are identified by their seat wind in the first hand of the game,
+/// i.e. [`Wind::East`] is the player who deals first.
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Wind {
+    /// East wind (ton).
+    East,
+    /// South wind (nan).
+    South,
+    /// West wind (shaa).
+    West,
+    /// North wind (pei).
+    North,
+}
+
+impl Wind {
+    /// All the winds, in the playing order.
+    pub const ALL: [Wind; 4] = [Wind::East, Wind::South, Wind::West, Wind::North];
+
+    /// Returns the wind that follows this one in the playing order.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::game::Wind;
+    ///
+    /// assert_eq!(Wind::East.next(), Wind::South);
+    /// assert_eq!(Wind::North.next(), Wind::East);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn next(self) -> Self {
+        Self::ALL[(self.index() + 1) % 4]
+    }
+
+    /// Returns the wind tile corresponding to this wind.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::game::Wind;
+    /// use riichi_hand::tiles::NAN;
+    ///
+    /// assert_eq!(Wind::South.tile(), NAN);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn tile(self) -> Tile {
+        match self {
+            Wind::East => TON,
+            Wind::South => NAN,
+            Wind::West => SHAA,
+            Wind::North => PEI,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl Display for Wind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let name = match self {
+            Wind::East => "East",
+            Wind::South => "South",
+            Wind::West => "West",
+            Wind::North => "North",
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// Length of the game.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum GameLength {
+    /// East round only.
+    Tonpuusen,
+    /// East and South rounds.
+    Hanchan,
+}
+
+impl GameLength {
+    #[inline]
+    #[must_use]
+    const fn last_round(self) -> Wind {
+        match self {
+            GameLength::Tonpuusen => Wind::East,
+            GameLength::Hanchan => Wind::South,
+        }
+    }
+}
+
+/// Set of rules used by [`Game`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct GameRules {
+    /// Number of points each player starts with.
+    pub starting_points: i32,
+    /// Length of the game.
+    pub length: GameLength,
+    /// Whether the game ends when any player's score drops below zero (tobi).
+    pub bust: bool,
+    /// Total number of points paid by the players who are not tenpai to the
+    /// players who are tenpai on an exhaustive draw.
+    pub noten_penalty: i32,
+}
+
+impl Default for GameRules {
+    fn default() -> Self {
+        Self {
+            starting_points: 25000,
+            length: GameLength::Hanchan,
+            bust: true,
+            noten_penalty: 3000,
+        }
+    }
+}
+
+/// Score tracker for a single four-player game.
+///
+/// The tracker keeps the scores of all the players, the riichi stick pool, the
+/// honba counter, and the current round and dealer. Hand outcomes are applied
+/// using [`Game::apply_ron`], [`Game::apply_tsumo`],
+/// [`Game::apply_exhaustive_draw`] and [`Game::apply_abortive_draw`].
+///
+/// The honbas of the [`Points`] passed to the tracker are ignored; the honba
+/// counter of the game is used instead. Riichi sticks left on the table when
+/// the game ends are not awarded to anyone.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::game::{Game, GameRules, Wind};
+/// use riichi_hand::points::{Honbas, Points};
+///
+/// let mut game = Game::new(GameRules::default());
+/// game.declare_riichi(Wind::South).unwrap();
+/// game.apply_ron(Wind::South, Wind::East, &Points::mangan(Honbas::ZERO)).unwrap();
+///
+/// assert_eq!(game.scores(), [17000, 33000, 25000, 25000]);
+/// assert_eq!(game.dealer(), Wind::South);
+/// assert_eq!(game.riichi_sticks(), 0);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Game {
+    rules: GameRules,
+    scores: [i32; 4],
+    round_wind: Wind,
+    dealer: Wind,
+    honbas: Honbas,
+    riichi_sticks: i32,
+    riichi_declared: [bool; 4],
+    finished: bool,
+}
+
+impl Game {
+    /// Creates a new game, with all the players having the starting number of
+    /// points.
+    #[must_use]
+    pub fn new(rules: GameRules) -> Self {
+        Self {
+            rules,
+            scores: [rules.starting_points; 4],
+            round_wind: Wind::East,
+            dealer: Wind::East,
+            honbas: Honbas::ZERO,
+            riichi_sticks: 0,
+            riichi_declared: [false; 4],
+            finished: false,
+        }
+    }
+
+    /// Returns the rules the game is played with.
+    #[inline]
+    #[must_use]
+    pub fn rules(&self) -> &GameRules {
+        &self.rules
+    }
+
+    /// Returns the scores of all the players, ordered by their starting seat.
+    #[inline]
+    #[must_use]
+    pub fn scores(&self) -> [i32; 4] {
+        self.scores
+    }
+
+    /// Returns the score of given player.
+    #[inline]
+    #[must_use]
+    pub fn score(&self, player: Wind) -> i32 {
+        self.scores[player.index()]
+    }
+
+    /// Returns the current round wind.
+    #[inline]
+    #[must_use]
+    pub fn round_wind(&self) -> Wind {
+        self.round_wind
+    }
+
+    /// Returns the player who is the current dealer.
+    #[inline]
+    #[must_use]
+    pub fn dealer(&self) -> Wind {
+        self.dealer
+    }
+
+    /// Returns the number of the current hand within the round, from 1 to 4
+    /// (e.g. 3 for "East 3").
+    #[inline]
+    #[must_use]
+    pub fn hand_number(&self) -> u8 {
+        self.dealer.index() as u8 + 1
+    }
+
+    /// Returns the seat wind of given player in the current hand.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::game::{Game, GameRules, Wind};
+    /// use riichi_hand::points::{Honbas, Points};
+    ///
+    /// let mut game = Game::new(GameRules::default());
+    /// game.apply_tsumo(Wind::West, &Points::mangan(Honbas::ZERO)).unwrap();
+    ///
+    /// assert_eq!(game.seat_wind(Wind::South), Wind::East);
+    /// assert_eq!(game.seat_wind(Wind::East), Wind::North);
+    /// ```
+    #[must_use]
+    pub fn seat_wind(&self, player: Wind) -> Wind {
+        Wind::ALL[(player.index() + 4 - self.dealer.index()) % 4]
+    }
+
+    /// Returns the current honba counter.
+    #[inline]
+    #[must_use]
+    pub fn honbas(&self) -> Honbas {
+        self.honbas
+    }
+
+    /// Returns the number of riichi sticks currently on the table.
+    #[inline]
+    #[must_use]
+    pub fn riichi_sticks(&self) -> i32 {
+        self.riichi_sticks
+    }
+
+    /// Returns true if the game has ended.
+    #[inline]
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Returns the players ordered by their score, from the highest. Ties are
+    /// broken by the starting seat.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::game::{Game, GameRules, Wind};
+    /// use riichi_hand::points::{Honbas, Points};
+    ///
+    /// let mut game = Game::new(GameRules::default());
+    /// game.apply_ron(Wind::North, Wind::South, &Points::mangan(Honbas::ZERO)).unwrap();
+    ///
+    /// assert_eq!(game.standings(), [Wind::North, Wind::East, Wind::West, Wind::South]);
+    /// ```
+    #[must_use]
+    pub fn standings(&self) -> [Wind; 4] {
+        let mut players = Wind::ALL;
+        players.sort_by_key(|&player| (-self.score(player), player));
+        players
+    }
+
+    /// Declares riichi for given player, moving a 1000 point stick from their
+    /// score to the table.
+    pub fn declare_riichi(&mut self, player: Wind) -> Result<(), GameError> {
+        self.check_not_finished()?;
+        if self.riichi_declared[player.index()] {
+            return Err(GameError::RiichiAlreadyDeclared(player));
+        }
+        if self.score(player) < RIICHI_STICK_VALUE {
+            return Err(GameError::InsufficientPoints(player));
+        }
+
+        self.riichi_declared[player.index()] = true;
+        self.scores[player.index()] -= RIICHI_STICK_VALUE;
+        self.riichi_sticks += 1;
+
+        Ok(())
+    }
+
+    /// Applies a win by ron. The discarding player pays the value of the hand
+    /// along with the honbas, and the winner collects all the riichi sticks on
+    /// the table.
+    pub fn apply_ron(
+        &mut self,
+        winner: Wind,
+        loser: Wind,
+        points: &Points,
+    ) -> Result<(), GameError> {
+        self.check_not_finished()?;
+        if winner == loser {
+            return Err(GameError::SameWinnerAndLoser(winner));
+        }
+
+        let payment = points
+            .clone()
+            .with_honbas(self.honbas)
+            .breakdown(self.winner_kind(winner), WinMethod::Ron)
+            .ok_or(GameError::NoPayment(WinMethod::Ron))?
+            .total();
+        self.scores[loser.index()] -= payment;
+        self.scores[winner.index()] += payment;
+
+        self.finish_win(winner);
+        Ok(())
+    }
+
+    /// Applies a win by tsumo. Each of the other players pays their share of
+    /// the hand value along with the honbas, and the winner collects all the
+    /// riichi sticks on the table.
+    pub fn apply_tsumo(&mut self, winner: Wind, points: &Points) -> Result<(), GameError> {
+        self.check_not_finished()?;
+
+        let points = points.clone().with_honbas(self.honbas);
+        let payments = if winner == self.dealer {
+            points.oya_tsumo().map(|payment| (payment, payment))
+        } else {
+            points.ko_tsumo()
+        };
+        let (payment_ko, payment_oya) = payments.ok_or(GameError::NoPayment(WinMethod::Tsumo))?;
+
+        for player in Wind::ALL.iter().copied().filter(|&player| player != winner) {
+            let payment = if player == self.dealer {
+                payment_oya
+            } else {
+                payment_ko
+            };
+            self.scores[player.index()] -= payment;
+            self.scores[winner.index()] += payment;
+        }
+
+        self.finish_win(winner);
+        Ok(())
+    }
+
+    /// Applies an exhaustive draw (ryuukyoku). The players who are not tenpai
+    /// pay the noten penalty to the players who are tenpai. The dealer keeps
+    /// their seat if they are tenpai.
+    pub fn apply_exhaustive_draw(&mut self, tenpai: &[Wind]) -> Result<(), GameError> {
+        self.check_not_finished()?;
+
+        let is_tenpai = |player: Wind| tenpai.contains(&player);
+        let tenpai_count = Wind::ALL
+            .iter()
+            .filter(|&&player| is_tenpai(player))
+            .count() as i32;
+        if tenpai_count > 0 && tenpai_count < 4 {
+            for player in Wind::ALL.iter().copied() {
+                if is_tenpai(player) {
+                    self.scores[player.index()] += self.rules.noten_penalty / tenpai_count;
+                } else {
+                    self.scores[player.index()] -= self.rules.noten_penalty / (4 - tenpai_count);
+                }
+            }
+        }
+
+        self.honbas += Honbas::new(1);
+        self.next_hand(is_tenpai(self.dealer));
+        Ok(())
+    }
+
+    /// Applies an abortive draw (e.g. four winds discarded). No points are
+    /// exchanged and the dealer keeps their seat.
+    pub fn apply_abortive_draw(&mut self) -> Result<(), GameError> {
+        self.check_not_finished()?;
+
+        self.honbas += Honbas::new(1);
+        self.next_hand(true);
+        Ok(())
+    }
+
+    fn winner_kind(&self, player: Wind) -> Winner {
+        if player == self.dealer {
+            Winner::Oya
+        } else {
+            Winner::Ko
+        }
+    }
+
+    fn check_not_finished(&self) -> Result<(), GameError> {
+        if self.finished {
+            Err(GameError::GameFinished)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn finish_win(&mut self, winner: Wind) {
+        self.scores[winner.index()] += self.riichi_sticks * RIICHI_STICK_VALUE;
+        self.riichi_sticks = 0;
+
+        let renchan = winner == self.dealer;
+        self.honbas = if renchan {
+            self.honbas + Honbas::new(1)
+        } else {
+            Honbas::ZERO
+        };
+        self.next_hand(renchan);
+    }
+
+    fn next_hand(&mut self, renchan: bool) {
+        self.riichi_declared = [false; 4];
+
+        if !renchan {
+            self.dealer = self.dealer.next();
+            if self.dealer == Wind::East {
+                if self.round_wind == self.rules.length.last_round() {
+                    self.finished = true;
+                } else {
+                    self.round_wind = self.round_wind.next();
+                }
+            }
+        }
+
+        if self.rules.bust && self.scores.iter().any(|&score| score < 0) {
+            self.finished = true;
+        }
+    }
+}
+
+impl Default for Game {
+    fn default() -> Self {
+        Self::new(GameRules::default())
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+/// An error that occurs when applying an action to a [`Game`].
+pub enum GameError {
+    /// The game has already ended.
+    GameFinished,
+    /// The winner of a ron is the same player as the discarding player.
+    SameWinnerAndLoser(Wind),
+    /// The points do not contain a payment for given win method (e.g. ron
+    /// for 2 han, 20 fu).
+    NoPayment(WinMethod),
+    /// The player has already declared riichi in the current hand.
+    RiichiAlreadyDeclared(Wind),
+    /// The player does not have enough points to declare riichi.
+    InsufficientPoints(Wind),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GameError {}
+
+impl Display for GameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            GameError::GameFinished => write!(f, "the game has already finished"),
+            GameError::SameWinnerAndLoser(player) => {
+                write!(
+                    f,
+                    "player {} cannot win by ron on their own discard",
+                    player
+                )
+            }
+            GameError::NoPayment(win_method) => {
+                write!(f, "the points have no payment for {:?}", win_method)
+            }
+            GameError::RiichiAlreadyDeclared(player) => {
+                write!(f, "player {} has already declared riichi", player)
+            }
+            GameError::InsufficientPoints(player) => {
+                write!(f, "player {} does not have enough points", player)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::{Game, GameError, GameLength, GameRules, Wind};
+    use crate::points::{Fu, Han, Honbas, Points, PointsCalculationMode};
+
+    fn total(game: &Game) -> i32 {
+        game.scores().iter().sum::<i32>() + game.riichi_sticks() * 1000
+    }
+
+    #[test]
+    fn should_apply_ron() {
+        let mut game = Game::default();
+        let points = Points::from_calculated(
+            PointsCalculationMode::Default,
+            Han::new(3),
+            Fu::new(30),
+            Honbas::ZERO,
+        )
+        .unwrap();
+
+        game.apply_ron(Wind::East, Wind::West, &points).unwrap();
+        assert_eq!(game.scores(), [30800, 25000, 19200, 25000]);
+        assert_eq!(game.dealer(), Wind::East);
+        assert_eq!(game.honbas(), Honbas::new(1));
+
+        game.apply_ron(Wind::East, Wind::West, &points).unwrap();
+        assert_eq!(game.scores(), [36900, 25000, 13100, 25000]);
+        assert_eq!(game.honbas(), Honbas::new(2));
+
+        game.apply_ron(Wind::South, Wind::East, &points).unwrap();
+        assert_eq!(game.scores(), [32400, 29500, 13100, 25000]);
+        assert_eq!(game.dealer(), Wind::South);
+        assert_eq!(game.hand_number(), 2);
+        assert_eq!(game.honbas(), Honbas::ZERO);
+        assert_eq!(total(&game), 100000);
+
+        assert!(matches!(
+            game.apply_ron(Wind::South, Wind::South, &points),
+            Err(GameError::SameWinnerAndLoser(Wind::South))
+        ));
+    }
+
+    #[test]
+    fn should_apply_tsumo() {
+        let mut game = Game::default();
+        let points = Points::from_calculated(
+            PointsCalculationMode::Default,
+            Han::new(2),
+            Fu::new(20),
+            Honbas::ZERO,
+        )
+        .unwrap();
+
+        game.apply_tsumo(Wind::North, &points).unwrap();
+        assert_eq!(game.scores(), [24300, 24600, 24600, 26500]);
+        assert_eq!(game.dealer(), Wind::South);
+
+        game.apply_tsumo(Wind::South, &points).unwrap();
+        assert_eq!(game.scores(), [23600, 26700, 23900, 25800]);
+        assert_eq!(game.dealer(), Wind::South);
+        assert_eq!(game.honbas(), Honbas::new(1));
+
+        game.apply_tsumo(Wind::South, &points).unwrap();
+        assert_eq!(game.scores(), [22800, 29100, 23100, 25000]);
+        assert_eq!(total(&game), 100000);
+
+        let points = Points::new_calculated(320, false, true, Honbas::ZERO);
+        assert!(matches!(
+            game.apply_tsumo(Wind::East, &points),
+            Err(GameError::NoPayment(_))
+        ));
+    }
+
+    #[test]
+    fn should_handle_riichi_sticks() {
+        let mut game = Game::default();
+
+        game.declare_riichi(Wind::East).unwrap();
+        game.declare_riichi(Wind::West).unwrap();
+        assert!(matches!(
+            game.declare_riichi(Wind::West),
+            Err(GameError::RiichiAlreadyDeclared(Wind::West))
+        ));
+        assert_eq!(game.riichi_sticks(), 2);
+        assert_eq!(total(&game), 100000);
+
+        game.apply_exhaustive_draw(&[Wind::East, Wind::West])
+            .unwrap();
+        assert_eq!(game.scores(), [25500, 23500, 25500, 23500]);
+        assert_eq!(game.riichi_sticks(), 2);
+        assert_eq!(game.dealer(), Wind::East);
+        assert_eq!(game.honbas(), Honbas::new(1));
+
+        game.declare_riichi(Wind::West).unwrap();
+        game.apply_ron(Wind::North, Wind::East, &Points::mangan(Honbas::ZERO))
+            .unwrap();
+        assert_eq!(game.scores(), [17200, 23500, 24500, 34800]);
+        assert_eq!(game.riichi_sticks(), 0);
+        assert_eq!(total(&game), 100000);
+    }
+
+    #[test]
+    fn should_apply_draws() {
+        let mut game = Game::default();
+
+        game.apply_exhaustive_draw(&[Wind::South]).unwrap();
+        assert_eq!(game.scores(), [24000, 28000, 24000, 24000]);
+        assert_eq!(game.dealer(), Wind::South);
+        assert_eq!(game.honbas(), Honbas::new(1));
+
+        game.apply_exhaustive_draw(&[]).unwrap();
+        assert_eq!(game.scores(), [24000, 28000, 24000, 24000]);
+        assert_eq!(game.dealer(), Wind::West);
+
+        game.apply_abortive_draw().unwrap();
+        assert_eq!(game.dealer(), Wind::West);
+        assert_eq!(game.honbas(), Honbas::new(3));
+    }
+
+    #[test]
+    fn should_finish_game() {
+        let mut game = Game::new(GameRules {
+            length: GameLength::Tonpuusen,
+            ..GameRules::default()
+        });
+
+        for _ in 0..3 {
+            game.apply_exhaustive_draw(&[]).unwrap();
+            assert!(!game.is_finished());
+        }
+        assert_eq!(game.round_wind(), Wind::East);
+        assert_eq!(game.hand_number(), 4);
+        game.apply_exhaustive_draw(&[Wind::North]).unwrap();
+        assert!(!game.is_finished());
+        game.apply_exhaustive_draw(&[]).unwrap();
+        assert!(game.is_finished());
+        assert!(matches!(
+            game.apply_abortive_draw(),
+            Err(GameError::GameFinished)
+        ));
+
+        let mut game = Game::default();
+        for _ in 0..4 {
+            game.apply_exhaustive_draw(&[]).unwrap();
+        }
+        assert_eq!(game.round_wind(), Wind::South);
+        assert_eq!(game.dealer(), Wind::East);
+        assert!(!game.is_finished());
+    }
+
+    #[test]
+    fn should_finish_game_on_bust() {
+        let mut game = Game::default();
+        game.apply_ron(Wind::South, Wind::East, &Points::yakuman(Honbas::ZERO))
+            .unwrap();
+        assert_eq!(game.score(Wind::East), -7000);
+        assert!(game.is_finished());
+        assert_eq!(
+            game.standings(),
+            [Wind::South, Wind::West, Wind::North, Wind::East]
+        );
+
+        let mut game = Game::new(GameRules {
+            bust: false,
+            ..GameRules::default()
+        });
+        game.apply_ron(Wind::South, Wind::East, &Points::yakuman(Honbas::ZERO))
+            .unwrap();
+        assert!(!game.is_finished());
+        assert!(matches!(
+            game.declare_riichi(Wind::East),
+            Err(GameError::InsufficientPoints(Wind::East))
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_serialize_game_compactly() {
+        let mut game = Game::default();
+        game.declare_riichi(Wind::West).unwrap();
+
+        let bytes = postcard::to_allocvec(&game).unwrap();
+        assert_eq!(
+            bytes,
+            vec![
+                208, 134, 3, 1, 1, 240, 46, 208, 134, 3, 208, 134, 3, 128, 247, 2, 208, 134, 3, 0,
+                0, 0, 2, 0, 0, 1, 0, 0
+            ]
+        );
+        assert_eq!(postcard::from_bytes::<Game>(&bytes).unwrap(), game);
+    }
+}
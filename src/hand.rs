@@ -1,8 +1,16 @@
-use std::error::Error;
-use std::fmt::{Display, Formatter};
-use std::ops::RangeInclusive;
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::fmt::{Display, Formatter};
+
+use crate::locale::Locale;
+use crate::tiles::ANY;
 
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 /// Tile suite, or Any (if used with an unknown tile).
 pub enum Suite {
     /// Manzu (characters).
@@ -18,7 +26,7 @@ pub enum Suite {
 }
 
 impl Display for Suite {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         let name = match self {
             Suite::Manzu => "Manzu",
             Suite::Pinzu => "Pinzu",
@@ -32,14 +40,18 @@ impl Display for Suite {
 }
 
 #[derive(Copy, Clone, Default, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 /// A value of a tile:
 /// * 1..9 for number tiles (manzu, pinzu, souzu), or 0 (which means red five),
 /// * 1..7 for honor tiles (winds, then dragons),
-/// * 0 for Any tile.
+/// * 0 for Any tile (the tile back), 1 for the blank tile, or 2 for the
+///   unknown ("?") tile.
 pub struct TileValue(pub u8);
 
 impl Display for TileValue {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(&self.0, f)
     }
 }
@@ -54,8 +66,26 @@ const TILE_NUMERALS: [&str; 10] = [
     "Akadora", "Ii", "Ryan", "San", "Suu", "Uu", "Rou", "Chii", "Paa", "Kyuu",
 ];
 const HONOR_NAMES: [&str; 7] = ["Ton", "Nan", "Shaa", "Pei", "Haku", "Hatsu", "Chun"];
+const TILE_NUMERALS_JAPANESE: [&str; 10] =
+    ["赤五", "一", "二", "三", "四", "五", "六", "七", "八", "九"];
+const HONOR_NAMES_JAPANESE: [&str; 7] = ["東", "南", "西", "北", "白", "發", "中"];
+const ANY_NAMES: [&str; 3] = ["Any", "Blank", "Unknown"];
+const ANY_NAMES_JAPANESE: [&str; 3] = ["裏", "白紙", "不明"];
+const ANY_NAMES_NUMERIC: [&str; 3] = ["?", "-", "??"];
+const HONOR_NAMES_ENGLISH: [&str; 7] = [
+    "East",
+    "South",
+    "West",
+    "North",
+    "White dragon",
+    "Green dragon",
+    "Red dragon",
+];
+const ANY_NAMES_ENGLISH: [&str; 3] = ["Tile back", "Blank", "Unknown"];
 
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 /// Tile representation (suite and value).
 pub struct Tile {
     /// Suite of the tile.
@@ -73,18 +103,19 @@ pub struct InvalidTileError {
     pub value: TileValue,
 }
 
-impl Error for InvalidTileError {}
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidTileError {}
 
 impl InvalidTileError {
     #[inline]
     /// Returns a new [InvalidTileError] object.
-    pub fn new(suite: Suite, value: TileValue) -> Self {
+    pub const fn new(suite: Suite, value: TileValue) -> Self {
         Self { suite, value }
     }
 }
 
 impl Display for InvalidTileError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "invalid value: {} for suite: {}", self.value, self.suite)
     }
 }
@@ -93,42 +124,281 @@ impl Tile {
     /// Creates a new tile with given suite and value.
     ///
     /// Returns an error if the provided suite-value pair is not valid.
-    pub fn new(suite: Suite, value: TileValue) -> Result<Tile, InvalidTileError> {
-        let range: RangeInclusive<usize> = match suite {
-            Suite::Manzu | Suite::Pinzu | Suite::Souzu => 0..=9,
-            Suite::Honor => 1..=7,
-            Suite::Any => 0..=0,
-        };
-        if range.contains(&usize::from(value)) {
-            Ok(Self { suite, value })
+    ///
+    /// This is a `const fn`, so it can be used to define custom tile
+    /// constants and lookup tables, with invalid tiles rejected at compile
+    /// time.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::tiles::{HAKU, II_MAN, KYUU_MAN};
+    /// use riichi_hand::{Suite, Tile, TileValue};
+    ///
+    /// const fn tile(suite: Suite, value: u8) -> Tile {
+    ///     match Tile::new(suite, TileValue(value)) {
+    ///         Ok(tile) => tile,
+    ///         Err(_) => panic!("invalid tile"),
+    ///     }
+    /// }
+    ///
+    /// /// Dora indicated by each of the manzu indicators.
+    /// const MANZU_DORA: [Tile; 9] = {
+    ///     let mut dora = [II_MAN; 9];
+    ///     let mut value = 1;
+    ///     while value < 9 {
+    ///         dora[value as usize - 1] = tile(Suite::Manzu, value + 1);
+    ///         value += 1;
+    ///     }
+    ///     dora
+    /// };
+    /// const MY_HAKU: Tile = tile(Suite::Honor, 5);
+    ///
+    /// assert_eq!(MANZU_DORA[0], tile(Suite::Manzu, 2));
+    /// assert_eq!(MANZU_DORA[8], II_MAN);
+    /// assert_eq!(MANZU_DORA[7], KYUU_MAN);
+    /// assert_eq!(MY_HAKU, HAKU);
+    /// ```
+    pub const fn new(suite: Suite, value: TileValue) -> Result<Tile, InvalidTileError> {
+        let tile = Self { suite, value };
+        if tile.is_valid() {
+            Ok(tile)
         } else {
             Err(InvalidTileError::new(suite, value))
         }
     }
 
+    /// Returns whether the tile is valid, i.e. its value is in the range
+    /// allowed for its suite (see [TileValue]). Only the tiles created
+    /// directly from the public fields can be invalid.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::tiles::CHUN;
+    /// use riichi_hand::{Suite, Tile, TileValue};
+    ///
+    /// const INVALID: Tile = Tile {
+    ///     suite: Suite::Honor,
+    ///     value: TileValue(8),
+    /// };
+    /// const _: () = assert!(CHUN.is_valid() && !INVALID.is_valid());
+    /// ```
+    pub const fn is_valid(&self) -> bool {
+        let (min, max) = match self.suite {
+            Suite::Manzu | Suite::Pinzu | Suite::Souzu => (0, 9),
+            Suite::Honor => (1, 7),
+            Suite::Any => (0, 2),
+        };
+
+        min <= self.value.0 && self.value.0 <= max
+    }
+
     /// Returns human-readable name of the tile.
     pub fn name(&self) -> String {
+        self.name_in(Locale::Romaji)
+    }
+
+    /// Returns human-readable name of the tile in given [Locale].
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::locale::Locale;
+    /// use riichi_hand::tiles::{AKADORA_MAN, CHUN, II_SOU};
+    ///
+    /// assert_eq!(II_SOU.name_in(Locale::Japanese), "一索");
+    /// assert_eq!(AKADORA_MAN.name_in(Locale::Japanese), "赤五萬");
+    /// assert_eq!(CHUN.name_in(Locale::Japanese), "中");
+    /// assert_eq!(AKADORA_MAN.name_in(Locale::Numeric), "0m");
+    /// assert_eq!(CHUN.name_in(Locale::Numeric), "7z");
+    /// ```
+    pub fn name_in(&self, locale: Locale) -> String {
+        match locale {
+            Locale::Romaji => self.name_romaji(),
+            Locale::Japanese => self.name_japanese(),
+            Locale::Numeric => self.name_numeric(),
+            Locale::English => self.name_english(),
+        }
+    }
+
+    fn name_romaji(&self) -> String {
+        let name = match self.suite {
+            Suite::Manzu => self.numeral(&TILE_NUMERALS).map(|x| format!("{} man", x)),
+            Suite::Pinzu => self.numeral(&TILE_NUMERALS).map(|x| format!("{} pin", x)),
+            Suite::Souzu => self.numeral(&TILE_NUMERALS).map(|x| format!("{} sou", x)),
+            Suite::Honor => self.honor_name(&HONOR_NAMES).map(str::to_owned),
+            Suite::Any => self.numeral(&ANY_NAMES).map(str::to_owned),
+        };
+
+        name.unwrap_or_else(|| self.invalid_name())
+    }
+
+    fn name_japanese(&self) -> String {
+        let name = match self.suite {
+            Suite::Manzu => self
+                .numeral(&TILE_NUMERALS_JAPANESE)
+                .map(|x| format!("{}萬", x)),
+            Suite::Pinzu => self
+                .numeral(&TILE_NUMERALS_JAPANESE)
+                .map(|x| format!("{}筒", x)),
+            Suite::Souzu => self
+                .numeral(&TILE_NUMERALS_JAPANESE)
+                .map(|x| format!("{}索", x)),
+            Suite::Honor => self.honor_name(&HONOR_NAMES_JAPANESE).map(str::to_owned),
+            Suite::Any => self.numeral(&ANY_NAMES_JAPANESE).map(str::to_owned),
+        };
+
+        name.unwrap_or_else(|| self.invalid_name())
+    }
+
+    fn name_numeric(&self) -> String {
         match self.suite {
-            Suite::Manzu => format!("{} man", TILE_NUMERALS[usize::from(self.value)]),
-            Suite::Pinzu => format!("{} pin", TILE_NUMERALS[usize::from(self.value)]),
-            Suite::Souzu => format!("{} sou", TILE_NUMERALS[usize::from(self.value)]),
-            Suite::Honor => HONOR_NAMES[usize::from(self.value) - 1].to_owned(),
-            Suite::Any => "Any".to_owned(),
+            Suite::Manzu => format!("{}m", self.value),
+            Suite::Pinzu => format!("{}p", self.value),
+            Suite::Souzu => format!("{}s", self.value),
+            Suite::Honor => format!("{}z", self.value),
+            Suite::Any => self
+                .numeral(&ANY_NAMES_NUMERIC)
+                .map_or_else(|| self.invalid_name(), str::to_owned),
+        }
+    }
+
+    fn name_english(&self) -> String {
+        let suite_name = match self.suite {
+            Suite::Manzu => "characters",
+            Suite::Pinzu => "circles",
+            Suite::Souzu => "bamboos",
+            Suite::Honor => {
+                return self
+                    .honor_name(&HONOR_NAMES_ENGLISH)
+                    .map_or_else(|| self.invalid_name(), str::to_owned)
+            }
+            Suite::Any => {
+                return self
+                    .numeral(&ANY_NAMES_ENGLISH)
+                    .map_or_else(|| self.invalid_name(), str::to_owned)
+            }
+        };
+
+        match self.value.0 {
+            0 => format!("Red 5 of {}", suite_name),
+            value @ 1..=9 => format!("{} of {}", value, suite_name),
+            _ => self.invalid_name(),
         }
     }
+
+    /// Returns the name at the index of the tile value.
+    fn numeral<'a>(&self, names: &[&'a str]) -> Option<&'a str> {
+        names.get(usize::from(self.value)).copied()
+    }
+
+    /// Returns the name of the honor tile, given the names of all the honors.
+    fn honor_name<'a>(&self, names: &[&'a str]) -> Option<&'a str> {
+        let index = usize::from(self.value).checked_sub(1)?;
+        names.get(index).copied()
+    }
+
+    /// Returns the name of an invalid tile (e.g. created without
+    /// [Tile::new]).
+    fn invalid_name(&self) -> String {
+        format!("Invalid {} {}", self.suite, self.value)
+    }
 }
 
 impl Display for Tile {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         f.write_str(&self.name())
     }
 }
 
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// Bonus tile (a flower or a season), used in MCR and Hong Kong mahjong, but
+/// not in Riichi.
+///
+/// Of the bundled tile sets of the raster renderer, only `GeneratedTileSet`
+/// can draw the bonus tiles; the image-based ones (FluffyStuff and Martin
+/// Persson) do not include any bonus tile art. A `TwoPartTileSet` can be given
+/// the bonus tile images with `TwoPartTileSet::with_bonus_tiles`.
+pub enum BonusTile {
+    /// Plum (flower 1).
+    Plum,
+    /// Orchid (flower 2).
+    Orchid,
+    /// Chrysanthemum (flower 3).
+    Chrysanthemum,
+    /// Bamboo (flower 4).
+    Bamboo,
+    /// Spring (season 1).
+    Spring,
+    /// Summer (season 2).
+    Summer,
+    /// Autumn (season 3).
+    Autumn,
+    /// Winter (season 4).
+    Winter,
+}
+
+impl BonusTile {
+    #[inline]
+    /// Returns whether the tile is a flower (rather than a season).
+    pub const fn is_flower(&self) -> bool {
+        matches!(
+            self,
+            BonusTile::Plum | BonusTile::Orchid | BonusTile::Chrysanthemum | BonusTile::Bamboo
+        )
+    }
+
+    /// Returns the number (1..4) of the flower or the season.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::BonusTile;
+    ///
+    /// assert_eq!(BonusTile::Orchid.number(), 2);
+    /// assert_eq!(BonusTile::Winter.number(), 4);
+    /// ```
+    pub const fn number(&self) -> u8 {
+        match self {
+            BonusTile::Plum | BonusTile::Spring => 1,
+            BonusTile::Orchid | BonusTile::Summer => 2,
+            BonusTile::Chrysanthemum | BonusTile::Autumn => 3,
+            BonusTile::Bamboo | BonusTile::Winter => 4,
+        }
+    }
+}
+
+impl Display for BonusTile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let name = match self {
+            BonusTile::Plum => "Plum",
+            BonusTile::Orchid => "Orchid",
+            BonusTile::Chrysanthemum => "Chrysanthemum",
+            BonusTile::Bamboo => "Bamboo",
+            BonusTile::Spring => "Spring",
+            BonusTile::Summer => "Summer",
+            BonusTile::Autumn => "Autumn",
+            BonusTile::Winter => "Winter",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[non_exhaustive]
 /// Representation of a tile placement:
 /// * `Normal`, for closed groups and tiles in open groups that are not rotated,
 /// * `Rotated` for the rotated tiles in open groups,
-/// * `RotatedAndShifted` for shouminkans ("added kans").
+/// * `RotatedAndShifted` for shouminkans ("added kans"),
+/// * `FaceDown` for the tiles placed face-down, such as the ends of ankans
+///   ("closed kans").
+///
+/// Only the first three placements can be written in the hand notation, and
+/// are cycled through by [TilePlacement::next] and [TilePlacement::prev].
 pub enum TilePlacement {
     /// A tile that is not rotated.
     Normal,
@@ -136,32 +406,123 @@ pub enum TilePlacement {
     Rotated,
     /// A rotated and shifted tile that is a part of a shouminkan.
     RotatedAndShifted,
+    /// A tile that is placed face-down. The tile is still known, but it is
+    /// rendered as the back of a tile.
+    FaceDown,
 }
 
 impl TilePlacement {
     #[inline]
     /// Returns next placement option in order, i.e. normal => rotated =>
     /// rotated and shifted => normal.
-    pub fn next(&self) -> TilePlacement {
+    ///
+    /// [TilePlacement::FaceDown] is not a part of this cycle: it is never
+    /// returned, and face-down tiles become normal.
+    pub const fn next(&self) -> TilePlacement {
         match self {
             TilePlacement::Normal => TilePlacement::Rotated,
             TilePlacement::Rotated => TilePlacement::RotatedAndShifted,
-            TilePlacement::RotatedAndShifted => TilePlacement::Normal,
+            TilePlacement::RotatedAndShifted | TilePlacement::FaceDown => TilePlacement::Normal,
         }
     }
+
+    #[inline]
+    /// Returns previous placement option in order, i.e. normal => rotated and
+    /// shifted => rotated => normal.
+    ///
+    /// [TilePlacement::FaceDown] is not a part of this cycle: it is never
+    /// returned, and face-down tiles become normal. Hence this is the inverse
+    /// of [TilePlacement::next] for all the placements except
+    /// [TilePlacement::FaceDown].
+    pub const fn prev(&self) -> TilePlacement {
+        match self {
+            TilePlacement::Normal => TilePlacement::RotatedAndShifted,
+            TilePlacement::Rotated | TilePlacement::FaceDown => TilePlacement::Normal,
+            TilePlacement::RotatedAndShifted => TilePlacement::Rotated,
+        }
+    }
+
+    #[inline]
+    /// Returns whether given character is a position modifier of the hand
+    /// notation (see [HandParser](crate::parser::HandParser)), i.e. `*` or
+    /// `'`.
+    pub const fn is_modifier(character: char) -> bool {
+        matches!(character, '*' | '\'')
+    }
+
+    #[inline]
+    /// Returns the placement after applying given position modifier character
+    /// (`*` or `'`), i.e. the next placement, as the parser does. Returns
+    /// `None` if the character is not a position modifier.
+    pub const fn with_modifier(&self, modifier: char) -> Option<TilePlacement> {
+        if Self::is_modifier(modifier) {
+            Some(self.next())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the position modifiers that follow a tile value in the hand
+    /// notation to give the tile this placement, or `None` for
+    /// [TilePlacement::FaceDown], which has no notation.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::TilePlacement;
+    ///
+    /// assert_eq!(TilePlacement::Normal.modifiers(), Some(""));
+    /// assert_eq!(TilePlacement::RotatedAndShifted.modifiers(), Some("**"));
+    /// assert_eq!(TilePlacement::FaceDown.modifiers(), None);
+    /// ```
+    pub const fn modifiers(&self) -> Option<&'static str> {
+        match self {
+            TilePlacement::Normal => Some(""),
+            TilePlacement::Rotated => Some("*"),
+            TilePlacement::RotatedAndShifted => Some("**"),
+            TilePlacement::FaceDown => None,
+        }
+    }
+
+    /// Returns the placement of a tile followed by given position modifiers
+    /// (any combination of `*` and `'`) in the hand notation, or `None` if
+    /// any of the characters is not a position modifier. As in the parser,
+    /// every modifier advances the placement to the next one.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::TilePlacement;
+    ///
+    /// assert_eq!(TilePlacement::from_modifiers(""), Some(TilePlacement::Normal));
+    /// assert_eq!(TilePlacement::from_modifiers("'"), Some(TilePlacement::Rotated));
+    /// assert_eq!(
+    ///     TilePlacement::from_modifiers("*'"),
+    ///     Some(TilePlacement::RotatedAndShifted)
+    /// );
+    /// assert_eq!(TilePlacement::from_modifiers("*x"), None);
+    /// ```
+    pub fn from_modifiers(modifiers: &str) -> Option<TilePlacement> {
+        modifiers
+            .chars()
+            .try_fold(TilePlacement::Normal, |placement, modifier| {
+                placement.with_modifier(modifier)
+            })
+    }
 }
 
 impl Display for TilePlacement {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
             TilePlacement::Normal => write!(f, "normal"),
             TilePlacement::Rotated => write!(f, "rotated"),
             TilePlacement::RotatedAndShifted => write!(f, "rotated and shifted"),
+            TilePlacement::FaceDown => write!(f, "face-down"),
         }
     }
 }
 
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 /// Representation of a tile on a hand (tile and rotation).
 pub struct HandTile {
     /// Specific tile.
@@ -173,36 +534,235 @@ pub struct HandTile {
 impl HandTile {
     #[inline]
     /// Returns new hand tile using given tile object and placement.
-    pub fn new(tile: Tile, placement: TilePlacement) -> Self {
+    pub const fn new(tile: Tile, placement: TilePlacement) -> Self {
         Self { tile, placement }
     }
+
+    #[inline]
+    #[must_use]
+    /// Returns the hand tile as it should be displayed, i.e. the back of a
+    /// tile (a normally placed [ANY] tile) for the face-down tiles, and the
+    /// tile itself otherwise.
+    pub const fn displayed(&self) -> Self {
+        if matches!(self.placement, TilePlacement::FaceDown) {
+            Self::new(ANY, TilePlacement::Normal)
+        } else {
+            *self
+        }
+    }
 }
 
 impl Display for HandTile {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "{} {}", self.placement, self.tile)
     }
 }
 
+/// Number of stacks in the dead wall.
+const DORA_WALL_LENGTH: usize = 7;
+
 /// A group consists of a list of hand tiles (tiles and their placements).
 pub type HandGroup = Vec<HandTile>;
 
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone, Default, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 /// Hand object representation.
 ///
 /// A hand consists of a number of tile groups. Note that an empty group is
-/// valid.
+/// valid, unless the hand is created with [Hand::try_new].
 pub struct Hand {
     groups: Vec<HandGroup>,
 }
 
+/// Maximum number of copies of a tile in a set (counting red fives as fives).
+const MAX_TILE_COPIES: usize = 4;
+
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash)]
+/// Set of invariants relaxed when creating a hand with [Hand::try_new]. By
+/// default, all the invariants are enforced.
+pub struct ValidationOptions {
+    /// Whether the groups without any tiles are allowed.
+    pub allow_empty_groups: bool,
+    /// Whether more than 4 copies of a tile (counting red fives as fives), or
+    /// more than one red five of a suite are allowed.
+    pub allow_excess_copies: bool,
+}
+
 impl Hand {
     #[inline]
     /// Returns a new hand representation object using given groups.
+    ///
+    /// No invariants are checked; see [Hand::try_new] for a validating
+    /// alternative.
     pub fn new(groups: Vec<HandGroup>) -> Self {
         Self { groups }
     }
 
+    /// Returns a new hand representation object using given groups, or an
+    /// error if the groups violate any of the invariants of a hand:
+    /// * all the tiles are valid (as checked by [Tile::new]),
+    /// * there are no empty groups,
+    /// * there are at most 4 copies of every tile (counting red fives as
+    ///   fives), and at most one red five of every suite.
+    ///
+    /// Given [ValidationOptions] allows to relax some of these invariants.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::tiles::{CHUN, II_MAN};
+    /// use riichi_hand::TilePlacement::Normal;
+    /// use riichi_hand::{Hand, HandTile, HandValidationError, ValidationOptions};
+    ///
+    /// let group = vec![HandTile::new(II_MAN, Normal); 3];
+    /// assert!(Hand::try_new(vec![group.clone()], ValidationOptions::default()).is_ok());
+    ///
+    /// let error = Hand::try_new(vec![group.clone(), group], ValidationOptions::default());
+    /// assert!(matches!(error, Err(HandValidationError::TooManyCopies(II_MAN))));
+    ///
+    /// let options = ValidationOptions {
+    ///     allow_empty_groups: true,
+    ///     ..Default::default()
+    /// };
+    /// let hand = Hand::try_new(vec![vec![HandTile::new(CHUN, Normal)], vec![]], options);
+    /// assert!(hand.is_ok());
+    /// ```
+    pub fn try_new(
+        groups: Vec<HandGroup>,
+        options: ValidationOptions,
+    ) -> Result<Self, HandValidationError> {
+        let mut copies = [[0; 10]; 4];
+        for (index, group) in groups.iter().enumerate() {
+            if group.is_empty() && !options.allow_empty_groups {
+                return Err(HandValidationError::EmptyGroup(index));
+            }
+
+            for hand_tile in group {
+                let tile = Tile::new(hand_tile.tile.suite, hand_tile.tile.value)?;
+                let suite_copies = match tile.suite {
+                    Suite::Manzu => &mut copies[0],
+                    Suite::Pinzu => &mut copies[1],
+                    Suite::Souzu => &mut copies[2],
+                    Suite::Honor => &mut copies[3],
+                    Suite::Any => continue,
+                };
+                let value = usize::from(tile.value);
+                suite_copies[value] += 1;
+
+                let too_many = match value {
+                    0 if tile.suite != Suite::Honor => {
+                        suite_copies[0] > 1 || suite_copies[0] + suite_copies[5] > MAX_TILE_COPIES
+                    }
+                    5 if tile.suite != Suite::Honor => {
+                        suite_copies[0] + suite_copies[5] > MAX_TILE_COPIES
+                    }
+                    _ => suite_copies[value] > MAX_TILE_COPIES,
+                };
+                if too_many && !options.allow_excess_copies {
+                    return Err(HandValidationError::TooManyCopies(tile));
+                }
+            }
+        }
+
+        Ok(Self::new(groups))
+    }
+
+    /// Returns a hand with a single group representing the dead wall strip, as
+    /// shown next to the hand on score cards and in replays.
+    ///
+    /// The strip starts with two face-down tiles (the kan replacement tiles),
+    /// followed by the dora indicators. Only the first `revealed` indicators
+    /// are face-up; the remaining ones are placed
+    /// [FaceDown](TilePlacement::FaceDown). The strip is padded with
+    /// face-down [ANY] tiles up to the usual 7 tiles.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::tiles::{ANY, CHUN, II_MAN};
+    /// use riichi_hand::TilePlacement::{FaceDown, Normal};
+    /// use riichi_hand::{Hand, HandTile};
+    ///
+    /// let wall = Hand::dora_wall(&[II_MAN, CHUN], 1);
+    /// assert_eq!(
+    ///     wall.hand_tiles().collect::<Vec<_>>(),
+    ///     vec![
+    ///         HandTile::new(ANY, FaceDown),
+    ///         HandTile::new(ANY, FaceDown),
+    ///         HandTile::new(II_MAN, Normal),
+    ///         HandTile::new(CHUN, FaceDown),
+    ///         HandTile::new(ANY, FaceDown),
+    ///         HandTile::new(ANY, FaceDown),
+    ///         HandTile::new(ANY, FaceDown),
+    ///     ]
+    /// );
+    /// ```
+    pub fn dora_wall(indicators: &[Tile], revealed: usize) -> Self {
+        let indicator_slots = indicators.len().max(DORA_WALL_LENGTH - 2);
+        let mut group = vec![HandTile::new(ANY, TilePlacement::FaceDown); 2];
+        group.extend(
+            (0..indicator_slots).map(|index| match indicators.get(index) {
+                Some(&indicator) if index < revealed => {
+                    HandTile::new(indicator, TilePlacement::Normal)
+                }
+                Some(&indicator) => HandTile::new(indicator, TilePlacement::FaceDown),
+                None => HandTile::new(ANY, TilePlacement::FaceDown),
+            }),
+        );
+
+        Self::new(vec![group])
+    }
+
+    /// Returns the hand with the first and last tiles of all the ankans
+    /// ("closed kans") placed face-down, as they are placed on the table.
+    ///
+    /// A group is considered an ankan if it consists of four identical
+    /// (counting a red five as a five), normally placed tiles.
+    ///
+    /// The renderers already do this when laying out a hand (see
+    /// [LayoutOptions::face_down_closed_kans]), so this is only needed
+    /// when the hand itself should contain the face-down tiles.
+    ///
+    /// [LayoutOptions::face_down_closed_kans]: crate::renderer::LayoutOptions::face_down_closed_kans
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::parser::HandParser;
+    /// use riichi_hand::TilePlacement::{FaceDown, Normal};
+    ///
+    /// let hand = HandParser::parse("123m_5550p").unwrap().with_face_down_closed_kans();
+    /// let placements: Vec<_> = hand.groups()[1].iter().map(|tile| tile.placement).collect();
+    /// assert_eq!(placements, vec![FaceDown, Normal, Normal, FaceDown]);
+    /// ```
+    #[must_use]
+    pub fn with_face_down_closed_kans(mut self) -> Self {
+        for group in &mut self.groups {
+            if Self::is_closed_kan(group) {
+                group[0].placement = TilePlacement::FaceDown;
+                group[3].placement = TilePlacement::FaceDown;
+            }
+        }
+
+        self
+    }
+
+    /// Returns whether given group consists of four identical (counting a red
+    /// five as a five), normally placed tiles.
+    pub(crate) fn is_closed_kan(group: &[HandTile]) -> bool {
+        let without_red_five = |tile: Tile| match tile.value {
+            TileValue(0) => TileValue(5),
+            value => value,
+        };
+
+        group.len() == 4
+            && group[0].tile != ANY
+            && group.iter().all(|hand_tile| {
+                hand_tile.placement == TilePlacement::Normal
+                    && hand_tile.tile.suite == group[0].tile.suite
+                    && without_red_five(hand_tile.tile) == without_red_five(group[0].tile)
+            })
+    }
+
     #[inline]
     /// Return groups stored in this hand.
     pub fn groups(&self) -> &Vec<HandGroup> {
@@ -224,10 +784,51 @@ impl Hand {
     }
 }
 
+#[derive(Copy, Clone, Debug)]
+/// An error that occurs when a hand created with [Hand::try_new] violates an
+/// invariant.
+pub enum HandValidationError {
+    /// The hand contains an invalid tile.
+    InvalidTile(InvalidTileError),
+    /// The group with given index (starting from 0) is empty.
+    EmptyGroup(usize),
+    /// The hand contains too many copies of given tile.
+    TooManyCopies(Tile),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HandValidationError {}
+
+impl Display for HandValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidTile(error) => write!(f, "{}", error),
+            Self::EmptyGroup(index) => write!(f, "group {} is empty", index),
+            Self::TooManyCopies(tile) => write!(f, "too many copies of tile: {}", tile),
+        }
+    }
+}
+
+impl From<InvalidTileError> for HandValidationError {
+    fn from(error: InvalidTileError) -> Self {
+        Self::InvalidTile(error)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::tiles::ALL_TILES;
-    use crate::{Suite, Tile, TileValue};
+    use crate::locale::Locale;
+    use crate::parser::HandParser;
+    use crate::tiles::ALL_BONUS_TILES;
+    use crate::tiles::{
+        AKADORA_MAN, AKADORA_SOU, ALL_TILES, ANY, BLANK, CHUN, II_MAN, II_PIN, II_SOU, NAN, TON,
+        UNKNOWN,
+    };
+    use crate::TilePlacement::{FaceDown, Normal, Rotated, RotatedAndShifted};
+    use crate::{
+        BonusTile, Hand, HandTile, HandValidationError, InvalidTileError, Suite, Tile,
+        TilePlacement, TileValue, ValidationOptions,
+    };
 
     #[test]
     fn should_return_valid_suite_names() {
@@ -258,6 +859,8 @@ mod tests {
         assert!(Tile::new(Suite::Honor, TileValue(2)).is_ok());
         assert!(Tile::new(Suite::Honor, TileValue(7)).is_ok());
         assert!(Tile::new(Suite::Any, TileValue(0)).is_ok());
+        assert!(Tile::new(Suite::Any, TileValue(1)).is_ok());
+        assert!(Tile::new(Suite::Any, TileValue(2)).is_ok());
     }
 
     #[test]
@@ -267,10 +870,68 @@ mod tests {
         assert!(Tile::new(Suite::Souzu, TileValue(10)).is_err());
         assert!(Tile::new(Suite::Honor, TileValue(0)).is_err());
         assert!(Tile::new(Suite::Honor, TileValue(8)).is_err());
-        assert!(Tile::new(Suite::Any, TileValue(1)).is_err());
+        assert!(Tile::new(Suite::Any, TileValue(3)).is_err());
         assert!(Tile::new(Suite::Any, TileValue(5)).is_err());
     }
 
+    #[test]
+    fn should_cycle_placements() {
+        for placement in [Normal, Rotated, RotatedAndShifted] {
+            assert_eq!(placement.next().prev(), placement);
+            assert_eq!(placement.prev().next(), placement);
+        }
+        assert_eq!(Normal.prev(), RotatedAndShifted);
+    }
+
+    #[test]
+    fn should_exclude_face_down_from_placement_cycle() {
+        assert_eq!(FaceDown.next(), Normal);
+        assert_eq!(FaceDown.prev(), Normal);
+        assert_ne!(FaceDown.next().prev(), FaceDown);
+
+        let mut placement = Normal;
+        for _ in 0..6 {
+            placement = placement.next();
+            assert_ne!(placement, FaceDown);
+            assert_ne!(placement.prev(), FaceDown);
+        }
+    }
+
+    #[test]
+    fn should_map_placement_modifiers() {
+        for placement in [Normal, Rotated, RotatedAndShifted] {
+            let modifiers = placement.modifiers().unwrap();
+            let hand = HandParser::parse(&format!("1{}m", modifiers)).unwrap();
+
+            assert_eq!(hand.hand_tiles().next().unwrap().placement, placement);
+            assert_eq!(TilePlacement::from_modifiers(modifiers), Some(placement));
+        }
+        assert_eq!(TilePlacement::from_modifiers("'''"), Some(Normal));
+        assert_eq!(Rotated.with_modifier('\''), Some(RotatedAndShifted));
+        assert_eq!(Rotated.with_modifier('_'), None);
+        assert!(TilePlacement::is_modifier('*'));
+        assert!(!TilePlacement::is_modifier('m'));
+    }
+
+    #[test]
+    fn should_validate_tiles_in_const_context() {
+        const VALID: [bool; 40] = {
+            let mut valid = [false; 40];
+            let mut index = 0;
+            while index < ALL_TILES.len() {
+                valid[index] = ALL_TILES[index].is_valid();
+                index += 1;
+            }
+            valid[38] = BLANK.is_valid();
+            valid[39] = UNKNOWN.is_valid();
+            valid
+        };
+        const INVALID: Result<Tile, InvalidTileError> = Tile::new(Suite::Any, TileValue(3));
+
+        assert_eq!(VALID, [true; 40]);
+        assert!(INVALID.is_err());
+    }
+
     #[test]
     fn should_return_valid_tile_names() {
         let names = ALL_TILES.map(|tile| format!("{}", tile));
@@ -318,4 +979,246 @@ mod tests {
 
         assert_eq!(names, expected);
     }
+
+    #[test]
+    fn should_return_localized_tile_names() {
+        let names = ALL_TILES.map(|tile| tile.name_in(Locale::Japanese));
+        assert_eq!(names[0], "赤五萬");
+        assert_eq!(names[13], "三筒");
+        assert_eq!(names[29], "九索");
+        assert_eq!(
+            names[30..],
+            ["東", "南", "西", "北", "白", "發", "中", "裏"]
+        );
+
+        let names = ALL_TILES.map(|tile| tile.name_in(Locale::Numeric));
+        assert_eq!(names[0], "0m");
+        assert_eq!(names[13], "3p");
+        assert_eq!(names[29], "9s");
+        assert_eq!(names[30..], ["1z", "2z", "3z", "4z", "5z", "6z", "7z", "?"]);
+
+        assert_eq!(
+            ALL_TILES.map(|tile| tile.name_in(Locale::Romaji)),
+            ALL_TILES.map(|tile| tile.name())
+        );
+
+        assert_eq!(BLANK.name(), "Blank");
+        assert_eq!(UNKNOWN.name_in(Locale::Japanese), "不明");
+        assert_eq!(UNKNOWN.name_in(Locale::Numeric), "??");
+
+        let names = ALL_TILES.map(|tile| tile.name_in(Locale::English));
+        assert_eq!(names[0], "Red 5 of characters");
+        assert_eq!(names[13], "3 of circles");
+        assert_eq!(names[29], "9 of bamboos");
+        assert_eq!(
+            names[30..],
+            [
+                "East",
+                "South",
+                "West",
+                "North",
+                "White dragon",
+                "Green dragon",
+                "Red dragon",
+                "Tile back"
+            ]
+        );
+        assert_eq!(BLANK.name_in(Locale::English), "Blank");
+    }
+
+    #[test]
+    fn should_validate_hands() {
+        let options = ValidationOptions::default();
+        let valid = HandParser::parse("1110m_5*55p_EEEE").unwrap();
+        assert!(Hand::try_new(valid.groups().clone(), options).is_ok());
+
+        let too_many_fives = HandParser::parse("55m_550m").unwrap();
+        assert!(matches!(
+            Hand::try_new(too_many_fives.groups().clone(), options),
+            Err(HandValidationError::TooManyCopies(AKADORA_MAN))
+        ));
+
+        let two_red_fives = HandParser::parse("00s").unwrap();
+        assert!(matches!(
+            Hand::try_new(two_red_fives.groups().clone(), options),
+            Err(HandValidationError::TooManyCopies(AKADORA_SOU))
+        ));
+
+        let invalid = vec![vec![HandTile::new(
+            Tile {
+                suite: Suite::Honor,
+                value: TileValue(8),
+            },
+            Normal,
+        )]];
+        let error = Hand::try_new(invalid, options).unwrap_err();
+        assert_eq!(error.to_string(), "invalid value: 8 for suite: Honor");
+
+        let empty_group = HandParser::parse("123m_").unwrap();
+        let error = Hand::try_new(empty_group.groups().clone(), options).unwrap_err();
+        assert_eq!(error.to_string(), "group 1 is empty");
+    }
+
+    #[test]
+    fn should_relax_validation() {
+        let options = ValidationOptions {
+            allow_empty_groups: true,
+            allow_excess_copies: true,
+        };
+        let hand = HandParser::parse("11111m_????????_").unwrap();
+
+        assert_eq!(Hand::try_new(hand.groups().clone(), options).unwrap(), hand);
+    }
+
+    #[test]
+    fn should_name_invalid_tiles() {
+        let invalid_honor = Tile {
+            suite: Suite::Honor,
+            value: TileValue(0),
+        };
+        let invalid_man = Tile {
+            suite: Suite::Manzu,
+            value: TileValue(10),
+        };
+
+        assert_eq!(invalid_honor.name(), "Invalid Honor 0");
+        assert_eq!(invalid_man.name_in(Locale::Japanese), "Invalid Manzu 10");
+        assert_eq!(invalid_man.name_in(Locale::Numeric), "10m");
+    }
+
+    #[test]
+    fn should_create_dora_wall() {
+        let empty = Hand::dora_wall(&[], 0);
+        assert_eq!(empty.groups().len(), 1);
+        assert_eq!(
+            empty.hand_tiles().collect::<Vec<_>>(),
+            vec![HandTile::new(ANY, FaceDown); 7]
+        );
+
+        let indicators = [II_MAN, II_PIN, II_SOU, TON, NAN, CHUN];
+        let wall = Hand::dora_wall(&indicators, 6);
+        assert_eq!(
+            wall.tiles().collect::<Vec<_>>(),
+            vec![ANY, ANY, II_MAN, II_PIN, II_SOU, TON, NAN, CHUN]
+        );
+
+        assert_eq!(
+            wall.hand_tiles()
+                .map(|tile| tile.placement)
+                .collect::<Vec<_>>(),
+            [vec![FaceDown; 2], vec![Normal; 6]].concat()
+        );
+
+        let hidden = Hand::dora_wall(&indicators[..2], 0);
+        assert_eq!(
+            hidden.tiles().collect::<Vec<_>>(),
+            vec![ANY, ANY, II_MAN, II_PIN, ANY, ANY, ANY]
+        );
+        assert!(hidden.hand_tiles().all(|tile| tile.placement == FaceDown));
+        assert!(hidden
+            .hand_tiles()
+            .all(|tile| tile.displayed() == HandTile::new(ANY, Normal)));
+    }
+
+    #[test]
+    fn should_place_closed_kans_face_down() {
+        let hand = HandParser::parse("1111m_1*111p_2222z_2223s_????_4444m")
+            .unwrap()
+            .with_face_down_closed_kans();
+        let placements: Vec<Vec<_>> = hand
+            .groups()
+            .iter()
+            .map(|group| group.iter().map(|tile| tile.placement).collect())
+            .collect();
+
+        assert_eq!(
+            placements,
+            vec![
+                vec![FaceDown, Normal, Normal, FaceDown],
+                vec![Rotated, Normal, Normal, Normal],
+                vec![FaceDown, Normal, Normal, FaceDown],
+                vec![Normal; 4],
+                vec![Normal; 4],
+                vec![FaceDown, Normal, Normal, FaceDown],
+            ]
+        );
+        assert_eq!(hand.groups()[0][0].tile, II_MAN);
+        assert_eq!(hand.groups()[0][0].displayed(), HandTile::new(ANY, Normal));
+        assert_eq!(hand.groups()[0][1].displayed(), hand.groups()[0][1]);
+    }
+
+    #[test]
+    fn should_return_bonus_tile_kinds_and_numbers() {
+        let flowers: Vec<_> = ALL_BONUS_TILES
+            .iter()
+            .filter(|tile| tile.is_flower())
+            .map(|tile| tile.number())
+            .collect();
+        let seasons: Vec<_> = ALL_BONUS_TILES
+            .iter()
+            .filter(|tile| !tile.is_flower())
+            .map(|tile| tile.number())
+            .collect();
+
+        assert_eq!(flowers, vec![1, 2, 3, 4]);
+        assert_eq!(seasons, vec![1, 2, 3, 4]);
+        assert_eq!(BonusTile::Chrysanthemum.to_string(), "Chrysanthemum");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_serialize_hands() {
+        let hand = HandParser::parse("1m_1*11z")
+            .unwrap()
+            .with_face_down_closed_kans();
+        let json = serde_json::to_string(&hand).unwrap();
+        assert_eq!(
+            json,
+            r#"[[{"tile":{"suite":"manzu","value":1},"placement":"normal"}],[{"tile":{"suite":"honor","value":1},"placement":"rotated"},{"tile":{"suite":"honor","value":1},"placement":"normal"},{"tile":{"suite":"honor","value":1},"placement":"normal"}]]"#
+        );
+        assert_eq!(serde_json::from_str::<Hand>(&json).unwrap(), hand);
+
+        let face_down = HandTile::new(II_PIN, FaceDown);
+        let json = serde_json::to_string(&face_down).unwrap();
+        assert!(json.ends_with(r#""placement":"face_down"}"#));
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn should_generate_json_schemas() {
+        let schema = serde_json::to_value(schemars::schema_for!(Hand)).unwrap();
+        assert_eq!(schema["type"], "array");
+        assert_eq!(schema["items"]["items"]["$ref"], "#/definitions/HandTile");
+
+        let placement = &schema["definitions"]["TilePlacement"];
+        assert_eq!(
+            placement["oneOf"][3]["enum"],
+            serde_json::json!(["face_down"])
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_serialize_tiles_compactly() {
+        assert_eq!(postcard::to_allocvec(&II_PIN).unwrap(), vec![1, 1]);
+        assert_eq!(postcard::to_allocvec(&CHUN).unwrap(), vec![3, 7]);
+        assert_eq!(postcard::to_allocvec(&ANY).unwrap(), vec![4, 0]);
+
+        for tile in ALL_TILES {
+            let bytes = postcard::to_allocvec(&tile).unwrap();
+            assert_eq!(postcard::from_bytes::<Tile>(&bytes).unwrap(), tile);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_serialize_hands_compactly() {
+        let hand = HandParser::parse("1p_1*11z").unwrap();
+        let bytes = postcard::to_allocvec(&hand).unwrap();
+        assert_eq!(bytes, vec![2, 1, 1, 1, 0, 3, 3, 1, 1, 3, 1, 0, 3, 1, 0]);
+        assert_eq!(postcard::from_bytes::<Hand>(&bytes).unwrap(), hand);
+
+        let face_down = HandTile::new(CHUN, FaceDown);
+        assert_eq!(postcard::to_allocvec(&face_down).unwrap(), vec![3, 7, 3]);
+    }
 }
@@ -0,0 +1,385 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter, Write};
+use std::io::Cursor;
+
+use image::{ImageError, ImageFormat};
+
+use crate::raster_renderer::{TileImageRetrieveError, TileSet};
+use crate::renderer::{HandRenderer, Layout, LayoutOptions, TilePosition};
+use crate::TilePlacement::{FaceDown, Normal, Rotated, RotatedAndShifted};
+use crate::{Hand, HandTile, Suite, Tile};
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Source of the tile images used by [HtmlRenderer].
+#[derive(Copy, Clone)]
+pub enum HtmlTileImages<'a> {
+    /// Every tile is rendered as an empty `<span>` element with CSS classes
+    /// that can be used to style it (e.g. with a CSS sprite):
+    /// * `riichi-tile` for all the tiles,
+    /// * `riichi-tile-<tile>`, where `<tile>` is the tile in the numeric
+    ///   notation (e.g. `riichi-tile-1m`, `riichi-tile-0p`, `riichi-tile-7z`),
+    ///   or `riichi-tile-back`, `riichi-tile-blank` and `riichi-tile-unknown`
+    ///   for the tile backs, blank tiles and unknown tiles, respectively,
+    /// * `riichi-rotated` or `riichi-shifted` for the rotated tiles.
+    CssClasses,
+    /// Every tile is rendered as an `<img>` element with the tile image from
+    /// given [TileSet] embedded as a PNG data URI.
+    DataUri(&'a dyn TileSet),
+}
+
+impl Debug for HtmlTileImages<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CssClasses => write!(f, "CssClasses"),
+            Self::DataUri(_) => write!(f, "DataUri(..)"),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+/// Rendering options used with [HtmlRenderer].
+pub struct HtmlRenderOptions {
+    /// Width of a single tile, in CSS pixels.
+    pub tile_width: u32,
+    /// Height of a single tile, in CSS pixels.
+    pub tile_height: u32,
+    /// Gap between tiles, expressed as a fraction of tile width.
+    pub tile_gap: f32,
+    /// Gap between groups, expressed as a fraction of tile width.
+    pub group_gap: f32,
+}
+
+impl HtmlRenderOptions {
+    #[inline]
+    /// Creates a new render options object instance.
+    pub fn new(tile_width: u32, tile_height: u32, tile_gap: f32, group_gap: f32) -> Self {
+        Self {
+            tile_width,
+            tile_height,
+            tile_gap,
+            group_gap,
+        }
+    }
+}
+
+impl Default for HtmlRenderOptions {
+    fn default() -> Self {
+        Self::new(30, 40, 0.0, 1.0 / 3.0)
+    }
+}
+
+#[derive(Debug)]
+/// Renders a [Hand] instance to an HTML markup fragment.
+///
+/// The hand is rendered as a `<span class="riichi-hand">` element with fixed
+/// dimensions, containing a `<span class="riichi-group">` element for every
+/// group. The tiles are absolutely positioned inside the hand, and the rotated
+/// tiles are rotated using CSS transforms, so the tile images (or CSS sprites)
+/// only need to contain the upright versions of the tiles.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::html_renderer::{HtmlRenderOptions, HtmlRenderer, HtmlTileImages};
+/// use riichi_hand::parser::HandParser;
+///
+/// let hand = HandParser::parse("1m_2*22p").unwrap();
+/// let html = HtmlRenderer::render(&hand, HtmlTileImages::CssClasses, HtmlRenderOptions::default())
+///     .unwrap();
+///
+/// assert!(html.starts_with("<span class=\"riichi-hand\""));
+/// assert!(html.contains("class=\"riichi-tile riichi-tile-2p riichi-rotated\""));
+/// ```
+pub struct HtmlRenderer<'a> {
+    images: HtmlTileImages<'a>,
+    options: HtmlRenderOptions,
+}
+
+/// Result of [HtmlRenderer::render].
+pub type HtmlRenderResult = Result<String, HtmlRenderError>;
+
+impl<'a> HtmlRenderer<'a> {
+    #[inline]
+    /// Renders given [Hand] instance using [HtmlTileImages] and
+    /// [HtmlRenderOptions].
+    pub fn render(
+        hand: &Hand,
+        images: HtmlTileImages<'a>,
+        options: HtmlRenderOptions,
+    ) -> HtmlRenderResult {
+        Self::new(images, options).render_hand(hand)
+    }
+
+    #[inline]
+    /// Creates a new renderer instance that uses given [HtmlTileImages] and
+    /// [HtmlRenderOptions]. The instance implements [HandRenderer].
+    #[must_use]
+    pub fn new(images: HtmlTileImages<'a>, options: HtmlRenderOptions) -> Self {
+        Self { images, options }
+    }
+
+    fn write_tile(
+        &self,
+        html: &mut String,
+        position: &TilePosition,
+        data_uris: &mut HashMap<Tile, String>,
+    ) -> Result<(), HtmlRenderError> {
+        let style = self.tile_style(position);
+        let classes = Self::tile_classes(&position.tile);
+
+        match self.images {
+            HtmlTileImages::CssClasses => {
+                write!(
+                    html,
+                    "<span class=\"{}\" style=\"{}\"></span>",
+                    classes, style
+                )
+            }
+            HtmlTileImages::DataUri(tile_set) => {
+                let tile = position.tile.tile;
+                let data_uri = match data_uris.entry(tile) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => entry.insert(Self::data_uri(tile_set, tile)?),
+                };
+
+                write!(
+                    html,
+                    "<img class=\"{}\" style=\"{}\" src=\"{}\" alt=\"{}\">",
+                    classes,
+                    style,
+                    data_uri,
+                    tile.name()
+                )
+            }
+        }
+        .expect("writing to a string cannot fail");
+
+        Ok(())
+    }
+
+    fn tile_style(&self, position: &TilePosition) -> String {
+        let mut style = format!(
+            "position:absolute;left:{}px;top:{}px;width:{}px;height:{}px",
+            position.x, position.y, self.options.tile_width, self.options.tile_height
+        );
+
+        if position.tile.placement != Normal {
+            // rotate clockwise around the top left corner, then move back into
+            // the area reserved for the tile
+            write!(
+                style,
+                ";transform-origin:0 0;transform:translateX({}px) rotate(90deg)",
+                self.options.tile_height
+            )
+            .expect("writing to a string cannot fail");
+        }
+
+        style
+    }
+
+    fn tile_classes(hand_tile: &HandTile) -> String {
+        let tile = hand_tile.tile;
+        let name = match tile.suite {
+            Suite::Manzu => format!("{}m", tile.value),
+            Suite::Pinzu => format!("{}p", tile.value),
+            Suite::Souzu => format!("{}s", tile.value),
+            Suite::Honor => format!("{}z", tile.value),
+            Suite::Any => ["back", "blank", "unknown"][usize::from(tile.value)].to_owned(),
+        };
+
+        let mut classes = format!("riichi-tile riichi-tile-{}", name);
+        match hand_tile.placement {
+            Normal | FaceDown => {}
+            Rotated => classes.push_str(" riichi-rotated"),
+            RotatedAndShifted => classes.push_str(" riichi-shifted"),
+        }
+
+        classes
+    }
+
+    fn data_uri(tile_set: &dyn TileSet, tile: Tile) -> Result<String, HtmlRenderError> {
+        let image = tile_set.tile_image_ref(&HandTile::new(tile, Normal))?;
+
+        let mut png = Vec::new();
+        image.write_to(&mut Cursor::new(&mut png), ImageFormat::Png)?;
+
+        Ok(format!("data:image/png;base64,{}", encode_base64(&png)))
+    }
+}
+
+impl<'a> HandRenderer for HtmlRenderer<'a> {
+    type Output = String;
+    type Error = HtmlRenderError;
+
+    fn layout_options(&self) -> LayoutOptions {
+        let tile_width = self.options.tile_width;
+
+        LayoutOptions::new(
+            tile_width,
+            self.options.tile_height,
+            (self.options.tile_gap * tile_width as f32) as u32,
+            (self.options.group_gap * tile_width as f32) as u32,
+        )
+    }
+
+    fn render_layout(&self, layout: &Layout) -> HtmlRenderResult {
+        let mut html = String::new();
+        write!(
+            html,
+            "<span class=\"riichi-hand\" style=\"display:inline-block;position:relative;\
+             width:{}px;height:{}px\">",
+            layout.width, layout.height
+        )
+        .expect("writing to a string cannot fail");
+
+        let mut data_uris = HashMap::new();
+        let mut last_group = None;
+        for position in &layout.tiles {
+            if last_group != Some(position.group) {
+                if last_group.is_some() {
+                    html.push_str("</span>");
+                }
+                html.push_str("<span class=\"riichi-group\">");
+                last_group = Some(position.group);
+            }
+
+            self.write_tile(&mut html, position, &mut data_uris)?;
+        }
+        if last_group.is_some() {
+            html.push_str("</span>");
+        }
+
+        html.push_str("</span>");
+        Ok(html)
+    }
+}
+
+fn encode_base64(data: &[u8]) -> String {
+    let mut result = String::with_capacity((data.len() + 2) / 3 * 4);
+
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let value = (u32::from(bytes[0]) << 16) | (u32::from(bytes[1]) << 8) | u32::from(bytes[2]);
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (value >> (18 - 6 * i)) & 0x3F;
+                result.push(char::from(BASE64_ALPHABET[index as usize]));
+            } else {
+                result.push('=');
+            }
+        }
+    }
+
+    result
+}
+
+#[derive(Debug)]
+/// An error that occurs when calling [HtmlRenderer::render].
+pub enum HtmlRenderError {
+    /// Error occurred when retrieving a tile image.
+    TileImageRetrieveError(TileImageRetrieveError),
+    /// Error occurred when encoding a tile image as PNG.
+    ImageEncodeError(ImageError),
+}
+
+impl Error for HtmlRenderError {}
+
+impl Display for HtmlRenderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TileImageRetrieveError(inner_error) => {
+                write!(f, "could not retrieve tile image: {}", inner_error)
+            }
+            Self::ImageEncodeError(inner_error) => {
+                write!(f, "could not encode tile image: {}", inner_error)
+            }
+        }
+    }
+}
+
+impl From<TileImageRetrieveError> for HtmlRenderError {
+    fn from(error: TileImageRetrieveError) -> Self {
+        Self::TileImageRetrieveError(error)
+    }
+}
+
+impl From<ImageError> for HtmlRenderError {
+    fn from(error: ImageError) -> Self {
+        Self::ImageEncodeError(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use image::RgbaImage;
+
+    use crate::html_renderer::{encode_base64, HtmlRenderOptions, HtmlRenderer, HtmlTileImages};
+    use crate::parser::HandParser;
+    use crate::raster_renderer::SimpleTileSet;
+    use crate::tiles::ALL_TILES;
+
+    #[test]
+    fn should_render_css_classes() {
+        let hand = HandParser::parse("12m_3*3z?").unwrap();
+        let html = HtmlRenderer::render(
+            &hand,
+            HtmlTileImages::CssClasses,
+            HtmlRenderOptions::new(3, 4, 0.0, 1.0),
+        )
+        .unwrap();
+
+        assert_eq!(
+            html,
+            "<span class=\"riichi-hand\" style=\"display:inline-block;position:relative;width:19px;height:4px\">\
+             <span class=\"riichi-group\">\
+             <span class=\"riichi-tile riichi-tile-1m\" style=\"position:absolute;left:0px;top:0px;width:3px;height:4px\"></span>\
+             <span class=\"riichi-tile riichi-tile-2m\" style=\"position:absolute;left:3px;top:0px;width:3px;height:4px\"></span>\
+             </span>\
+             <span class=\"riichi-group\">\
+             <span class=\"riichi-tile riichi-tile-3z riichi-rotated\" style=\"position:absolute;left:9px;top:1px;width:3px;height:4px;transform-origin:0 0;transform:translateX(4px) rotate(90deg)\"></span>\
+             <span class=\"riichi-tile riichi-tile-3z\" style=\"position:absolute;left:13px;top:0px;width:3px;height:4px\"></span>\
+             <span class=\"riichi-tile riichi-tile-back\" style=\"position:absolute;left:16px;top:0px;width:3px;height:4px\"></span>\
+             </span>\
+             </span>"
+        );
+    }
+
+    #[test]
+    fn should_render_data_uris() {
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::new(3, 4)))
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let hand = HandParser::parse("1*11m").unwrap();
+        let html = HtmlRenderer::render(
+            &hand,
+            HtmlTileImages::DataUri(&tile_set),
+            HtmlRenderOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(html.matches("<img ").count(), 3);
+        assert_eq!(html.matches("src=\"data:image/png;base64,").count(), 3);
+        assert!(html.contains("alt=\"Ii man\""));
+    }
+
+    #[test]
+    fn should_encode_base64() {
+        assert_eq!(encode_base64(b""), "");
+        assert_eq!(encode_base64(b"f"), "Zg==");
+        assert_eq!(encode_base64(b"fo"), "Zm8=");
+        assert_eq!(encode_base64(b"foo"), "Zm9v");
+        assert_eq!(encode_base64(b"foobar"), "Zm9vYmFy");
+    }
+}
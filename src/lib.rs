@@ -6,19 +6,80 @@
 //!   such as `123m456p_7*77z`
 //! * Renderer that allows to draw a hand to a raster image (along with a few
 //!   ready-to-use sets of tile images)
+//! * Renderer that allows to draw a hand to an SVG image
+//! * Renderer that allows to write a hand as plain or Unicode text
+//! * Renderer that allows to write a hand as an HTML markup fragment
+//! * Points calculator and a game score tracker
 //!
 //! ## Feature flags
 //! By default, all the crate features are enabled. You can disable some of them
 //! on demand to save on the compilation time and resulting library size.
 //!
+//! * `std` - support for the standard library. Without it, the crate is
+//!   `no_std` (but requires `alloc`), and only the hand representation, the
+//!   parser, the points calculator and the game types are available. All
+//!   the other features except `points` enable it
+//! * `points` - the points calculator and the game score tracker (the
+//!   `points` and `game` modules). Can be disabled to drop the `num-traits`
+//!   dependency in render-only builds
+//! * `rational` - unrounded point calculation with `num-rational`'s `Ratio`
+//!   as the base type
 //! * `raster-renderer` - renderer for hands that outputs raster images
 //! * `fluffy-stuff-tile-sets` - ready-to-use tile sets based on FluffyStuff's
-//!   work. Greatly increases build time
+//!   work; consists of `fluffy-stuff-yellow`, `fluffy-stuff-red` and
+//!   `fluffy-stuff-black` features, which can be enabled separately to only
+//!   include given colors
+//! * `fluffy-stuff-blue`, `fluffy-stuff-green` - additional colors of the
+//!   FluffyStuff tile sets (not included in `fluffy-stuff-tile-sets`), with
+//!   the tile backs recolored at build time
 //! * `martin-persson-tile-sets` - ready-to-use tile sets based on Martin
 //!   Persson's work
 //!
+//! Additionally, the following features are disabled by default:
+//!
+//! * `serde` - serialization and deserialization of tiles, hands, points,
+//!   game types and raster renderer options, both in human-readable formats
+//!   (e.g. JSON) and in compact binary formats (e.g. postcard or bincode)
+//! * `schemars` - JSON Schemas of the types supporting `serde`, generated with
+//!   `schemars`
+//! * `svg-renderer` - renderer for hands that outputs SVG images, along with
+//!   ready-to-use SVG tile sets based on FluffyStuff's work
+//! * `html-renderer` - renderer for hands that outputs HTML markup fragments
+//! * `labels` - text labels and captions above or below the tiles, score
+//!   cards and nanikiru problems in the raster renderer
+//! * `animation` - animated GIFs of hands revealed tile by tile
+//! * `regenerate-tile-sets` - render the enabled FluffyStuff tile sets from
+//!   their SVG files at build time, instead of using the pre-rasterized
+//!   images. Greatly increases build time
+//! * `extra-formats` - encoding the rendered images as JPEG, WebP (lossy or
+//!   lossless) and animated PNG
+//! * `parallel` - rendering the tile images of a hand in parallel using
+//!   `rayon`
+//! * `tile-set-loader` - loading raster renderer tile sets from PNG or JPEG
+//!   images at runtime
+//! * `vector-tile-sets` - raster renderer tile sets that rasterize the SVG tile
+//!   sets at exactly the requested tile size
+//! * `zip-tile-sets` - loading raster renderer tile sets from zip archives
+//! * `tenhou` - parsing Tenhou game logs (mjlog) into game events
+//! * `majsoul` - replaying Mahjong Soul game records (decoded from protobuf
+//!   to JSON) as hands, calls and rivers
+//! * `mjai` - (de)serializing the MJAI protocol events with the tiles
+//!   converted to [Tile] objects
+//! * `riichi-tools` - conversions between the tiles and hands of this crate
+//!   and the ones of `riichi-tools-rs`
+//! * `arbitrary` - [`Arbitrary`](https://docs.rs/arbitrary) implementations
+//!   generating valid tiles, hands and render options, for fuzzing and
+//!   property tests
+//! * `tracing` - `tracing` spans and debug events when parsing hands, loading
+//!   tile sets and rendering images (e.g. tile cache hits, image sizes and
+//!   timings)
+//! * `cli` - the `riichi-hand` command-line tool rendering hands to image
+//!   files and printing the payments of hands
+//!
 //! ## Example
 //! ```
+//! # #[cfg(feature = "fluffy-stuff-yellow")]
+//! # {
 //! use riichi_hand::parser::HandParser;
 //! use riichi_hand::raster_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_TILE_SET;
 //! use riichi_hand::raster_renderer::{RasterRenderer, RenderOptions};
@@ -27,8 +88,10 @@
 //! let image = RasterRenderer::render(&hand, &*YELLOW_FLUFFY_STUFF_TILE_SET, RenderOptions::default()).unwrap();
 //! image.save("hand.png").unwrap();
 //! # std::fs::remove_file("hand.png").unwrap();
+//! # }
 //! ```
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![deny(
     missing_docs,
     missing_debug_implementations,
@@ -42,21 +105,86 @@
     unused_qualifications
 )]
 
+extern crate alloc;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls;
+
+#[cfg(feature = "labels")]
+pub use ab_glyph;
+pub use error::Error;
 pub use hand::*;
+#[cfg(feature = "raster-renderer")]
 pub use image;
 
+mod error;
 mod hand;
 
 /// Hand parser that is able to convert string representation of a hand (e.g.
 /// `123m456p_7*77z`) into [Hand] instance
 pub mod parser;
 
+#[cfg(feature = "std")]
+/// Backend-agnostic hand layout and the [HandRenderer](renderer::HandRenderer)
+/// trait implemented by all the renderers
+pub mod renderer;
+
 #[cfg(feature = "raster-renderer")]
 /// Module that renders [Hand] instance into raster images
 pub mod raster_renderer;
 
+#[cfg(feature = "svg-renderer")]
+/// Module that renders [Hand] instance into SVG images
+pub mod svg_renderer;
+
+#[cfg(feature = "html-renderer")]
+/// Module that renders [Hand] instance into HTML markup
+pub mod html_renderer;
+
+#[cfg(feature = "std")]
+/// Module that renders [Hand] instance into ASCII or Unicode text
+pub mod text_renderer;
+
+#[cfg(feature = "std")]
+/// Module that renders [Hand] instance into chat messages made of custom
+/// emojis
+pub mod emoji_renderer;
+
+/// Melds (calls) of the players, along with who the tiles were called from
+pub mod meld;
+
+/// Discard piles (rivers) of the players
+pub mod river;
+
+/// Snapshots of the whole table (hands and rivers of all the players)
+pub mod table;
+
 /// Constant objects that represent all valid tiles
 pub mod tiles;
 
+#[cfg(feature = "points")]
 /// Storing the number of han and fu, and calculating winning points
 pub mod points;
+
+#[cfg(feature = "points")]
+/// Tracking the scores of the players across the hands of a game
+pub mod game;
+
+/// Languages and notations used when formatting names and points
+pub mod locale;
+
+#[cfg(feature = "tenhou")]
+/// Parsing Tenhou game logs into game events
+pub mod tenhou;
+
+#[cfg(feature = "majsoul")]
+/// Replaying Mahjong Soul game records on a [Table](table::Table)
+pub mod majsoul;
+
+#[cfg(feature = "mjai")]
+/// Events of the MJAI protocol used by mahjong AI bots
+pub mod mjai;
+
+#[cfg(feature = "riichi-tools")]
+/// Conversions from and to the tiles and hands of `riichi-tools-rs`
+pub mod riichi_tools;
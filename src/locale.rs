@@ -0,0 +1,32 @@
+/// Language and notation used when formatting tile names, limit names and
+/// points.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::locale::Locale;
+/// use riichi_hand::tiles::SAN_PIN;
+///
+/// assert_eq!(SAN_PIN.name_in(Locale::Romaji), "San pin");
+/// assert_eq!(SAN_PIN.name_in(Locale::Japanese), "三筒");
+/// assert_eq!(SAN_PIN.name_in(Locale::Numeric), "3p");
+/// assert_eq!(SAN_PIN.name_in(Locale::English), "3 of circles");
+/// ```
+#[derive(Copy, Clone, Default, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[non_exhaustive]
+pub enum Locale {
+    /// Japanese terms written in romaji, e.g. `Ii man` or `Mangan`. This is the
+    /// default locale.
+    #[default]
+    Romaji,
+    /// Japanese terms written in kanji and kana, e.g. `一萬` or `満貫`.
+    Japanese,
+    /// Numbers and the short tile notation only, e.g. `1m`. Names that have no
+    /// numeric form are omitted where possible.
+    Numeric,
+    /// English terms, e.g. `1 of characters` or `East`. The terms commonly
+    /// used untranslated in English (such as `Mangan`) are written in romaji.
+    English,
+}
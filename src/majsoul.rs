@@ -0,0 +1,739 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use serde::de::{Error as _, IgnoredAny, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+
+use crate::meld::{add_shouminkan_tile, Meld, MeldKind, RelativeSeat};
+use crate::river::{Discard, River};
+use crate::table::{Table, PLAYER_COUNT};
+use crate::tiles::PEI;
+use crate::TilePlacement::Normal;
+use crate::{Hand, HandGroup, HandTile, Suite, Tile, TileValue};
+
+/// Converts given Mahjong Soul tile notation (e.g. `3m`, `0p` for the red
+/// five of pinzu, or `7z` for chun) to a tile, or returns `None` if the
+/// notation is invalid.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::majsoul::tile_from_str;
+/// use riichi_hand::tiles::{AKADORA_PIN, CHUN, SAN_MAN};
+///
+/// assert_eq!(tile_from_str("3m"), Some(SAN_MAN));
+/// assert_eq!(tile_from_str("0p"), Some(AKADORA_PIN));
+/// assert_eq!(tile_from_str("7z"), Some(CHUN));
+/// assert_eq!(tile_from_str("8z"), None);
+/// ```
+#[must_use]
+pub fn tile_from_str(tile: &str) -> Option<Tile> {
+    let mut chars = tile.chars();
+    let value = chars.next()?.to_digit(10)?;
+    let suite = match chars.next()? {
+        'm' => Suite::Manzu,
+        'p' => Suite::Pinzu,
+        's' => Suite::Souzu,
+        'z' => Suite::Honor,
+        _ => return None,
+    };
+    if chars.next().is_some() {
+        return None;
+    }
+
+    Tile::new(suite, TileValue(value as u8)).ok()
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+/// An error that occurs when converting Mahjong Soul records.
+pub enum MajsoulConversionError {
+    /// Invalid tile notation.
+    InvalidTile(String),
+    /// Invalid seat number (above 3).
+    InvalidSeat(u32),
+    /// A player discarded or called with a tile that is not in their hand.
+    TileNotInHand {
+        /// The seat of the player.
+        seat: u32,
+        /// The missing tile.
+        tile: Tile,
+    },
+    /// A shouminkan was made without a matching pon.
+    MissingPon {
+        /// The seat of the player.
+        seat: u32,
+        /// The added tile.
+        tile: Tile,
+    },
+}
+
+impl Error for MajsoulConversionError {}
+
+impl Display for MajsoulConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidTile(tile) => write!(f, "invalid tile: {}", tile),
+            Self::InvalidSeat(seat) => write!(f, "invalid seat: {}", seat),
+            Self::TileNotInHand { seat, tile } => {
+                write!(f, "tile {} is not in the hand of seat {}", tile, seat)
+            }
+            Self::MissingPon { seat, tile } => {
+                write!(f, "seat {} has no pon of tile {} to add to", seat, tile)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug, Eq, PartialEq, Hash, Deserialize)]
+#[serde(default)]
+/// Start of a round (`RecordNewRound`).
+pub struct NewRound {
+    /// Round wind (0 for east, 1 for south, and so on).
+    pub chang: u32,
+    /// Number of the hand in the round, counted from zero.
+    pub ju: u32,
+    /// Number of honbas.
+    pub ben: u32,
+    /// Number of riichi sticks on the table.
+    pub liqibang: u32,
+    /// Scores of the players.
+    pub scores: Vec<i32>,
+    /// Dora indicators.
+    pub doras: Vec<String>,
+    /// Starting hand of the player in seat 0.
+    pub tiles0: Vec<String>,
+    /// Starting hand of the player in seat 1.
+    pub tiles1: Vec<String>,
+    /// Starting hand of the player in seat 2.
+    pub tiles2: Vec<String>,
+    /// Starting hand of the player in seat 3.
+    pub tiles3: Vec<String>,
+}
+
+#[derive(Clone, Default, Debug, Eq, PartialEq, Hash, Deserialize)]
+#[serde(default)]
+/// A tile drawn by a player (`RecordDealTile`).
+pub struct DealTile {
+    /// The seat of the player.
+    pub seat: u32,
+    /// The drawn tile.
+    pub tile: String,
+    /// Dora indicators, if changed (after a kan).
+    pub doras: Vec<String>,
+}
+
+#[derive(Clone, Default, Debug, Eq, PartialEq, Hash, Deserialize)]
+#[serde(default)]
+/// A tile discarded by a player (`RecordDiscardTile`).
+pub struct DiscardTile {
+    /// The seat of the player.
+    pub seat: u32,
+    /// The discarded tile.
+    pub tile: String,
+    /// Whether the discard declares riichi.
+    pub is_liqi: bool,
+    /// Whether the discard declares double riichi.
+    pub is_wliqi: bool,
+    /// Whether the discarded tile is the tile the player has just drawn
+    /// (tsumogiri).
+    pub moqie: bool,
+    /// Dora indicators, if changed (after a kan).
+    pub doras: Vec<String>,
+}
+
+#[derive(Clone, Default, Debug, Eq, PartialEq, Hash, Deserialize)]
+#[serde(default)]
+/// A chii, pon or daiminkan (`RecordChiPengGang`).
+pub struct ChiPengGang {
+    /// The seat of the player who called.
+    pub seat: u32,
+    /// Kind of the call: 0 for a chii, 1 for a pon, 2 for a daiminkan.
+    #[serde(rename = "type")]
+    pub kind: u32,
+    /// Tiles of the call.
+    pub tiles: Vec<String>,
+    /// The seats the tiles of the call come from (the seat of the player who
+    /// called for the tiles from their hand).
+    pub froms: Vec<u32>,
+}
+
+#[derive(Clone, Default, Debug, Eq, PartialEq, Hash, Deserialize)]
+#[serde(default)]
+/// An ankan or shouminkan (`RecordAnGangAddGang`).
+pub struct AnGangAddGang {
+    /// The seat of the player.
+    pub seat: u32,
+    /// Kind of the kan: 2 for a shouminkan, 3 for an ankan.
+    #[serde(rename = "type")]
+    pub kind: u32,
+    /// The tile of the kan.
+    pub tiles: String,
+    /// Dora indicators, if changed.
+    pub doras: Vec<String>,
+}
+
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash, Deserialize)]
+#[serde(default)]
+/// A north tile set aside as a nukidora in three-player games
+/// (`RecordBaBei`).
+pub struct BaBei {
+    /// The seat of the player.
+    pub seat: u32,
+}
+
+#[derive(Clone, Default, Debug, Eq, PartialEq, Hash, Deserialize)]
+#[serde(default)]
+/// A single player's win, a part of [Hule].
+pub struct HuleInfo {
+    /// The seat of the winner.
+    pub seat: u32,
+    /// Whether the hand was won by tsumo.
+    pub zimo: bool,
+    /// Closed tiles of the hand, without the winning tile.
+    pub hand: Vec<String>,
+    /// The winning tile.
+    pub hu_tile: String,
+    /// Number of han.
+    pub count: u32,
+    /// Number of fu.
+    pub fu: u32,
+    /// Ura dora indicators, if revealed.
+    pub li_doras: Vec<String>,
+    /// Number of points won.
+    pub point_sum: i32,
+}
+
+#[derive(Clone, Default, Debug, Eq, PartialEq, Hash, Deserialize)]
+#[serde(default)]
+/// Wins ending the round (`RecordHule`).
+pub struct Hule {
+    /// The wins (more than one on a multiple ron).
+    pub hules: Vec<HuleInfo>,
+    /// Changes of the scores of the players.
+    pub delta_scores: Vec<i32>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+/// A single action of a Mahjong Soul game record, in the JSON form of the
+/// decoded protobuf messages (i.e. objects with the `name` of the message
+/// type, optionally prefixed with `.lq.`, followed by its `data`), as
+/// exported by the commonly used record tools.
+///
+/// The fields omitted from the JSON default to zero, `false`, or empty
+/// lists, as in protobuf.
+pub enum MajsoulRecord {
+    /// Start of a round (`RecordNewRound`).
+    NewRound(NewRound),
+    /// A tile drawn by a player (`RecordDealTile`).
+    DealTile(DealTile),
+    /// A tile discarded by a player (`RecordDiscardTile`).
+    DiscardTile(DiscardTile),
+    /// A chii, pon or daiminkan (`RecordChiPengGang`).
+    ChiPengGang(ChiPengGang),
+    /// An ankan or shouminkan (`RecordAnGangAddGang`).
+    AnGangAddGang(AnGangAddGang),
+    /// A nukidora (`RecordBaBei`).
+    BaBei(BaBei),
+    /// Wins ending the round (`RecordHule`).
+    Hule(Hule),
+    /// Any other action (e.g. a draw ending the round), which does not
+    /// change the tiles on the table.
+    Other,
+}
+
+impl<'de> Deserialize<'de> for MajsoulRecord {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(RecordVisitor)
+    }
+}
+
+/// Deserializes the [MajsoulRecord] objects, choosing the type of the data
+/// by the preceding name, so that the data of unknown records is skipped,
+/// while the errors in the data of known records are still reported.
+struct RecordVisitor;
+
+impl<'de> Visitor<'de> for RecordVisitor {
+    type Value = MajsoulRecord;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("a record object with a name and data")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<MajsoulRecord, A::Error> {
+        let mut name: Option<String> = None;
+        let mut record = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "name" => name = Some(map.next_value()?),
+                "data" => {
+                    let name = name
+                        .as_deref()
+                        .ok_or_else(|| A::Error::custom("record name must precede its data"))?;
+                    let name = name.strip_prefix(".lq.").unwrap_or(name);
+                    record = Some(match name {
+                        "RecordNewRound" => MajsoulRecord::NewRound(map.next_value()?),
+                        "RecordDealTile" => MajsoulRecord::DealTile(map.next_value()?),
+                        "RecordDiscardTile" => MajsoulRecord::DiscardTile(map.next_value()?),
+                        "RecordChiPengGang" => MajsoulRecord::ChiPengGang(map.next_value()?),
+                        "RecordAnGangAddGang" => MajsoulRecord::AnGangAddGang(map.next_value()?),
+                        "RecordBaBei" => MajsoulRecord::BaBei(map.next_value()?),
+                        "RecordHule" => MajsoulRecord::Hule(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                            MajsoulRecord::Other
+                        }
+                    });
+                }
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        match (record, name) {
+            (Some(record), _) => Ok(record),
+            (None, Some(_)) => Err(A::Error::missing_field("data")),
+            (None, None) => Err(A::Error::missing_field("name")),
+        }
+    }
+}
+
+/// Returns the sorting key of given tile, placing the red fives right after
+/// the other fives.
+fn sort_key(tile: &Tile) -> (Suite, u8, bool) {
+    let is_red_five = tile.value == TileValue(0);
+    let value = if is_red_five { 5 } else { tile.value.0 };
+
+    (tile.suite, value, is_red_five)
+}
+
+/// Returns whether given tiles are the same, counting a red five as a five.
+fn same_kind(first: Tile, second: Tile) -> bool {
+    sort_key(&first).0 == sort_key(&second).0 && sort_key(&first).1 == sort_key(&second).1
+}
+
+fn tile(tile: &str) -> Result<Tile, MajsoulConversionError> {
+    tile_from_str(tile).ok_or_else(|| MajsoulConversionError::InvalidTile(tile.to_owned()))
+}
+
+fn tiles(tiles: &[String]) -> Result<Vec<Tile>, MajsoulConversionError> {
+    tiles.iter().map(|value| tile(value)).collect()
+}
+
+fn seat(seat: u32) -> Result<usize, MajsoulConversionError> {
+    if (seat as usize) < PLAYER_COUNT {
+        Ok(seat as usize)
+    } else {
+        Err(MajsoulConversionError::InvalidSeat(seat))
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+/// Replays Mahjong Soul records, keeping the [Table] (the hands, including
+/// the calls, the rivers and the dora indicators) up to date, so that the
+/// replay can be rendered with the existing renderers after each action.
+///
+/// The hand of each player consists of the closed tiles (sorted), the tile
+/// the player has just drawn, if any, and the calls, as separate groups.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::majsoul::{MajsoulRecord, MajsoulReplay};
+/// use riichi_hand::tiles::CHUN;
+///
+/// let records: Vec<MajsoulRecord> = serde_json::from_str(
+///     r#"[
+///         {"name": "RecordNewRound", "data": {
+///             "doras": ["1m"],
+///             "tiles0": ["1m", "2m", "3m"], "tiles1": ["4p"], "tiles2": ["5s"], "tiles3": ["7z"]
+///         }},
+///         {"name": "RecordDealTile", "data": {"tile": "7z"}},
+///         {"name": "RecordDiscardTile", "data": {"tile": "7z", "moqie": true}}
+///     ]"#,
+/// )
+/// .unwrap();
+///
+/// let mut replay = MajsoulReplay::new();
+/// for record in &records {
+///     replay.apply(record).unwrap();
+/// }
+/// assert_eq!(replay.table().rivers[0].discards()[0].tile, CHUN);
+/// assert_eq!(replay.table().hands[0].tiles().count(), 3);
+/// ```
+pub struct MajsoulReplay {
+    table: Table,
+    closed: [Vec<Tile>; PLAYER_COUNT],
+    drawn: [Option<Tile>; PLAYER_COUNT],
+    calls: [Vec<HandGroup>; PLAYER_COUNT],
+    last_discard_seat: Option<usize>,
+}
+
+impl MajsoulReplay {
+    #[inline]
+    #[must_use]
+    /// Creates a new replay with an empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the table after the actions applied so far.
+    pub fn table(&self) -> &Table {
+        &self.table
+    }
+
+    /// Applies given action to the table.
+    pub fn apply(&mut self, record: &MajsoulRecord) -> Result<(), MajsoulConversionError> {
+        match record {
+            MajsoulRecord::NewRound(round) => self.new_round(round)?,
+            MajsoulRecord::DealTile(deal) => {
+                let seat = seat(deal.seat)?;
+                self.last_discard_seat = None;
+                self.update_doras(&deal.doras)?;
+                self.drawn[seat] = Some(tile(&deal.tile)?);
+                self.update_hand(seat);
+            }
+            MajsoulRecord::DiscardTile(discard) => self.discard(discard)?,
+            MajsoulRecord::ChiPengGang(call) => self.call(call)?,
+            MajsoulRecord::AnGangAddGang(kan) => self.kan(kan)?,
+            MajsoulRecord::BaBei(ba_bei) => {
+                let seat = seat(ba_bei.seat)?;
+                self.take_from_hand(seat, PEI)?;
+                self.calls[seat].push(vec![HandTile::new(PEI, Normal)]);
+                self.update_hand(seat);
+            }
+            MajsoulRecord::Hule(hule) => {
+                for info in &hule.hules {
+                    let seat = seat(info.seat)?;
+                    self.closed[seat] = tiles(&info.hand)?;
+                    self.drawn[seat] = Some(tile(&info.hu_tile)?);
+                    if !info.zimo {
+                        self.mark_last_discard_called();
+                    }
+                    self.update_hand(seat);
+                }
+            }
+            MajsoulRecord::Other => {}
+        }
+
+        Ok(())
+    }
+
+    fn new_round(&mut self, round: &NewRound) -> Result<(), MajsoulConversionError> {
+        *self = Self::new();
+        let hands = [&round.tiles0, &round.tiles1, &round.tiles2, &round.tiles3];
+        for (seat, hand) in hands.iter().enumerate() {
+            self.closed[seat] = tiles(hand)?;
+            self.update_hand(seat);
+        }
+        self.update_doras(&round.doras)
+    }
+
+    fn discard(&mut self, discard: &DiscardTile) -> Result<(), MajsoulConversionError> {
+        let seat = seat(discard.seat)?;
+        let discarded = tile(&discard.tile)?;
+        self.take_from_hand(seat, discarded)?;
+        self.update_hand(seat);
+
+        let mut river_discard = Discard::new(discarded);
+        if discard.is_liqi || discard.is_wliqi {
+            river_discard = river_discard.riichi();
+        }
+        if discard.moqie {
+            river_discard = river_discard.tsumogiri();
+        }
+        self.table.rivers[seat].push(river_discard);
+        self.last_discard_seat = Some(seat);
+        self.update_doras(&discard.doras)
+    }
+
+    fn call(&mut self, call: &ChiPengGang) -> Result<(), MajsoulConversionError> {
+        let seat = seat(call.seat)?;
+        let kind = match call.kind {
+            0 => MeldKind::Chii,
+            2 => MeldKind::Daiminkan,
+            _ => MeldKind::Pon,
+        };
+        let mut meld = Meld::new(kind, Vec::new(), None, None);
+        for (value, &from) in call.tiles.iter().zip(&call.froms) {
+            let call_tile = tile(value)?;
+            let from = self::seat(from)?;
+            if from == seat {
+                self.take_from_hand(seat, call_tile)?;
+                meld.tiles.push(call_tile);
+            } else {
+                meld.called_tile = Some(call_tile);
+                meld.from_seat = RelativeSeat::between(seat, from);
+            }
+        }
+
+        if meld.called_tile.is_some() {
+            self.mark_last_discard_called();
+        }
+        self.calls[seat].push(meld.to_group());
+        self.update_hand(seat);
+
+        Ok(())
+    }
+
+    fn kan(&mut self, kan: &AnGangAddGang) -> Result<(), MajsoulConversionError> {
+        let seat = seat(kan.seat)?;
+        let kan_tile = tile(&kan.tiles)?;
+        if kan.kind == 3 {
+            let mut tiles = (0..4)
+                .map(|_| self.take_from_hand(seat, kan_tile))
+                .collect::<Result<Vec<_>, _>>()?;
+            tiles.sort_by_key(sort_key);
+            self.calls[seat].push(Meld::ankan(tiles).to_group());
+        } else {
+            let added = self.take_from_hand(seat, kan_tile)?;
+            let pon = self.calls[seat]
+                .iter_mut()
+                .find(|group| {
+                    group.len() == 3 && group.iter().all(|tile| same_kind(tile.tile, kan_tile))
+                })
+                .ok_or(MajsoulConversionError::MissingPon {
+                    seat: kan.seat,
+                    tile: kan_tile,
+                })?;
+            add_shouminkan_tile(pon, added);
+        }
+        self.update_hand(seat);
+
+        self.update_doras(&kan.doras)
+    }
+
+    /// Moves the drawn tile to the closed tiles of given player, and removes
+    /// given tile (or, if not found, another tile of the same kind, e.g. the
+    /// red five instead of a five) from them, returning the removed tile.
+    fn take_from_hand(&mut self, seat: usize, tile: Tile) -> Result<Tile, MajsoulConversionError> {
+        let closed = &mut self.closed[seat];
+        closed.extend(self.drawn[seat].take());
+
+        let index = closed
+            .iter()
+            .position(|&closed_tile| closed_tile == tile)
+            .or_else(|| {
+                closed
+                    .iter()
+                    .position(|&closed_tile| same_kind(closed_tile, tile))
+            })
+            .ok_or(MajsoulConversionError::TileNotInHand {
+                seat: seat as u32,
+                tile,
+            })?;
+
+        Ok(closed.remove(index))
+    }
+
+    /// Marks the last discard made as called by another player.
+    fn mark_last_discard_called(&mut self) {
+        if let Some(seat) = self.last_discard_seat.take() {
+            let river = &mut self.table.rivers[seat];
+            let mut discards = river.discards().to_vec();
+            if let Some(discard) = discards.last_mut() {
+                *discard = discard.called();
+            }
+            *river = River::new(discards);
+        }
+    }
+
+    fn update_doras(&mut self, doras: &[String]) -> Result<(), MajsoulConversionError> {
+        if !doras.is_empty() {
+            self.table.dora_indicators = tiles(doras)?;
+            self.table.revealed_dora = doras.len();
+        }
+
+        Ok(())
+    }
+
+    /// Updates the hand of given player on the table.
+    fn update_hand(&mut self, seat: usize) {
+        self.closed[seat].sort_by_key(sort_key);
+        let as_group = |tiles: &[Tile]| -> HandGroup {
+            tiles
+                .iter()
+                .map(|&tile| HandTile::new(tile, Normal))
+                .collect()
+        };
+
+        let mut groups = vec![as_group(&self.closed[seat])];
+        if let Some(drawn) = self.drawn[seat] {
+            groups.push(as_group(&[drawn]));
+        }
+        groups.extend(self.calls[seat].iter().cloned());
+        self.table.hands[seat] = Hand::new(groups);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::majsoul::{MajsoulConversionError, MajsoulRecord, MajsoulReplay};
+    use crate::river::Discard;
+    use crate::tiles::*;
+    use crate::HandTile;
+    use crate::TilePlacement::{FaceDown, Normal, Rotated, RotatedAndShifted};
+
+    fn records(json: &str) -> Vec<MajsoulRecord> {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn replay(json: &str) -> Result<MajsoulReplay, MajsoulConversionError> {
+        let mut replay = MajsoulReplay::new();
+        for record in &records(json) {
+            replay.apply(record)?;
+        }
+
+        Ok(replay)
+    }
+
+    #[test]
+    fn should_replay_calls() {
+        let replay = replay(
+            r#"[
+                {"name": ".lq.RecordNewRound", "data": {
+                    "doras": ["1m"],
+                    "tiles0": ["1m", "2m", "3m", "5p", "5p", "0p", "7z", "7z", "9s"],
+                    "tiles1": ["1z"],
+                    "tiles2": ["2s", "3s", "9m"],
+                    "tiles3": ["1p"]
+                }},
+                {"name": ".lq.RecordDealTile", "data": {"tile": "4m"}},
+                {"name": ".lq.RecordDiscardTile", "data": {"tile": "1m"}},
+                {"name": ".lq.RecordDealTile", "data": {"seat": 1, "tile": "7z"}},
+                {"name": ".lq.RecordDiscardTile", "data": {"seat": 1, "tile": "7z", "moqie": true}},
+                {"name": ".lq.RecordChiPengGang", "data": {
+                    "type": 1, "tiles": ["7z", "7z", "7z"], "froms": [0, 0, 1]
+                }},
+                {"name": ".lq.RecordDiscardTile", "data": {"tile": "9s", "is_liqi": true}},
+                {"name": ".lq.RecordDealTile", "data": {"seat": 1, "tile": "1s"}},
+                {"name": ".lq.RecordDiscardTile", "data": {"seat": 1, "tile": "1s", "moqie": true}},
+                {"name": ".lq.RecordChiPengGang", "data": {
+                    "seat": 2, "tiles": ["1s", "2s", "3s"], "froms": [1, 2, 2]
+                }},
+                {"name": ".lq.RecordDiscardTile", "data": {"seat": 2, "tile": "9m"}},
+                {"name": ".lq.RecordDealTile", "data": {"tile": "5p"}},
+                {"name": ".lq.RecordAnGangAddGang", "data": {"type": 3, "tiles": "5p"}},
+                {"name": ".lq.RecordDealTile", "data": {"tile": "7z", "doras": ["1m", "2m"]}},
+                {"name": ".lq.RecordAnGangAddGang", "data": {"type": 2, "tiles": "7z"}},
+                {"name": ".lq.RecordDealTile", "data": {"tile": "6m"}},
+                {"name": ".lq.RecordNoTile", "data": {"liujumanguan": false}}
+            ]"#,
+        )
+        .unwrap();
+        let table = replay.table();
+
+        let hand = table.hands[0].groups();
+        assert_eq!(hand.len(), 4);
+        assert_eq!(hand[0].len(), 3);
+        assert_eq!(hand[1], vec![HandTile::new(ROU_MAN, Normal)]);
+        assert_eq!(
+            hand[2],
+            vec![
+                HandTile::new(CHUN, Normal),
+                HandTile::new(CHUN, Normal),
+                HandTile::new(CHUN, Rotated),
+                HandTile::new(CHUN, RotatedAndShifted),
+            ]
+        );
+        assert_eq!(
+            hand[3],
+            vec![
+                HandTile::new(UU_PIN, FaceDown),
+                HandTile::new(UU_PIN, Normal),
+                HandTile::new(UU_PIN, Normal),
+                HandTile::new(AKADORA_PIN, FaceDown),
+            ]
+        );
+        assert_eq!(
+            table.hands[2].groups()[1],
+            vec![
+                HandTile::new(II_SOU, Rotated),
+                HandTile::new(RYAN_SOU, Normal),
+                HandTile::new(SAN_SOU, Normal),
+            ]
+        );
+
+        assert_eq!(
+            table.rivers[0].discards(),
+            &[Discard::new(II_MAN), Discard::new(KYUU_SOU).riichi()]
+        );
+        assert_eq!(
+            table.rivers[1].discards(),
+            &[
+                Discard::new(CHUN).tsumogiri().called(),
+                Discard::new(II_SOU).tsumogiri().called()
+            ]
+        );
+        assert_eq!(table.rivers[2].discards(), &[Discard::new(KYUU_MAN)]);
+        assert_eq!(table.dora_indicators, vec![II_MAN, RYAN_MAN]);
+        assert_eq!(table.revealed_dora, 2);
+    }
+
+    #[test]
+    fn should_reveal_winning_hands() {
+        let replay = replay(
+            r#"[
+                {"name": "RecordNewRound", "data": {"tiles0": ["1m"], "tiles1": ["2m"]}},
+                {"name": "RecordDiscardTile", "data": {"tile": "1m"}},
+                {"name": "RecordHule", "data": {"hules": [
+                    {"seat": 1, "hand": ["2m", "3m"], "hu_tile": "1m", "count": 1, "fu": 30}
+                ]}}
+            ]"#,
+        )
+        .unwrap();
+
+        let table = replay.table();
+        assert_eq!(table.rivers[0].discards(), &[Discard::new(II_MAN).called()]);
+        assert_eq!(
+            table.hands[1].tiles().collect::<Vec<_>>(),
+            vec![RYAN_MAN, SAN_MAN, II_MAN]
+        );
+    }
+
+    #[test]
+    fn should_return_conversion_errors() {
+        let new_round = r#"{"name": "RecordNewRound", "data": {"tiles0": ["1m"]}}"#;
+
+        assert_eq!(
+            replay(&format!(
+                r#"[{}, {{"name": "RecordDiscardTile", "data": {{"tile": "2m"}}}}]"#,
+                new_round
+            ))
+            .unwrap_err(),
+            MajsoulConversionError::TileNotInHand {
+                seat: 0,
+                tile: RYAN_MAN
+            }
+        );
+        assert!(serde_json::from_str::<MajsoulRecord>(
+            r#"{"name": "RecordDealTile", "data": {"seat": "first"}}"#
+        )
+        .is_err());
+        assert!(
+            serde_json::from_str::<MajsoulRecord>(r#"{"data": {}, "name": "RecordLiuJu"}"#)
+                .is_err()
+        );
+        assert_eq!(
+            replay(r#"[{"name": "RecordDealTile", "data": {"seat": 4, "tile": "1m"}}]"#)
+                .unwrap_err(),
+            MajsoulConversionError::InvalidSeat(4)
+        );
+        assert_eq!(
+            replay(r#"[{"name": "RecordDealTile", "data": {"tile": "1x"}}]"#).unwrap_err(),
+            MajsoulConversionError::InvalidTile("1x".to_owned())
+        );
+        assert_eq!(
+            replay(&format!(
+                r#"[{}, {{"name": "RecordAnGangAddGang", "data": {{"type": 2, "tiles": "1m"}}}}]"#,
+                new_round
+            ))
+            .unwrap_err(),
+            MajsoulConversionError::MissingPon {
+                seat: 0,
+                tile: II_MAN
+            }
+        );
+    }
+}
@@ -0,0 +1,477 @@
+use alloc::vec::Vec;
+
+use crate::table::PLAYER_COUNT;
+use crate::TilePlacement::{FaceDown, Normal, Rotated, RotatedAndShifted};
+use crate::{Hand, HandGroup, HandTile, Suite, Tile, TileValue};
+
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+/// Kind of a meld (call).
+pub enum MeldKind {
+    /// Chii (a sequence called from the player on the left).
+    Chii,
+    /// Pon (a triplet called from another player).
+    Pon,
+    /// Daiminkan ("open kan"), a kan called from another player's discard.
+    Daiminkan,
+    /// Shouminkan ("added kan"), a tile added to a pon.
+    Shouminkan,
+    /// Ankan ("closed kan"), a kan made of the player's own tiles.
+    Ankan,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+/// Position of another player at the table, relative to the player making a
+/// meld.
+pub enum RelativeSeat {
+    /// The player on the left (kamicha), who discards just before the player.
+    Left,
+    /// The player across (toimen).
+    Across,
+    /// The player on the right (shimocha), who discards just after the
+    /// player.
+    Right,
+}
+
+impl RelativeSeat {
+    /// Returns the position of the player at `other_seat` relative to the
+    /// player at `seat`, or `None` if it is the same player. The seats are
+    /// numbered in the order of turns (counter-clockwise), as in a
+    /// [Table](crate::table::Table).
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::meld::RelativeSeat;
+    ///
+    /// assert_eq!(RelativeSeat::between(0, 3), Some(RelativeSeat::Left));
+    /// assert_eq!(RelativeSeat::between(3, 1), Some(RelativeSeat::Across));
+    /// assert_eq!(RelativeSeat::between(3, 0), Some(RelativeSeat::Right));
+    /// assert_eq!(RelativeSeat::between(2, 2), None);
+    /// ```
+    #[must_use]
+    pub fn between(seat: usize, other_seat: usize) -> Option<Self> {
+        match (other_seat % PLAYER_COUNT + PLAYER_COUNT - seat % PLAYER_COUNT) % PLAYER_COUNT {
+            1 => Some(Self::Right),
+            2 => Some(Self::Across),
+            3 => Some(Self::Left),
+            _ => None,
+        }
+    }
+
+    /// Returns the position of the rotated tile called from this player among
+    /// given number of other tiles of a meld: first when called from the
+    /// player on the left, second when called from the player across, and
+    /// last when called from the player on the right.
+    #[must_use]
+    pub fn called_tile_position(self, other_tiles: usize) -> usize {
+        match self {
+            Self::Left => 0,
+            Self::Across => 1.min(other_tiles),
+            Self::Right => other_tiles,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+/// Convention used to display an ankan (closed kan), which has two of its
+/// four tiles placed face-down.
+pub enum AnkanStyle {
+    /// The first and the last tile are face-down.
+    #[default]
+    FaceDownEnds,
+    /// The two middle tiles are face-down.
+    FaceDownMiddle,
+}
+
+impl AnkanStyle {
+    /// Returns whether the tile at given index (from 0 to 3) of an ankan is
+    /// face-down.
+    #[inline]
+    #[must_use]
+    pub const fn is_face_down(self, index: usize) -> bool {
+        match self {
+            Self::FaceDownEnds => index == 0 || index == 3,
+            Self::FaceDownMiddle => index == 1 || index == 2,
+        }
+    }
+
+    /// Returns given hand group displayed with this convention if it is an
+    /// ankan, or `None` otherwise. A group is considered an ankan if it
+    /// consists of four tiles of the same kind, none of which is rotated, and
+    /// either exactly two of which are face-down, or none is (as in an ankan
+    /// parsed from `1111m`, see [Hand::with_face_down_closed_kans]).
+    ///
+    /// [Hand::with_face_down_closed_kans]: crate::Hand::with_face_down_closed_kans
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::meld::{AnkanStyle, Meld};
+    /// use riichi_hand::tiles::SHAA;
+    /// use riichi_hand::HandTile;
+    /// use riichi_hand::TilePlacement::{FaceDown, Normal};
+    ///
+    /// let ankan = Meld::ankan(vec![SHAA; 4]).to_group();
+    /// let restyled = AnkanStyle::FaceDownMiddle.restyle(&ankan).unwrap();
+    ///
+    /// let placements: Vec<_> = restyled.iter().map(|tile| tile.placement).collect();
+    /// assert_eq!(placements, vec![Normal, FaceDown, FaceDown, Normal]);
+    /// assert_eq!(AnkanStyle::FaceDownMiddle.restyle(&ankan[1..]), None);
+    ///
+    /// let unmarked = vec![HandTile::new(SHAA, Normal); 4];
+    /// assert_eq!(AnkanStyle::FaceDownMiddle.restyle(&unmarked), Some(restyled));
+    /// ```
+    #[must_use]
+    pub fn restyle(self, group: &[HandTile]) -> Option<HandGroup> {
+        let is_ankan = Hand::is_closed_kan(group)
+            || group.len() == 4
+                && group
+                    .iter()
+                    .all(|tile| matches!(tile.placement, Normal | FaceDown))
+                && group
+                    .iter()
+                    .filter(|tile| tile.placement == FaceDown)
+                    .count()
+                    == 2
+                && group.iter().all(|tile| same_kind(tile.tile, group[0].tile));
+        if !is_ankan {
+            return None;
+        }
+
+        Some(self.apply(group.iter().map(|tile| tile.tile)))
+    }
+
+    /// Returns given tiles as an ankan displayed with this convention.
+    fn apply<I: IntoIterator<Item = Tile>>(self, tiles: I) -> HandGroup {
+        tiles
+            .into_iter()
+            .enumerate()
+            .map(|(index, tile)| {
+                let placement = if self.is_face_down(index) {
+                    FaceDown
+                } else {
+                    Normal
+                };
+                HandTile::new(tile, placement)
+            })
+            .collect()
+    }
+}
+
+/// Returns whether given tiles are the same, counting a red five as a five.
+fn same_kind(first: Tile, second: Tile) -> bool {
+    let value = |tile: Tile| match (tile.suite, tile.value) {
+        (Suite::Manzu | Suite::Pinzu | Suite::Souzu, TileValue(0)) => 5,
+        (_, TileValue(value)) => value,
+    };
+
+    first.suite == second.suite && value(first) == value(second)
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+/// A meld (call) as it happened in the game: what kind of meld it is, which
+/// tile was called, and who it was called from.
+///
+/// Unlike a [HandGroup], which only describes how the tiles are displayed, a
+/// meld keeps the player the tile was called from. It can be converted into
+/// a hand group with [Meld::to_group].
+///
+/// # Examples
+/// ```
+/// use riichi_hand::meld::{Meld, MeldKind, RelativeSeat};
+/// use riichi_hand::parser::HandParser;
+/// use riichi_hand::tiles::CHUN;
+///
+/// let pon = Meld::new(
+///     MeldKind::Pon,
+///     vec![CHUN, CHUN],
+///     Some(CHUN),
+///     Some(RelativeSeat::Across),
+/// );
+///
+/// assert_eq!(pon.to_group(), HandParser::parse("77*7z").unwrap().groups()[0]);
+/// ```
+pub struct Meld {
+    /// Kind of the meld.
+    pub kind: MeldKind,
+    /// Tiles of the meld taken from the player's hand, without the called
+    /// tile. For a shouminkan, the tile added to the pon is the last one.
+    pub tiles: Vec<Tile>,
+    /// The tile called from another player's discard, if any (for a
+    /// shouminkan, the tile called for the original pon).
+    pub called_tile: Option<Tile>,
+    /// The player the tile was called from, if any.
+    pub from_seat: Option<RelativeSeat>,
+}
+
+impl Meld {
+    #[inline]
+    #[must_use]
+    /// Creates a new meld instance.
+    pub fn new(
+        kind: MeldKind,
+        tiles: Vec<Tile>,
+        called_tile: Option<Tile>,
+        from_seat: Option<RelativeSeat>,
+    ) -> Self {
+        Self {
+            kind,
+            tiles,
+            called_tile,
+            from_seat,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Creates a new ankan made of given tiles.
+    pub fn ankan(tiles: Vec<Tile>) -> Self {
+        Self::new(MeldKind::Ankan, tiles, None, None)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the meld as a hand group, with the tiles placed as they are
+    /// displayed on the table.
+    ///
+    /// The called tile is rotated, and placed first when called from the
+    /// player on the left (or when the player is unknown), second when
+    /// called from the player across, and last when called from the player
+    /// on the right. The tile added in a shouminkan is rotated and stacked on
+    /// the called tile, and the ends of an ankan are face-down (see
+    /// [Meld::to_group_with_ankan_style] for the other convention).
+    pub fn to_group(&self) -> HandGroup {
+        self.to_group_with_ankan_style(AnkanStyle::default())
+    }
+
+    #[must_use]
+    /// Returns the meld as a hand group (see [Meld::to_group]), displaying an
+    /// ankan with given convention.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::meld::{AnkanStyle, Meld};
+    /// use riichi_hand::tiles::HATSU;
+    /// use riichi_hand::TilePlacement::{FaceDown, Normal};
+    ///
+    /// let group = Meld::ankan(vec![HATSU; 4]).to_group_with_ankan_style(AnkanStyle::FaceDownMiddle);
+    ///
+    /// let placements: Vec<_> = group.iter().map(|tile| tile.placement).collect();
+    /// assert_eq!(placements, vec![Normal, FaceDown, FaceDown, Normal]);
+    /// ```
+    pub fn to_group_with_ankan_style(&self, ankan_style: AnkanStyle) -> HandGroup {
+        if self.kind == MeldKind::Ankan && self.tiles.len() == 4 {
+            return ankan_style.apply(self.tiles.iter().copied());
+        }
+
+        let (own_tiles, added_tile) = match (self.kind, self.tiles.split_last()) {
+            (MeldKind::Shouminkan, Some((&added, rest))) => (rest, Some(added)),
+            _ => (&self.tiles[..], None),
+        };
+        let mut group: HandGroup = own_tiles
+            .iter()
+            .map(|&tile| HandTile::new(tile, Normal))
+            .collect();
+
+        if let Some(called_tile) = self.called_tile {
+            let from_seat = self.from_seat.unwrap_or(RelativeSeat::Left);
+            let position = from_seat.called_tile_position(group.len());
+            group.insert(position, HandTile::new(called_tile, Rotated));
+        }
+        if let Some(added_tile) = added_tile {
+            add_shouminkan_tile(&mut group, added_tile);
+        }
+
+        group
+    }
+}
+
+/// Turns a pon displayed as given hand group into a shouminkan, by stacking
+/// given tile on the rotated (called) tile of the pon, or placing it at the
+/// end of the group if no tile is rotated.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::meld::add_shouminkan_tile;
+/// use riichi_hand::parser::HandParser;
+/// use riichi_hand::tiles::HATSU;
+///
+/// let mut group = HandParser::parse("66*6z").unwrap().groups()[0].clone();
+/// add_shouminkan_tile(&mut group, HATSU);
+///
+/// assert_eq!(group, HandParser::parse("66*6**6z").unwrap().groups()[0]);
+/// ```
+pub fn add_shouminkan_tile(group: &mut HandGroup, tile: Tile) {
+    let position = group
+        .iter()
+        .position(|hand_tile| hand_tile.placement == Rotated)
+        .map_or(group.len(), |position| position + 1);
+    group.insert(position, HandTile::new(tile, RotatedAndShifted));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::meld::{add_shouminkan_tile, AnkanStyle, Meld, MeldKind, RelativeSeat};
+    use crate::parser::HandParser;
+    use crate::tiles::*;
+    use crate::TilePlacement::{FaceDown, Normal};
+    use crate::{HandGroup, HandTile};
+
+    fn group(hand: &str) -> HandGroup {
+        HandParser::parse(hand).unwrap().groups()[0].clone()
+    }
+
+    #[test]
+    fn should_rotate_called_tile() {
+        let chii = Meld::new(
+            MeldKind::Chii,
+            vec![II_MAN, SAN_MAN],
+            Some(RYAN_MAN),
+            Some(RelativeSeat::Left),
+        );
+        let pon = |from_seat| Meld::new(MeldKind::Pon, vec![TON, TON], Some(TON), from_seat);
+        let daiminkan = Meld::new(
+            MeldKind::Daiminkan,
+            vec![UU_PIN, UU_PIN, AKADORA_PIN],
+            Some(UU_PIN),
+            Some(RelativeSeat::Right),
+        );
+
+        assert_eq!(chii.to_group(), group("2*13m"));
+        assert_eq!(pon(Some(RelativeSeat::Left)).to_group(), group("1*11z"));
+        assert_eq!(pon(Some(RelativeSeat::Across)).to_group(), group("11*1z"));
+        assert_eq!(pon(Some(RelativeSeat::Right)).to_group(), group("111*z"));
+        assert_eq!(pon(None).to_group(), group("1*11z"));
+        assert_eq!(daiminkan.to_group(), group("5505*p"));
+    }
+
+    #[test]
+    fn should_stack_added_tile_of_shouminkan() {
+        let shouminkan = |from_seat| {
+            Meld::new(
+                MeldKind::Shouminkan,
+                vec![HAKU, HAKU, HAKU],
+                Some(HAKU),
+                Some(from_seat),
+            )
+        };
+
+        assert_eq!(shouminkan(RelativeSeat::Left).to_group(), group("5*5**55z"));
+        assert_eq!(
+            shouminkan(RelativeSeat::Across).to_group(),
+            group("55*5**5z")
+        );
+        assert_eq!(
+            shouminkan(RelativeSeat::Right).to_group(),
+            group("555*5**z")
+        );
+    }
+
+    #[test]
+    fn should_place_ankan_ends_face_down() {
+        let ankan = Meld::ankan(vec![NAN; 4]);
+
+        assert_eq!(
+            ankan.to_group(),
+            vec![
+                HandTile::new(NAN, FaceDown),
+                HandTile::new(NAN, Normal),
+                HandTile::new(NAN, Normal),
+                HandTile::new(NAN, FaceDown),
+            ]
+        );
+        assert_eq!(ankan.called_tile, None);
+        assert!(Meld::ankan(vec![]).to_group().is_empty());
+    }
+
+    #[test]
+    fn should_convert_melds_by_seats() {
+        let pons: Vec<_> = (0..4)
+            .map(|from| {
+                let from_seat = RelativeSeat::between(2, from);
+                Meld::new(MeldKind::Pon, vec![II_SOU; 2], Some(II_SOU), from_seat).to_group()
+            })
+            .collect();
+
+        assert_eq!(
+            pons,
+            vec![
+                group("11*1s"),
+                group("1*11s"),
+                group("1*11s"),
+                group("111*s")
+            ]
+        );
+        assert_eq!(RelativeSeat::between(1, 6), Some(RelativeSeat::Right));
+    }
+
+    #[test]
+    fn should_add_shouminkan_tile() {
+        let mut pon = group("1*11p");
+        let mut unrotated = group("111p");
+        add_shouminkan_tile(&mut pon, AKADORA_PIN);
+        add_shouminkan_tile(&mut unrotated, II_PIN);
+
+        assert_eq!(pon, group("1*0**11p"));
+        assert_eq!(unrotated, group("1111**p"));
+    }
+
+    #[test]
+    fn should_display_ankans_with_given_style() {
+        let ankan = Meld::ankan(vec![UU_MAN, AKADORA_MAN, UU_MAN, UU_MAN]);
+        let middle = ankan.to_group_with_ankan_style(AnkanStyle::FaceDownMiddle);
+
+        assert_eq!(
+            middle,
+            vec![
+                HandTile::new(UU_MAN, Normal),
+                HandTile::new(AKADORA_MAN, FaceDown),
+                HandTile::new(UU_MAN, FaceDown),
+                HandTile::new(UU_MAN, Normal),
+            ]
+        );
+        assert_eq!(
+            AnkanStyle::FaceDownEnds.restyle(&middle),
+            Some(ankan.to_group())
+        );
+        assert_eq!(AnkanStyle::FaceDownMiddle.restyle(&middle), Some(middle));
+    }
+
+    #[test]
+    fn should_not_restyle_other_groups() {
+        let mut mixed = Meld::ankan(vec![UU_MAN, UU_MAN, UU_MAN, UU_PIN]).to_group();
+        let pon = group("1*11z");
+        let open_kan = group("111*1z");
+        let mut one_face_down = Meld::ankan(vec![TON; 4]).to_group();
+        one_face_down[0].placement = Normal;
+
+        for group in [&mixed, &pon, &open_kan, &one_face_down] {
+            assert_eq!(AnkanStyle::FaceDownMiddle.restyle(group), None);
+        }
+        mixed[3].tile = AKADORA_MAN;
+        assert!(AnkanStyle::FaceDownMiddle.restyle(&mixed).is_some());
+    }
+
+    #[test]
+    fn should_restyle_unmarked_ankans() {
+        let unmarked = group("1111z");
+
+        assert_eq!(
+            AnkanStyle::FaceDownEnds.restyle(&unmarked),
+            Some(Meld::ankan(vec![TON; 4]).to_group())
+        );
+        assert_eq!(
+            AnkanStyle::FaceDownMiddle.restyle(&unmarked),
+            Some(Meld::ankan(vec![TON; 4]).to_group_with_ankan_style(AnkanStyle::FaceDownMiddle))
+        );
+    }
+}
@@ -0,0 +1,463 @@
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::tiles::ANY;
+use crate::{Suite, Tile, TileValue};
+
+/// MJAI notation of the honor tiles, in the order of their values.
+const HONOR_NOTATION: [&str; 7] = ["E", "S", "W", "N", "P", "F", "C"];
+/// MJAI notation of a hidden tile (e.g. in other players' starting hands).
+const HIDDEN_NOTATION: &str = "?";
+
+/// Converts given MJAI tile notation (e.g. `3m`, `5pr` for the red five of
+/// pinzu, `C` for chun, or `?` for a hidden tile) to a tile, or returns
+/// `None` if the notation is invalid.
+///
+/// Hidden tiles are converted to [ANY], i.e. the back of a
+/// tile.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::mjai::tile_from_str;
+/// use riichi_hand::tiles::{AKADORA_PIN, ANY, CHUN, SAN_MAN};
+///
+/// assert_eq!(tile_from_str("3m"), Some(SAN_MAN));
+/// assert_eq!(tile_from_str("5pr"), Some(AKADORA_PIN));
+/// assert_eq!(tile_from_str("C"), Some(CHUN));
+/// assert_eq!(tile_from_str("?"), Some(ANY));
+/// assert_eq!(tile_from_str("0m"), None);
+/// ```
+#[must_use]
+pub fn tile_from_str(notation: &str) -> Option<Tile> {
+    if notation == HIDDEN_NOTATION {
+        return Some(ANY);
+    }
+    if let Some(index) = HONOR_NOTATION.iter().position(|&honor| honor == notation) {
+        return Some(Tile {
+            suite: Suite::Honor,
+            value: TileValue(index as u8 + 1),
+        });
+    }
+
+    let mut chars = notation.chars();
+    let value = chars.next()?.to_digit(10)?;
+    let suite = match chars.next()? {
+        'm' => Suite::Manzu,
+        'p' => Suite::Pinzu,
+        's' => Suite::Souzu,
+        _ => return None,
+    };
+    let value = match (value, chars.as_str()) {
+        (1..=9, "") => value,
+        (5, "r") => 0,
+        _ => return None,
+    };
+
+    Some(Tile {
+        suite,
+        value: TileValue(value as u8),
+    })
+}
+
+/// Returns the MJAI notation of given tile, or `None` if there is none (for
+/// the [BLANK](crate::tiles::BLANK) and [UNKNOWN](crate::tiles::UNKNOWN)
+/// tiles).
+///
+/// # Examples
+/// ```
+/// use riichi_hand::mjai::tile_to_string;
+/// use riichi_hand::tiles::{AKADORA_SOU, ANY, HATSU, II_MAN, UNKNOWN};
+///
+/// assert_eq!(tile_to_string(II_MAN).as_deref(), Some("1m"));
+/// assert_eq!(tile_to_string(AKADORA_SOU).as_deref(), Some("5sr"));
+/// assert_eq!(tile_to_string(HATSU).as_deref(), Some("F"));
+/// assert_eq!(tile_to_string(ANY).as_deref(), Some("?"));
+/// assert_eq!(tile_to_string(UNKNOWN), None);
+/// ```
+#[must_use]
+pub fn tile_to_string(tile: Tile) -> Option<String> {
+    let suite = match tile.suite {
+        Suite::Manzu => 'm',
+        Suite::Pinzu => 'p',
+        Suite::Souzu => 's',
+        Suite::Honor => {
+            let index = usize::from(tile.value).checked_sub(1)?;
+            return HONOR_NOTATION.get(index).map(|&honor| honor.to_owned());
+        }
+        Suite::Any if tile == ANY => return Some(HIDDEN_NOTATION.to_owned()),
+        Suite::Any => return None,
+    };
+
+    match tile.value {
+        TileValue(0) => Some(format!("5{}r", suite)),
+        value => Some(format!("{}{}", value, suite)),
+    }
+}
+
+/// A tile (de)serialized using the MJAI tile notation.
+struct Notation(Tile);
+
+impl Serialize for Notation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let notation = tile_to_string(self.0)
+            .ok_or_else(|| S::Error::custom(format!("tile {} has no MJAI notation", self.0)))?;
+        serializer.serialize_str(&notation)
+    }
+}
+
+impl<'de> Deserialize<'de> for Notation {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let notation = String::deserialize(deserializer)?;
+        tile_from_str(&notation)
+            .map(Notation)
+            .ok_or_else(|| D::Error::custom(format!("invalid MJAI tile: {}", notation)))
+    }
+}
+
+/// Types of the event fields containing tiles, so that they can be
+/// (de)serialized using the MJAI tile notation with [notation].
+trait TileField: Sized {
+    type Notation: Serialize + for<'de> Deserialize<'de>;
+
+    fn to_notation(&self) -> Self::Notation;
+
+    fn from_notation(notation: Self::Notation) -> Self;
+}
+
+impl TileField for Tile {
+    type Notation = Notation;
+
+    fn to_notation(&self) -> Notation {
+        Notation(*self)
+    }
+
+    fn from_notation(notation: Notation) -> Self {
+        notation.0
+    }
+}
+
+impl<T: TileField> TileField for Vec<T> {
+    type Notation = Vec<T::Notation>;
+
+    fn to_notation(&self) -> Self::Notation {
+        self.iter().map(TileField::to_notation).collect()
+    }
+
+    fn from_notation(notation: Self::Notation) -> Self {
+        notation.into_iter().map(T::from_notation).collect()
+    }
+}
+
+impl<T: TileField> TileField for Option<T> {
+    type Notation = Option<T::Notation>;
+
+    fn to_notation(&self) -> Self::Notation {
+        self.as_ref().map(TileField::to_notation)
+    }
+
+    fn from_notation(notation: Self::Notation) -> Self {
+        notation.map(T::from_notation)
+    }
+}
+
+/// Serde `with` module (de)serializing the tile fields using the MJAI tile
+/// notation.
+mod notation {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::TileField;
+
+    pub(super) fn serialize<T: TileField, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.to_notation().serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, T: TileField, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        T::Notation::deserialize(deserializer).map(T::from_notation)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+/// Start of a round (`start_kyoku` event).
+pub struct StartKyoku {
+    /// Round wind tile.
+    #[serde(with = "notation")]
+    pub bakaze: Tile,
+    /// The first dora indicator.
+    #[serde(with = "notation")]
+    pub dora_marker: Tile,
+    /// Number of the hand in the round, from 1 to 4.
+    pub kyoku: u8,
+    /// Number of honbas.
+    pub honba: u32,
+    /// Number of riichi sticks on the table.
+    pub kyotaku: u32,
+    /// The dealer.
+    pub oya: usize,
+    /// Scores of the players.
+    pub scores: Vec<i32>,
+    /// Starting hands of the players (with hidden tiles converted to
+    /// [ANY]).
+    #[serde(with = "notation")]
+    pub tehais: Vec<Vec<Tile>>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
+/// An event of the MJAI protocol, used by mahjong AI bots and their
+/// simulators, with the tiles converted to [Tile] objects.
+///
+/// The players (actors and targets) are identified by their seats, from 0
+/// to 3.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::mjai::MjaiEvent;
+/// use riichi_hand::tiles::AKADORA_MAN;
+///
+/// let event: MjaiEvent =
+///     serde_json::from_str(r#"{"type":"dahai","actor":1,"pai":"5mr","tsumogiri":false}"#)
+///         .unwrap();
+/// assert_eq!(
+///     event,
+///     MjaiEvent::Dahai {
+///         actor: 1,
+///         pai: AKADORA_MAN,
+///         tsumogiri: false,
+///     }
+/// );
+/// ```
+pub enum MjaiEvent {
+    /// Start of the game.
+    StartGame {
+        /// Names of the players, if known.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        names: Vec<String>,
+    },
+    /// Start of a round.
+    StartKyoku(StartKyoku),
+    /// A tile drawn by a player.
+    Tsumo {
+        /// The player.
+        actor: usize,
+        /// The drawn tile ([ANY] if hidden).
+        #[serde(with = "notation")]
+        pai: Tile,
+    },
+    /// A tile discarded by a player.
+    Dahai {
+        /// The player.
+        actor: usize,
+        /// The discarded tile.
+        #[serde(with = "notation")]
+        pai: Tile,
+        /// Whether the discarded tile is the tile the player has just drawn.
+        tsumogiri: bool,
+    },
+    /// A chii.
+    Chi {
+        /// The player who called.
+        actor: usize,
+        /// The player who discarded the called tile.
+        target: usize,
+        /// The called tile.
+        #[serde(with = "notation")]
+        pai: Tile,
+        /// The tiles from the caller's hand.
+        #[serde(with = "notation")]
+        consumed: Vec<Tile>,
+    },
+    /// A pon.
+    Pon {
+        /// The player who called.
+        actor: usize,
+        /// The player who discarded the called tile.
+        target: usize,
+        /// The called tile.
+        #[serde(with = "notation")]
+        pai: Tile,
+        /// The tiles from the caller's hand.
+        #[serde(with = "notation")]
+        consumed: Vec<Tile>,
+    },
+    /// A daiminkan ("open kan").
+    Daiminkan {
+        /// The player who called.
+        actor: usize,
+        /// The player who discarded the called tile.
+        target: usize,
+        /// The called tile.
+        #[serde(with = "notation")]
+        pai: Tile,
+        /// The tiles from the caller's hand.
+        #[serde(with = "notation")]
+        consumed: Vec<Tile>,
+    },
+    /// A shouminkan ("added kan").
+    Kakan {
+        /// The player.
+        actor: usize,
+        /// The added tile.
+        #[serde(with = "notation")]
+        pai: Tile,
+        /// The tiles of the pon.
+        #[serde(with = "notation")]
+        consumed: Vec<Tile>,
+    },
+    /// An ankan ("closed kan").
+    Ankan {
+        /// The player.
+        actor: usize,
+        /// The tiles of the kan.
+        #[serde(with = "notation")]
+        consumed: Vec<Tile>,
+    },
+    /// A new dora indicator revealed.
+    Dora {
+        /// The dora indicator.
+        #[serde(with = "notation")]
+        dora_marker: Tile,
+    },
+    /// Riichi declared by a player.
+    Reach {
+        /// The player.
+        actor: usize,
+    },
+    /// Riichi accepted, i.e. the declaration tile was not called for a ron.
+    ReachAccepted {
+        /// The player.
+        actor: usize,
+    },
+    /// A win.
+    Hora {
+        /// The winner.
+        actor: usize,
+        /// The player who discarded the winning tile, or the winner for a win
+        /// by tsumo.
+        target: usize,
+        /// Changes of the scores of the players, if known.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        deltas: Option<Vec<i32>>,
+        /// Ura dora indicators, if revealed.
+        #[serde(default, skip_serializing_if = "Option::is_none", with = "notation")]
+        ura_markers: Option<Vec<Tile>>,
+    },
+    /// A draw ending the round.
+    Ryukyoku {
+        /// Changes of the scores of the players, if known.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        deltas: Option<Vec<i32>>,
+    },
+    /// End of a round.
+    EndKyoku,
+    /// End of the game.
+    EndGame,
+    /// No action (a bot passing on a call).
+    None,
+}
+
+impl MjaiEvent {
+    /// Returns the player who performed the action, if any.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::mjai::MjaiEvent;
+    ///
+    /// assert_eq!(MjaiEvent::Reach { actor: 2 }.actor(), Some(2));
+    /// assert_eq!(MjaiEvent::EndKyoku.actor(), None);
+    /// ```
+    #[must_use]
+    pub fn actor(&self) -> Option<usize> {
+        match self {
+            Self::Tsumo { actor, .. }
+            | Self::Dahai { actor, .. }
+            | Self::Chi { actor, .. }
+            | Self::Pon { actor, .. }
+            | Self::Daiminkan { actor, .. }
+            | Self::Kakan { actor, .. }
+            | Self::Ankan { actor, .. }
+            | Self::Reach { actor }
+            | Self::ReachAccepted { actor }
+            | Self::Hora { actor, .. } => Some(*actor),
+            Self::StartGame { .. }
+            | Self::StartKyoku(_)
+            | Self::Dora { .. }
+            | Self::Ryukyoku { .. }
+            | Self::EndKyoku
+            | Self::EndGame
+            | Self::None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mjai::{tile_from_str, tile_to_string, MjaiEvent, StartKyoku};
+    use crate::tiles::*;
+
+    #[test]
+    fn should_convert_all_tiles() {
+        for tile in ALL_TILES {
+            let notation = tile_to_string(tile).unwrap();
+            assert_eq!(tile_from_str(&notation), Some(tile), "{}", notation);
+        }
+        assert_eq!(tile_to_string(BLANK), None);
+        for invalid in ["", "5", "0p", "5pp", "10m", "5z", "X", "??"] {
+            assert_eq!(tile_from_str(invalid), None, "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn should_round_trip_events() {
+        let log = [
+            r#"{"type":"start_game","names":["A","B","C","D"]}"#,
+            r#"{"type":"start_kyoku","bakaze":"E","dora_marker":"5sr","kyoku":1,"honba":0,"kyotaku":0,"oya":0,"scores":[25000,25000,25000,25000],"tehais":[["1m","2m"],["?","?"],["?","?"],["?","?"]]}"#,
+            r#"{"type":"tsumo","actor":0,"pai":"C"}"#,
+            r#"{"type":"dahai","actor":0,"pai":"C","tsumogiri":true}"#,
+            r#"{"type":"pon","actor":2,"target":0,"pai":"C","consumed":["C","C"]}"#,
+            r#"{"type":"ankan","actor":1,"consumed":["5p","5p","5p","5pr"]}"#,
+            r#"{"type":"reach_accepted","actor":3}"#,
+            r#"{"type":"hora","actor":3,"target":0,"deltas":[-8000,0,0,9000],"ura_markers":["E"]}"#,
+            r#"{"type":"ryukyoku"}"#,
+            r#"{"type":"end_kyoku"}"#,
+            r#"{"type":"none"}"#,
+        ];
+
+        for line in log {
+            let event: MjaiEvent = serde_json::from_str(line).unwrap();
+            assert_eq!(serde_json::to_string(&event).unwrap(), line);
+        }
+
+        let event: MjaiEvent = serde_json::from_str(log[1]).unwrap();
+        match event {
+            MjaiEvent::StartKyoku(StartKyoku {
+                bakaze,
+                dora_marker,
+                tehais,
+                ..
+            }) => {
+                assert_eq!((bakaze, dora_marker), (TON, AKADORA_SOU));
+                assert_eq!(tehais[0], vec![II_MAN, RYAN_MAN]);
+                assert_eq!(tehais[1], vec![ANY, ANY]);
+            }
+            event => panic!("unexpected event: {:?}", event),
+        }
+    }
+
+    #[test]
+    fn should_reject_invalid_tiles() {
+        assert!(
+            serde_json::from_str::<MjaiEvent>(r#"{"type":"tsumo","actor":0,"pai":"0m"}"#).is_err()
+        );
+        assert!(serde_json::to_string(&MjaiEvent::Dora {
+            dora_marker: UNKNOWN
+        })
+        .is_err());
+    }
+}
@@ -1,5 +1,6 @@
-use std::error::Error;
-use std::fmt::{Display, Formatter};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
 
 use crate::tiles::*;
 use crate::{Hand, HandGroup, HandTile, InvalidTileError, Suite, Tile, TilePlacement, TileValue};
@@ -93,6 +94,10 @@ impl HandParser {
     ///     ]
     /// );
     /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", err(level = "debug"))
+    )]
     pub fn parse(hand: &str) -> Result<Hand, HandParseError> {
         Self::new().parse_internal(hand)
     }
@@ -109,11 +114,20 @@ impl HandParser {
         for (pos, char) in hand.chars().enumerate() {
             let result = match char {
                 '0'..='9' => self.handle_value(char),
-                SUITE_MANZU | SUITE_PINZU | SUITE_SOUZU | SUITE_HONOR => self.handle_suite(char),
-                SPECIAL_TON | SPECIAL_NAN | SPECIAL_SHAA | SPECIAL_PEI | SPECIAL_HAKU
-                | SPECIAL_HATSU | SPECIAL_CHUN | SPECIAL_ANY => self.handle_special_symbol(char),
+                SUITE_MANZU => self.handle_suite(Suite::Manzu),
+                SUITE_PINZU => self.handle_suite(Suite::Pinzu),
+                SUITE_SOUZU => self.handle_suite(Suite::Souzu),
+                SUITE_HONOR => self.handle_suite(Suite::Honor),
+                SPECIAL_TON => self.add_temp_tile(TON, TilePlacement::Normal),
+                SPECIAL_NAN => self.add_temp_tile(NAN, TilePlacement::Normal),
+                SPECIAL_SHAA => self.add_temp_tile(SHAA, TilePlacement::Normal),
+                SPECIAL_PEI => self.add_temp_tile(PEI, TilePlacement::Normal),
+                SPECIAL_HAKU => self.add_temp_tile(HAKU, TilePlacement::Normal),
+                SPECIAL_HATSU => self.add_temp_tile(HATSU, TilePlacement::Normal),
+                SPECIAL_CHUN => self.add_temp_tile(CHUN, TilePlacement::Normal),
+                SPECIAL_ANY => self.add_temp_tile(ANY, TilePlacement::Normal),
                 POSITION_MODIFIER_ASTERISK | POSITION_MODIFIER_APOSTROPHE => {
-                    self.handle_position_modifier()
+                    self.handle_position_modifier(char)
                 }
                 GROUP_SEPARATOR => self.handle_group_separator(),
                 _ => Err(HandParseErrorType::InvalidCharacter),
@@ -139,15 +153,7 @@ impl HandParser {
         Ok(())
     }
 
-    fn handle_suite(&mut self, suite_char: char) -> HandParseResult {
-        let suite = match suite_char {
-            SUITE_MANZU => Suite::Manzu,
-            SUITE_PINZU => Suite::Pinzu,
-            SUITE_SOUZU => Suite::Souzu,
-            SUITE_HONOR => Suite::Honor,
-            _ => unreachable!(),
-        };
-
+    fn handle_suite(&mut self, suite: Suite) -> HandParseResult {
         for (tile_suite, _, _) in &mut self.new_tiles {
             *tile_suite = Some(tile_suite.unwrap_or(suite));
         }
@@ -159,7 +165,7 @@ impl HandParser {
 
     fn add_remaining_tiles(&mut self) -> HandParseResult {
         let mut new_tiles = Vec::new();
-        std::mem::swap(&mut new_tiles, &mut self.new_tiles);
+        core::mem::swap(&mut new_tiles, &mut self.new_tiles);
 
         for (actual_suite, value, placement) in new_tiles {
             let suite = actual_suite.ok_or(HandParseErrorType::UnfinishedSuite)?;
@@ -169,28 +175,14 @@ impl HandParser {
         Ok(())
     }
 
-    fn handle_special_symbol(&mut self, honor: char) -> HandParseResult {
-        match honor {
-            SPECIAL_TON => self.add_temp_tile(TON, TilePlacement::Normal)?,
-            SPECIAL_NAN => self.add_temp_tile(NAN, TilePlacement::Normal)?,
-            SPECIAL_SHAA => self.add_temp_tile(SHAA, TilePlacement::Normal)?,
-            SPECIAL_PEI => self.add_temp_tile(PEI, TilePlacement::Normal)?,
-            SPECIAL_HAKU => self.add_temp_tile(HAKU, TilePlacement::Normal)?,
-            SPECIAL_HATSU => self.add_temp_tile(HATSU, TilePlacement::Normal)?,
-            SPECIAL_CHUN => self.add_temp_tile(CHUN, TilePlacement::Normal)?,
-            SPECIAL_ANY => self.add_temp_tile(ANY, TilePlacement::Normal)?,
-            _ => unreachable!(),
-        }
-
-        Ok(())
-    }
-
-    fn handle_position_modifier(&mut self) -> HandParseResult {
+    fn handle_position_modifier(&mut self, modifier: char) -> HandParseResult {
         let last_tile = self.new_tiles.last_mut();
 
         if let Some(tile) = last_tile {
             let placement = &mut tile.2;
-            *placement = placement.next();
+            *placement = placement
+                .with_modifier(modifier)
+                .ok_or(HandParseErrorType::InvalidCharacter)?;
             Ok(())
         } else {
             Err(HandParseErrorType::PositionModifierWithNoTile)
@@ -211,10 +203,11 @@ impl HandParser {
     }
 
     fn add_tile(&mut self, tile: Tile, placement: TilePlacement) -> HandParseResult {
-        self.groups
-            .last_mut()
-            .expect("List of groups is empty")
-            .push(HandTile::new(tile, placement));
+        let hand_tile = HandTile::new(tile, placement);
+        match self.groups.last_mut() {
+            Some(group) => group.push(hand_tile),
+            None => self.groups.push(vec![hand_tile]),
+        }
 
         Ok(())
     }
@@ -229,7 +222,8 @@ pub struct HandParseError {
     error_type: HandParseErrorType,
 }
 
-impl Error for HandParseError {}
+#[cfg(feature = "std")]
+impl std::error::Error for HandParseError {}
 
 impl HandParseError {
     /// Creates a new [HandParseError] instance.
@@ -242,7 +236,7 @@ impl HandParseError {
 }
 
 impl Display for HandParseError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
             "error when parsing hand at position {}: {}",
@@ -267,7 +261,8 @@ pub enum HandParseErrorType {
     PositionModifierWithNoTile,
 }
 
-impl Error for HandParseErrorType {}
+#[cfg(feature = "std")]
+impl std::error::Error for HandParseErrorType {}
 
 impl From<InvalidTileError> for HandParseErrorType {
     fn from(_: InvalidTileError) -> Self {
@@ -276,7 +271,7 @@ impl From<InvalidTileError> for HandParseErrorType {
 }
 
 impl Display for HandParseErrorType {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         let s = match self {
             HandParseErrorType::InvalidCharacter => "invalid character",
             HandParseErrorType::InvalidValue => "invalid tile value",
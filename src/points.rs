@@ -1,11 +1,79 @@
-use std::error::Error;
-use std::fmt::{Display, Formatter};
-use std::ops::{Add, Div, Mul, Neg, RangeFrom, RangeInclusive};
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, RangeFrom, RangeInclusive};
+
+use num_traits::{CheckedAdd, CheckedMul, FromPrimitive, Pow, Signed};
+
+use crate::locale::Locale;
+pub use fu::{FuCalculation, FuComponent, FuHand, FuMeld, MeldShape, Wait};
+pub use score::{DoraCount, ScoreBreakdown, YakuEntry};
+pub use table::{PointsTable, PointsTableEntry, PointsTableLimitEntry};
+
+mod fu;
+mod score;
+mod table;
+
+macro_rules! impl_arithmetic {
+    ($type:ident) => {
+        impl $type {
+            #[doc = concat!(
+                "Adds two `", stringify!($type), "` values, saturating at the numeric bounds ",
+                "instead of overflowing."
+            )]
+            #[inline]
+            #[must_use]
+            pub const fn saturating_add(self, rhs: Self) -> Self {
+                Self(self.0.saturating_add(rhs.0))
+            }
+
+            #[doc = concat!(
+                "Subtracts two `", stringify!($type), "` values, saturating at the numeric ",
+                "bounds instead of overflowing."
+            )]
+            #[inline]
+            #[must_use]
+            pub const fn saturating_sub(self, rhs: Self) -> Self {
+                Self(self.0.saturating_sub(rhs.0))
+            }
+        }
+
+        impl Add for $type {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Self(self.0 + rhs.0)
+            }
+        }
 
-use num_traits::{Pow, Signed};
+        impl AddAssign for $type {
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl Sum for $type {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self(0), Add::add)
+            }
+        }
+
+        impl<'a> Sum<&'a $type> for $type {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().sum()
+            }
+        }
+    };
+}
 
 /// Number of han (big) points.
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 #[repr(transparent)]
 pub struct Han(i32);
 
@@ -48,13 +116,16 @@ impl<T: Into<i32>> From<T> for Han {
 }
 
 impl Display for Han {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "{} han", self.0)
     }
 }
 
 /// Number of fu (small) points.
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 #[repr(transparent)]
 pub struct Fu(i32);
 
@@ -97,13 +168,16 @@ impl<T: Into<i32>> From<T> for Fu {
 }
 
 impl Display for Fu {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "{} fu", self.0)
     }
 }
 
 /// Number of honbas (counter sticks).
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 #[repr(transparent)]
 pub struct Honbas(i32);
 
@@ -158,7 +232,7 @@ impl<T: Into<i32>> From<T> for Honbas {
 }
 
 impl Display for Honbas {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "{} honbas", self.0)
     }
 }
@@ -169,7 +243,16 @@ impl Default for Honbas {
     }
 }
 
+impl_arithmetic!(Han);
+impl_arithmetic!(Fu);
+impl_arithmetic!(Honbas);
+
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(tag = "kind", rename_all = "snake_case")
+)]
 enum PointsMode {
     Calculated { has_tsumo: bool, has_ron: bool },
     Limited,
@@ -195,6 +278,49 @@ impl PointsMode {
     }
 }
 
+/// Internally tagged representation of [PointsMode], used by the
+/// human-readable formats (e.g. JSON).
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "PointsMode", tag = "kind", rename_all = "snake_case")]
+enum TaggedPointsMode {
+    Calculated { has_tsumo: bool, has_ron: bool },
+    Limited,
+}
+
+/// Externally tagged representation of [PointsMode], used by the compact
+/// binary formats (e.g. postcard or bincode), which do not support internally
+/// tagged enums.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "PointsMode")]
+enum CompactPointsMode {
+    Calculated { has_tsumo: bool, has_ron: bool },
+    Limited,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PointsMode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            TaggedPointsMode::serialize(self, serializer)
+        } else {
+            CompactPointsMode::serialize(self, serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PointsMode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            TaggedPointsMode::deserialize(deserializer)
+        } else {
+            CompactPointsMode::deserialize(deserializer)
+        }
+    }
+}
+
 /// Number of (scoring) points.
 ///
 /// This struct can be constructed using so-called base points. Base points are
@@ -223,9 +349,13 @@ pub type Points = PointsCustom<i32>;
 /// Normally, [`Points`] type alias should be used instead of using this type
 /// directly.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PointsCustom<T> {
     base_points: T,
     honbas: Honbas,
+    #[cfg_attr(feature = "serde", serde(default))]
+    honba_value: HonbaValue,
     mode: PointsMode,
 }
 
@@ -246,6 +376,16 @@ where
     /// There are different modes for calculating the points; see
     /// [`PointsCalculationMode`] documentation for more details.
     ///
+    /// This method does not check for overflows, and it deliberately does not
+    /// saturate either: it also supports the base types that have no maximum
+    /// value (such as `BigInt`). With fixed-width base types (such as [`i32`])
+    /// and high han values in the [`PointsCalculationMode::Unlimited`] mode,
+    /// the calculation follows the overflow behavior of the base type's
+    /// arithmetic: it panics in debug builds and wraps around in release
+    /// builds. Use [`PointsCustom::checked_from_calculated`] to get an error
+    /// instead (and e.g. fall back to a maximum value of your choice), or an
+    /// arbitrary-precision base type.
+    ///
     /// # Examples
     /// ```
     /// use riichi_hand::points::{Fu, Han, Honbas, Points, PointsCalculationMode};
@@ -280,29 +420,93 @@ where
         fu: Fu,
         honbas: Honbas,
     ) -> Result<Self, PointCalculationError> {
-        if calculation_mode == PointsCalculationMode::Default {
-            if han < Han::new(1) {
-                return Err(PointCalculationError::InvalidHan(han));
-            }
-            if !VALID_FU.contains(&fu) {
-                return Err(PointCalculationError::InvalidFu(fu));
-            }
-            if honbas < Honbas::ZERO {
-                return Err(PointCalculationError::InvalidHonbas(honbas));
-            }
-        }
+        Self::from_calculated_with_ruleset(calculation_mode, &Ruleset::default(), han, fu, honbas)
+    }
+
+    /// Constructs an instance of `PointsCustom` by calculating the number of
+    /// points for given [`Han`] and [`Fu`] values, using given [`Ruleset`].
+    ///
+    /// See [`PointsCustom::from_calculated`] for more details.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Fu, Han, Honbas, Points, PointsCalculationMode, Ruleset};
+    ///
+    /// let ruleset = Ruleset::default().with_kiriage_mangan(true);
+    /// let points = Points::from_calculated_with_ruleset(
+    ///     PointsCalculationMode::Default,
+    ///     &ruleset,
+    ///     Han::new(4),
+    ///     Fu::new(30),
+    ///     Honbas::ZERO
+    /// ).unwrap();
+    /// assert_eq!(points.ko_ron().unwrap(), 8000);
+    /// ```
+    pub fn from_calculated_with_ruleset(
+        calculation_mode: PointsCalculationMode,
+        ruleset: &Ruleset,
+        han: Han,
+        fu: Fu,
+        honbas: Honbas,
+    ) -> Result<Self, PointCalculationError> {
+        Self::calculate(calculation_mode, ruleset, han, fu, honbas)
+            .map(|points| points.with_honba_value(ruleset.honba_value))
+    }
+
+    /// Constructs an instance of `PointsCustom` from a list of yaku, given as
+    /// `(name, han)` pairs, and the [`Fu`] value of the hand. The han values
+    /// of the yaku are summed up, and the points are calculated using given
+    /// [`Ruleset`].
+    ///
+    /// Returns the points along with the limit the hand was scored as, if any.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Fu, Han, Honbas, Limit, Points, PointsCalculationMode, Ruleset};
+    ///
+    /// let yaku = [
+    ///     ("Riichi", Han::new(1)),
+    ///     ("Tsumo", Han::new(1)),
+    ///     ("Pinfu", Han::new(1)),
+    ///     ("Dora", Han::new(2)),
+    /// ];
+    /// let (points, limit) = Points::from_yaku_list(
+    ///     PointsCalculationMode::Default,
+    ///     &Ruleset::default(),
+    ///     &yaku,
+    ///     Fu::new(20),
+    ///     Honbas::ZERO,
+    /// ).unwrap();
+    /// assert_eq!(limit, Some(Limit::Mangan));
+    /// assert_eq!(points.ko_tsumo().unwrap(), (2000, 4000));
+    /// ```
+    pub fn from_yaku_list(
+        calculation_mode: PointsCalculationMode,
+        ruleset: &Ruleset,
+        yaku: &[(&str, Han)],
+        fu: Fu,
+        honbas: Honbas,
+    ) -> Result<(Self, Option<Limit>), PointCalculationError> {
+        let han = yaku.iter().map(|&(_, han)| han).sum();
+        let points =
+            Self::from_calculated_with_ruleset(calculation_mode, ruleset, han, fu, honbas)?;
+        let limit = points.limit();
+
+        Ok((points, limit))
+    }
+
+    fn calculate(
+        calculation_mode: PointsCalculationMode,
+        ruleset: &Ruleset,
+        han: Han,
+        fu: Fu,
+        honbas: Honbas,
+    ) -> Result<Self, PointCalculationError> {
+        validate(calculation_mode, han, fu, honbas)?;
 
         if calculation_mode != PointsCalculationMode::Unlimited {
-            if MANGAN_HAN_RANGE.contains(&han) {
-                return Ok(Self::mangan(honbas));
-            } else if HANEMAN_HAN_RANGE.contains(&han) {
-                return Ok(Self::haneman(honbas));
-            } else if BAIMAN_HAN_RANGE.contains(&han) {
-                return Ok(Self::baiman(honbas));
-            } else if SANBAIMAN_HAN_RANGE.contains(&han) {
-                return Ok(Self::sanbaiman(honbas));
-            } else if KAZOE_YAKUMAN_HAN_RANGE.contains(&han) {
-                return Ok(Self::yakuman(honbas));
+            if let Some(limit) = Limit::from_han(han) {
+                return Ok(Self::new_limited(limit.base_points().into(), honbas));
             }
         }
 
@@ -322,7 +526,8 @@ where
             };
             T::from(value as i32)
         };
-        if calculation_mode != PointsCalculationMode::Unlimited && points_base >= T::from(7900 / 4)
+        if calculation_mode != PointsCalculationMode::Unlimited
+            && points_base >= T::from(mangan_threshold(ruleset))
         {
             Ok(Self::mangan(honbas))
         } else {
@@ -337,164 +542,461 @@ where
     }
 }
 
+/// Overflow-checked point calculation for fixed-width base types.
 impl<T> PointsCustom<T>
 where
     T: Clone,
     T: Signed,
     T: From<i32>,
+    T: PartialOrd<T>,
     T: Add<i32, Output = T>,
     T: Mul<i32, Output = T>,
     T: Div<i32, Output = T>,
+    T: Pow<u32, Output = T>,
+    T: CheckedAdd,
+    T: CheckedMul,
 {
-    /// Constructs a new instance of `PointsCustom`, marking it as limited
-    /// (i.e. mangan or above) with given number of honbas.
+    /// Constructs an instance of `PointsCustom` by calculating the number of
+    /// points for given [`Han`] and [`Fu`] values, returning
+    /// [`PointCalculationError::Overflow`] if the base points or any of the
+    /// payments cannot be represented by the base type.
+    ///
+    /// See [`PointsCustom::from_calculated`] for more details.
     ///
     /// # Examples
     /// ```
-    /// use riichi_hand::points::{Honbas, Points};
+    /// use riichi_hand::points::{
+    ///     Fu, Han, Honbas, PointCalculationError, Points, PointsCalculationMode,
+    /// };
     ///
-    /// let points = Points::new_limited(2000, Honbas::ZERO);
-    /// assert_eq!(points.is_limited(), true);
-    /// assert_eq!(points.ko_ron().unwrap(), 8000);
+    /// let points = Points::checked_from_calculated(
+    ///     PointsCalculationMode::Unlimited,
+    ///     Han::new(15),
+    ///     Fu::new(50),
+    ///     Honbas::ZERO
+    /// ).unwrap();
+    /// assert_eq!(points.ko_ron().unwrap(), 26214400);
+    ///
+    /// let error = Points::checked_from_calculated(
+    ///     PointsCalculationMode::Unlimited,
+    ///     Han::new(40),
+    ///     Fu::new(50),
+    ///     Honbas::ZERO
+    /// ).unwrap_err();
+    /// assert!(matches!(error, PointCalculationError::Overflow));
     /// ```
-    #[inline]
-    #[must_use]
-    pub const fn new_limited(base_points: T, honbas: Honbas) -> Self {
-        Self {
-            base_points,
-            mode: PointsMode::Limited,
+    pub fn checked_from_calculated(
+        calculation_mode: PointsCalculationMode,
+        han: Han,
+        fu: Fu,
+        honbas: Honbas,
+    ) -> Result<Self, PointCalculationError> {
+        Self::checked_from_calculated_with_ruleset(
+            calculation_mode,
+            &Ruleset::default(),
+            han,
+            fu,
             honbas,
-        }
+        )
     }
 
-    /// Constructs a new instance of `PointsCustom` with the base points value
-    /// of 2000 and given number of honbas.
-    ///
-    /// # Examples
-    /// ```
-    /// use riichi_hand::points::{Honbas, Points};
+    /// Constructs an instance of `PointsCustom` by calculating the number of
+    /// points for given [`Han`] and [`Fu`] values, using given [`Ruleset`] and
+    /// checking for overflows.
     ///
-    /// let points = Points::mangan(Honbas::ZERO);
-    /// assert_eq!(points.ko_ron().unwrap(), 8000);
-    /// assert_eq!(points.is_limited(), true);
-    /// ```
-    #[inline]
-    #[must_use]
-    pub fn mangan(honbas: Honbas) -> Self {
-        Self::new_limited(2000.into(), honbas)
+    /// See [`PointsCustom::checked_from_calculated`] for more details.
+    pub fn checked_from_calculated_with_ruleset(
+        calculation_mode: PointsCalculationMode,
+        ruleset: &Ruleset,
+        han: Han,
+        fu: Fu,
+        honbas: Honbas,
+    ) -> Result<Self, PointCalculationError> {
+        validate(calculation_mode, han, fu, honbas)?;
+        Self::check_overflow(calculation_mode, ruleset, han, fu, honbas)
+            .ok_or(PointCalculationError::Overflow)?;
+
+        Self::from_calculated_with_ruleset(calculation_mode, ruleset, han, fu, honbas)
     }
 
-    /// Constructs a new instance of `PointsCustom` with the base points value
-    /// of 3000 and given number of honbas.
+    fn check_overflow(
+        calculation_mode: PointsCalculationMode,
+        ruleset: &Ruleset,
+        han: Han,
+        fu: Fu,
+        honbas: Honbas,
+    ) -> Option<()> {
+        let power = han.0 + 2;
+        let limit = match calculation_mode {
+            PointsCalculationMode::Unlimited => None,
+            _ => Limit::from_han(han),
+        };
+        let base_points = if let Some(limit) = limit {
+            T::from(limit.base_points())
+        } else if power.is_positive() {
+            num_traits::checked_pow(T::from(2), power as usize)?.checked_mul(&T::from(fu.0))?
+        } else {
+            T::from(fu.0)
+        };
+
+        // The highest payment is the dealer ron, which is then rounded up
+        let honba_points = honbas
+            .0
+            .checked_mul(ruleset.honba_value.ron.max(ruleset.honba_value.tsumo))?;
+        base_points
+            .checked_mul(&T::from(6))?
+            .checked_add(&T::from(99))?
+            .checked_add(&T::from(honba_points))?;
+
+        Some(())
+    }
+}
+
+/// Point calculation for base types that do not require rounding, such as
+/// floating point or rational numbers.
+///
+/// Contrary to the integer-based calculations, the values returned by the
+/// methods in this block are not rounded up to the next 100. This is useful
+/// e.g. for analytical expected value computations.
+///
+/// The arithmetic is checked (see [`UnroundedBase`]), so with fixed-width
+/// base types the values that do not fit are reported instead of
+/// overflowing.
+impl<T> PointsCustom<T>
+where
+    T: Clone,
+    T: Signed,
+    T: FromPrimitive,
+    T: PartialOrd<T>,
+    T: UnroundedBase,
+{
+    /// Constructs an instance of `PointsCustom` by calculating the exact
+    /// (unrounded) number of base points for given [`Han`] and [`Fu`] values.
+    ///
+    /// Limits are applied the same way as in [`PointsCustom::from_calculated`],
+    /// but the base points of non-limited hands are not rounded in any way,
+    /// including the hands with negative han values. If any of the values used
+    /// in the calculation cannot be represented by the base type,
+    /// [`PointCalculationError::Overflow`] is returned.
     ///
     /// # Examples
     /// ```
-    /// use riichi_hand::points::{Honbas, Points};
+    /// use riichi_hand::points::{Fu, Han, Honbas, PointsCalculationMode, PointsCustom, Ruleset};
     ///
-    /// let points = Points::haneman(Honbas::ZERO);
-    /// assert_eq!(points.ko_ron().unwrap(), 12000);
-    /// assert_eq!(points.is_limited(), true);
+    /// let points = PointsCustom::<f64>::from_calculated_unrounded(
+    ///     PointsCalculationMode::Unlimited,
+    ///     &Ruleset::default(),
+    ///     Han::new(-3),
+    ///     Fu::new(30),
+    ///     Honbas::ZERO
+    /// ).unwrap();
+    /// assert_eq!(points.ko_ron_unrounded().unwrap(), 60.0);
     /// ```
-    #[inline]
-    #[must_use]
-    pub fn haneman(honbas: Honbas) -> Self {
-        Self::new_limited(3000.into(), honbas)
+    pub fn from_calculated_unrounded(
+        calculation_mode: PointsCalculationMode,
+        ruleset: &Ruleset,
+        han: Han,
+        fu: Fu,
+        honbas: Honbas,
+    ) -> Result<Self, PointCalculationError> {
+        validate(calculation_mode, han, fu, honbas)?;
+
+        let limited = |limit: Limit| {
+            Ok(Self::new_limited(from_i32(limit.base_points())?, honbas)
+                .with_honba_value(ruleset.honba_value))
+        };
+
+        if calculation_mode != PointsCalculationMode::Unlimited {
+            if let Some(limit) = Limit::from_han(han) {
+                return limited(limit);
+            }
+        }
+
+        let power = han.0 + 2;
+        let two = from_i32::<T>(2)?;
+        let multiplier = (0..power.unsigned_abs())
+            .try_fold(T::one(), |value, _| value.checked_mul(&two))
+            .ok_or(PointCalculationError::Overflow)?;
+        let points_base = if power.is_negative() {
+            from_i32::<T>(fu.0)? / multiplier
+        } else {
+            from_i32::<T>(fu.0)?
+                .checked_mul(&multiplier)
+                .ok_or(PointCalculationError::Overflow)?
+        };
+
+        if calculation_mode != PointsCalculationMode::Unlimited
+            && points_base >= from_i32(mangan_threshold(ruleset))?
+        {
+            limited(Limit::Mangan)
+        } else {
+            let val_has_tsumo =
+                calculation_mode != PointsCalculationMode::Default || has_tsumo(han, fu);
+            let val_has_ron =
+                calculation_mode != PointsCalculationMode::Default || has_ron(han, fu);
+
+            let value = Self::new_calculated(points_base, val_has_tsumo, val_has_ron, honbas)
+                .with_honba_value(ruleset.honba_value);
+            Ok(value)
+        }
     }
 
-    /// Constructs a new instance of `PointsCustom` with the base points value
-    /// of 4000 and given number of honbas.
+    /// Returns the unrounded number of points paid for the dealer on a win by
+    /// tsumo, or `None` if it is not possible or the payment cannot be
+    /// represented by the base type.
     ///
     /// # Examples
     /// ```
-    /// use riichi_hand::points::{Honbas, Points};
+    /// use riichi_hand::points::{Honbas, PointsCustom};
     ///
-    /// let points = Points::baiman(Honbas::ZERO);
-    /// assert_eq!(points.ko_ron().unwrap(), 16000);
-    /// assert_eq!(points.is_limited(), true);
+    /// let points = PointsCustom::new_calculated(960.5, true, true, Honbas::new(1));
+    /// assert_eq!(points.oya_tsumo_unrounded().unwrap(), 2021.0);
     /// ```
     #[inline]
     #[must_use]
-    pub fn baiman(honbas: Honbas) -> Self {
-        Self::new_limited(4000.into(), honbas)
+    pub fn oya_tsumo_unrounded(&self) -> Option<T> {
+        self.unrounded_payment(self.mode.has_tsumo(), 2, self.tsumo_honba_points())
     }
 
-    /// Constructs a new instance of `PointsCustom` with the base points value
-    /// of 6000 and given number of honbas.
+    /// Returns the unrounded number of points paid for the dealer on a win by
+    /// ron, or `None` if it is not possible or the payment cannot be
+    /// represented by the base type.
     ///
     /// # Examples
     /// ```
-    /// use riichi_hand::points::{Honbas, Points};
+    /// use riichi_hand::points::{Honbas, PointsCustom};
     ///
-    /// let points = Points::sanbaiman(Honbas::ZERO);
-    /// assert_eq!(points.ko_ron().unwrap(), 24000);
-    /// assert_eq!(points.is_limited(), true);
+    /// let points = PointsCustom::new_calculated(960.5, true, true, Honbas::new(1));
+    /// assert_eq!(points.oya_ron_unrounded().unwrap(), 6063.0);
     /// ```
     #[inline]
     #[must_use]
-    pub fn sanbaiman(honbas: Honbas) -> Self {
-        Self::new_limited(6000.into(), honbas)
+    pub fn oya_ron_unrounded(&self) -> Option<T> {
+        self.unrounded_payment(self.mode.has_ron(), 6, self.ron_honba_points())
     }
 
-    /// Constructs a new instance of `PointsCustom` with the base points value
-    /// of 8000 and given number of honbas.
+    /// Returns the unrounded number of points paid for the non-dealer on a
+    /// win by tsumo, or `None` if it is not possible or the payments cannot be
+    /// represented by the base type. The first number is the number of points
+    /// paid by non-dealers, and the second is paid by the dealer.
     ///
     /// # Examples
     /// ```
-    /// use riichi_hand::points::{Honbas, Points};
+    /// use riichi_hand::points::{Honbas, PointsCustom};
     ///
-    /// let points = Points::yakuman(Honbas::ZERO);
-    /// assert_eq!(points.ko_ron().unwrap(), 32000);
-    /// assert_eq!(points.is_limited(), true);
+    /// let points = PointsCustom::new_calculated(960.5, true, true, Honbas::new(1));
+    /// assert_eq!(points.ko_tsumo_unrounded().unwrap(), (1060.5, 2021.0));
     /// ```
     #[inline]
     #[must_use]
-    pub fn yakuman(honbas: Honbas) -> Self {
-        Self::new_limited(8000.into(), honbas)
+    pub fn ko_tsumo_unrounded(&self) -> Option<(T, T)> {
+        let honba_points = self.tsumo_honba_points();
+        let value_ko = self.unrounded_payment(self.mode.has_tsumo(), 1, honba_points)?;
+        let value_oya = self.unrounded_payment(self.mode.has_tsumo(), 2, honba_points)?;
+        Some((value_ko, value_oya))
     }
 
-    /// Constructs a new instance of `PointsCustom`, marking it as non-limited,
-    /// or calculated (i.e. below mangan).
-    ///
-    /// This method allows to specify whether a value for tsumo and ron is
-    /// present with `has_tsumo` and `has_ron` parameters, respectively. The
-    /// number of honbas is also required.
+    /// Returns the unrounded number of points paid for the non-dealer on a
+    /// win by ron, or `None` if it is not possible or the payment cannot be
+    /// represented by the base type.
     ///
     /// # Examples
     /// ```
-    /// use riichi_hand::points::{Honbas, Points};
+    /// use riichi_hand::points::{Honbas, PointsCustom};
     ///
-    /// // 2 han, 20 fu
-    /// let points = Points::new_calculated(320, true, false, Honbas::ZERO);
-    /// assert_eq!(points.is_calculated(), true);
-    /// assert_eq!(points.ko_tsumo().unwrap(), (400, 700));
-    /// assert_eq!(points.ko_ron().is_none(), true);
+    /// let points = PointsCustom::new_calculated(960.5, true, true, Honbas::new(1));
+    /// assert_eq!(points.ko_ron_unrounded().unwrap(), 4142.0);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn new_calculated(
-        base_points: T,
-        has_tsumo: bool,
-        has_ron: bool,
-        honbas: Honbas,
-    ) -> Self {
-        Self {
-            base_points,
-            mode: PointsMode::Calculated { has_tsumo, has_ron },
-            honbas,
-        }
+    pub fn ko_ron_unrounded(&self) -> Option<T> {
+        self.unrounded_payment(self.mode.has_ron(), 4, self.ron_honba_points())
     }
 
-    /// Returns true if the instance was constructed with
-    /// [`Points::new_limited`].
-    ///
-    /// # Examples
-    /// ```
-    /// use riichi_hand::points::{Honbas, Points};
-    ///
-    /// let points = Points::new_limited(2000, Honbas::ZERO);
-    /// assert_eq!(points.is_limited(), true);
-    /// assert_eq!(points.is_calculated(), false);
-    /// ```
+    #[inline]
+    #[must_use]
+    fn unrounded_payment(&self, present: bool, multiplier: i32, honba_points: i32) -> Option<T> {
+        if present {
+            let multiplier = from_i32(multiplier).ok()?;
+            let honba_points = from_i32(honba_points).ok()?;
+            UnroundedBase::checked_mul(&self.base_points, &multiplier)?.checked_add(&honba_points)
+        } else {
+            None
+        }
+    }
+}
+
+/// Base type of the unrounded point calculation (see
+/// [`PointsCustom::from_calculated_unrounded`]), with arithmetic that returns
+/// `None` instead of overflowing.
+///
+/// Implemented for the primitive integer and floating point types (where the
+/// infinite and NaN results are considered overflows), and, with the
+/// `rational` feature, for `num_rational::Ratio`.
+pub trait UnroundedBase: Sized {
+    /// Multiplies two numbers, or returns `None` if the result cannot be
+    /// represented.
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+
+    /// Adds two numbers, or returns `None` if the result cannot be
+    /// represented.
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+}
+
+macro_rules! impl_unrounded_base_int {
+    ($($t:ty),*) => {$(
+        impl UnroundedBase for $t {
+            #[inline]
+            fn checked_mul(&self, other: &Self) -> Option<Self> {
+                <$t>::checked_mul(*self, *other)
+            }
+
+            #[inline]
+            fn checked_add(&self, other: &Self) -> Option<Self> {
+                <$t>::checked_add(*self, *other)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_unrounded_base_float {
+    ($($t:ty),*) => {$(
+        impl UnroundedBase for $t {
+            #[inline]
+            fn checked_mul(&self, other: &Self) -> Option<Self> {
+                Some(self * other).filter(|value| value.is_finite())
+            }
+
+            #[inline]
+            fn checked_add(&self, other: &Self) -> Option<Self> {
+                Some(self + other).filter(|value| value.is_finite())
+            }
+        }
+    )*};
+}
+
+impl_unrounded_base_int!(i8, i16, i32, i64, i128, isize);
+impl_unrounded_base_float!(f32, f64);
+
+#[cfg(feature = "rational")]
+impl<T> UnroundedBase for num_rational::Ratio<T>
+where
+    Self: CheckedMul + CheckedAdd,
+{
+    #[inline]
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        CheckedMul::checked_mul(self, other)
+    }
+
+    #[inline]
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        CheckedAdd::checked_add(self, other)
+    }
+}
+
+#[inline]
+fn from_i32<T: FromPrimitive>(value: i32) -> Result<T, PointCalculationError> {
+    T::from_i32(value).ok_or(PointCalculationError::Overflow)
+}
+
+fn validate(
+    calculation_mode: PointsCalculationMode,
+    han: Han,
+    fu: Fu,
+    honbas: Honbas,
+) -> Result<(), PointCalculationError> {
+    if calculation_mode == PointsCalculationMode::Default {
+        if han < Han::new(1) {
+            return Err(PointCalculationError::InvalidHan(han));
+        }
+        if !VALID_FU.contains(&fu) {
+            return Err(PointCalculationError::InvalidFu(fu));
+        }
+        if honbas < Honbas::ZERO {
+            return Err(PointCalculationError::InvalidHonbas(honbas));
+        }
+    }
+
+    Ok(())
+}
+
+#[inline]
+#[must_use]
+fn mangan_threshold(ruleset: &Ruleset) -> i32 {
+    if ruleset.kiriage_mangan {
+        KIRIAGE_MANGAN_BASE_POINTS
+    } else {
+        MANGAN_BASE_POINTS
+    }
+}
+
+impl<T> PointsCustom<T> {
+    /// Constructs a new instance of `PointsCustom`, marking it as limited
+    /// (i.e. mangan or above) with given number of honbas.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Honbas, Points};
+    ///
+    /// let points = Points::new_limited(2000, Honbas::ZERO);
+    /// assert_eq!(points.is_limited(), true);
+    /// assert_eq!(points.ko_ron().unwrap(), 8000);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn new_limited(base_points: T, honbas: Honbas) -> Self {
+        Self {
+            base_points,
+            mode: PointsMode::Limited,
+            honbas,
+            honba_value: HonbaValue::STANDARD,
+        }
+    }
+
+    /// Constructs a new instance of `PointsCustom`, marking it as non-limited,
+    /// or calculated (i.e. below mangan).
+    ///
+    /// This method allows to specify whether a value for tsumo and ron is
+    /// present with `has_tsumo` and `has_ron` parameters, respectively. The
+    /// number of honbas is also required.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Honbas, Points};
+    ///
+    /// // 2 han, 20 fu
+    /// let points = Points::new_calculated(320, true, false, Honbas::ZERO);
+    /// assert_eq!(points.is_calculated(), true);
+    /// assert_eq!(points.ko_tsumo().unwrap(), (400, 700));
+    /// assert_eq!(points.ko_ron().is_none(), true);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn new_calculated(
+        base_points: T,
+        has_tsumo: bool,
+        has_ron: bool,
+        honbas: Honbas,
+    ) -> Self {
+        Self {
+            base_points,
+            mode: PointsMode::Calculated { has_tsumo, has_ron },
+            honbas,
+            honba_value: HonbaValue::STANDARD,
+        }
+    }
+
+    /// Returns true if the instance was constructed with
+    /// [`Points::new_limited`].
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Honbas, Points};
+    ///
+    /// let points = Points::new_limited(2000, Honbas::ZERO);
+    /// assert_eq!(points.is_limited(), true);
+    /// assert_eq!(points.is_calculated(), false);
+    /// ```
     #[inline]
     #[must_use]
     pub const fn is_limited(&self) -> bool {
@@ -524,165 +1026,971 @@ where
         }
     }
 
-    /// Returns the number of points paid for the dealer on a win by tsumo.
+    /// Returns the number of honbas passed when creating the value.
     ///
     /// # Examples
     /// ```
     /// use riichi_hand::points::{Honbas, Points};
     ///
-    /// let points = Points::mangan(Honbas::ZERO);
-    /// assert_eq!(points.oya_tsumo().unwrap(), 4000);
+    /// let points = Points::mangan(Honbas::new(3));
+    /// assert_eq!(points.ko_ron().unwrap(), 8900);
+    /// assert_eq!(points.honbas().get(), 3);
     /// ```
     #[inline]
     #[must_use]
-    pub fn oya_tsumo(&self) -> Option<T> {
-        if self.mode.has_tsumo() {
-            let value = round_up_points(self.base_points.clone() * 2) + self.tsumo_honba_points();
-            Some(value)
-        } else {
-            None
+    pub fn honbas(&self) -> Honbas {
+        self.honbas
+    }
+
+    /// Returns a copy of the instance with given number of honbas.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Honbas, Points};
+    ///
+    /// let points = Points::mangan(Honbas::ZERO).with_honbas(Honbas::new(2));
+    /// assert_eq!(points.honbas(), Honbas::new(2));
+    /// assert_eq!(points.ko_ron().unwrap(), 8600);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_honbas(self, honbas: Honbas) -> Self {
+        Self { honbas, ..self }
+    }
+
+    /// Returns a copy of the instance that uses given number of points paid
+    /// per honba.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{HonbaValue, Honbas, Points};
+    ///
+    /// let points = Points::mangan(Honbas::new(2)).with_honba_value(HonbaValue::new(1500, 500));
+    /// assert_eq!(points.ko_ron().unwrap(), 11000);
+    /// assert_eq!(points.ko_tsumo().unwrap(), (3000, 5000));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_honba_value(self, honba_value: HonbaValue) -> Self {
+        Self {
+            honba_value,
+            ..self
         }
     }
 
-    /// Returns the number of points paid for the dealer on a win by ron.
+    /// Returns the number of points paid per honba.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{HonbaValue, Honbas, Points};
+    ///
+    /// let points = Points::mangan(Honbas::new(2));
+    /// assert_eq!(points.honba_value(), HonbaValue::STANDARD);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn honba_value(&self) -> HonbaValue {
+        self.honba_value
+    }
+
+    #[inline]
+    #[must_use]
+    fn tsumo_honba_points(&self) -> i32 {
+        self.honbas.get() * self.honba_value.tsumo
+    }
+
+    #[inline]
+    #[must_use]
+    fn ron_honba_points(&self) -> i32 {
+        self.honbas.get() * self.honba_value.ron
+    }
+}
+
+impl<T> PointsCustom<T>
+where
+    T: Clone,
+    T: Signed,
+    T: From<i32>,
+    T: Add<i32, Output = T>,
+    T: Mul<i32, Output = T>,
+    T: Div<i32, Output = T>,
+{
+    /// Constructs a new instance of `PointsCustom` with the base points value
+    /// of 2000 and given number of honbas.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Honbas, Points};
+    ///
+    /// let points = Points::mangan(Honbas::ZERO);
+    /// assert_eq!(points.ko_ron().unwrap(), 8000);
+    /// assert_eq!(points.is_limited(), true);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn mangan(honbas: Honbas) -> Self {
+        Self::new_limited(2000.into(), honbas)
+    }
+
+    /// Constructs a new instance of `PointsCustom` with the base points value
+    /// of 3000 and given number of honbas.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Honbas, Points};
+    ///
+    /// let points = Points::haneman(Honbas::ZERO);
+    /// assert_eq!(points.ko_ron().unwrap(), 12000);
+    /// assert_eq!(points.is_limited(), true);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn haneman(honbas: Honbas) -> Self {
+        Self::new_limited(3000.into(), honbas)
+    }
+
+    /// Constructs a new instance of `PointsCustom` with the base points value
+    /// of 4000 and given number of honbas.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Honbas, Points};
+    ///
+    /// let points = Points::baiman(Honbas::ZERO);
+    /// assert_eq!(points.ko_ron().unwrap(), 16000);
+    /// assert_eq!(points.is_limited(), true);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn baiman(honbas: Honbas) -> Self {
+        Self::new_limited(4000.into(), honbas)
+    }
+
+    /// Constructs a new instance of `PointsCustom` with the base points value
+    /// of 6000 and given number of honbas.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Honbas, Points};
+    ///
+    /// let points = Points::sanbaiman(Honbas::ZERO);
+    /// assert_eq!(points.ko_ron().unwrap(), 24000);
+    /// assert_eq!(points.is_limited(), true);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sanbaiman(honbas: Honbas) -> Self {
+        Self::new_limited(6000.into(), honbas)
+    }
+
+    /// Constructs a new instance of `PointsCustom` with the base points value
+    /// of 8000 and given number of honbas.
     ///
     /// # Examples
     /// ```
     /// use riichi_hand::points::{Honbas, Points};
     ///
-    /// let points = Points::mangan(Honbas::ZERO);
-    /// assert_eq!(points.oya_ron().unwrap(), 12000);
+    /// let points = Points::yakuman(Honbas::ZERO);
+    /// assert_eq!(points.ko_ron().unwrap(), 32000);
+    /// assert_eq!(points.is_limited(), true);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn yakuman(honbas: Honbas) -> Self {
+        Self::new_limited(8000.into(), honbas)
+    }
+
+    /// Returns the number of points paid for the dealer on a win by tsumo.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Honbas, Points};
+    ///
+    /// let points = Points::mangan(Honbas::ZERO);
+    /// assert_eq!(points.oya_tsumo().unwrap(), 4000);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn oya_tsumo(&self) -> Option<T> {
+        if self.mode.has_tsumo() {
+            let value = round_up_points(self.base_points.clone() * 2) + self.tsumo_honba_points();
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of points paid for the dealer on a win by ron.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Honbas, Points};
+    ///
+    /// let points = Points::mangan(Honbas::ZERO);
+    /// assert_eq!(points.oya_ron().unwrap(), 12000);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn oya_ron(&self) -> Option<T> {
+        if self.mode.has_ron() {
+            let value = round_up_points(self.base_points.clone() * 6) + self.ron_honba_points();
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of points paid for the non-dealer on a win by ron.
+    /// The first number is the number of points paid by non-dealers, and the
+    /// second is paid by the dealer.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Honbas, Points};
+    ///
+    /// let points = Points::mangan(Honbas::ZERO);
+    /// assert_eq!(points.ko_tsumo().unwrap(), (2000, 4000));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ko_tsumo(&self) -> Option<(T, T)> {
+        if self.mode.has_tsumo() {
+            let honba_points = self.tsumo_honba_points();
+            let value_ko = round_up_points(self.base_points.clone()) + honba_points;
+            let value_oya = round_up_points(self.base_points.clone() * 2) + honba_points;
+            Some((value_ko, value_oya))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of points paid for the non-dealer on a win by ron.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Honbas, Points};
+    ///
+    /// let points = Points::mangan(Honbas::ZERO);
+    /// assert_eq!(points.ko_ron().unwrap(), 8000);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ko_ron(&self) -> Option<T> {
+        if self.mode.has_ron() {
+            let value = round_up_points(self.base_points.clone() * 4) + self.ron_honba_points();
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the limit of the hand, if the instance is limited and its base
+    /// points match one of the standard limits.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Honbas, Limit, Points};
+    ///
+    /// assert_eq!(Points::haneman(Honbas::ZERO).limit(), Some(Limit::Haneman));
+    /// assert_eq!(Points::new_calculated(320, true, true, Honbas::ZERO).limit(), None);
+    /// ```
+    #[must_use]
+    pub fn limit(&self) -> Option<Limit> {
+        if !self.is_limited() {
+            return None;
+        }
+
+        ALL_LIMITS
+            .iter()
+            .copied()
+            .find(|limit| self.base_points == T::from(limit.base_points()))
+    }
+
+    /// Returns the number of points paid for given winner and win method,
+    /// formatted as a human-readable string, or `None` if such payment is not
+    /// possible (e.g. ron for 2 han, 20 fu).
+    ///
+    /// Tsumo payments for a non-dealer are formatted as `ko/oya`, and tsumo
+    /// payments for the dealer are formatted as `value all`. The name of the
+    /// limit is prepended for limited hands.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Fu, Han, Honbas, Points, PointsCalculationMode, WinMethod, Winner};
+    ///
+    /// let points = Points::mangan(Honbas::ZERO);
+    /// assert_eq!(points.summary(Winner::Ko, WinMethod::Tsumo).unwrap(), "Mangan – 2000/4000");
+    /// assert_eq!(points.summary(Winner::Oya, WinMethod::Tsumo).unwrap(), "Mangan – 4000 all");
+    ///
+    /// let points = Points::from_calculated(
+    ///     PointsCalculationMode::Default,
+    ///     Han::new(2),
+    ///     Fu::new(20),
+    ///     Honbas::ZERO
+    /// ).unwrap();
+    /// assert_eq!(points.summary(Winner::Ko, WinMethod::Tsumo).unwrap(), "400/700");
+    /// assert_eq!(points.summary(Winner::Ko, WinMethod::Ron), None);
+    /// ```
+    #[must_use]
+    pub fn summary(&self, winner: Winner, win_method: WinMethod) -> Option<String>
+    where
+        T: Display,
+    {
+        self.summary_in(winner, win_method, Locale::Romaji)
+    }
+
+    /// Returns the number of points paid for given winner and win method,
+    /// formatted as a human-readable string in given [`Locale`], or `None` if
+    /// such payment is not possible.
+    ///
+    /// See [`PointsCustom::summary`] for more details. The name of the limit is
+    /// omitted in [`Locale::Numeric`].
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::locale::Locale;
+    /// use riichi_hand::points::{Honbas, Points, WinMethod, Winner};
+    ///
+    /// let points = Points::mangan(Honbas::ZERO);
+    /// assert_eq!(
+    ///     points.summary_in(Winner::Oya, WinMethod::Tsumo, Locale::Japanese).unwrap(),
+    ///     "満貫 – 4000オール"
+    /// );
+    /// assert_eq!(
+    ///     points.summary_in(Winner::Ko, WinMethod::Tsumo, Locale::Numeric).unwrap(),
+    ///     "2000/4000"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn summary_in(
+        &self,
+        winner: Winner,
+        win_method: WinMethod,
+        locale: Locale,
+    ) -> Option<String>
+    where
+        T: Display,
+    {
+        let value = match (winner, win_method) {
+            (Winner::Oya, WinMethod::Tsumo) => {
+                let value = self.oya_tsumo()?;
+                match locale {
+                    Locale::Romaji | Locale::English => format!("{} all", value),
+                    Locale::Japanese => format!("{}オール", value),
+                    Locale::Numeric => value.to_string(),
+                }
+            }
+            (Winner::Oya, WinMethod::Ron) => self.oya_ron()?.to_string(),
+            (Winner::Ko, WinMethod::Tsumo) => {
+                let (value_ko, value_oya) = self.ko_tsumo()?;
+                format!("{}/{}", value_ko, value_oya)
+            }
+            (Winner::Ko, WinMethod::Ron) => self.ko_ron()?.to_string(),
+        };
+
+        Some(self.with_limit_prefix(value, locale))
+    }
+
+    /// Returns an object that formats the points in given [`Locale`].
+    ///
+    /// See the [`Display`] implementation of [`PointsCustom`] for the format
+    /// description.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::locale::Locale;
+    /// use riichi_hand::points::{Honbas, Points};
+    ///
+    /// let points = Points::haneman(Honbas::ZERO);
+    /// assert_eq!(points.display_in(Locale::Japanese).to_string(), "跳満 – 12000 (3000/6000)");
+    /// assert_eq!(points.display_in(Locale::English).to_string(), "Haneman – 12000 (3000/6000)");
+    /// assert_eq!(points.display_in(Locale::Numeric).to_string(), "12000 (3000/6000)");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn display_in(&self, locale: Locale) -> PointsDisplay<'_, T> {
+        PointsDisplay {
+            points: self,
+            locale,
+        }
+    }
+
+    /// Returns the payment for given winner and win method, split into the
+    /// hand value and the honba component, or `None` if such payment is not
+    /// possible (e.g. ron for 2 han, 20 fu).
+    ///
+    /// For tsumo, the values are the totals received by the winner from all
+    /// the paying players.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Fu, Han, Honbas, Points, PointsCalculationMode, WinMethod, Winner};
+    ///
+    /// let points = Points::from_calculated(
+    ///     PointsCalculationMode::Default,
+    ///     Han::new(3),
+    ///     Fu::new(60),
+    ///     Honbas::new(1),
+    /// ).unwrap();
+    /// let breakdown = points.breakdown(Winner::Ko, WinMethod::Ron).unwrap();
+    /// assert_eq!(breakdown.hand_value, 7700);
+    /// assert_eq!(breakdown.honba_points, 300);
+    /// assert_eq!(breakdown.total(), 8000);
+    /// assert_eq!(breakdown.to_string(), "7700 + 300 honba");
+    /// ```
+    #[must_use]
+    pub fn breakdown(&self, winner: Winner, win_method: WinMethod) -> Option<PaymentBreakdown<T>> {
+        let has_payment = match win_method {
+            WinMethod::Tsumo => self.mode.has_tsumo(),
+            WinMethod::Ron => self.mode.has_ron(),
+        };
+        if !has_payment {
+            return None;
+        }
+
+        let (hand_value, honba_points) = match (winner, win_method) {
+            (Winner::Oya, WinMethod::Tsumo) => {
+                let value = round_up_points(self.base_points.clone() * 2);
+                (value * 3, self.tsumo_honba_points() * 3)
+            }
+            (Winner::Oya, WinMethod::Ron) => (
+                round_up_points(self.base_points.clone() * 6),
+                self.ron_honba_points(),
+            ),
+            (Winner::Ko, WinMethod::Tsumo) => {
+                let value_ko = round_up_points(self.base_points.clone());
+                let value_oya = round_up_points(self.base_points.clone() * 2);
+                (value_ko * 2 + value_oya, self.tsumo_honba_points() * 3)
+            }
+            (Winner::Ko, WinMethod::Ron) => (
+                round_up_points(self.base_points.clone() * 4),
+                self.ron_honba_points(),
+            ),
+        };
+
+        Some(PaymentBreakdown {
+            base_points: self.base_points.clone(),
+            hand_value,
+            honba_points,
+            riichi_stick_points: 0,
+        })
+    }
+
+    fn with_limit_prefix(&self, value: String, locale: Locale) -> String {
+        match self.limit() {
+            Some(_) if locale == Locale::Numeric => value,
+            Some(limit) => format!("{} – {}", limit.name_in(locale), value),
+            None => value,
+        }
+    }
+
+    fn fmt_in(&self, f: &mut Formatter<'_>, locale: Locale) -> core::fmt::Result
+    where
+        T: Display,
+    {
+        let value = match (self.ko_ron(), self.ko_tsumo()) {
+            (Some(ron), Some((tsumo_ko, tsumo_oya))) => {
+                format!("{} ({}/{})", ron, tsumo_ko, tsumo_oya)
+            }
+            (Some(ron), None) => ron.to_string(),
+            (None, Some((tsumo_ko, tsumo_oya))) => format!("({}/{})", tsumo_ko, tsumo_oya),
+            (None, None) => match locale {
+                Locale::Romaji | Locale::English => "no payment".to_owned(),
+                Locale::Japanese => "支払いなし".to_owned(),
+                Locale::Numeric => "-".to_owned(),
+            },
+        };
+
+        f.write_str(&self.with_limit_prefix(value, locale))
+    }
+}
+
+impl Points {
+    /// Constructs a new instance of `Points` with the base points value of given
+    /// limit and given number of honbas.
+    ///
+    /// Contrary to [`PointsCustom::mangan`] and similar methods, this is a
+    /// `const fn`, so it can be used to build constant score tables.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Honbas, Limit, Points};
+    ///
+    /// const BAIMAN: Points = Points::from_limit(Limit::Baiman, Honbas::ZERO);
+    /// assert_eq!(BAIMAN, Points::baiman(Honbas::ZERO));
+    /// assert_eq!(BAIMAN.ko_ron().unwrap(), 16000);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_limit(limit: Limit, honbas: Honbas) -> Self {
+        Self::new_limited(limit.base_points(), honbas)
+    }
+
+    /// Returns all the [`Han`] and [`Fu`] pairs that produce given number of
+    /// points paid for a non-dealer win by ron, using the default calculation
+    /// mode and no honbas.
+    ///
+    /// Only han values from 1 to 13 are considered, as all higher values are
+    /// scored as a kazoe yakuman anyway. The pairs are sorted by han, then fu.
+    ///
+    /// Limit hands are returned once per han, with the lowest fu value that
+    /// reaches the limit, since any higher fu value is worth the same.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Fu, Han, Points};
+    ///
+    /// assert_eq!(
+    ///     Points::candidates_for(2600),
+    ///     vec![(Han::new(1), Fu::new(80)), (Han::new(2), Fu::new(40))]
+    /// );
+    /// assert_eq!(
+    ///     Points::candidates_for(12000),
+    ///     vec![(Han::new(6), Fu::new(20)), (Han::new(7), Fu::new(20))]
+    /// );
+    /// assert_eq!(Points::candidates_for(1234), vec![]);
+    /// ```
+    #[must_use]
+    pub fn candidates_for(ko_ron: i32) -> Vec<(Han, Fu)> {
+        let calculation_mode = PointsCalculationMode::Default;
+        let mut candidates = Vec::new();
+
+        for han in (1..=KAZOE_YAKUMAN_HAN_RANGE.start.get()).map(Han::new) {
+            for &fu in &VALID_FU {
+                let points = match Self::from_calculated(calculation_mode, han, fu, Honbas::ZERO) {
+                    Ok(points) => points,
+                    Err(_) => continue,
+                };
+                if points.ko_ron() == Some(ko_ron) {
+                    candidates.push((han, fu));
+                    if points.limit().is_some() {
+                        break;
+                    }
+                }
+            }
+        }
+
+        candidates
+    }
+}
+
+/// Formats the points as the non-dealer ron value followed by the non-dealer
+/// tsumo values in parentheses, e.g. `Mangan – 8000 (2000/4000)`. Payments that
+/// are not possible are omitted.
+impl<T> Display for PointsCustom<T>
+where
+    T: Clone,
+    T: Display,
+    T: Signed,
+    T: From<i32>,
+    T: Add<i32, Output = T>,
+    T: Mul<i32, Output = T>,
+    T: Div<i32, Output = T>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.fmt_in(f, Locale::Romaji)
+    }
+}
+
+/// Helper object that formats [`PointsCustom`] in given [`Locale`], as
+/// returned by [`PointsCustom::display_in`].
+#[derive(Debug)]
+pub struct PointsDisplay<'a, T> {
+    points: &'a PointsCustom<T>,
+    locale: Locale,
+}
+
+impl<T> Display for PointsDisplay<'_, T>
+where
+    T: Clone,
+    T: Display,
+    T: Signed,
+    T: From<i32>,
+    T: Add<i32, Output = T>,
+    T: Mul<i32, Output = T>,
+    T: Div<i32, Output = T>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.points.fmt_in(f, self.locale)
+    }
+}
+
+#[inline]
+#[must_use]
+fn round_up_points<T>(num: T) -> T
+where
+    T: Signed,
+    T: Add<i32, Output = T>,
+    T: Mul<i32, Output = T>,
+    T: Div<i32, Output = T>,
+{
+    round_up_to(num, 100)
+}
+
+#[inline]
+#[must_use]
+fn round_up_to<T>(num: T, divisor: i32) -> T
+where
+    T: Signed,
+    T: Add<i32, Output = T>,
+    T: Mul<i32, Output = T>,
+    T: Div<i32, Output = T>,
+{
+    if num.is_positive() {
+        (num + (divisor - 1)) / divisor * divisor
+    } else {
+        num / divisor * divisor
+    }
+}
+
+/// The range of [`Han`] points for a Mangan hand, no matter what the Fu value
+/// is. In other words, this only includes 5 han.
+pub const MANGAN_HAN_RANGE: RangeInclusive<Han> = Han::new(5)..=Han::new(5);
+/// The range of [`Han`] points for a Haneman hand.
+pub const HANEMAN_HAN_RANGE: RangeInclusive<Han> = Han::new(6)..=Han::new(7);
+/// The range of [`Han`] points for a Baiman hand.
+pub const BAIMAN_HAN_RANGE: RangeInclusive<Han> = Han::new(8)..=Han::new(10);
+/// The range of [`Han`] points for a Sanbaiman hand.
+pub const SANBAIMAN_HAN_RANGE: RangeInclusive<Han> = Han::new(11)..=Han::new(12);
+/// The range of [`Han`] points for a Kazoe yakuman hand.
+pub const KAZOE_YAKUMAN_HAN_RANGE: RangeFrom<Han> = Han::new(13)..;
+
+/// Limit (i.e. mangan or above) a hand can be scored as.
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Limit {
+    /// Mangan (2000 base points).
+    Mangan,
+    /// Haneman (3000 base points).
+    Haneman,
+    /// Baiman (4000 base points).
+    Baiman,
+    /// Sanbaiman (6000 base points).
+    Sanbaiman,
+    /// Yakuman, including kazoe yakuman (8000 base points).
+    Yakuman,
+}
+
+impl Limit {
+    /// Returns the limit a hand with given number of han is always scored as,
+    /// no matter what the fu value is, or `None` if the number of han is below
+    /// 5.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Han, Limit};
+    ///
+    /// const LIMIT: Option<Limit> = Limit::from_han(Han::new(7));
+    /// assert_eq!(LIMIT, Some(Limit::Haneman));
+    /// assert_eq!(Limit::from_han(Han::new(4)), None);
+    /// assert_eq!(Limit::from_han(Han::new(20)), Some(Limit::Yakuman));
+    /// ```
+    #[must_use]
+    pub const fn from_han(han: Han) -> Option<Limit> {
+        let han = han.get();
+        if han >= KAZOE_YAKUMAN_HAN_RANGE.start.get() {
+            Some(Limit::Yakuman)
+        } else if han >= SANBAIMAN_HAN_RANGE.start().get() {
+            Some(Limit::Sanbaiman)
+        } else if han >= BAIMAN_HAN_RANGE.start().get() {
+            Some(Limit::Baiman)
+        } else if han >= HANEMAN_HAN_RANGE.start().get() {
+            Some(Limit::Haneman)
+        } else if han >= MANGAN_HAN_RANGE.start().get() {
+            Some(Limit::Mangan)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of base points for this limit.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::Limit;
+    ///
+    /// assert_eq!(Limit::Mangan.base_points(), 2000);
+    /// assert_eq!(Limit::Yakuman.base_points(), 8000);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn base_points(&self) -> i32 {
+        match self {
+            Limit::Mangan => 2000,
+            Limit::Haneman => 3000,
+            Limit::Baiman => 4000,
+            Limit::Sanbaiman => 6000,
+            Limit::Yakuman => 8000,
+        }
+    }
+
+    /// Returns the name of the limit in given [`Locale`].
+    ///
+    /// Limits have no numeric representation, nor common English names, so the
+    /// romaji name is returned for [`Locale::Numeric`] and [`Locale::English`].
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::locale::Locale;
+    /// use riichi_hand::points::Limit;
+    ///
+    /// assert_eq!(Limit::Baiman.name_in(Locale::Romaji), "Baiman");
+    /// assert_eq!(Limit::Baiman.name_in(Locale::Japanese), "倍満");
+    /// ```
+    #[must_use]
+    pub const fn name_in(&self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::Romaji | Locale::Numeric | Locale::English => match self {
+                Limit::Mangan => "Mangan",
+                Limit::Haneman => "Haneman",
+                Limit::Baiman => "Baiman",
+                Limit::Sanbaiman => "Sanbaiman",
+                Limit::Yakuman => "Yakuman",
+            },
+            Locale::Japanese => match self {
+                Limit::Mangan => "満貫",
+                Limit::Haneman => "跳満",
+                Limit::Baiman => "倍満",
+                Limit::Sanbaiman => "三倍満",
+                Limit::Yakuman => "役満",
+            },
+        }
+    }
+}
+
+impl Display for Limit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name_in(Locale::Romaji))
+    }
+}
+
+const ALL_LIMITS: [Limit; 5] = [
+    Limit::Mangan,
+    Limit::Haneman,
+    Limit::Baiman,
+    Limit::Sanbaiman,
+    Limit::Yakuman,
+];
+
+/// The player who won the hand.
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Winner {
+    /// The dealer.
+    Oya,
+    /// A non-dealer.
+    Ko,
+}
+
+/// The way the hand was won.
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum WinMethod {
+    /// Win by self-draw.
+    Tsumo,
+    /// Win by discard.
+    Ron,
+}
+
+/// Itemized payment received by the winner, as returned by
+/// [`PointsCustom::breakdown`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PaymentBreakdown<T> {
+    /// Base points of the hand.
+    pub base_points: T,
+    /// Value of the hand, rounded up to the next 100, without the honba and
+    /// riichi stick components.
+    pub hand_value: T,
+    /// Number of points received for the honbas.
+    pub honba_points: i32,
+    /// Number of points received for the riichi sticks on the table.
+    pub riichi_stick_points: i32,
+}
+
+impl<T> PaymentBreakdown<T> {
+    /// Returns a copy of the breakdown that includes given number of riichi
+    /// sticks collected by the winner.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Honbas, Points, WinMethod, Winner};
+    ///
+    /// let breakdown = Points::mangan(Honbas::ZERO)
+    ///     .breakdown(Winner::Ko, WinMethod::Tsumo)
+    ///     .unwrap()
+    ///     .with_riichi_sticks(2);
+    /// assert_eq!(breakdown.riichi_stick_points, 2000);
+    /// assert_eq!(breakdown.total(), 10000);
     /// ```
     #[inline]
     #[must_use]
-    pub fn oya_ron(&self) -> Option<T> {
-        if self.mode.has_ron() {
-            let value = round_up_points(self.base_points.clone() * 6) + self.ron_honba_points();
-            Some(value)
-        } else {
-            None
+    pub fn with_riichi_sticks(self, riichi_sticks: i32) -> Self {
+        Self {
+            riichi_stick_points: riichi_sticks * RIICHI_STICK_VALUE,
+            ..self
         }
     }
 
-    /// Returns the number of points paid for the non-dealer on a win by ron.
-    /// The first number is the number of points paid by non-dealers, and the
-    /// second is paid by the dealer.
+    /// Returns the total number of points received by the winner.
     ///
     /// # Examples
     /// ```
-    /// use riichi_hand::points::{Honbas, Points};
+    /// use riichi_hand::points::{Honbas, Points, WinMethod, Winner};
     ///
-    /// let points = Points::mangan(Honbas::ZERO);
-    /// assert_eq!(points.ko_tsumo().unwrap(), (2000, 4000));
+    /// let breakdown = Points::mangan(Honbas::new(2))
+    ///     .breakdown(Winner::Oya, WinMethod::Ron)
+    ///     .unwrap();
+    /// assert_eq!(breakdown.total(), 12600);
     /// ```
     #[inline]
     #[must_use]
-    pub fn ko_tsumo(&self) -> Option<(T, T)> {
-        if self.mode.has_tsumo() {
-            let honba_points = self.tsumo_honba_points();
-            let value_ko = round_up_points(self.base_points.clone()) + honba_points;
-            let value_oya = round_up_points(self.base_points.clone() * 2) + honba_points;
-            Some((value_ko, value_oya))
-        } else {
-            None
+    pub fn total(&self) -> T
+    where
+        T: Clone,
+        T: Add<i32, Output = T>,
+    {
+        self.hand_value.clone() + self.honba_points + self.riichi_stick_points
+    }
+}
+
+/// Formats the breakdown as the hand value followed by non-zero honba and
+/// riichi stick components, e.g. `7700 + 300 honba + 1000 riichi`.
+impl<T: Display> Display for PaymentBreakdown<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.hand_value)?;
+        if self.honba_points != 0 {
+            write!(f, " + {} honba", self.honba_points)?;
         }
+        if self.riichi_stick_points != 0 {
+            write!(f, " + {} riichi", self.riichi_stick_points)?;
+        }
+
+        Ok(())
     }
+}
 
-    /// Returns the number of points paid for the non-dealer on a win by ron.
+/// Number of points paid per honba.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HonbaValue {
+    /// Number of points paid per honba by the discarding player on a win by
+    /// ron.
+    pub ron: i32,
+    /// Number of points paid per honba by each of the paying players on a win
+    /// by tsumo.
+    pub tsumo: i32,
+}
+
+impl HonbaValue {
+    /// The standard honba value: 300 points for a ron, and 100 points paid by
+    /// each player for a tsumo. `HonbaValue::STANDARD` is also the default
+    /// value.
     ///
     /// # Examples
     /// ```
-    /// use riichi_hand::points::{Honbas, Points};
+    /// use riichi_hand::points::HonbaValue;
     ///
-    /// let points = Points::mangan(Honbas::ZERO);
-    /// assert_eq!(points.ko_ron().unwrap(), 8000);
+    /// assert_eq!(HonbaValue::STANDARD, HonbaValue::new(300, 100));
+    /// assert_eq!(HonbaValue::STANDARD, HonbaValue::default());
     /// ```
-    #[inline]
-    #[must_use]
-    pub fn ko_ron(&self) -> Option<T> {
-        if self.mode.has_ron() {
-            let value = round_up_points(self.base_points.clone() * 4) + self.ron_honba_points();
-            Some(value)
-        } else {
-            None
-        }
-    }
+    pub const STANDARD: HonbaValue = HonbaValue::new(300, 100);
 
-    /// Returns the number of honbas passed when creating the value.
+    /// Constructs new `HonbaValue` object.
     ///
     /// # Examples
     /// ```
-    /// use riichi_hand::points::{Honbas, Points};
+    /// use riichi_hand::points::HonbaValue;
     ///
-    /// let points = Points::mangan(Honbas::new(3));
-    /// assert_eq!(points.ko_ron().unwrap(), 8900);
-    /// assert_eq!(points.honbas().get(), 3);
+    /// // "Bubu" honba
+    /// let honba_value = HonbaValue::new(1500, 500);
+    /// assert_eq!(honba_value.ron, 1500);
+    /// assert_eq!(honba_value.tsumo, 500);
     /// ```
     #[inline]
     #[must_use]
-    pub fn honbas(&self) -> Honbas {
-        self.honbas
+    pub const fn new(ron: i32, tsumo: i32) -> Self {
+        Self { ron, tsumo }
     }
+}
 
-    #[inline]
-    #[must_use]
-    fn tsumo_honba_points(&self) -> i32 {
-        self.honbas.get() * 100
+impl Default for HonbaValue {
+    fn default() -> Self {
+        Self::STANDARD
     }
+}
+
+/// Set of optional rules that affect the point calculation.
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[non_exhaustive]
+pub struct Ruleset {
+    /// Whether 4 han, 30 fu and 3 han, 60 fu hands are rounded up to a mangan
+    /// (kiriage mangan). Ignored in the [`PointsCalculationMode::Unlimited`]
+    /// mode.
+    pub kiriage_mangan: bool,
+    /// Number of points paid per honba.
+    pub honba_value: HonbaValue,
+}
 
+impl Ruleset {
+    /// Returns the ruleset with kiriage mangan enabled or disabled.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::Ruleset;
+    ///
+    /// let ruleset = Ruleset::default().with_kiriage_mangan(true);
+    /// assert!(ruleset.kiriage_mangan);
+    /// ```
     #[inline]
     #[must_use]
-    fn ron_honba_points(&self) -> i32 {
-        self.honbas.get() * 300
+    pub const fn with_kiriage_mangan(self, kiriage_mangan: bool) -> Self {
+        Self {
+            kiriage_mangan,
+            ..self
+        }
     }
-}
-
-#[inline]
-#[must_use]
-fn round_up_points<T>(num: T) -> T
-where
-    T: Signed,
-    T: Add<i32, Output = T>,
-    T: Mul<i32, Output = T>,
-    T: Div<i32, Output = T>,
-{
-    round_up_to(num, 100)
-}
 
-#[inline]
-#[must_use]
-fn round_up_to<T>(num: T, divisor: i32) -> T
-where
-    T: Signed,
-    T: Add<i32, Output = T>,
-    T: Mul<i32, Output = T>,
-    T: Div<i32, Output = T>,
-{
-    if num.is_positive() {
-        (num + (divisor - 1)) / divisor * divisor
-    } else {
-        num / divisor * divisor
+    /// Returns the ruleset with given number of points paid per honba.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{HonbaValue, Ruleset};
+    ///
+    /// let ruleset = Ruleset::default().with_honba_value(HonbaValue::new(1500, 500));
+    /// assert_eq!(ruleset.honba_value, HonbaValue::new(1500, 500));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_honba_value(self, honba_value: HonbaValue) -> Self {
+        Self {
+            honba_value,
+            ..self
+        }
     }
 }
 
-/// The range of [`Han`] points for a Mangan hand, no matter what the Fu value
-/// is. In other words, this only includes 5 han.
-pub const MANGAN_HAN_RANGE: RangeInclusive<Han> = Han::new(5)..=Han::new(5);
-/// The range of [`Han`] points for a Haneman hand.
-pub const HANEMAN_HAN_RANGE: RangeInclusive<Han> = Han::new(6)..=Han::new(7);
-/// The range of [`Han`] points for a Baiman hand.
-pub const BAIMAN_HAN_RANGE: RangeInclusive<Han> = Han::new(8)..=Han::new(10);
-/// The range of [`Han`] points for a Sanbaiman hand.
-pub const SANBAIMAN_HAN_RANGE: RangeInclusive<Han> = Han::new(11)..=Han::new(12);
-/// The range of [`Han`] points for a Kazoe yakuman hand.
-pub const KAZOE_YAKUMAN_HAN_RANGE: RangeFrom<Han> = Han::new(13)..;
-
 /// Point calculation mode for use with [`PointsCustom::from_calculated`].
-#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Default, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum PointsCalculationMode {
     /// Default, most strict mode. The point table is strictly followed
     /// (including missing ron/tsumo values e.g for 1 han, 20 fu), and only
     /// valid fu values can be provided.
+    #[default]
     Default,
     /// Loose mode. Contrary to the Default mode, this allows fu values to be
     /// invalid (e.g. 10 or 21), and it returns values for all possible han/fu
@@ -697,11 +2005,14 @@ pub enum PointsCalculationMode {
     Unlimited,
 }
 
-impl Default for PointsCalculationMode {
-    fn default() -> Self {
-        Self::Default
-    }
-}
+// The lowest number of base points that is scored as a mangan (rounded up to
+// 8000 for a non-dealer ron)
+const MANGAN_BASE_POINTS: i32 = 7900 / 4;
+// The number of base points of 4 han, 30 fu and 3 han, 60 fu hands
+const KIRIAGE_MANGAN_BASE_POINTS: i32 = 1920;
+
+/// Number of points a single riichi stick is worth.
+pub(crate) const RIICHI_STICK_VALUE: i32 = 1000;
 
 const VALID_FU: [Fu; 11] = [
     Fu::new(20),
@@ -744,6 +2055,9 @@ fn has_ron(han: Han, fu: Fu) -> bool {
 /// Error type returned when point calculation in
 /// [`PointsCustom::from_calculated`] fails.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum PointCalculationError {
     /// Invalid han value provided (below 1).
     /// Only returned with [`PointsCalculationMode::Default`].
@@ -754,10 +2068,14 @@ pub enum PointCalculationError {
     /// Invalid honba counter provided (below 0).
     /// Only returned with [`PointsCalculationMode::Default`].
     InvalidHonbas(Honbas),
+    /// The number of points cannot be represented by the base type.
+    /// Only returned by [`PointsCustom::checked_from_calculated`] and
+    /// [`PointsCustom::from_calculated_unrounded`].
+    Overflow,
 }
 
 impl Display for PointCalculationError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
             PointCalculationError::InvalidHan(han) => {
                 write!(f, "Han cannot be less than 1: {}", han)
@@ -768,17 +2086,28 @@ impl Display for PointCalculationError {
             PointCalculationError::InvalidHonbas(honbas) => {
                 write!(f, "Invalid honba count: {}", honbas)
             }
+            PointCalculationError::Overflow => {
+                write!(f, "Number of points overflows the base type")
+            }
         }
     }
 }
 
-impl Error for PointCalculationError {}
+#[cfg(feature = "std")]
+impl std::error::Error for PointCalculationError {}
 
 #[cfg(test)]
 mod tests {
     use num_bigint::BigInt;
+    #[cfg(feature = "rational")]
+    use num_rational::Ratio;
 
-    use crate::points::{Fu, Han, Honbas, Points, PointsCalculationMode, PointsCustom};
+    use crate::locale::Locale;
+    use crate::points::{
+        Fu, Han, HonbaValue, Honbas, Limit, PointCalculationError, Points, PointsCalculationMode,
+        PointsCustom, Ruleset, WinMethod, Winner, BAIMAN_HAN_RANGE, HANEMAN_HAN_RANGE,
+        KAZOE_YAKUMAN_HAN_RANGE, MANGAN_HAN_RANGE, SANBAIMAN_HAN_RANGE,
+    };
 
     #[derive(Debug, serde::Deserialize)]
     struct PointsRecord {
@@ -790,6 +2119,40 @@ mod tests {
         oya_ron: i32,
     }
 
+    #[test]
+    fn should_add_points_types() {
+        let yaku_han = [Han::new(1), Han::new(2), Han::new(3)];
+        assert_eq!(yaku_han.iter().sum::<Han>(), Han::new(6));
+        assert_eq!(yaku_han.iter().copied().sum::<Han>(), Han::new(6));
+        assert_eq!(Vec::<Han>::new().into_iter().sum::<Han>(), Han::new(0));
+
+        let mut fu = Fu::new(20) + Fu::new(10);
+        fu += Fu::new(2);
+        assert_eq!(fu, Fu::new(32));
+
+        let mut honbas = Honbas::ZERO;
+        honbas += Honbas::new(1);
+        assert_eq!(honbas + Honbas::new(2), Honbas::new(3));
+    }
+
+    #[test]
+    fn should_saturate_points_types() {
+        assert_eq!(
+            Han::new(i32::MAX).saturating_add(Han::new(1)),
+            Han::new(i32::MAX)
+        );
+        assert_eq!(Han::new(3).saturating_add(Han::new(2)), Han::new(5));
+        assert_eq!(
+            Fu::new(i32::MIN).saturating_sub(Fu::new(1)),
+            Fu::new(i32::MIN)
+        );
+        assert_eq!(Fu::new(30).saturating_sub(Fu::new(10)), Fu::new(20));
+        assert_eq!(
+            Honbas::new(i32::MAX).saturating_add(Honbas::new(5)),
+            Honbas::new(i32::MAX)
+        );
+    }
+
     #[test]
     fn should_fail_for_invalid_fu() {
         // Valid fu
@@ -934,6 +2297,143 @@ mod tests {
         check_points_loose_with_honbas(5, 30, 1, (2100, 4100, 8300, 12300));
     }
 
+    #[test]
+    fn should_handle_custom_honba_value() {
+        let ruleset = Ruleset::default().with_honba_value(HonbaValue::new(1500, 500));
+        let calculation_mode = PointsCalculationMode::Default;
+        let points = Points::from_calculated_with_ruleset(
+            calculation_mode,
+            &ruleset,
+            Han::new(3),
+            Fu::new(30),
+            Honbas::new(2),
+        )
+        .unwrap();
+        assert_eq!(points.honba_value(), HonbaValue::new(1500, 500));
+        assert_eq!(points.ko_ron(), Some(3900 + 3000));
+        assert_eq!(points.oya_ron(), Some(5800 + 3000));
+        assert_eq!(points.ko_tsumo(), Some((1000 + 1000, 2000 + 1000)));
+        assert_eq!(points.oya_tsumo(), Some(2000 + 1000));
+
+        let points = Points::yakuman(Honbas::new(1)).with_honba_value(HonbaValue::new(300, 150));
+        assert_eq!(points.ko_ron(), Some(32300));
+        assert_eq!(points.ko_tsumo(), Some((8150, 16150)));
+    }
+
+    #[test]
+    fn should_work_with_floats() {
+        let calculation_mode = PointsCalculationMode::Default;
+        let ruleset = Ruleset::default();
+        let points = PointsCustom::<f64>::from_calculated_unrounded(
+            calculation_mode,
+            &ruleset,
+            Han::new(3),
+            Fu::new(30),
+            Honbas::new(1),
+        )
+        .unwrap();
+        assert_eq!(points.ko_ron_unrounded(), Some(3840.0 + 300.0));
+        assert_eq!(points.oya_ron_unrounded(), Some(5760.0 + 300.0));
+        assert_eq!(points.ko_tsumo_unrounded(), Some((1060.0, 2020.0)));
+        assert_eq!(points.oya_tsumo_unrounded(), Some(2020.0));
+
+        let points = PointsCustom::<f64>::from_calculated_unrounded(
+            calculation_mode,
+            &ruleset,
+            Han::new(1),
+            Fu::new(20),
+            Honbas::ZERO,
+        )
+        .unwrap();
+        assert_eq!(points.ko_ron_unrounded(), None);
+        assert_eq!(points.ko_tsumo_unrounded(), None);
+
+        let points = PointsCustom::<f64>::from_calculated_unrounded(
+            calculation_mode,
+            &ruleset,
+            Han::new(4),
+            Fu::new(40),
+            Honbas::ZERO,
+        )
+        .unwrap();
+        assert!(points.is_limited());
+        assert_eq!(points.ko_ron_unrounded(), Some(8000.0));
+    }
+
+    #[test]
+    fn should_not_panic_when_unrounded_values_do_not_fit() {
+        let result = PointsCustom::<i8>::from_calculated_unrounded(
+            PointsCalculationMode::Default,
+            &Ruleset::default(),
+            Han::new(5),
+            Fu::new(30),
+            Honbas::ZERO,
+        );
+        assert!(matches!(result, Err(PointCalculationError::Overflow)));
+
+        let points = PointsCustom::<i16>::from_calculated_unrounded(
+            PointsCalculationMode::Default,
+            &Ruleset::default(),
+            Han::new(5),
+            Fu::new(30),
+            Honbas::new(200),
+        )
+        .unwrap();
+        assert_eq!(points.ko_ron_unrounded(), None);
+        assert_eq!(points.ko_tsumo_unrounded(), Some((22000, 24000)));
+    }
+
+    #[test]
+    fn should_not_overflow_unrounded_integers() {
+        let points = PointsCustom::<i16>::from_calculated_unrounded(
+            PointsCalculationMode::Default,
+            &Ruleset::default(),
+            Han::new(13),
+            Fu::new(30),
+            Honbas::ZERO,
+        )
+        .unwrap();
+        assert_eq!(points.oya_ron_unrounded(), None);
+        assert_eq!(points.ko_ron_unrounded(), Some(32000));
+        assert_eq!(points.ko_tsumo_unrounded(), Some((8000, 16000)));
+
+        let result = PointsCustom::<i32>::from_calculated_unrounded(
+            PointsCalculationMode::Unlimited,
+            &Ruleset::default(),
+            Han::new(40),
+            Fu::new(30),
+            Honbas::ZERO,
+        );
+        assert!(matches!(result, Err(PointCalculationError::Overflow)));
+
+        let result = PointsCustom::<f64>::from_calculated_unrounded(
+            PointsCalculationMode::Unlimited,
+            &Ruleset::default(),
+            Han::new(2000),
+            Fu::new(30),
+            Honbas::ZERO,
+        );
+        assert!(matches!(result, Err(PointCalculationError::Overflow)));
+    }
+
+    #[cfg(feature = "rational")]
+    #[test]
+    fn should_work_with_rationals() {
+        let points = PointsCustom::<Ratio<i64>>::from_calculated_unrounded(
+            PointsCalculationMode::Unlimited,
+            &Ruleset::default(),
+            Han::new(-5),
+            Fu::new(25),
+            Honbas::ZERO,
+        )
+        .unwrap();
+        assert_eq!(points.ko_ron_unrounded(), Some(Ratio::new(100, 8)));
+        assert_eq!(
+            points.ko_tsumo_unrounded(),
+            Some((Ratio::new(25, 8), Ratio::new(25, 4)))
+        );
+    }
+
     #[test]
     fn should_return_calculated() {
         let points_table = include_bytes!("points/points_table.csv");
@@ -965,6 +2465,393 @@ mod tests {
         }
     }
 
+    #[test]
+    fn should_display_points() {
+        let calculation_mode = PointsCalculationMode::Default;
+        let points = |han, fu| {
+            Points::from_calculated(calculation_mode, Han::new(han), Fu::new(fu), Honbas::ZERO)
+                .unwrap()
+                .to_string()
+        };
+
+        assert_eq!(points(5, 30), "Mangan – 8000 (2000/4000)");
+        assert_eq!(points(13, 30), "Yakuman – 32000 (8000/16000)");
+        assert_eq!(points(3, 30), "3900 (1000/2000)");
+        assert_eq!(points(2, 20), "(400/700)");
+        assert_eq!(points(2, 25), "1600");
+        assert_eq!(points(1, 20), "no payment");
+    }
+
+    #[test]
+    fn should_return_summary() {
+        let points = Points::baiman(Honbas::new(1));
+        assert_eq!(points.limit(), Some(Limit::Baiman));
+        assert_eq!(
+            points.summary(Winner::Oya, WinMethod::Ron).unwrap(),
+            "Baiman – 24300"
+        );
+        assert_eq!(
+            points.summary(Winner::Oya, WinMethod::Tsumo).unwrap(),
+            "Baiman – 8100 all"
+        );
+        assert_eq!(
+            points.summary(Winner::Ko, WinMethod::Ron).unwrap(),
+            "Baiman – 16300"
+        );
+        assert_eq!(
+            points.summary(Winner::Ko, WinMethod::Tsumo).unwrap(),
+            "Baiman – 4100/8100"
+        );
+
+        let points = Points::new_limited(16000, Honbas::ZERO);
+        assert_eq!(points.limit(), None);
+        assert_eq!(points.summary(Winner::Ko, WinMethod::Ron).unwrap(), "64000");
+
+        let points = Points::new_calculated(320, false, true, Honbas::ZERO);
+        assert_eq!(points.summary(Winner::Ko, WinMethod::Tsumo), None);
+        assert_eq!(points.summary(Winner::Ko, WinMethod::Ron).unwrap(), "1300");
+    }
+
+    #[test]
+    fn should_format_localized_points() {
+        let points = Points::yakuman(Honbas::ZERO);
+        assert_eq!(
+            points.display_in(Locale::Japanese).to_string(),
+            "役満 – 32000 (8000/16000)"
+        );
+        assert_eq!(
+            points
+                .summary_in(Winner::Oya, WinMethod::Ron, Locale::Japanese)
+                .unwrap(),
+            "役満 – 48000"
+        );
+        assert_eq!(
+            points
+                .summary_in(Winner::Oya, WinMethod::Tsumo, Locale::Numeric)
+                .unwrap(),
+            "16000"
+        );
+        assert_eq!(
+            points.display_in(Locale::Romaji).to_string(),
+            points.to_string()
+        );
+
+        let points = Points::new_calculated(320, true, false, Honbas::ZERO);
+        assert_eq!(points.display_in(Locale::Japanese).to_string(), "(400/700)");
+        assert_eq!(
+            points.summary_in(Winner::Ko, WinMethod::Ron, Locale::Japanese),
+            None
+        );
+
+        let points = Points::new_calculated(160, false, false, Honbas::ZERO);
+        assert_eq!(
+            points.display_in(Locale::Japanese).to_string(),
+            "支払いなし"
+        );
+        assert_eq!(points.display_in(Locale::Numeric).to_string(), "-");
+    }
+
+    #[test]
+    fn should_calculate_from_yaku_list() {
+        let calculation_mode = PointsCalculationMode::Default;
+        let ruleset = Ruleset::default();
+
+        let yaku = [("Riichi", Han::new(1)), ("Chiitoitsu", Han::new(2))];
+        let (points, limit) = Points::from_yaku_list(
+            calculation_mode,
+            &ruleset,
+            &yaku,
+            Fu::new(25),
+            Honbas::new(1),
+        )
+        .unwrap();
+        assert_eq!(limit, None);
+        assert_eq!(points.ko_ron(), Some(3500));
+
+        let yaku = [
+            ("Riichi", Han::new(1)),
+            ("Ippatsu", Han::new(1)),
+            ("Tanyao", Han::new(1)),
+            ("Dora", Han::new(3)),
+        ];
+        let (points, limit) =
+            Points::from_yaku_list(calculation_mode, &ruleset, &yaku, Fu::new(40), Honbas::ZERO)
+                .unwrap();
+        assert_eq!(limit, Some(Limit::Haneman));
+        assert_eq!(points.ko_ron(), Some(12000));
+
+        let ruleset = Ruleset {
+            kiriage_mangan: true,
+            ..Ruleset::default()
+        };
+        let yaku = [("Riichi", Han::new(1)), ("Dora", Han::new(3))];
+        let (_, limit) =
+            Points::from_yaku_list(calculation_mode, &ruleset, &yaku, Fu::new(30), Honbas::ZERO)
+                .unwrap();
+        assert_eq!(limit, Some(Limit::Mangan));
+
+        let (_, limit) = Points::from_yaku_list(
+            PointsCalculationMode::Unlimited,
+            &ruleset,
+            &yaku,
+            Fu::new(30),
+            Honbas::ZERO,
+        )
+        .unwrap();
+        assert_eq!(limit, None);
+
+        assert!(matches!(
+            Points::from_yaku_list(calculation_mode, &ruleset, &[], Fu::new(30), Honbas::ZERO),
+            Err(PointCalculationError::InvalidHan(han)) if han == Han::new(0)
+        ));
+    }
+
+    #[test]
+    fn should_check_overflows() {
+        let calculation_mode = PointsCalculationMode::Unlimited;
+
+        let points = Points::checked_from_calculated(
+            calculation_mode,
+            Han::new(21),
+            Fu::new(30),
+            Honbas::ZERO,
+        )
+        .unwrap();
+        assert_eq!(points.oya_ron(), Some(1509949500));
+        assert!(matches!(
+            Points::checked_from_calculated(
+                calculation_mode,
+                Han::new(22),
+                Fu::new(30),
+                Honbas::ZERO
+            ),
+            Err(PointCalculationError::Overflow)
+        ));
+        assert!(matches!(
+            Points::checked_from_calculated(
+                calculation_mode,
+                Han::new(100),
+                Fu::new(30),
+                Honbas::ZERO
+            ),
+            Err(PointCalculationError::Overflow)
+        ));
+        assert!(matches!(
+            Points::checked_from_calculated(
+                calculation_mode,
+                Han::new(21),
+                Fu::new(30),
+                Honbas::new(i32::MAX)
+            ),
+            Err(PointCalculationError::Overflow)
+        ));
+        assert!(Points::checked_from_calculated(
+            PointsCalculationMode::Default,
+            Han::new(100),
+            Fu::new(30),
+            Honbas::ZERO
+        )
+        .is_ok());
+
+        let points = PointsCustom::<BigInt>::checked_from_calculated(
+            calculation_mode,
+            Han::new(100),
+            Fu::new(30),
+            Honbas::ZERO,
+        )
+        .unwrap();
+        assert!(points.ko_ron().unwrap() > BigInt::from(i64::MAX));
+    }
+
+    #[test]
+    fn should_work_in_const_contexts() {
+        const HANEMAN_MIN_HAN: Han = *HANEMAN_HAN_RANGE.start();
+        const HANEMAN: Option<Limit> = Limit::from_han(HANEMAN_MIN_HAN);
+        const TABLE: [Points; 2] = [
+            Points::from_limit(Limit::Mangan, Honbas::ZERO),
+            Points::from_limit(Limit::Yakuman, Honbas::new(1)),
+        ];
+
+        assert_eq!(HANEMAN, Some(Limit::Haneman));
+        assert_eq!(TABLE[0], Points::mangan(Honbas::ZERO));
+        assert_eq!(TABLE[1].oya_ron(), Some(48300));
+
+        for han in 1..=20 {
+            let han = Han::new(han);
+            let expected = [
+                (&MANGAN_HAN_RANGE, Limit::Mangan),
+                (&HANEMAN_HAN_RANGE, Limit::Haneman),
+                (&BAIMAN_HAN_RANGE, Limit::Baiman),
+                (&SANBAIMAN_HAN_RANGE, Limit::Sanbaiman),
+            ]
+            .iter()
+            .find(|(range, _)| range.contains(&han))
+            .map(|&(_, limit)| limit)
+            .or_else(|| {
+                KAZOE_YAKUMAN_HAN_RANGE
+                    .contains(&han)
+                    .then_some(Limit::Yakuman)
+            });
+            assert_eq!(Limit::from_han(han), expected);
+        }
+    }
+
+    #[test]
+    fn should_return_breakdown() {
+        let points = Points::from_calculated(
+            PointsCalculationMode::Default,
+            Han::new(2),
+            Fu::new(30),
+            Honbas::new(2),
+        )
+        .unwrap();
+
+        let breakdown = points.breakdown(Winner::Ko, WinMethod::Ron).unwrap();
+        assert_eq!(breakdown.base_points, 480);
+        assert_eq!(breakdown.hand_value, 2000);
+        assert_eq!(breakdown.honba_points, 600);
+        assert_eq!(breakdown.riichi_stick_points, 0);
+        assert_eq!(Some(breakdown.total()), points.ko_ron());
+
+        let breakdown = points.breakdown(Winner::Ko, WinMethod::Tsumo).unwrap();
+        assert_eq!(breakdown.hand_value, 2000);
+        assert_eq!(breakdown.honba_points, 600);
+        assert_eq!(breakdown.to_string(), "2000 + 600 honba");
+
+        let breakdown = points.breakdown(Winner::Oya, WinMethod::Tsumo).unwrap();
+        assert_eq!(breakdown.hand_value, 3000);
+        assert_eq!(
+            Some(breakdown.total()),
+            points.oya_tsumo().map(|value| value * 3)
+        );
+
+        let breakdown = points
+            .breakdown(Winner::Oya, WinMethod::Ron)
+            .unwrap()
+            .with_riichi_sticks(3);
+        assert_eq!(breakdown.hand_value, 2900);
+        assert_eq!(breakdown.total(), 6500);
+        assert_eq!(breakdown.to_string(), "2900 + 600 honba + 3000 riichi");
+
+        let points = Points::new_calculated(320, true, false, Honbas::ZERO);
+        assert_eq!(points.breakdown(Winner::Ko, WinMethod::Ron), None);
+        let breakdown = points.breakdown(Winner::Ko, WinMethod::Tsumo).unwrap();
+        assert_eq!(breakdown.to_string(), "1500");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_serialize_points() {
+        let calculation_mode = PointsCalculationMode::Default;
+        let points =
+            Points::from_calculated(calculation_mode, Han::new(3), Fu::new(30), Honbas::new(2))
+                .unwrap();
+        let json = serde_json::to_string(&points).unwrap();
+        assert_eq!(
+            json,
+            r#"{"base_points":960,"honbas":2,"honba_value":{"ron":300,"tsumo":100},"mode":{"kind":"calculated","has_tsumo":true,"has_ron":true}}"#
+        );
+        assert_eq!(serde_json::from_str::<Points>(&json).unwrap(), points);
+
+        let points = Points::mangan(Honbas::ZERO);
+        let json = r#"{"base_points":2000,"honbas":0,"mode":{"kind":"limited"}}"#;
+        assert_eq!(serde_json::from_str::<Points>(json).unwrap(), points);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_serialize_points_compactly() {
+        let points = Points::from_calculated(
+            PointsCalculationMode::Default,
+            Han::new(3),
+            Fu::new(30),
+            Honbas::new(2),
+        )
+        .unwrap();
+        let bytes = postcard::to_allocvec(&points).unwrap();
+        assert_eq!(bytes, vec![128, 15, 4, 216, 4, 200, 1, 0, 1, 1]);
+        assert_eq!(postcard::from_bytes::<Points>(&bytes).unwrap(), points);
+
+        let points = Points::yakuman(Honbas::ZERO);
+        let bytes = postcard::to_allocvec(&points).unwrap();
+        assert_eq!(bytes, vec![128, 125, 0, 216, 4, 200, 1, 1]);
+        assert_eq!(postcard::from_bytes::<Points>(&bytes).unwrap(), points);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_serialize_points_types() {
+        assert_eq!(serde_json::to_string(&Han::new(3)).unwrap(), "3");
+        assert_eq!(serde_json::to_string(&Fu::new(30)).unwrap(), "30");
+        assert_eq!(serde_json::to_string(&Honbas::new(1)).unwrap(), "1");
+        assert_eq!(
+            serde_json::to_string(&PointsCalculationMode::Unlimited).unwrap(),
+            r#""unlimited""#
+        );
+        assert_eq!(
+            serde_json::from_str::<PointsCalculationMode>(r#""loose""#).unwrap(),
+            PointsCalculationMode::Loose
+        );
+
+        let error = Points::from_calculated(
+            PointsCalculationMode::Default,
+            Han::new(1),
+            Fu::new(35),
+            Honbas::ZERO,
+        )
+        .unwrap_err();
+        assert_eq!(
+            serde_json::to_string(&error).unwrap(),
+            r#"{"invalid_fu":35}"#
+        );
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn should_generate_json_schemas() {
+        let schema = serde_json::to_value(schemars::schema_for!(Han)).unwrap();
+        assert_eq!(schema["type"], "integer");
+
+        let schema = serde_json::to_value(schemars::schema_for!(Points)).unwrap();
+        let properties = schema["properties"].as_object().unwrap();
+        assert!(properties.contains_key("base_points"));
+        assert!(properties.contains_key("honbas"));
+        assert_eq!(properties["honbas"]["type"], "integer");
+        assert_eq!(
+            properties["honba_value"]["default"],
+            serde_json::json!({"ron": 300, "tsumo": 100})
+        );
+
+        let schema = serde_json::to_value(schemars::schema_for!(PointsCalculationMode)).unwrap();
+        assert_eq!(schema["oneOf"][2]["enum"], serde_json::json!(["unlimited"]));
+    }
+
+    #[test]
+    fn should_return_candidates_for_points() {
+        assert_eq!(
+            Points::candidates_for(7700),
+            vec![(Han::new(3), Fu::new(60)), (Han::new(4), Fu::new(30))]
+        );
+        assert_eq!(
+            Points::candidates_for(1000),
+            vec![(Han::new(1), Fu::new(30))]
+        );
+        assert_eq!(Points::candidates_for(1500), vec![]);
+
+        assert_eq!(
+            Points::candidates_for(8000),
+            vec![
+                (Han::new(3), Fu::new(70)),
+                (Han::new(4), Fu::new(40)),
+                (Han::new(5), Fu::new(20)),
+            ]
+        );
+        assert_eq!(
+            Points::candidates_for(32000),
+            vec![(Han::new(13), Fu::new(20))]
+        );
+    }
+
     #[test]
     fn should_work_with_loose_mode() {
         check_points_loose(1, 1, (100, 100, 100, 100));
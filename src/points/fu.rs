@@ -0,0 +1,529 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+
+use crate::game::Wind;
+use crate::points::{Fu, WinMethod};
+use crate::tiles::{CHUN, HAKU, HATSU, NAN, PEI, SHAA, TON};
+use crate::{Suite, Tile};
+
+/// Shape of a meld (a group of three or four tiles), as seen by the fu
+/// calculation.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum MeldShape {
+    /// Three consecutive tiles of the same suite (shuntsu).
+    Sequence,
+    /// Three identical tiles (koutsu).
+    Triplet,
+    /// Four identical tiles (kantsu).
+    Kan,
+}
+
+/// A single meld of a winning hand, as seen by the fu calculation. Unlike a
+/// [Meld](crate::meld::Meld), it only keeps what the fu depend on, and not how
+/// the meld was called.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FuMeld {
+    /// Shape of the meld.
+    pub shape: MeldShape,
+    /// The lowest tile of the meld.
+    pub tile: Tile,
+    /// Whether the meld was called from another player's discard.
+    pub open: bool,
+}
+
+impl FuMeld {
+    /// Creates a new meld.
+    #[inline]
+    #[must_use]
+    pub const fn new(shape: MeldShape, tile: Tile, open: bool) -> Self {
+        Self { shape, tile, open }
+    }
+
+    #[must_use]
+    fn fu(&self) -> i32 {
+        let base = match self.shape {
+            MeldShape::Sequence => return 0,
+            MeldShape::Triplet => 2,
+            MeldShape::Kan => 8,
+        };
+        let terminal_multiplier = if is_terminal_or_honor(self.tile) {
+            2
+        } else {
+            1
+        };
+        let closed_multiplier = if self.open { 1 } else { 2 };
+
+        base * terminal_multiplier * closed_multiplier
+    }
+}
+
+/// The wait a winning hand was completed on.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Wait {
+    /// Open wait on two sides of a sequence, e.g. `23` waiting for `1` or `4`.
+    Ryanmen,
+    /// Wait on one of two pairs, completing a triplet.
+    Shanpon,
+    /// Closed wait inside a sequence, e.g. `13` waiting for `2`.
+    Kanchan,
+    /// Edge wait, e.g. `12` waiting for `3`.
+    Penchan,
+    /// Single wait on the pair.
+    Tanki,
+}
+
+impl Wait {
+    #[inline]
+    #[must_use]
+    const fn fu(&self) -> i32 {
+        match self {
+            Wait::Ryanmen | Wait::Shanpon => 0,
+            Wait::Kanchan | Wait::Penchan | Wait::Tanki => 2,
+        }
+    }
+}
+
+impl Display for Wait {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let name = match self {
+            Wait::Ryanmen => "ryanmen",
+            Wait::Shanpon => "shanpon",
+            Wait::Kanchan => "kanchan",
+            Wait::Penchan => "penchan",
+            Wait::Tanki => "tanki",
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// Description of a standard-shaped (four melds and a pair) winning hand, used
+/// to calculate its fu value.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FuHand {
+    /// Melds of the hand, including the one completed by the winning tile.
+    pub melds: Vec<FuMeld>,
+    /// Tile of the pair.
+    pub pair: Tile,
+    /// The winning tile.
+    pub winning_tile: Tile,
+    /// The wait the hand was completed on.
+    pub wait: Wait,
+    /// The way the hand was won.
+    pub win_method: WinMethod,
+    /// Seat wind of the winner.
+    pub seat_wind: Wind,
+    /// Round wind.
+    pub round_wind: Wind,
+}
+
+/// A single item contributing to the fu value of a hand.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum FuComponent {
+    /// Base value of every hand (futei), 20 fu.
+    Base,
+    /// Fixed value of a seven pairs hand, 25 fu.
+    Chiitoitsu,
+    /// Win by ron with a closed hand (menzen kafu), 10 fu.
+    ClosedRon,
+    /// Win by tsumo, 2 fu. Not awarded for a pinfu hand.
+    Tsumo,
+    /// Open hand that would otherwise be worth 20 fu is rounded up to 30 fu.
+    OpenPinfu,
+    /// Kanchan, penchan or tanki wait, 2 fu.
+    Wait(Wait),
+    /// Pair of dragons, seat wind or round wind tiles, 2 fu. A pair of the
+    /// seat wind that is also the round wind is counted twice.
+    ValuePair(Tile),
+    /// Triplet or kan, from 2 to 32 fu depending on whether it is open and on
+    /// the tiles.
+    Meld(FuMeld),
+}
+
+impl FuComponent {
+    /// Returns the number of fu this component is worth.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Fu, FuComponent, FuMeld, MeldShape, Wait};
+    /// use riichi_hand::tiles::{CHUN, RYAN_PIN};
+    ///
+    /// assert_eq!(FuComponent::Wait(Wait::Kanchan).fu(), Fu::new(2));
+    /// assert_eq!(FuComponent::Meld(FuMeld::new(MeldShape::Triplet, RYAN_PIN, true)).fu(), Fu::new(2));
+    /// assert_eq!(FuComponent::Meld(FuMeld::new(MeldShape::Kan, CHUN, false)).fu(), Fu::new(32));
+    /// ```
+    #[must_use]
+    pub fn fu(&self) -> Fu {
+        let value = match self {
+            FuComponent::Base => 20,
+            FuComponent::Chiitoitsu => 25,
+            FuComponent::ClosedRon | FuComponent::OpenPinfu => 10,
+            FuComponent::Tsumo | FuComponent::ValuePair(_) => 2,
+            FuComponent::Wait(wait) => wait.fu(),
+            FuComponent::Meld(meld) => meld.fu(),
+        };
+
+        Fu::new(value)
+    }
+}
+
+/// Formats the component as a short description followed by its value, e.g.
+/// `closed triplet of terminals or honors +8 fu`.
+impl Display for FuComponent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FuComponent::Base => write!(f, "base"),
+            FuComponent::Chiitoitsu => write!(f, "chiitoitsu"),
+            FuComponent::ClosedRon => write!(f, "closed ron"),
+            FuComponent::Tsumo => write!(f, "tsumo"),
+            FuComponent::OpenPinfu => write!(f, "open pinfu"),
+            FuComponent::Wait(wait) => write!(f, "{} wait", wait),
+            FuComponent::ValuePair(tile) => write!(f, "pair of {}", tile),
+            FuComponent::Meld(meld) => {
+                let state = if meld.open { "open" } else { "closed" };
+                let shape = match meld.shape {
+                    MeldShape::Sequence => "sequence",
+                    MeldShape::Triplet => "triplet",
+                    MeldShape::Kan => "kan",
+                };
+                let tiles = if is_terminal_or_honor(meld.tile) {
+                    "terminals or honors"
+                } else {
+                    "simples"
+                };
+                write!(f, "{} {} of {}", state, shape, tiles)
+            }
+        }?;
+
+        write!(f, " +{}", self.fu())
+    }
+}
+
+/// Result of the fu calculation: the list of the components, along with the
+/// total value.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::game::Wind;
+/// use riichi_hand::points::{Fu, FuCalculation, FuComponent, FuHand, FuMeld, MeldShape, Wait, WinMethod};
+/// use riichi_hand::tiles::*;
+///
+/// let hand = FuHand {
+///     melds: vec![
+///         FuMeld::new(MeldShape::Sequence, II_MAN, false),
+///         FuMeld::new(MeldShape::Sequence, SAN_PIN, false),
+///         FuMeld::new(MeldShape::Triplet, KYUU_SOU, false),
+///         FuMeld::new(MeldShape::Sequence, RYAN_SOU, true),
+///     ],
+///     pair: UU_PIN,
+///     winning_tile: SAN_MAN,
+///     wait: Wait::Penchan,
+///     win_method: WinMethod::Ron,
+///     seat_wind: Wind::South,
+///     round_wind: Wind::East,
+/// };
+/// let calculation = FuCalculation::calculate(&hand);
+///
+/// assert_eq!(calculation.raw_total(), Fu::new(30));
+/// assert_eq!(calculation.total(), Fu::new(30));
+/// assert_eq!(
+///     calculation.components(),
+///     &[
+///         FuComponent::Base,
+///         FuComponent::Wait(Wait::Penchan),
+///         FuComponent::Meld(FuMeld::new(MeldShape::Triplet, KYUU_SOU, false)),
+///     ]
+/// );
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FuCalculation {
+    components: Vec<FuComponent>,
+}
+
+impl FuCalculation {
+    /// Calculates the fu value of a standard-shaped hand.
+    #[must_use]
+    pub fn calculate(hand: &FuHand) -> Self {
+        let closed = hand.melds.iter().all(|meld| !meld.open);
+        let mut components = vec![FuComponent::Base];
+
+        if hand.win_method == WinMethod::Ron && closed {
+            components.push(FuComponent::ClosedRon);
+        }
+        if hand.wait.fu() > 0 {
+            components.push(FuComponent::Wait(hand.wait));
+        }
+        for value_tile in value_tiles(hand.seat_wind, hand.round_wind) {
+            if hand.pair == value_tile {
+                components.push(FuComponent::ValuePair(value_tile));
+            }
+        }
+        for meld in &hand.melds {
+            // A triplet completed by ron on a shanpon wait is considered open
+            let completed_by_ron = hand.win_method == WinMethod::Ron
+                && hand.wait == Wait::Shanpon
+                && meld.shape == MeldShape::Triplet
+                && meld.tile == hand.winning_tile;
+            let meld = FuMeld {
+                open: meld.open || completed_by_ron,
+                ..*meld
+            };
+            if meld.fu() > 0 {
+                components.push(FuComponent::Meld(meld));
+            }
+        }
+
+        let is_pinfu_shape = components.len() == 1
+            || (components.len() == 2 && components[1] == FuComponent::ClosedRon);
+        if hand.win_method == WinMethod::Tsumo && !(closed && is_pinfu_shape) {
+            components.push(FuComponent::Tsumo);
+        }
+        if !closed && is_pinfu_shape && hand.win_method == WinMethod::Ron {
+            components.push(FuComponent::OpenPinfu);
+        }
+
+        Self { components }
+    }
+
+    /// Returns the fu calculation of a seven pairs hand, which is always
+    /// worth exactly 25 fu.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Fu, FuCalculation};
+    ///
+    /// assert_eq!(FuCalculation::chiitoitsu().total(), Fu::new(25));
+    /// ```
+    #[must_use]
+    pub fn chiitoitsu() -> Self {
+        Self {
+            components: vec![FuComponent::Chiitoitsu],
+        }
+    }
+
+    /// Returns the components contributing to the fu value.
+    #[inline]
+    #[must_use]
+    pub fn components(&self) -> &[FuComponent] {
+        &self.components
+    }
+
+    /// Returns the sum of all the components, before rounding.
+    #[must_use]
+    pub fn raw_total(&self) -> Fu {
+        self.components.iter().map(FuComponent::fu).sum()
+    }
+
+    /// Returns the fu value of the hand, rounded up to the next 10 (except for
+    /// chiitoitsu, which is always worth 25 fu).
+    #[must_use]
+    pub fn total(&self) -> Fu {
+        let raw_total = self.raw_total();
+        if self.components == [FuComponent::Chiitoitsu] {
+            raw_total
+        } else {
+            Fu::new((raw_total.get() + 9) / 10 * 10)
+        }
+    }
+}
+
+#[must_use]
+fn is_terminal_or_honor(tile: Tile) -> bool {
+    match tile.suite {
+        Suite::Manzu | Suite::Pinzu | Suite::Souzu => tile.value.0 == 1 || tile.value.0 == 9,
+        Suite::Honor | Suite::Any => true,
+    }
+}
+
+#[must_use]
+fn value_tiles(seat_wind: Wind, round_wind: Wind) -> [Tile; 5] {
+    [
+        HAKU,
+        HATSU,
+        CHUN,
+        wind_tile(seat_wind),
+        wind_tile(round_wind),
+    ]
+}
+
+#[must_use]
+fn wind_tile(wind: Wind) -> Tile {
+    match wind {
+        Wind::East => TON,
+        Wind::South => NAN,
+        Wind::West => SHAA,
+        Wind::North => PEI,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::Wind;
+    use crate::points::{
+        Fu, FuCalculation, FuComponent, FuHand, FuMeld, MeldShape, Wait, WinMethod,
+    };
+    use crate::tiles::*;
+
+    fn pinfu_hand(win_method: WinMethod, open: bool) -> FuHand {
+        FuHand {
+            melds: vec![
+                FuMeld::new(MeldShape::Sequence, II_MAN, false),
+                FuMeld::new(MeldShape::Sequence, SUU_MAN, false),
+                FuMeld::new(MeldShape::Sequence, SAN_PIN, open),
+                FuMeld::new(MeldShape::Sequence, ROU_SOU, false),
+            ],
+            pair: PAA_PIN,
+            winning_tile: II_MAN,
+            wait: Wait::Ryanmen,
+            win_method,
+            seat_wind: Wind::South,
+            round_wind: Wind::East,
+        }
+    }
+
+    #[test]
+    fn should_calculate_pinfu() {
+        let calculation = FuCalculation::calculate(&pinfu_hand(WinMethod::Tsumo, false));
+        assert_eq!(calculation.components(), &[FuComponent::Base]);
+        assert_eq!(calculation.total(), Fu::new(20));
+
+        let calculation = FuCalculation::calculate(&pinfu_hand(WinMethod::Ron, false));
+        assert_eq!(
+            calculation.components(),
+            &[FuComponent::Base, FuComponent::ClosedRon]
+        );
+        assert_eq!(calculation.total(), Fu::new(30));
+
+        let calculation = FuCalculation::calculate(&pinfu_hand(WinMethod::Ron, true));
+        assert_eq!(
+            calculation.components(),
+            &[FuComponent::Base, FuComponent::OpenPinfu]
+        );
+        assert_eq!(calculation.total(), Fu::new(30));
+
+        let calculation = FuCalculation::calculate(&pinfu_hand(WinMethod::Tsumo, true));
+        assert_eq!(
+            calculation.components(),
+            &[FuComponent::Base, FuComponent::Tsumo]
+        );
+        assert_eq!(calculation.total(), Fu::new(30));
+    }
+
+    #[test]
+    fn should_calculate_melds_and_pairs() {
+        let hand = FuHand {
+            melds: vec![
+                FuMeld::new(MeldShape::Kan, TON, false),
+                FuMeld::new(MeldShape::Triplet, CHUN, true),
+                FuMeld::new(MeldShape::Triplet, RYAN_SOU, false),
+                FuMeld::new(MeldShape::Sequence, CHII_PIN, false),
+            ],
+            pair: TON,
+            winning_tile: TON,
+            wait: Wait::Tanki,
+            win_method: WinMethod::Tsumo,
+            seat_wind: Wind::East,
+            round_wind: Wind::East,
+        };
+        let calculation = FuCalculation::calculate(&hand);
+
+        assert_eq!(
+            calculation.components(),
+            &[
+                FuComponent::Base,
+                FuComponent::Wait(Wait::Tanki),
+                FuComponent::ValuePair(TON),
+                FuComponent::ValuePair(TON),
+                FuComponent::Meld(FuMeld::new(MeldShape::Kan, TON, false)),
+                FuComponent::Meld(FuMeld::new(MeldShape::Triplet, CHUN, true)),
+                FuComponent::Meld(FuMeld::new(MeldShape::Triplet, RYAN_SOU, false)),
+                FuComponent::Tsumo,
+            ]
+        );
+        assert_eq!(calculation.raw_total(), Fu::new(68));
+        assert_eq!(calculation.total(), Fu::new(70));
+    }
+
+    #[test]
+    fn should_treat_shanpon_ron_triplet_as_open() {
+        let hand = FuHand {
+            melds: vec![
+                FuMeld::new(MeldShape::Triplet, II_SOU, false),
+                FuMeld::new(MeldShape::Triplet, UU_MAN, false),
+                FuMeld::new(MeldShape::Sequence, II_PIN, false),
+                FuMeld::new(MeldShape::Sequence, ROU_PIN, false),
+            ],
+            pair: HAKU,
+            winning_tile: II_SOU,
+            wait: Wait::Shanpon,
+            win_method: WinMethod::Ron,
+            seat_wind: Wind::West,
+            round_wind: Wind::South,
+        };
+        let calculation = FuCalculation::calculate(&hand);
+
+        assert_eq!(
+            calculation.components(),
+            &[
+                FuComponent::Base,
+                FuComponent::ClosedRon,
+                FuComponent::ValuePair(HAKU),
+                FuComponent::Meld(FuMeld::new(MeldShape::Triplet, II_SOU, true)),
+                FuComponent::Meld(FuMeld::new(MeldShape::Triplet, UU_MAN, false)),
+            ]
+        );
+        assert_eq!(calculation.raw_total(), Fu::new(40));
+        assert_eq!(calculation.total(), Fu::new(40));
+    }
+
+    #[test]
+    fn should_describe_components() {
+        let descriptions: Vec<String> = [
+            FuComponent::Base,
+            FuComponent::ClosedRon,
+            FuComponent::Wait(Wait::Kanchan),
+            FuComponent::ValuePair(HATSU),
+            FuComponent::Meld(FuMeld::new(MeldShape::Triplet, KYUU_MAN, false)),
+            FuComponent::Meld(FuMeld::new(MeldShape::Kan, SAN_SOU, true)),
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+        assert_eq!(
+            descriptions,
+            vec![
+                "base +20 fu",
+                "closed ron +10 fu",
+                "kanchan wait +2 fu",
+                "pair of Hatsu +2 fu",
+                "closed triplet of terminals or honors +8 fu",
+                "open kan of simples +8 fu",
+            ]
+        );
+    }
+
+    #[test]
+    fn should_calculate_chiitoitsu() {
+        let calculation = FuCalculation::chiitoitsu();
+        assert_eq!(calculation.components(), &[FuComponent::Chiitoitsu]);
+        assert_eq!(calculation.raw_total(), Fu::new(25));
+        assert_eq!(calculation.total(), Fu::new(25));
+    }
+}
@@ -0,0 +1,324 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+
+use crate::points::{
+    Fu, Han, Honbas, Limit, PointCalculationError, Points, PointsCalculationMode, Ruleset,
+};
+
+/// A single yaku of a scored hand.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct YakuEntry {
+    /// Name of the yaku.
+    pub name: String,
+    /// Number of han the yaku is worth in the hand.
+    pub han: Han,
+    /// Whether the yaku is worth one han less because the hand is open
+    /// (kuisagari).
+    pub open_downgraded: bool,
+}
+
+impl YakuEntry {
+    /// Creates a new yaku entry worth given number of han.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Han, YakuEntry};
+    ///
+    /// let yaku = YakuEntry::new("Riichi", Han::new(1));
+    /// assert_eq!(yaku.han, Han::new(1));
+    /// assert!(!yaku.open_downgraded);
+    /// ```
+    #[must_use]
+    pub fn new<S: Into<String>>(name: S, han: Han) -> Self {
+        Self {
+            name: name.into(),
+            han,
+            open_downgraded: false,
+        }
+    }
+
+    /// Creates a new yaku entry for a yaku that was downgraded because the
+    /// hand is open. `closed_han` is the value of the yaku in a closed hand;
+    /// the entry is worth one han less.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{Han, YakuEntry};
+    ///
+    /// let yaku = YakuEntry::downgraded("Honitsu", Han::new(3));
+    /// assert_eq!(yaku.han, Han::new(2));
+    /// assert!(yaku.open_downgraded);
+    /// ```
+    #[must_use]
+    pub fn downgraded<S: Into<String>>(name: S, closed_han: Han) -> Self {
+        Self {
+            name: name.into(),
+            han: closed_han.saturating_sub(Han::new(1)),
+            open_downgraded: true,
+        }
+    }
+}
+
+impl Display for YakuEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}: {}", self.name, self.han)?;
+        if self.open_downgraded {
+            write!(f, " (open)")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Number of dora of each kind in a scored hand.
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct DoraCount {
+    /// Number of regular dora (indicated by the dora indicators).
+    pub dora: i32,
+    /// Number of red fives (akadora).
+    pub aka_dora: i32,
+    /// Number of uradora (indicated by the uradora indicators).
+    pub ura_dora: i32,
+}
+
+impl DoraCount {
+    /// Creates a new dora count.
+    #[inline]
+    #[must_use]
+    pub const fn new(dora: i32, aka_dora: i32, ura_dora: i32) -> Self {
+        Self {
+            dora,
+            aka_dora,
+            ura_dora,
+        }
+    }
+
+    /// Returns the number of han all the dora are worth.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::points::{DoraCount, Han};
+    ///
+    /// assert_eq!(DoraCount::new(2, 1, 1).han(), Han::new(4));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn han(&self) -> Han {
+        Han::new(self.dora + self.aka_dora + self.ura_dora)
+    }
+}
+
+/// Complete scoring result of a hand: the yaku with their han values, the
+/// dora, the total han and fu, and the resulting [`Points`].
+///
+/// # Examples
+/// ```
+/// use riichi_hand::points::{
+///     DoraCount, Fu, Han, Honbas, PointsCalculationMode, Ruleset, ScoreBreakdown, YakuEntry,
+/// };
+///
+/// let breakdown = ScoreBreakdown::new(
+///     PointsCalculationMode::Default,
+///     &Ruleset::default(),
+///     vec![
+///         YakuEntry::new("Riichi", Han::new(1)),
+///         YakuEntry::new("Pinfu", Han::new(1)),
+///     ],
+///     DoraCount::new(1, 0, 1),
+///     Fu::new(30),
+///     Honbas::ZERO,
+/// ).unwrap();
+///
+/// assert_eq!(breakdown.total_han(), Han::new(4));
+/// assert_eq!(breakdown.points().ko_ron().unwrap(), 7700);
+/// assert_eq!(breakdown.limit(), None);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ScoreBreakdown {
+    yaku: Vec<YakuEntry>,
+    dora: DoraCount,
+    fu: Fu,
+    points: Points,
+    limit: Option<Limit>,
+}
+
+impl ScoreBreakdown {
+    /// Calculates the score of a hand with given yaku, dora and fu, using
+    /// given calculation mode and [`Ruleset`].
+    pub fn new(
+        calculation_mode: PointsCalculationMode,
+        ruleset: &Ruleset,
+        yaku: Vec<YakuEntry>,
+        dora: DoraCount,
+        fu: Fu,
+        honbas: Honbas,
+    ) -> Result<Self, PointCalculationError> {
+        let han = yaku.iter().map(|yaku| yaku.han).sum::<Han>() + dora.han();
+        let points =
+            Points::from_calculated_with_ruleset(calculation_mode, ruleset, han, fu, honbas)?;
+        let limit = points.limit();
+
+        Ok(Self {
+            yaku,
+            dora,
+            fu,
+            points,
+            limit,
+        })
+    }
+
+    /// Returns the yaku of the hand.
+    #[inline]
+    #[must_use]
+    pub fn yaku(&self) -> &[YakuEntry] {
+        &self.yaku
+    }
+
+    /// Returns the number of dora in the hand.
+    #[inline]
+    #[must_use]
+    pub fn dora(&self) -> DoraCount {
+        self.dora
+    }
+
+    /// Returns the total number of han, including the dora.
+    #[must_use]
+    pub fn total_han(&self) -> Han {
+        self.yaku.iter().map(|yaku| yaku.han).sum::<Han>() + self.dora.han()
+    }
+
+    /// Returns the fu value of the hand.
+    #[inline]
+    #[must_use]
+    pub fn fu(&self) -> Fu {
+        self.fu
+    }
+
+    /// Returns the points the hand is worth.
+    #[inline]
+    #[must_use]
+    pub fn points(&self) -> &Points {
+        &self.points
+    }
+
+    /// Returns the limit the hand was scored as, if any.
+    #[inline]
+    #[must_use]
+    pub fn limit(&self) -> Option<Limit> {
+        self.limit
+    }
+}
+
+/// Formats the breakdown as one yaku per line, followed by the non-zero dora
+/// counts and the total, e.g.:
+///
+/// ```text
+/// Riichi: 1 han
+/// Dora: 2 han
+/// Total: 3 han, 40 fu – 5200 (1300/2600)
+/// ```
+impl Display for ScoreBreakdown {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for yaku in &self.yaku {
+            writeln!(f, "{}", yaku)?;
+        }
+
+        let dora = [
+            ("Dora", self.dora.dora),
+            ("Aka dora", self.dora.aka_dora),
+            ("Ura dora", self.dora.ura_dora),
+        ];
+        for (name, count) in dora.iter().filter(|(_, count)| *count != 0) {
+            writeln!(f, "{}: {}", name, Han::new(*count))?;
+        }
+
+        write!(
+            f,
+            "Total: {}, {} – {}",
+            self.total_han(),
+            self.fu,
+            self.points
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::points::{
+        DoraCount, Fu, Han, Honbas, Limit, PointsCalculationMode, Ruleset, ScoreBreakdown,
+        YakuEntry,
+    };
+
+    #[test]
+    fn should_calculate_score_breakdown() {
+        let breakdown = ScoreBreakdown::new(
+            PointsCalculationMode::Default,
+            &Ruleset::default(),
+            vec![
+                YakuEntry::new("Tanyao", Han::new(1)),
+                YakuEntry::downgraded("Sanshoku", Han::new(2)),
+            ],
+            DoraCount::new(0, 1, 0),
+            Fu::new(30),
+            Honbas::new(1),
+        )
+        .unwrap();
+
+        assert_eq!(breakdown.yaku().len(), 2);
+        assert!(breakdown.yaku()[1].open_downgraded);
+        assert_eq!(breakdown.dora().aka_dora, 1);
+        assert_eq!(breakdown.total_han(), Han::new(3));
+        assert_eq!(breakdown.fu(), Fu::new(30));
+        assert_eq!(breakdown.points().ko_ron(), Some(4200));
+        assert_eq!(breakdown.limit(), None);
+        assert_eq!(
+            breakdown.to_string(),
+            "Tanyao: 1 han\n\
+             Sanshoku: 1 han (open)\n\
+             Aka dora: 1 han\n\
+             Total: 3 han, 30 fu – 4200 (1100/2100)"
+        );
+    }
+
+    #[test]
+    fn should_resolve_limit() {
+        let breakdown = ScoreBreakdown::new(
+            PointsCalculationMode::Default,
+            &Ruleset::default(),
+            vec![
+                YakuEntry::new("Riichi", Han::new(1)),
+                YakuEntry::new("Chinitsu", Han::new(6)),
+            ],
+            DoraCount::new(3, 0, 2),
+            Fu::new(40),
+            Honbas::ZERO,
+        )
+        .unwrap();
+
+        assert_eq!(breakdown.total_han(), Han::new(12));
+        assert_eq!(breakdown.limit(), Some(Limit::Sanbaiman));
+        assert_eq!(breakdown.points().ko_ron(), Some(24000));
+    }
+
+    #[test]
+    fn should_fail_without_han() {
+        let result = ScoreBreakdown::new(
+            PointsCalculationMode::Default,
+            &Ruleset::default(),
+            vec![],
+            DoraCount::default(),
+            Fu::new(30),
+            Honbas::ZERO,
+        );
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,220 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::points::{
+    Fu, Han, Honbas, Limit, Points, PointsCalculationMode, Ruleset, BAIMAN_HAN_RANGE,
+    HANEMAN_HAN_RANGE, KAZOE_YAKUMAN_HAN_RANGE, MANGAN_HAN_RANGE, SANBAIMAN_HAN_RANGE, VALID_FU,
+};
+
+/// The highest han value included in the calculated part of a
+/// [`PointsTable`] (unless the table is generated in the unlimited mode).
+const MAX_TABLE_HAN: i32 = 4;
+
+/// A single han/fu cell of a [`PointsTable`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PointsTableEntry {
+    /// Number of han.
+    pub han: Han,
+    /// Number of fu.
+    pub fu: Fu,
+    /// Points for given han and fu values.
+    pub points: Points,
+}
+
+/// A single limit row of a [`PointsTable`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PointsTableLimitEntry {
+    /// The limit.
+    pub limit: Limit,
+    /// The lowest number of han that is always scored as this limit.
+    pub min_han: Han,
+    /// The highest number of han that is scored as this limit, or `None` if
+    /// there is no upper bound.
+    pub max_han: Option<Han>,
+    /// Points for the limit.
+    pub points: Points,
+}
+
+/// The classic scoring table, containing all the han/fu combinations along
+/// with the limit hands.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::points::{Fu, Han, PointsCalculationMode, PointsTable, Ruleset};
+///
+/// let table = PointsTable::generate(PointsCalculationMode::Default, &Ruleset::default());
+/// let points = table.get(Han::new(3), Fu::new(30)).unwrap();
+/// assert_eq!(points.ko_ron().unwrap(), 3900);
+/// assert_eq!(table.limits().len(), 5);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PointsTable {
+    entries: Vec<PointsTableEntry>,
+    limits: Vec<PointsTableLimitEntry>,
+}
+
+impl PointsTable {
+    /// Generates the scoring table using given calculation mode and ruleset.
+    ///
+    /// The table contains entries for 1 to 4 han and all the valid fu values
+    /// (20 to 110), followed by the limit rows. In the
+    /// [`PointsCalculationMode::Unlimited`] mode, there are no limits, so the
+    /// entries are generated for 1 to 13 han instead.
+    #[must_use]
+    pub fn generate(calculation_mode: PointsCalculationMode, ruleset: &Ruleset) -> Self {
+        let max_han = if calculation_mode == PointsCalculationMode::Unlimited {
+            KAZOE_YAKUMAN_HAN_RANGE.start.get()
+        } else {
+            MAX_TABLE_HAN
+        };
+
+        let entries = (1..=max_han)
+            .map(Han::new)
+            .flat_map(|han| VALID_FU.iter().map(move |&fu| (han, fu)))
+            .filter_map(|(han, fu)| {
+                Points::from_calculated_with_ruleset(
+                    calculation_mode,
+                    ruleset,
+                    han,
+                    fu,
+                    Honbas::ZERO,
+                )
+                .ok()
+                .map(|points| PointsTableEntry { han, fu, points })
+            })
+            .collect();
+
+        let limits = if calculation_mode == PointsCalculationMode::Unlimited {
+            Vec::new()
+        } else {
+            vec![
+                Self::limit_entry(
+                    Limit::Mangan,
+                    *MANGAN_HAN_RANGE.start(),
+                    Some(*MANGAN_HAN_RANGE.end()),
+                ),
+                Self::limit_entry(
+                    Limit::Haneman,
+                    *HANEMAN_HAN_RANGE.start(),
+                    Some(*HANEMAN_HAN_RANGE.end()),
+                ),
+                Self::limit_entry(
+                    Limit::Baiman,
+                    *BAIMAN_HAN_RANGE.start(),
+                    Some(*BAIMAN_HAN_RANGE.end()),
+                ),
+                Self::limit_entry(
+                    Limit::Sanbaiman,
+                    *SANBAIMAN_HAN_RANGE.start(),
+                    Some(*SANBAIMAN_HAN_RANGE.end()),
+                ),
+                Self::limit_entry(Limit::Yakuman, KAZOE_YAKUMAN_HAN_RANGE.start, None),
+            ]
+        };
+
+        Self { entries, limits }
+    }
+
+    fn limit_entry(limit: Limit, min_han: Han, max_han: Option<Han>) -> PointsTableLimitEntry {
+        PointsTableLimitEntry {
+            limit,
+            min_han,
+            max_han,
+            points: Points::new_limited(limit.base_points(), Honbas::ZERO),
+        }
+    }
+
+    /// Returns the han/fu entries of the table, sorted by han, then fu.
+    #[inline]
+    #[must_use]
+    pub fn entries(&self) -> &[PointsTableEntry] {
+        &self.entries
+    }
+
+    /// Returns the limit rows of the table, from mangan to yakuman.
+    #[inline]
+    #[must_use]
+    pub fn limits(&self) -> &[PointsTableLimitEntry] {
+        &self.limits
+    }
+
+    /// Returns the points for given han and fu values, if the table contains
+    /// such an entry.
+    #[must_use]
+    pub fn get(&self, han: Han, fu: Fu) -> Option<&Points> {
+        self.entries
+            .iter()
+            .find(|entry| entry.han == han && entry.fu == fu)
+            .map(|entry| &entry.points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::points::{Fu, Han, Limit, PointsCalculationMode, PointsTable, Ruleset};
+
+    #[test]
+    fn should_generate_default_table() {
+        let table = PointsTable::generate(PointsCalculationMode::Default, &Ruleset::default());
+
+        assert_eq!(table.entries().len(), 4 * 11);
+        let points = table.get(Han::new(1), Fu::new(30)).unwrap();
+        assert_eq!(points.ko_ron(), Some(1000));
+        assert_eq!(points.ko_tsumo(), Some((300, 500)));
+        let points = table.get(Han::new(1), Fu::new(20)).unwrap();
+        assert_eq!(points.ko_ron(), None);
+        assert_eq!(points.ko_tsumo(), None);
+        let points = table.get(Han::new(4), Fu::new(30)).unwrap();
+        assert_eq!(points.ko_ron(), Some(7700));
+        assert!(table.get(Han::new(5), Fu::new(30)).is_none());
+
+        let limits: Vec<Limit> = table.limits().iter().map(|entry| entry.limit).collect();
+        assert_eq!(
+            limits,
+            vec![
+                Limit::Mangan,
+                Limit::Haneman,
+                Limit::Baiman,
+                Limit::Sanbaiman,
+                Limit::Yakuman
+            ]
+        );
+        assert_eq!(table.limits()[1].min_han, Han::new(6));
+        assert_eq!(table.limits()[1].max_han, Some(Han::new(7)));
+        assert_eq!(table.limits()[4].max_han, None);
+        assert_eq!(table.limits()[4].points.oya_ron(), Some(48000));
+    }
+
+    #[test]
+    fn should_generate_table_with_kiriage_mangan() {
+        let ruleset = Ruleset {
+            kiriage_mangan: true,
+            ..Ruleset::default()
+        };
+        let table = PointsTable::generate(PointsCalculationMode::Default, &ruleset);
+
+        let points = table.get(Han::new(4), Fu::new(30)).unwrap();
+        assert!(points.is_limited());
+        assert_eq!(points.ko_ron(), Some(8000));
+        let points = table.get(Han::new(3), Fu::new(60)).unwrap();
+        assert_eq!(points.oya_ron(), Some(12000));
+        let points = table.get(Han::new(3), Fu::new(50)).unwrap();
+        assert_eq!(points.ko_ron(), Some(6400));
+    }
+
+    #[test]
+    fn should_generate_unlimited_table() {
+        let table = PointsTable::generate(PointsCalculationMode::Unlimited, &Ruleset::default());
+
+        assert_eq!(table.entries().len(), 13 * 11);
+        assert!(table.limits().is_empty());
+        let points = table.get(Han::new(5), Fu::new(30)).unwrap();
+        assert_eq!(points.ko_ron(), Some(15400));
+    }
+}
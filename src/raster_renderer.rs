@@ -1,10 +1,51 @@
-pub use renderer::{HandRenderError, HandRenderResult, ImageType, RasterRenderer, RenderOptions};
+#[cfg(feature = "animation")]
+pub use animation::{
+    AnimationFramesResult, AnimationRenderError, AnimationRenderResult, AnimationStyle,
+};
+#[cfg(feature = "zip-tile-sets")]
+pub use archive_tile_set::ArchiveTileSet;
+pub use atlas_export::{export_atlas, AtlasEntry, AtlasLayout, AtlasMap, AtlasRect};
+pub use atlas_tile_set::{AtlasGrid, AtlasTileSet, AtlasTileSetCreationError};
+#[cfg(feature = "tile-set-loader")]
+pub use directory_tile_set::{DirectoryTileSet, NamingScheme, TileSetLoadError};
+#[cfg(feature = "extra-formats")]
+pub use encoding::{
+    encode_apng, render_hand_to_bytes, EncodeError, EncodeResult, OutputFormat, RenderToBytesError,
+    RenderedImage,
+};
+pub use fallback_tile_set::FallbackTileSet;
+pub use flatten::{flatten, OpaquePixel};
+pub use fn_tile_set::FnTileSet;
+pub use generated_tile_set::GeneratedTileSet;
+pub use highlight::Highlight;
+#[cfg(feature = "labels")]
+pub use labels::LabelOptions;
+#[cfg(feature = "labels")]
+pub use nanikiru::NanikiruProblem;
+pub use options_builder::{RenderOptionsBuilder, RenderOptionsError};
+pub use renderer::{
+    Gap, HandRenderError, HandRenderResult, ImageType, RasterRenderer, RenderOptions, Rotation,
+    Scale, Shadow, TileWidthRatio,
+};
+pub use scaled_tile_set::ScaledTileSet;
+#[cfg(feature = "labels")]
+pub use score_card::ScoreCard;
+pub use table::TableRenderer;
+pub use texture::Texture;
 pub use tile_set::{
-    SimpleTileSet, TileImageResult, TileImageRetrieveError, TileSet, TileSetCreationError,
-    TwoPartTileSet,
+    SimpleTileSet, TileImageRefResult, TileImageResult, TileImageRetrieveError, TileSet,
+    TileSetCreationError, TwoPartTileSet,
 };
+pub use tile_set_metadata::TileSetMetadata;
+pub use tinted_tile_set::{TintOptions, TintedTileSet};
+#[cfg(feature = "vector-tile-sets")]
+pub use vector_tile_set::{VectorTileSet, VectorTileSetCreationError};
 
-#[cfg(feature = "fluffy-stuff-tile-sets")]
+#[cfg(any(
+    feature = "fluffy-stuff-yellow",
+    feature = "fluffy-stuff-red",
+    feature = "fluffy-stuff-black"
+))]
 /// Ready-to-use tile sets based on FluffyStuff's tile images.
 pub mod fluffy_stuff_tile_sets;
 
@@ -12,6 +53,37 @@ pub mod fluffy_stuff_tile_sets;
 /// Ready-to-use tile sets based on Martin Persson's tile images.
 pub mod martin_persson_tile_sets;
 
+#[cfg(feature = "animation")]
+mod animation;
+#[cfg(feature = "zip-tile-sets")]
+mod archive_tile_set;
+mod atlas_export;
+mod atlas_tile_set;
+#[cfg(feature = "tile-set-loader")]
+mod directory_tile_set;
+#[cfg(feature = "extra-formats")]
+mod encoding;
+mod fallback_tile_set;
+mod flatten;
+mod fn_tile_set;
+mod generated_tile_set;
+mod highlight;
+#[cfg(feature = "labels")]
+mod labels;
+#[cfg(feature = "labels")]
+mod nanikiru;
+mod options_builder;
+#[cfg(feature = "parallel")]
+mod parallel;
 mod renderer;
+mod scaled_tile_set;
+#[cfg(feature = "labels")]
+mod score_card;
+mod table;
+mod texture;
 mod tile_set;
+mod tile_set_metadata;
 mod tile_set_util;
+mod tinted_tile_set;
+#[cfg(feature = "vector-tile-sets")]
+mod vector_tile_set;
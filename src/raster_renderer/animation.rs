@@ -0,0 +1,253 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{Delay, Frame, ImageError};
+
+use crate::raster_renderer::{
+    HandRenderError, ImageType, RasterRenderer, RenderOptions, TileImageRetrieveError, TileSet,
+};
+use crate::renderer::HandRenderer;
+use crate::tiles::ANY;
+use crate::{Hand, HandTile};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+/// Style of an animated hand rendered with [RasterRenderer::render_frames].
+pub enum AnimationStyle {
+    /// The tiles appear one by one, from left to right. The first frame
+    /// contains only the first tile, and the last one contains the whole hand.
+    Appear,
+    /// The tiles are flipped face-up one by one, from left to right. The first
+    /// frame contains the whole hand face-down, and the last one contains the
+    /// whole hand face-up.
+    Flip,
+}
+
+/// Result of [RasterRenderer::render_frames].
+pub type AnimationFramesResult = Result<Vec<ImageType>, HandRenderError>;
+/// Result of [RasterRenderer::render_gif].
+pub type AnimationRenderResult = Result<Vec<u8>, AnimationRenderError>;
+
+impl<'a, T: TileSet> RasterRenderer<'a, T> {
+    /// Renders given [Hand] instance as a sequence of frames of an animation
+    /// of given style, using [TileSet] and [RenderOptions]. All the frames
+    /// have the same dimensions.
+    pub fn render_frames(
+        hand: &Hand,
+        tile_set: &'a T,
+        options: RenderOptions,
+        style: AnimationStyle,
+    ) -> AnimationFramesResult {
+        let renderer = Self::new(tile_set, options);
+        let layout = renderer.layout(hand, renderer.layout_options());
+
+        let frame_count = match style {
+            AnimationStyle::Appear => layout.tiles.len(),
+            AnimationStyle::Flip => layout.tiles.len() + 1,
+        };
+        (0..frame_count)
+            .map(|frame| {
+                let tiles = layout
+                    .tiles
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, position)| match style {
+                        AnimationStyle::Appear if index > frame => None,
+                        AnimationStyle::Flip if index >= frame => {
+                            let mut position = *position;
+                            position.tile = HandTile::new(ANY, position.tile.placement);
+                            Some((position, None))
+                        }
+                        _ => Some((*position, None)),
+                    });
+                renderer.render_tiles(layout.width, layout.height, tiles)
+            })
+            .collect()
+    }
+
+    /// Renders given [Hand] instance as an infinitely looping animated GIF of
+    /// given style, using [TileSet] and [RenderOptions]. Each frame is shown
+    /// for `frame_delay_ms` milliseconds, and the last one is held for three
+    /// times as long.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::parser::HandParser;
+    /// use riichi_hand::raster_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_TILE_SET;
+    /// use riichi_hand::raster_renderer::{AnimationStyle, RasterRenderer, RenderOptions};
+    ///
+    /// let hand = HandParser::parse("123m456p").unwrap();
+    /// let gif = RasterRenderer::render_gif(
+    ///     &hand,
+    ///     &*YELLOW_FLUFFY_STUFF_TILE_SET,
+    ///     RenderOptions::default().target_tile_height(64),
+    ///     AnimationStyle::Flip,
+    ///     200,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(&gif[..6], b"GIF89a");
+    /// ```
+    pub fn render_gif(
+        hand: &Hand,
+        tile_set: &'a T,
+        options: RenderOptions,
+        style: AnimationStyle,
+        frame_delay_ms: u32,
+    ) -> AnimationRenderResult {
+        let frames = Self::render_frames(hand, tile_set, options, style)?;
+        let last_frame = frames.len().saturating_sub(1);
+
+        let mut gif = Vec::new();
+        {
+            let mut encoder = GifEncoder::new(&mut gif);
+            encoder.set_repeat(Repeat::Infinite)?;
+            for (index, frame) in frames.into_iter().enumerate() {
+                let delay = if index == last_frame {
+                    3 * frame_delay_ms
+                } else {
+                    frame_delay_ms
+                };
+                let delay = Delay::from_numer_denom_ms(delay, 1);
+                encoder.encode_frame(Frame::from_parts(frame, 0, 0, delay))?;
+            }
+        }
+
+        Ok(gif)
+    }
+}
+
+#[derive(Debug)]
+/// An error that occurs when calling [RasterRenderer::render_gif].
+pub enum AnimationRenderError {
+    /// Error occurred when retrieving a tile image.
+    TileImageRetrieveError(TileImageRetrieveError),
+    /// Error occurred when encoding the animation as GIF.
+    ImageEncodeError(ImageError),
+}
+
+impl Error for AnimationRenderError {}
+
+impl Display for AnimationRenderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TileImageRetrieveError(inner_error) => {
+                write!(f, "could not retrieve tile image: {}", inner_error)
+            }
+            Self::ImageEncodeError(inner_error) => {
+                write!(f, "could not encode animation: {}", inner_error)
+            }
+        }
+    }
+}
+
+impl From<HandRenderError> for AnimationRenderError {
+    fn from(error: HandRenderError) -> Self {
+        match error {
+            HandRenderError::TileImageRetrieveError(inner_error) => {
+                Self::TileImageRetrieveError(inner_error)
+            }
+        }
+    }
+}
+
+impl From<ImageError> for AnimationRenderError {
+    fn from(error: ImageError) -> Self {
+        Self::ImageEncodeError(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::io::Cursor;
+
+    use image::codecs::gif::GifDecoder;
+    use image::{AnimationDecoder, Rgba, RgbaImage};
+
+    use crate::parser::HandParser;
+    use crate::raster_renderer::{AnimationStyle, RasterRenderer, RenderOptions, TwoPartTileSet};
+    use crate::tiles::*;
+
+    fn tile_set() -> TwoPartTileSet {
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| {
+                let color = if tile == ANY {
+                    Rgba([0, 0, 255, 255])
+                } else {
+                    Rgba([0, 0, 0, 0])
+                };
+                (tile, RgbaImage::from_pixel(3, 4, color))
+            })
+            .collect::<HashMap<_, _>>();
+        TwoPartTileSet::new(RgbaImage::from_pixel(3, 4, Rgba([255, 255, 255, 255])), map).unwrap()
+    }
+
+    #[test]
+    fn should_render_appearing_tiles() {
+        let hand = HandParser::parse("12m_3m").unwrap();
+
+        let frames = RasterRenderer::render_frames(
+            &hand,
+            &tile_set(),
+            RenderOptions::default(),
+            AnimationStyle::Appear,
+        )
+        .unwrap();
+
+        assert_eq!(frames.len(), 3);
+        assert!(frames.iter().all(|frame| frame.dimensions() == (10, 4)));
+        assert_eq!(frames[0].get_pixel(3, 0)[3], 0);
+        assert_eq!(frames[1].get_pixel(3, 0)[3], 255);
+        assert_eq!(frames[1].get_pixel(7, 0)[3], 0);
+        assert_eq!(frames[2].get_pixel(7, 0)[3], 255);
+    }
+
+    #[test]
+    fn should_render_flipping_tiles() {
+        let hand = HandParser::parse("12m").unwrap();
+
+        let frames = RasterRenderer::render_frames(
+            &hand,
+            &tile_set(),
+            RenderOptions::default(),
+            AnimationStyle::Flip,
+        )
+        .unwrap();
+
+        let blue = Rgba([0, 0, 255, 255]);
+        let white = Rgba([255, 255, 255, 255]);
+        let first_tiles: Vec<_> = frames
+            .iter()
+            .map(|frame| (*frame.get_pixel(0, 0), *frame.get_pixel(3, 0)))
+            .collect();
+        assert_eq!(
+            first_tiles,
+            vec![(blue, blue), (white, blue), (white, white)]
+        );
+    }
+
+    #[test]
+    fn should_render_gif() {
+        let hand = HandParser::parse("12m").unwrap();
+
+        let gif = RasterRenderer::render_gif(
+            &hand,
+            &tile_set(),
+            RenderOptions::default(),
+            AnimationStyle::Appear,
+            100,
+        )
+        .unwrap();
+
+        let frames = GifDecoder::new(Cursor::new(gif))
+            .unwrap()
+            .into_frames()
+            .collect_frames()
+            .unwrap();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].delay().numer_denom_ms(), (100, 1));
+        assert_eq!(frames[1].delay().numer_denom_ms(), (300, 1));
+        assert_eq!(frames[1].buffer().dimensions(), (6, 4));
+    }
+}
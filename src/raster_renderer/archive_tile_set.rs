@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use std::io::{Cursor, Read, Seek};
+use std::path::{Path, PathBuf};
+
+use image::ImageError;
+use zip::result::ZipError;
+use zip::ZipArchive;
+
+use crate::raster_renderer::directory_tile_set::{LoadedTileSet, IMAGE_EXTENSIONS};
+use crate::raster_renderer::{
+    NamingScheme, TileImageRefResult, TileImageResult, TileSet, TileSetLoadError,
+};
+use crate::HandTile;
+
+impl From<ZipError> for TileSetLoadError {
+    fn from(e: ZipError) -> Self {
+        Self::InvalidArchive(e)
+    }
+}
+
+#[derive(Debug)]
+/// An implementation of [TileSet] that loads PNG or JPEG tile images from a
+/// zip archive, so that tile packs can be shipped or downloaded as single
+/// files.
+///
+/// The images are looked up by their file names (without the extensions)
+/// as returned by given [NamingScheme], in any directory of the archive.
+///
+/// # Examples
+/// ```no_run
+/// use riichi_hand::parser::HandParser;
+/// use riichi_hand::raster_renderer::{
+///     ArchiveTileSet, NamingScheme, RasterRenderer, RenderOptions,
+/// };
+///
+/// let bundle = std::fs::read("my_tiles.zip").unwrap();
+/// let tile_set = ArchiveTileSet::from_bytes(&bundle, NamingScheme::Numeric).unwrap();
+/// let hand = HandParser::parse("123m456p").unwrap();
+/// let image = RasterRenderer::render(&hand, &tile_set, RenderOptions::default()).unwrap();
+/// ```
+pub struct ArchiveTileSet {
+    tile_set: LoadedTileSet,
+}
+
+impl ArchiveTileSet {
+    /// Loads the tile images from the zip archive read from given reader
+    /// (e.g. a [File](std::fs::File)).
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn load<R: Read + Seek>(
+        reader: R,
+        naming_scheme: NamingScheme,
+    ) -> Result<Self, TileSetLoadError> {
+        let mut archive = ZipArchive::new(reader)?;
+
+        let mut entries = HashMap::new();
+        for index in 0..archive.len() {
+            let file = archive.by_index(index)?;
+            let path = Path::new(file.name());
+            let is_image = path
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .map_or(false, |extension| {
+                    IMAGE_EXTENSIONS.contains(&extension.to_ascii_lowercase().as_str())
+                });
+            if let (true, true, Some(stem)) = (file.is_file(), is_image, path.file_stem()) {
+                entries.insert(stem.to_string_lossy().into_owned(), index);
+            }
+        }
+
+        let tile_set = LoadedTileSet::load(naming_scheme, |name| {
+            let index = match entries.get(name) {
+                Some(&index) => index,
+                None => return Ok(None),
+            };
+            let mut file = archive.by_index(index)?;
+            let path = PathBuf::from(file.name());
+            let mut data = Vec::with_capacity(file.size() as usize);
+            file.read_to_end(&mut data).map_err(|e| {
+                TileSetLoadError::InvalidImage(path.clone(), ImageError::IoError(e))
+            })?;
+
+            image::load_from_memory(&data)
+                .map(|image| Some(image.to_rgba8()))
+                .map_err(|e| TileSetLoadError::InvalidImage(path, e))
+        })?;
+
+        Ok(Self { tile_set })
+    }
+
+    /// Loads the tile images from given zip archive bundled in memory.
+    pub fn from_bytes(bytes: &[u8], naming_scheme: NamingScheme) -> Result<Self, TileSetLoadError> {
+        Self::load(Cursor::new(bytes), naming_scheme)
+    }
+}
+
+impl TileSet for ArchiveTileSet {
+    #[inline]
+    fn tile_image(&self, hand_tile: &HandTile) -> TileImageResult {
+        self.tile_set.tile_image(hand_tile)
+    }
+
+    #[inline]
+    fn tile_image_ref(&self, hand_tile: &HandTile) -> TileImageRefResult<'_> {
+        self.tile_set.tile_image_ref(hand_tile)
+    }
+
+    #[inline]
+    fn tile_width(&self) -> u32 {
+        self.tile_set.tile_width()
+    }
+
+    #[inline]
+    fn tile_height(&self) -> u32 {
+        self.tile_set.tile_height()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use image::{ImageFormat, Rgba, RgbaImage};
+    use zip::write::FileOptions;
+    use zip::ZipWriter;
+
+    use crate::raster_renderer::{ArchiveTileSet, NamingScheme, TileSet, TileSetLoadError};
+    use crate::tiles::*;
+    use crate::HandTile;
+    use crate::TilePlacement::Normal;
+
+    fn png(color: Rgba<u8>) -> Vec<u8> {
+        let mut data = Cursor::new(Vec::new());
+        RgbaImage::from_pixel(3, 4, color)
+            .write_to(&mut data, ImageFormat::Png)
+            .unwrap();
+
+        data.into_inner()
+    }
+
+    fn bundle(naming_scheme: NamingScheme) -> Vec<u8> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        writer
+            .add_directory("tiles/", FileOptions::default())
+            .unwrap();
+        for tile in ALL_TILES {
+            let name = format!("tiles/{}.PNG", naming_scheme.tile_file_stem(tile));
+            writer.start_file(name, FileOptions::default()).unwrap();
+            writer.write_all(&png(Rgba([255, 0, 0, 255]))).unwrap();
+        }
+        writer
+            .start_file("README.txt", FileOptions::default())
+            .unwrap();
+        writer.write_all(b"Red tiles").unwrap();
+
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn should_load_tile_set_from_zip_archive() {
+        let bundle = bundle(NamingScheme::Default);
+
+        let tile_set = ArchiveTileSet::from_bytes(&bundle, NamingScheme::Default).unwrap();
+
+        assert_eq!((tile_set.tile_width(), tile_set.tile_height()), (3, 4));
+        let image = tile_set.tile_image(&HandTile::new(CHUN, Normal)).unwrap();
+        assert_eq!(*image.get_pixel(0, 0), Rgba([255, 0, 0, 255]));
+    }
+
+    #[test]
+    fn should_return_archive_errors() {
+        let bundle = bundle(NamingScheme::Default);
+
+        assert!(matches!(
+            ArchiveTileSet::from_bytes(&bundle, NamingScheme::Numeric),
+            Err(TileSetLoadError::TileMissing(_))
+        ));
+        assert!(matches!(
+            ArchiveTileSet::from_bytes(b"not a zip archive", NamingScheme::Default),
+            Err(TileSetLoadError::InvalidArchive(_))
+        ));
+    }
+}
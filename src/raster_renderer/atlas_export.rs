@@ -0,0 +1,288 @@
+use image::RgbaImage;
+
+use crate::raster_renderer::TileSet;
+use crate::tiles::{ALL_TILES, BLANK, UNKNOWN};
+use crate::TilePlacement::{Normal, Rotated};
+use crate::{HandTile, Tile};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+/// Layout of the texture atlas created with [export_atlas].
+pub struct AtlasLayout {
+    /// Number of tile images in a row of the atlas.
+    pub columns: u32,
+    /// Empty space, in pixels, around each tile image, so that the
+    /// neighboring tiles do not bleed into each other when the atlas is
+    /// sampled with filtering.
+    pub padding: u32,
+    /// Whether the rotated tile images are included as well.
+    pub rotated_tiles: bool,
+}
+
+impl AtlasLayout {
+    #[inline]
+    #[must_use]
+    /// Creates a new atlas layout with given number of tile images in a row,
+    /// without padding and without the rotated tiles.
+    pub fn new(columns: u32) -> Self {
+        Self {
+            columns,
+            padding: 0,
+            rotated_tiles: false,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the atlas layout with given padding around each tile image.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::raster_renderer::AtlasLayout;
+    ///
+    /// let layout = AtlasLayout::new(10).padding(2);
+    /// assert_eq!(layout.padding, 2);
+    /// ```
+    pub fn padding(self, padding: u32) -> Self {
+        Self { padding, ..self }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the atlas layout that includes the rotated tile images as
+    /// well.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::raster_renderer::AtlasLayout;
+    ///
+    /// let layout = AtlasLayout::new(10).rotated_tiles();
+    /// assert!(layout.rotated_tiles);
+    /// ```
+    pub fn rotated_tiles(self) -> Self {
+        Self {
+            rotated_tiles: true,
+            ..self
+        }
+    }
+}
+
+impl Default for AtlasLayout {
+    fn default() -> Self {
+        Self::new(10)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+/// Area of a texture atlas containing a single tile image, in pixels.
+pub struct AtlasRect {
+    /// Horizontal position of the left edge.
+    pub x: u32,
+    /// Vertical position of the top edge.
+    pub y: u32,
+    /// Width of the image.
+    pub width: u32,
+    /// Height of the image.
+    pub height: u32,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+/// A tile image placed in a texture atlas.
+pub struct AtlasEntry {
+    /// The tile.
+    pub tile: Tile,
+    /// Whether the tile image is rotated.
+    pub rotated: bool,
+    /// Area of the atlas containing the tile image.
+    pub rect: AtlasRect,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+/// Positions of the tile images in a texture atlas created with
+/// [export_atlas].
+///
+/// With the `serde` feature enabled, the map can be serialized (e.g. to JSON)
+/// to be consumed by game engine pipelines.
+pub struct AtlasMap {
+    /// Width of the atlas, in pixels.
+    pub width: u32,
+    /// Height of the atlas, in pixels.
+    pub height: u32,
+    /// Tile images placed in the atlas.
+    pub entries: Vec<AtlasEntry>,
+}
+
+impl AtlasMap {
+    /// Returns the area of the atlas containing the image of given tile,
+    /// rotated or not, if the atlas contains it.
+    #[must_use]
+    pub fn get(&self, tile: Tile, rotated: bool) -> Option<AtlasRect> {
+        self.entries
+            .iter()
+            .find(|entry| entry.tile == tile && entry.rotated == rotated)
+            .map(|entry| entry.rect)
+    }
+
+    /// Returns given area of the atlas as the texture coordinates (`u` and
+    /// `v`, from 0 to 1) of its top left and bottom right corners, i.e.
+    /// `[u_min, v_min, u_max, v_max]`.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::raster_renderer::{AtlasMap, AtlasRect};
+    ///
+    /// let map = AtlasMap {
+    ///     width: 200,
+    ///     height: 100,
+    ///     entries: Vec::new(),
+    /// };
+    /// let rect = AtlasRect {
+    ///     x: 50,
+    ///     y: 0,
+    ///     width: 50,
+    ///     height: 100,
+    /// };
+    /// assert_eq!(map.uv(rect), [0.25, 0.0, 0.5, 1.0]);
+    /// ```
+    #[must_use]
+    pub fn uv(&self, rect: AtlasRect) -> [f32; 4] {
+        let width = self.width.max(1) as f32;
+        let height = self.height.max(1) as f32;
+
+        [
+            rect.x as f32 / width,
+            rect.y as f32 / height,
+            (rect.x + rect.width) as f32 / width,
+            (rect.y + rect.height) as f32 / height,
+        ]
+    }
+}
+
+/// Packs the images of all the tiles of given tile set into a single image
+/// (texture atlas), along with the map of the tile positions, for use with
+/// game engines.
+///
+/// The tiles are placed row by row, in the order of
+/// [ALL_TILES](crate::tiles::ALL_TILES), followed by
+/// [BLANK](crate::tiles::BLANK) and [UNKNOWN](crate::tiles::UNKNOWN), and
+/// then the rotated tiles, if requested. The tiles the tile set does not
+/// support are skipped.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::raster_renderer::{export_atlas, AtlasLayout, GeneratedTileSet};
+/// use riichi_hand::tiles::CHUN;
+///
+/// let tile_set = GeneratedTileSet::new(40);
+/// let (image, map) = export_atlas(&tile_set, AtlasLayout::new(10));
+///
+/// assert_eq!(image.dimensions(), (map.width, map.height));
+/// assert_eq!(map.get(CHUN, false).unwrap().height, 40);
+/// ```
+pub fn export_atlas<T: TileSet>(tile_set: &T, layout: AtlasLayout) -> (RgbaImage, AtlasMap) {
+    let placements: &[_] = if layout.rotated_tiles {
+        &[Normal, Rotated]
+    } else {
+        &[Normal]
+    };
+    let images: Vec<_> = placements
+        .iter()
+        .flat_map(|&placement| {
+            ALL_TILES
+                .iter()
+                .chain(&[BLANK, UNKNOWN])
+                .map(move |&tile| HandTile::new(tile, placement))
+        })
+        .filter_map(|hand_tile| {
+            let image = tile_set.tile_image_ref(&hand_tile).ok()?;
+            Some((hand_tile, image))
+        })
+        .collect();
+
+    let columns = layout.columns.max(1) as usize;
+    let mut entries = Vec::with_capacity(images.len());
+    let (mut width, mut y) = (0, 0);
+    for row in images.chunks(columns) {
+        let mut x = 0;
+        let mut row_height = 0;
+        for (hand_tile, image) in row {
+            entries.push(AtlasEntry {
+                tile: hand_tile.tile,
+                rotated: hand_tile.placement != Normal,
+                rect: AtlasRect {
+                    x: x + layout.padding,
+                    y: y + layout.padding,
+                    width: image.width(),
+                    height: image.height(),
+                },
+            });
+            x += image.width() + 2 * layout.padding;
+            row_height = row_height.max(image.height() + 2 * layout.padding);
+        }
+        width = width.max(x);
+        y += row_height;
+    }
+
+    let mut atlas = RgbaImage::new(width, y);
+    for ((_, image), entry) in images.iter().zip(&entries) {
+        image::imageops::replace(
+            &mut atlas,
+            &**image,
+            i64::from(entry.rect.x),
+            i64::from(entry.rect.y),
+        );
+    }
+
+    let map = AtlasMap {
+        width,
+        height: y,
+        entries,
+    };
+    (atlas, map)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use image::{Rgba, RgbaImage};
+
+    use crate::raster_renderer::{export_atlas, AtlasLayout, SimpleTileSet};
+    use crate::tiles::*;
+
+    #[test]
+    fn should_export_atlas() {
+        let map: HashMap<_, _> = ALL_TILES
+            .iter()
+            .zip(0..)
+            .map(|(&tile, index)| (tile, RgbaImage::from_pixel(3, 4, Rgba([index, 0, 0, 255]))))
+            .collect();
+        let tile_set = SimpleTileSet::new(map).unwrap().with_auto_rotation();
+
+        let (image, map) = export_atlas(&tile_set, AtlasLayout::new(10).padding(1));
+
+        // the rotated, blank and unknown tiles are not included
+        assert_eq!(map.entries.len(), ALL_TILES.len());
+        assert_eq!((map.width, map.height), (10 * 5, 4 * 6));
+        assert_eq!(image.dimensions(), (map.width, map.height));
+        let rect = map.get(CHUN, false).unwrap();
+        assert_eq!(
+            (rect.x, rect.y, rect.width, rect.height),
+            (6 * 5 + 1, 3 * 6 + 1, 3, 4)
+        );
+        assert_eq!(*image.get_pixel(rect.x, rect.y), Rgba([36, 0, 0, 255]));
+        assert_eq!(image.get_pixel(rect.x - 1, rect.y)[3], 0);
+        assert_eq!(map.get(CHUN, true), None);
+
+        let (image, map) = export_atlas(&tile_set, AtlasLayout::default().rotated_tiles());
+        assert_eq!(map.entries.len(), 2 * ALL_TILES.len());
+        let rect = map.get(CHUN, true).unwrap();
+        assert_eq!((rect.width, rect.height), (4, 3));
+        assert_eq!(*image.get_pixel(rect.x, rect.y), Rgba([36, 0, 0, 255]));
+    }
+}
@@ -0,0 +1,314 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use image::RgbaImage;
+
+use crate::raster_renderer::{
+    SimpleTileSet, TileImageRefResult, TileImageResult, TileSet, TileSetCreationError,
+    TileSetMetadata,
+};
+use crate::{HandTile, Tile};
+
+#[derive(Copy, Clone, Debug)]
+/// An error that occurs when creating an [AtlasTileSet].
+pub enum AtlasTileSetCreationError {
+    /// Given tile's cell does not fit in the sprite sheet.
+    CellOutOfBounds(Tile),
+    /// The tile set could not be created from the sliced tile images (e.g.
+    /// because there is no cell for some tile).
+    InvalidTileSet(TileSetCreationError),
+}
+
+impl Error for AtlasTileSetCreationError {}
+
+impl Display for AtlasTileSetCreationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CellOutOfBounds(tile) => {
+                write!(f, "the cell of tile {} is outside the sprite sheet", tile)
+            }
+            Self::InvalidTileSet(e) => write!(f, "could not create the tile set: {}", e),
+        }
+    }
+}
+
+impl From<TileSetCreationError> for AtlasTileSetCreationError {
+    fn from(e: TileSetCreationError) -> Self {
+        Self::InvalidTileSet(e)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// Layout of a sprite sheet: the size of its cells, and the cell (column and
+/// row) of each tile, used to create an [AtlasTileSet].
+pub struct AtlasGrid {
+    cell_width: u32,
+    cell_height: u32,
+    margin: u32,
+    spacing: u32,
+    cells: HashMap<Tile, (u32, u32)>,
+}
+
+impl AtlasGrid {
+    #[inline]
+    #[must_use]
+    /// Creates a new grid with cells of given size, in pixels, without any
+    /// tiles mapped to them.
+    pub fn new(cell_width: u32, cell_height: u32) -> Self {
+        Self {
+            cell_width,
+            cell_height,
+            margin: 0,
+            spacing: 0,
+            cells: HashMap::new(),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the grid with given margin, in pixels, between the edges of the
+    /// sprite sheet and the cells.
+    pub fn margin(self, margin: u32) -> Self {
+        Self { margin, ..self }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the grid with given spacing, in pixels, between the adjacent
+    /// cells.
+    pub fn spacing(self, spacing: u32) -> Self {
+        Self { spacing, ..self }
+    }
+
+    #[must_use]
+    /// Returns the grid with given tile mapped to the cell in given column
+    /// and row (both counted from zero).
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::raster_renderer::AtlasGrid;
+    /// use riichi_hand::tiles::{ANY, CHUN};
+    ///
+    /// let grid = AtlasGrid::new(30, 40).tile(CHUN, 6, 3).tile(ANY, 7, 3);
+    /// ```
+    pub fn tile(mut self, tile: Tile, column: u32, row: u32) -> Self {
+        self.cells.insert(tile, (column, row));
+        self
+    }
+
+    #[must_use]
+    /// Returns the grid with given tiles mapped to the consecutive cells of
+    /// given row (counted from zero), starting with the first column.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::raster_renderer::AtlasGrid;
+    /// use riichi_hand::tiles::{CHUN, HAKU, HATSU};
+    ///
+    /// let grid = AtlasGrid::new(30, 40).row(3, &[HAKU, HATSU, CHUN]);
+    /// let expected = AtlasGrid::new(30, 40)
+    ///     .tile(HAKU, 0, 3)
+    ///     .tile(HATSU, 1, 3)
+    ///     .tile(CHUN, 2, 3);
+    /// assert_eq!(grid, expected);
+    /// ```
+    pub fn row(self, row: u32, tiles: &[Tile]) -> Self {
+        tiles
+            .iter()
+            .zip(0..)
+            .fold(self, |grid, (&tile, column)| grid.tile(tile, column, row))
+    }
+
+    /// Returns the position of the top left corner of given cell, in pixels.
+    fn cell_position(&self, column: u32, row: u32) -> (u64, u64) {
+        let position = |index: u32, size: u32| {
+            u64::from(self.margin) + u64::from(index) * (u64::from(size) + u64::from(self.spacing))
+        };
+
+        (
+            position(column, self.cell_width),
+            position(row, self.cell_height),
+        )
+    }
+}
+
+#[derive(Debug)]
+/// An implementation of [TileSet] that slices a single sprite sheet (texture
+/// atlas) image into the tiles, as many tile art packs are shipped this way.
+///
+/// Similarly to [SimpleTileSet], the tiles are returned as is, and rotated
+/// tiles are not supported unless the tile set is created with
+/// [with_auto_rotation](AtlasTileSet::with_auto_rotation).
+///
+/// # Examples
+/// ```
+/// use riichi_hand::image::RgbaImage;
+/// use riichi_hand::parser::HandParser;
+/// use riichi_hand::raster_renderer::{AtlasGrid, AtlasTileSet, RasterRenderer, RenderOptions};
+/// use riichi_hand::tiles::ALL_TILES;
+///
+/// // 10 tiles per row: the red five and 1-9 of each suite, then the honors
+/// // and the tile back
+/// let sprite_sheet = RgbaImage::new(10 * 30, 4 * 40);
+/// let grid = ALL_TILES
+///     .chunks(10)
+///     .zip(0..)
+///     .fold(AtlasGrid::new(30, 40), |grid, (tiles, row)| grid.row(row, tiles));
+///
+/// let tile_set = AtlasTileSet::new(&sprite_sheet, &grid).unwrap();
+/// let hand = HandParser::parse("123m456p").unwrap();
+/// let image = RasterRenderer::render(&hand, &tile_set, RenderOptions::default()).unwrap();
+/// assert_eq!(image.height(), 40);
+/// ```
+pub struct AtlasTileSet {
+    tile_set: SimpleTileSet,
+}
+
+impl AtlasTileSet {
+    /// Creates a new [AtlasTileSet] instance slicing given sprite sheet into
+    /// the tiles using given grid. The grid must contain the cells of all the
+    /// tiles.
+    pub fn new(
+        sprite_sheet: &RgbaImage,
+        grid: &AtlasGrid,
+    ) -> Result<Self, AtlasTileSetCreationError> {
+        let mut tile_map = HashMap::with_capacity(grid.cells.len());
+        for (&tile, &(column, row)) in &grid.cells {
+            let (x, y) = grid.cell_position(column, row);
+            if x + u64::from(grid.cell_width) > u64::from(sprite_sheet.width())
+                || y + u64::from(grid.cell_height) > u64::from(sprite_sheet.height())
+            {
+                return Err(AtlasTileSetCreationError::CellOutOfBounds(tile));
+            }
+
+            let image = image::imageops::crop_imm(
+                sprite_sheet,
+                x as u32,
+                y as u32,
+                grid.cell_width,
+                grid.cell_height,
+            );
+            tile_map.insert(tile, image.to_image());
+        }
+
+        Ok(Self {
+            tile_set: SimpleTileSet::new(tile_map)?,
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the tile set that supports rotated tiles by rotating the tile
+    /// images, as in [SimpleTileSet::with_auto_rotation].
+    pub fn with_auto_rotation(self) -> Self {
+        Self {
+            tile_set: self.tile_set.with_auto_rotation(),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the tile set with given attribution information, returned by
+    /// [TileSet::metadata].
+    pub fn with_metadata(self, metadata: TileSetMetadata) -> Self {
+        Self {
+            tile_set: self.tile_set.with_metadata(metadata),
+        }
+    }
+}
+
+impl TileSet for AtlasTileSet {
+    #[inline]
+    fn tile_image(&self, hand_tile: &HandTile) -> TileImageResult {
+        self.tile_set.tile_image(hand_tile)
+    }
+
+    #[inline]
+    fn tile_image_ref(&self, hand_tile: &HandTile) -> TileImageRefResult<'_> {
+        self.tile_set.tile_image_ref(hand_tile)
+    }
+
+    #[inline]
+    fn metadata(&self) -> Option<TileSetMetadata> {
+        self.tile_set.metadata()
+    }
+
+    #[inline]
+    fn tile_width(&self) -> u32 {
+        self.tile_set.tile_width()
+    }
+
+    #[inline]
+    fn tile_height(&self) -> u32 {
+        self.tile_set.tile_height()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use image::{Rgba, RgbaImage};
+
+    use crate::raster_renderer::{
+        AtlasGrid, AtlasTileSet, AtlasTileSetCreationError, TileSet, TileSetCreationError,
+    };
+    use crate::tiles::*;
+    use crate::HandTile;
+    use crate::TilePlacement::{Normal, Rotated};
+
+    fn grid() -> AtlasGrid {
+        ALL_TILES
+            .chunks(10)
+            .zip(0..)
+            .fold(AtlasGrid::new(3, 4), |grid, (tiles, row)| {
+                grid.row(row, tiles)
+            })
+            .margin(2)
+            .spacing(1)
+    }
+
+    #[test]
+    fn should_slice_sprite_sheet() {
+        let red = Rgba([255, 0, 0, 255]);
+        let mut sprite_sheet = RgbaImage::new(2 + 10 * 4, 2 + 4 * 5);
+        // CHUN is the 7th tile of the 4th row
+        for x in 0..3 {
+            for y in 0..4 {
+                sprite_sheet.put_pixel(2 + 6 * 4 + x, 2 + 3 * 5 + y, red);
+            }
+        }
+
+        let tile_set = AtlasTileSet::new(&sprite_sheet, &grid()).unwrap();
+
+        assert_eq!((tile_set.tile_width(), tile_set.tile_height()), (3, 4));
+        let chun = tile_set.tile_image(&HandTile::new(CHUN, Normal)).unwrap();
+        assert!(chun.pixels().all(|&pixel| pixel == red));
+        let hatsu = tile_set.tile_image(&HandTile::new(HATSU, Normal)).unwrap();
+        assert!(hatsu.pixels().all(|&pixel| pixel != red));
+        assert!(tile_set.tile_image(&HandTile::new(CHUN, Rotated)).is_err());
+        let tile_set = tile_set.with_auto_rotation();
+        assert_eq!(
+            tile_set
+                .tile_image(&HandTile::new(CHUN, Rotated))
+                .unwrap()
+                .dimensions(),
+            (4, 3)
+        );
+    }
+
+    #[test]
+    fn should_return_creation_errors() {
+        let sprite_sheet = RgbaImage::new(2 + 10 * 4, 2 + 4 * 5);
+
+        assert!(matches!(
+            AtlasTileSet::new(&sprite_sheet, &grid().tile(CHUN, 10, 0)),
+            Err(AtlasTileSetCreationError::CellOutOfBounds(CHUN))
+        ));
+        assert!(matches!(
+            AtlasTileSet::new(&sprite_sheet, &AtlasGrid::new(3, 4).tile(CHUN, 0, 0)),
+            Err(AtlasTileSetCreationError::InvalidTileSet(
+                TileSetCreationError::TileMissing(_)
+            ))
+        ));
+    }
+}
@@ -0,0 +1,353 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+use image::{ImageError, RgbaImage};
+
+use crate::raster_renderer::{
+    SimpleTileSet, TileImageRefResult, TileImageResult, TileSet, TileSetCreationError,
+    TwoPartTileSet,
+};
+use crate::tiles::ALL_TILES;
+use crate::{HandTile, Suite, Tile};
+
+/// File extensions of the tile images, in the order they are looked up.
+pub(super) const IMAGE_EXTENSIONS: [&str; 3] = ["png", "jpg", "jpeg"];
+
+const SUITE_NAMES: [&str; 3] = ["Man", "Pin", "Sou"];
+const HONOR_NAMES: [&str; 7] = ["Ton", "Nan", "Shaa", "Pei", "Haku", "Hatsu", "Chun"];
+
+#[derive(Copy, Clone, Debug)]
+/// Mapping between the tiles and the names (without the extensions) of the
+/// image files of a tile set.
+///
+/// Along with the images of all the tiles (where [ANY](crate::tiles::ANY) is
+/// the tile back), a tile set can optionally contain a front (background)
+/// image. If it does, the tile images are treated as foregrounds drawn on top
+/// of it (see [TwoPartTileSet]); otherwise, they are complete tile images
+/// (see [SimpleTileSet]).
+pub enum NamingScheme {
+    /// Names used by the bundled tile sets, e.g. `Man1`, `Man5-Dora` (red
+    /// five), `Ton`, `Chun`, `Back` and `Front`.
+    Default,
+    /// Names in the numeric (MPSZ) notation, e.g. `1m`, `0m` (red five),
+    /// `1z`, `7z`, `back` and `front`.
+    Numeric,
+    /// Custom names.
+    Custom {
+        /// Function that returns the name of given tile's image.
+        tile: fn(Tile) -> String,
+        /// Name of the front image.
+        front: &'static str,
+    },
+}
+
+impl NamingScheme {
+    /// Returns the name (without the extension) of given tile's image file.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::raster_renderer::NamingScheme;
+    /// use riichi_hand::tiles::{AKADORA_PIN, ANY, HATSU};
+    ///
+    /// assert_eq!(NamingScheme::Default.tile_file_stem(AKADORA_PIN), "Pin5-Dora");
+    /// assert_eq!(NamingScheme::Default.tile_file_stem(HATSU), "Hatsu");
+    /// assert_eq!(NamingScheme::Numeric.tile_file_stem(HATSU), "6z");
+    /// assert_eq!(NamingScheme::Numeric.tile_file_stem(ANY), "back");
+    /// ```
+    pub fn tile_file_stem(&self, tile: Tile) -> String {
+        match self {
+            Self::Default => match tile.suite {
+                Suite::Manzu | Suite::Pinzu | Suite::Souzu => {
+                    let suite = SUITE_NAMES[tile.suite as usize];
+                    match tile.value.0 {
+                        0 => format!("{}5-Dora", suite),
+                        value => format!("{}{}", suite, value),
+                    }
+                }
+                Suite::Honor => HONOR_NAMES[usize::from(tile.value) - 1].to_owned(),
+                Suite::Any => "Back".to_owned(),
+            },
+            Self::Numeric => match tile.suite {
+                Suite::Manzu => format!("{}m", tile.value),
+                Suite::Pinzu => format!("{}p", tile.value),
+                Suite::Souzu => format!("{}s", tile.value),
+                Suite::Honor => format!("{}z", tile.value),
+                Suite::Any => "back".to_owned(),
+            },
+            Self::Custom { tile: name, .. } => name(tile),
+        }
+    }
+
+    /// Returns the name (without the extension) of the front image file.
+    pub fn front_file_stem(&self) -> &str {
+        match self {
+            Self::Default => "Front",
+            Self::Numeric => "front",
+            Self::Custom { front, .. } => front,
+        }
+    }
+}
+
+#[derive(Debug)]
+/// An error that occurs when loading a tile set with [DirectoryTileSet::load]
+/// (or from a zip archive).
+pub enum TileSetLoadError {
+    /// There is no image of given tile (with any of the supported
+    /// extensions).
+    TileMissing(Tile),
+    /// Given image file could not be read or decoded.
+    InvalidImage(PathBuf, ImageError),
+    /// The tile set could not be created from the loaded images.
+    TileSetCreationError(TileSetCreationError),
+    #[cfg(feature = "zip-tile-sets")]
+    /// The zip archive could not be read.
+    InvalidArchive(zip::result::ZipError),
+}
+
+impl Error for TileSetLoadError {}
+
+impl Display for TileSetLoadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TileMissing(tile) => write!(f, "tile image missing: {}", tile),
+            Self::InvalidImage(path, e) => {
+                write!(f, "could not load image {}: {}", path.display(), e)
+            }
+            Self::TileSetCreationError(e) => write!(f, "could not create tile set: {}", e),
+            #[cfg(feature = "zip-tile-sets")]
+            Self::InvalidArchive(e) => write!(f, "could not read zip archive: {}", e),
+        }
+    }
+}
+
+impl From<TileSetCreationError> for TileSetLoadError {
+    fn from(e: TileSetCreationError) -> Self {
+        Self::TileSetCreationError(e)
+    }
+}
+
+#[derive(Debug)]
+/// Tile set loaded at runtime: either complete tile images, or foregrounds
+/// drawn on top of a front image.
+pub(super) enum LoadedTileSet {
+    Simple(SimpleTileSet),
+    TwoPart(TwoPartTileSet),
+}
+
+impl LoadedTileSet {
+    /// Loads a tile set using given function that returns the image with
+    /// given name, if it exists.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(load_image), err(level = "debug"))
+    )]
+    pub(super) fn load<F>(
+        naming_scheme: NamingScheme,
+        mut load_image: F,
+    ) -> Result<Self, TileSetLoadError>
+    where
+        F: FnMut(&str) -> Result<Option<RgbaImage>, TileSetLoadError>,
+    {
+        #[cfg(feature = "tracing")]
+        let start = std::time::Instant::now();
+
+        let mut tile_map = HashMap::with_capacity(ALL_TILES.len());
+        for tile in ALL_TILES {
+            let image = load_image(&naming_scheme.tile_file_stem(tile))?
+                .ok_or(TileSetLoadError::TileMissing(tile))?;
+            tile_map.insert(tile, image);
+        }
+
+        let tile_set = match load_image(naming_scheme.front_file_stem())? {
+            Some(front) => Self::TwoPart(TwoPartTileSet::new(front, tile_map)?),
+            None => Self::Simple(SimpleTileSet::new(tile_map)?),
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            tile_width = tile_set.tile_width(),
+            tile_height = tile_set.tile_height(),
+            two_part = matches!(tile_set, Self::TwoPart(_)),
+            elapsed = ?start.elapsed(),
+            "loaded tile set"
+        );
+
+        Ok(tile_set)
+    }
+
+    fn tile_set(&self) -> &dyn TileSet {
+        match self {
+            Self::Simple(tile_set) => tile_set,
+            Self::TwoPart(tile_set) => tile_set,
+        }
+    }
+}
+
+impl TileSet for LoadedTileSet {
+    #[inline]
+    fn tile_image(&self, hand_tile: &HandTile) -> TileImageResult {
+        self.tile_set().tile_image(hand_tile)
+    }
+
+    #[inline]
+    fn tile_image_ref(&self, hand_tile: &HandTile) -> TileImageRefResult<'_> {
+        self.tile_set().tile_image_ref(hand_tile)
+    }
+
+    #[inline]
+    fn tile_width(&self) -> u32 {
+        self.tile_set().tile_width()
+    }
+
+    #[inline]
+    fn tile_height(&self) -> u32 {
+        self.tile_set().tile_height()
+    }
+}
+
+#[derive(Debug)]
+/// An implementation of [TileSet] that loads PNG or JPEG tile images from a
+/// directory at runtime, so that custom tile art can be used without
+/// building the tile map by hand.
+///
+/// # Examples
+/// ```no_run
+/// use riichi_hand::parser::HandParser;
+/// use riichi_hand::raster_renderer::{
+///     DirectoryTileSet, NamingScheme, RasterRenderer, RenderOptions,
+/// };
+///
+/// let tile_set = DirectoryTileSet::load("my_tiles", NamingScheme::Numeric).unwrap();
+/// let hand = HandParser::parse("123m456p").unwrap();
+/// let image = RasterRenderer::render(&hand, &tile_set, RenderOptions::default()).unwrap();
+/// ```
+pub struct DirectoryTileSet {
+    tile_set: LoadedTileSet,
+}
+
+impl DirectoryTileSet {
+    /// Loads the tile images from given directory. The images are looked up
+    /// by the names returned by given [NamingScheme], with the `png`, `jpg`
+    /// or `jpeg` extension.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(path = %path.as_ref().display()))
+    )]
+    pub fn load<P: AsRef<Path>>(
+        path: P,
+        naming_scheme: NamingScheme,
+    ) -> Result<Self, TileSetLoadError> {
+        let path = path.as_ref();
+        let tile_set = LoadedTileSet::load(naming_scheme, |name| {
+            let file = IMAGE_EXTENSIONS
+                .iter()
+                .map(|extension| path.join(format!("{}.{}", name, extension)))
+                .find(|file| file.is_file());
+            match file {
+                Some(file) => image::open(&file)
+                    .map(|image| Some(image.to_rgba8()))
+                    .map_err(|e| TileSetLoadError::InvalidImage(file, e)),
+                None => Ok(None),
+            }
+        })?;
+
+        Ok(Self { tile_set })
+    }
+}
+
+impl TileSet for DirectoryTileSet {
+    #[inline]
+    fn tile_image(&self, hand_tile: &HandTile) -> TileImageResult {
+        self.tile_set.tile_image(hand_tile)
+    }
+
+    #[inline]
+    fn tile_image_ref(&self, hand_tile: &HandTile) -> TileImageRefResult<'_> {
+        self.tile_set.tile_image_ref(hand_tile)
+    }
+
+    #[inline]
+    fn tile_width(&self) -> u32 {
+        self.tile_set.tile_width()
+    }
+
+    #[inline]
+    fn tile_height(&self) -> u32 {
+        self.tile_set.tile_height()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use image::{Rgba, RgbaImage};
+
+    use crate::raster_renderer::{DirectoryTileSet, NamingScheme, TileSet, TileSetLoadError};
+    use crate::tiles::*;
+    use crate::HandTile;
+    use crate::TilePlacement::Normal;
+
+    fn tile_set_dir(name: &str, naming_scheme: NamingScheme, front: bool) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("riichi_hand_{}_{}", name, std::process::id()));
+        fs::create_dir_all(&path).unwrap();
+
+        let red = Rgba([255, 0, 0, 255]);
+        for tile in ALL_TILES {
+            let file = path.join(format!("{}.png", naming_scheme.tile_file_stem(tile)));
+            RgbaImage::from_pixel(3, 4, red).save(file).unwrap();
+        }
+        if front {
+            let file = path.join(format!("{}.png", naming_scheme.front_file_stem()));
+            RgbaImage::from_pixel(3, 4, Rgba([255, 255, 255, 255]))
+                .save(file)
+                .unwrap();
+        }
+
+        path
+    }
+
+    #[test]
+    fn should_load_tile_set_from_directory() {
+        let path = tile_set_dir("directory_tile_set", NamingScheme::Numeric, false);
+
+        let tile_set = DirectoryTileSet::load(&path, NamingScheme::Numeric).unwrap();
+
+        assert_eq!((tile_set.tile_width(), tile_set.tile_height()), (3, 4));
+        let image = tile_set.tile_image(&HandTile::new(HATSU, Normal)).unwrap();
+        assert_eq!(*image.get_pixel(0, 0), Rgba([255, 0, 0, 255]));
+        assert!(matches!(
+            DirectoryTileSet::load(&path, NamingScheme::Default),
+            Err(TileSetLoadError::TileMissing(_))
+        ));
+        fs::remove_dir_all(path).unwrap();
+    }
+
+    #[test]
+    fn should_load_two_part_tile_set_from_directory() {
+        let path = tile_set_dir("two_part_directory_tile_set", NamingScheme::Default, true);
+
+        let tile_set = DirectoryTileSet::load(&path, NamingScheme::Default).unwrap();
+
+        let image = tile_set.tile_image(&HandTile::new(II_MAN, Normal)).unwrap();
+        assert_eq!(*image.get_pixel(0, 0), Rgba([255, 0, 0, 255]));
+        fs::remove_dir_all(path).unwrap();
+    }
+
+    #[test]
+    fn should_name_tiles_with_custom_scheme() {
+        let naming_scheme = NamingScheme::Custom {
+            tile: |tile| format!("tile_{}", tile.name().replace(' ', "_")),
+            front: "tile_front",
+        };
+
+        assert_eq!(naming_scheme.tile_file_stem(II_MAN), "tile_Ii_man");
+        assert_eq!(naming_scheme.front_file_stem(), "tile_front");
+        assert_eq!(NamingScheme::Default.tile_file_stem(KYUU_SOU), "Sou9");
+        assert_eq!(NamingScheme::Numeric.tile_file_stem(AKADORA_MAN), "0m");
+    }
+}
@@ -0,0 +1,518 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::PngEncoder;
+use image::{DynamicImage, ImageEncoder, ImageError};
+
+use crate::parser::{HandParseError, HandParser};
+use crate::raster_renderer::{
+    HandRenderError, ImageType, RasterRenderer, RenderOptions, TileImageRetrieveError, TileSet,
+};
+use crate::Hand;
+
+/// Length of an inch, in meters.
+const METERS_PER_INCH: f64 = 0.0254;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+/// Format of an encoded rendered image.
+pub enum OutputFormat {
+    /// Lossless PNG.
+    Png,
+    /// Lossless PNG with the physical resolution metadata (the `pHYs` chunk),
+    /// so that the image is printed at a predictable size.
+    PngWithDpi {
+        /// Resolution of the image, in dots (pixels) per inch.
+        dpi: u32,
+    },
+    /// Lossy JPEG of given quality (from 1 to 100). JPEG does not support
+    /// transparency, so the transparent areas become black; consider setting
+    /// an opaque background in the render options.
+    Jpeg {
+        /// Quality of the image, from 1 (smallest) to 100 (best).
+        quality: u8,
+    },
+    /// Lossless WebP.
+    WebPLossless,
+    /// Lossy WebP of given quality (from 0 to 100). Usually much smaller than
+    /// PNG, which makes it a good choice for sending images over the network.
+    WebPLossy {
+        /// Quality of the image, from 0 (smallest) to 100 (best).
+        quality: f32,
+    },
+}
+
+/// Result of [RenderedImage::encode] and [encode_apng].
+pub type EncodeResult = Result<Vec<u8>, EncodeError>;
+
+/// Helpers for encoding the images rendered with
+/// [RasterRenderer](super::RasterRenderer), including the formats that
+/// `image::save` does not support or does not allow to configure.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::parser::HandParser;
+/// use riichi_hand::raster_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_TILE_SET;
+/// use riichi_hand::raster_renderer::{OutputFormat, RasterRenderer, RenderOptions, RenderedImage};
+///
+/// let hand = HandParser::parse("123m456p").unwrap();
+/// let image = RasterRenderer::render(&hand, &*YELLOW_FLUFFY_STUFF_TILE_SET, RenderOptions::default())
+///     .unwrap();
+/// let webp = image.encode(OutputFormat::WebPLossy { quality: 80.0 }).unwrap();
+/// assert_eq!(&webp[8..12], b"WEBP");
+/// ```
+pub trait RenderedImage {
+    /// Encodes the image in given format, writing it to given stream (e.g. a
+    /// file, or an HTTP response body).
+    fn encode_to<W: Write>(&self, writer: W, format: OutputFormat) -> Result<(), EncodeError>;
+
+    /// Encodes the image in given format.
+    fn encode(&self, format: OutputFormat) -> EncodeResult {
+        let mut data = Vec::new();
+        self.encode_to(&mut data, format)?;
+
+        Ok(data)
+    }
+
+    /// Encodes the image in given format and saves it to given path,
+    /// regardless of the file extension.
+    fn save_as<P: AsRef<Path>>(&self, path: P, format: OutputFormat) -> Result<(), EncodeError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.encode_to(&mut writer, format)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+}
+
+impl RenderedImage for ImageType {
+    fn encode_to<W: Write>(&self, mut writer: W, format: OutputFormat) -> Result<(), EncodeError> {
+        match format {
+            OutputFormat::Png => {
+                PngEncoder::new(writer).write_image(
+                    self,
+                    self.width(),
+                    self.height(),
+                    image::ExtendedColorType::Rgba8,
+                )?;
+            }
+            OutputFormat::PngWithDpi { dpi } => encode_png_with_dpi(self, writer, dpi)?,
+            OutputFormat::Jpeg { quality } => {
+                let rgb = DynamicImage::ImageRgba8(self.clone()).to_rgb8();
+                JpegEncoder::new_with_quality(writer, quality).write_image(
+                    &rgb,
+                    rgb.width(),
+                    rgb.height(),
+                    image::ExtendedColorType::Rgb8,
+                )?;
+            }
+            OutputFormat::WebPLossless => writer.write_all(&encode_webp(self, true, 100.0)?)?,
+            OutputFormat::WebPLossy { quality } => {
+                writer.write_all(&encode_webp(self, false, quality)?)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl RenderedImage for DynamicImage {
+    fn encode_to<W: Write>(&self, writer: W, format: OutputFormat) -> Result<(), EncodeError> {
+        match self.as_rgba8() {
+            Some(image) => image.encode_to(writer, format),
+            None => self.to_rgba8().encode_to(writer, format),
+        }
+    }
+}
+
+impl<'a, T: TileSet> RasterRenderer<'a, T> {
+    /// Renders given [Hand] instance using [TileSet] and [RenderOptions], and
+    /// encodes the image in given format straight into given stream (e.g. an
+    /// HTTP response body), without saving it to a file first.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::parser::HandParser;
+    /// use riichi_hand::raster_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_TILE_SET;
+    /// use riichi_hand::raster_renderer::{OutputFormat, RasterRenderer, RenderOptions};
+    ///
+    /// let hand = HandParser::parse("123m456p").unwrap();
+    /// let mut response_body = Vec::new();
+    /// RasterRenderer::render_to_writer(
+    ///     &hand,
+    ///     &*YELLOW_FLUFFY_STUFF_TILE_SET,
+    ///     RenderOptions::default(),
+    ///     OutputFormat::Png,
+    ///     &mut response_body,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(&response_body[1..4], b"PNG");
+    /// ```
+    pub fn render_to_writer<W: Write>(
+        hand: &Hand,
+        tile_set: &'a T,
+        options: RenderOptions,
+        format: OutputFormat,
+        writer: W,
+    ) -> Result<(), EncodeError> {
+        let image = Self::render(hand, tile_set, options)?;
+
+        image.encode_to(writer, format)
+    }
+}
+
+/// Parses given hand string, renders it using [TileSet] and [RenderOptions],
+/// and encodes the image in given format, all in one call. This is what most
+/// web services and chat bots rendering the hands need.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::raster_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_TILE_SET;
+/// use riichi_hand::raster_renderer::{render_hand_to_bytes, OutputFormat, RenderOptions};
+///
+/// let png = render_hand_to_bytes(
+///     "123m456p",
+///     &*YELLOW_FLUFFY_STUFF_TILE_SET,
+///     RenderOptions::default(),
+///     OutputFormat::Png,
+/// )
+/// .unwrap();
+/// assert_eq!(&png[1..4], b"PNG");
+/// ```
+pub fn render_hand_to_bytes<T: TileSet>(
+    hand: &str,
+    tile_set: &T,
+    options: RenderOptions,
+    format: OutputFormat,
+) -> Result<Vec<u8>, RenderToBytesError> {
+    let hand = HandParser::parse(hand)?;
+    let mut data = Vec::new();
+    RasterRenderer::render_to_writer(&hand, tile_set, options, format, &mut data)?;
+
+    Ok(data)
+}
+
+fn encode_png_with_dpi<W: Write>(
+    image: &ImageType,
+    writer: W,
+    dpi: u32,
+) -> Result<(), EncodeError> {
+    let pixels_per_meter = (f64::from(dpi) / METERS_PER_INCH).round() as u32;
+
+    let mut encoder = png::Encoder::new(writer, image.width(), image.height());
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_pixel_dims(Some(png::PixelDimensions {
+        xppu: pixels_per_meter,
+        yppu: pixels_per_meter,
+        unit: png::Unit::Meter,
+    }));
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(image)?;
+    writer.finish()?;
+
+    Ok(())
+}
+
+fn encode_webp(image: &ImageType, lossless: bool, quality: f32) -> EncodeResult {
+    let encoder = webp::Encoder::from_rgba(image.as_raw(), image.width(), image.height());
+    let memory = encoder
+        .encode_simple(lossless, quality)
+        .map_err(|error| EncodeError::WebPEncodeError(format!("{:?}", error)))?;
+
+    Ok(memory.to_vec())
+}
+
+/// Encodes given frames (e.g. rendered with
+/// `RasterRenderer::render_frames`) as an infinitely looping animated PNG.
+/// Each frame is shown for `frame_delay_ms` milliseconds. All the frames must
+/// have the same dimensions.
+pub fn encode_apng(frames: &[ImageType], frame_delay_ms: u16) -> EncodeResult {
+    let (width, height) = match frames.first() {
+        Some(frame) => frame.dimensions(),
+        None => return Err(EncodeError::NoFrames),
+    };
+    if frames
+        .iter()
+        .any(|frame| frame.dimensions() != (width, height))
+    {
+        return Err(EncodeError::FrameDimensionsDiffer);
+    }
+
+    let mut data = Vec::new();
+    {
+        let mut encoder = png::Encoder::new(&mut data, width, height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.set_animated(frames.len() as u32, 0)?;
+        encoder.set_frame_delay(frame_delay_ms, 1000)?;
+
+        let mut writer = encoder.write_header()?;
+        for frame in frames {
+            writer.write_image_data(frame)?;
+        }
+        writer.finish()?;
+    }
+
+    Ok(data)
+}
+
+#[derive(Debug)]
+/// An error that occurs when encoding a rendered image.
+pub enum EncodeError {
+    /// Error occurred when retrieving a tile image.
+    TileImageRetrieveError(TileImageRetrieveError),
+    /// Error occurred when encoding an image with the `image` crate.
+    ImageEncodeError(ImageError),
+    /// Error occurred when encoding an animated PNG.
+    PngEncodeError(png::EncodingError),
+    /// Error occurred when encoding a WebP image.
+    WebPEncodeError(String),
+    /// Error occurred when writing the encoded image to a file.
+    IoError(io::Error),
+    /// No frames were given to encode an animation.
+    NoFrames,
+    /// The frames of an animation do not have equal dimensions.
+    FrameDimensionsDiffer,
+}
+
+impl Error for EncodeError {}
+
+impl Display for EncodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TileImageRetrieveError(inner_error) => {
+                write!(f, "could not retrieve tile image: {}", inner_error)
+            }
+            Self::ImageEncodeError(inner_error) => {
+                write!(f, "could not encode image: {}", inner_error)
+            }
+            Self::PngEncodeError(inner_error) => {
+                write!(f, "could not encode animated PNG: {}", inner_error)
+            }
+            Self::WebPEncodeError(inner_error) => {
+                write!(f, "could not encode WebP image: {}", inner_error)
+            }
+            Self::IoError(inner_error) => {
+                write!(f, "could not write image: {}", inner_error)
+            }
+            Self::NoFrames => write!(f, "no frames to encode"),
+            Self::FrameDimensionsDiffer => {
+                write!(f, "frames do not have equal dimensions")
+            }
+        }
+    }
+}
+
+impl From<HandRenderError> for EncodeError {
+    fn from(error: HandRenderError) -> Self {
+        match error {
+            HandRenderError::TileImageRetrieveError(inner_error) => {
+                Self::TileImageRetrieveError(inner_error)
+            }
+        }
+    }
+}
+
+impl From<ImageError> for EncodeError {
+    fn from(error: ImageError) -> Self {
+        Self::ImageEncodeError(error)
+    }
+}
+
+impl From<png::EncodingError> for EncodeError {
+    fn from(error: png::EncodingError) -> Self {
+        Self::PngEncodeError(error)
+    }
+}
+
+impl From<io::Error> for EncodeError {
+    fn from(error: io::Error) -> Self {
+        Self::IoError(error)
+    }
+}
+
+#[derive(Debug)]
+/// An error that occurs when parsing, rendering and encoding a hand with
+/// [render_hand_to_bytes].
+pub enum RenderToBytesError {
+    /// Error occurred when parsing the hand.
+    HandParseError(HandParseError),
+    /// Error occurred when rendering or encoding the image.
+    EncodeError(EncodeError),
+}
+
+impl Error for RenderToBytesError {}
+
+impl Display for RenderToBytesError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::HandParseError(inner_error) => write!(f, "{}", inner_error),
+            Self::EncodeError(inner_error) => write!(f, "{}", inner_error),
+        }
+    }
+}
+
+impl From<HandParseError> for RenderToBytesError {
+    fn from(error: HandParseError) -> Self {
+        Self::HandParseError(error)
+    }
+}
+
+impl From<EncodeError> for RenderToBytesError {
+    fn from(error: EncodeError) -> Self {
+        Self::EncodeError(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use image::{DynamicImage, ImageFormat, Rgba, RgbaImage};
+
+    use crate::parser::HandParser;
+    use crate::raster_renderer::encoding::{EncodeError, RenderToBytesError};
+    use crate::raster_renderer::{
+        encode_apng, render_hand_to_bytes, OutputFormat, RasterRenderer, RenderOptions,
+        RenderedImage, SimpleTileSet,
+    };
+    use crate::tiles::*;
+
+    fn test_image() -> RgbaImage {
+        RgbaImage::from_fn(8, 6, |x, y| Rgba([(x * 30) as u8, (y * 40) as u8, 0, 255]))
+    }
+
+    #[test]
+    fn should_encode_image_formats() {
+        let image = test_image();
+
+        let png = image.encode(OutputFormat::Png).unwrap();
+        let jpeg = image.encode(OutputFormat::Jpeg { quality: 90 }).unwrap();
+        let lossless = image.encode(OutputFormat::WebPLossless).unwrap();
+        let lossy = image
+            .encode(OutputFormat::WebPLossy { quality: 50.0 })
+            .unwrap();
+
+        assert_eq!(image::guess_format(&png).unwrap(), ImageFormat::Png);
+        assert_eq!(image::guess_format(&jpeg).unwrap(), ImageFormat::Jpeg);
+        assert_eq!(image::guess_format(&lossless).unwrap(), ImageFormat::WebP);
+        assert_eq!(image::guess_format(&lossy).unwrap(), ImageFormat::WebP);
+        let decoded = image::load_from_memory(&png).unwrap().to_rgba8();
+        assert!(decoded == image, "decoded and original images differ");
+    }
+
+    #[test]
+    fn should_encode_dynamic_images() {
+        let image = DynamicImage::ImageRgba8(test_image());
+
+        let png = image.encode(OutputFormat::Png).unwrap();
+        let gray = DynamicImage::ImageLuma8(image.to_luma8())
+            .encode(OutputFormat::WebPLossless)
+            .unwrap();
+
+        assert_eq!(image::guess_format(&png).unwrap(), ImageFormat::Png);
+        assert_eq!(image::guess_format(&gray).unwrap(), ImageFormat::WebP);
+        let decoded = image::load_from_memory(&png).unwrap();
+        assert!(decoded == image, "decoded and original images differ");
+    }
+
+    #[test]
+    fn should_render_to_writer() {
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(3, 4, Rgba([255, 0, 0, 255]))))
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let hand = HandParser::parse("12m").unwrap();
+
+        let mut data = Vec::new();
+        RasterRenderer::render_to_writer(
+            &hand,
+            &tile_set,
+            RenderOptions::default(),
+            OutputFormat::Png,
+            &mut data,
+        )
+        .unwrap();
+
+        assert_eq!(image::guess_format(&data).unwrap(), ImageFormat::Png);
+        let expected = RasterRenderer::render(&hand, &tile_set, RenderOptions::default()).unwrap();
+        let decoded = image::load_from_memory(&data).unwrap().to_rgba8();
+        assert!(decoded == expected, "decoded and rendered images differ");
+    }
+
+    #[test]
+    fn should_render_hand_to_bytes() {
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(3, 4, Rgba([255, 0, 0, 255]))))
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+
+        let data = render_hand_to_bytes(
+            "12m",
+            &tile_set,
+            RenderOptions::default(),
+            OutputFormat::Jpeg { quality: 90 },
+        )
+        .unwrap();
+        assert_eq!(image::guess_format(&data).unwrap(), ImageFormat::Jpeg);
+        assert_eq!(image::load_from_memory(&data).unwrap().width(), 6);
+
+        let error = render_hand_to_bytes(
+            "12x",
+            &tile_set,
+            RenderOptions::default(),
+            OutputFormat::Png,
+        )
+        .unwrap_err();
+        assert!(matches!(error, RenderToBytesError::HandParseError(_)));
+    }
+
+    #[test]
+    fn should_encode_png_with_dpi() {
+        let image = test_image();
+
+        let png = image.encode(OutputFormat::PngWithDpi { dpi: 300 }).unwrap();
+
+        let decoded = image::load_from_memory(&png).unwrap().to_rgba8();
+        assert!(decoded == image, "decoded and original images differ");
+        let reader = png::Decoder::new(std::io::Cursor::new(png))
+            .read_info()
+            .unwrap();
+        let pixel_dims = reader.info().pixel_dims.unwrap();
+        assert_eq!(pixel_dims.xppu, 11811);
+        assert_eq!(pixel_dims.yppu, 11811);
+        assert_eq!(pixel_dims.unit, png::Unit::Meter);
+    }
+
+    #[test]
+    fn should_encode_apng() {
+        let frames = vec![test_image(), RgbaImage::new(8, 6)];
+
+        let apng = encode_apng(&frames, 250).unwrap();
+
+        assert_eq!(image::guess_format(&apng).unwrap(), ImageFormat::Png);
+        let decoder = png::Decoder::new(std::io::Cursor::new(apng));
+        let reader = decoder.read_info().unwrap();
+        let animation = reader.info().animation_control().unwrap();
+        assert_eq!(animation.num_frames, 2);
+        assert_eq!(animation.num_plays, 0);
+    }
+
+    #[test]
+    fn should_fail_encoding_invalid_apng() {
+        let frames = vec![test_image(), RgbaImage::new(3, 3)];
+
+        assert!(matches!(encode_apng(&[], 100), Err(EncodeError::NoFrames)));
+        assert!(matches!(
+            encode_apng(&frames, 100),
+            Err(EncodeError::FrameDimensionsDiffer)
+        ));
+    }
+}
@@ -0,0 +1,136 @@
+use crate::raster_renderer::{
+    ScaledTileSet, TileImageRefResult, TileImageResult, TileImageRetrieveError, TileSet,
+    TileSetMetadata,
+};
+use crate::{BonusTile, HandTile, TilePlacement};
+
+#[derive(Debug)]
+/// An implementation of [TileSet] that returns the tiles of the primary tile
+/// set, or the tiles of the secondary tile set if the primary one does not
+/// support them (e.g. the rotated tiles of a
+/// [SimpleTileSet](crate::raster_renderer::SimpleTileSet)).
+///
+/// The tiles of the secondary tile set are resized to the tile height of the
+/// primary tile set, if needed.
+///
+/// The [metadata](TileSet::metadata) of the primary tile set is returned, if
+/// any; otherwise, the metadata of the secondary tile set is. Note that the
+/// authors of both tile sets might need to be credited.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::parser::HandParser;
+/// use riichi_hand::raster_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_TILE_SET;
+/// use riichi_hand::raster_renderer::martin_persson_tile_sets::MARTIN_PERSSON_TILE_SET;
+/// use riichi_hand::raster_renderer::{FallbackTileSet, RasterRenderer, RenderOptions};
+///
+/// let tile_set = FallbackTileSet::new(&*MARTIN_PERSSON_TILE_SET, &*YELLOW_FLUFFY_STUFF_TILE_SET);
+/// let hand = HandParser::parse("123m_1*11p").unwrap();
+/// let image = RasterRenderer::render(&hand, &tile_set, RenderOptions::default()).unwrap();
+/// ```
+pub struct FallbackTileSet<P: TileSet, S: TileSet> {
+    primary: P,
+    secondary: ScaledTileSet<S>,
+}
+
+impl<P: TileSet, S: TileSet> FallbackTileSet<P, S> {
+    /// Creates a new [FallbackTileSet] instance using given primary and
+    /// secondary tile sets.
+    pub fn new(primary: P, secondary: S) -> Self {
+        let secondary = ScaledTileSet::new(secondary, primary.tile_height());
+
+        Self { primary, secondary }
+    }
+}
+
+impl<P: TileSet, S: TileSet> TileSet for FallbackTileSet<P, S> {
+    fn tile_image(&self, hand_tile: &HandTile) -> TileImageResult {
+        match self.primary.tile_image(hand_tile) {
+            Err(TileImageRetrieveError::TileNotSupported(..)) => {
+                self.secondary.tile_image(hand_tile)
+            }
+            result => result,
+        }
+    }
+
+    fn tile_image_ref(&self, hand_tile: &HandTile) -> TileImageRefResult<'_> {
+        match self.primary.tile_image_ref(hand_tile) {
+            Err(TileImageRetrieveError::TileNotSupported(..)) => {
+                self.secondary.tile_image_ref(hand_tile)
+            }
+            result => result,
+        }
+    }
+
+    fn bonus_tile_image(&self, tile: BonusTile, placement: TilePlacement) -> TileImageResult {
+        match self.primary.bonus_tile_image(tile, placement) {
+            Err(TileImageRetrieveError::BonusTileNotSupported(..)) => {
+                self.secondary.bonus_tile_image(tile, placement)
+            }
+            result => result,
+        }
+    }
+
+    fn tile_size(&self, hand_tile: &HandTile) -> (u32, u32) {
+        match self.primary.tile_image_ref(hand_tile) {
+            Err(TileImageRetrieveError::TileNotSupported(..)) => {
+                self.secondary.tile_size(hand_tile)
+            }
+            _ => self.primary.tile_size(hand_tile),
+        }
+    }
+
+    fn metadata(&self) -> Option<TileSetMetadata> {
+        self.primary
+            .metadata()
+            .or_else(|| self.secondary.inner().metadata())
+    }
+
+    #[inline]
+    fn tile_width(&self) -> u32 {
+        self.primary.tile_width()
+    }
+
+    #[inline]
+    fn tile_height(&self) -> u32 {
+        self.primary.tile_height()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use image::{Rgba, RgbaImage};
+
+    use crate::raster_renderer::{FallbackTileSet, SimpleTileSet, TileSet, TwoPartTileSet};
+    use crate::tiles::*;
+    use crate::HandTile;
+    use crate::TilePlacement::{Normal, Rotated};
+
+    #[test]
+    fn should_fall_back_to_secondary_tile_set() {
+        let red = Rgba([255, 0, 0, 255]);
+        let blue = Rgba([0, 0, 255, 255]);
+        let map = |width, height, color| {
+            ALL_TILES
+                .iter()
+                .map(|&tile| (tile, RgbaImage::from_pixel(width, height, color)))
+                .collect::<HashMap<_, _>>()
+        };
+        let primary = SimpleTileSet::new(map(3, 4, red)).unwrap();
+        let secondary =
+            TwoPartTileSet::new(RgbaImage::from_pixel(6, 8, blue), map(6, 8, blue)).unwrap();
+
+        let tile_set = FallbackTileSet::new(&primary, &secondary);
+
+        assert_eq!((tile_set.tile_width(), tile_set.tile_height()), (3, 4));
+        let normal = tile_set.tile_image(&HandTile::new(II_MAN, Normal)).unwrap();
+        assert_eq!(*normal.get_pixel(0, 0), red);
+        let rotated = tile_set
+            .tile_image(&HandTile::new(II_MAN, Rotated))
+            .unwrap();
+        assert_eq!(rotated.dimensions(), (4, 3));
+        assert_eq!(*rotated.get_pixel(0, 0), blue);
+    }
+}
@@ -0,0 +1,139 @@
+use image::{ImageBuffer, Luma, Pixel, Rgb};
+
+use crate::raster_renderer::{HandRenderError, ImageType, RasterRenderer, RenderOptions, TileSet};
+use crate::Hand;
+
+/// A pixel type without an alpha channel that the rendered images can be
+/// flattened into (see [flatten]).
+pub trait OpaquePixel: Pixel<Subpixel = u8> {
+    /// Converts given opaque RGB color into this pixel type.
+    fn from_rgb(rgb: Rgb<u8>) -> Self;
+}
+
+impl OpaquePixel for Rgb<u8> {
+    #[inline]
+    fn from_rgb(rgb: Rgb<u8>) -> Self {
+        rgb
+    }
+}
+
+impl OpaquePixel for Luma<u8> {
+    #[inline]
+    fn from_rgb(rgb: Rgb<u8>) -> Self {
+        rgb.to_luma()
+    }
+}
+
+/// Flattens given rendered image into an image without an alpha channel
+/// (e.g. `RgbImage` or `GrayImage`), blending the (semi-)transparent pixels
+/// with given matte color.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::image::{GrayImage, Luma, Rgb, Rgba, RgbaImage};
+/// use riichi_hand::raster_renderer::flatten;
+///
+/// let image = RgbaImage::from_pixel(2, 2, Rgba([0, 0, 0, 0]));
+/// let gray: GrayImage = flatten(&image, Rgb([255, 255, 255]));
+/// assert_eq!(*gray.get_pixel(0, 0), Luma([255]));
+/// ```
+pub fn flatten<P: OpaquePixel>(image: &ImageType, matte: Rgb<u8>) -> ImageBuffer<P, Vec<u8>> {
+    ImageBuffer::from_fn(image.width(), image.height(), |x, y| {
+        let pixel = image.get_pixel(x, y);
+        let alpha = u32::from(pixel[3]);
+        let mut rgb = matte;
+        for (channel, &color) in rgb.0.iter_mut().zip(&pixel.0[..3]) {
+            *channel =
+                ((u32::from(color) * alpha + u32::from(*channel) * (255 - alpha)) / 255) as u8;
+        }
+
+        P::from_rgb(rgb)
+    })
+}
+
+impl<'a, T: TileSet> RasterRenderer<'a, T> {
+    /// Renders given [Hand] instance using [TileSet] and [RenderOptions] into
+    /// an image without an alpha channel (e.g. for e-ink displays or thermal
+    /// printers), blending the transparent areas with given matte color (see
+    /// [flatten]).
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::image::{GrayImage, Rgb};
+    /// use riichi_hand::parser::HandParser;
+    /// use riichi_hand::raster_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_TILE_SET;
+    /// use riichi_hand::raster_renderer::{RasterRenderer, RenderOptions};
+    ///
+    /// let hand = HandParser::parse("123m456p").unwrap();
+    /// let image: GrayImage = RasterRenderer::render_flattened(
+    ///     &hand,
+    ///     &*YELLOW_FLUFFY_STUFF_TILE_SET,
+    ///     RenderOptions::default(),
+    ///     Rgb([255, 255, 255]),
+    /// )
+    /// .unwrap();
+    /// ```
+    pub fn render_flattened<P: OpaquePixel>(
+        hand: &Hand,
+        tile_set: &'a T,
+        options: RenderOptions,
+        matte: Rgb<u8>,
+    ) -> Result<ImageBuffer<P, Vec<u8>>, HandRenderError> {
+        let image = Self::render(hand, tile_set, options)?;
+
+        Ok(flatten(&image, matte))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use image::{GrayImage, Luma, Rgb, RgbImage, Rgba, RgbaImage};
+
+    use crate::raster_renderer::{flatten, RasterRenderer, RenderOptions, SimpleTileSet};
+    use crate::tiles::*;
+    use crate::TilePlacement::Normal;
+    use crate::{Hand, HandTile};
+
+    #[test]
+    fn should_flatten_transparency() {
+        let mut image = RgbaImage::new(3, 1);
+        image.put_pixel(0, 0, Rgba([255, 0, 0, 255]));
+        image.put_pixel(1, 0, Rgba([255, 0, 0, 51]));
+
+        let rgb: RgbImage = flatten(&image, Rgb([0, 0, 255]));
+        let gray: GrayImage = flatten(&image, Rgb([255, 255, 255]));
+
+        assert_eq!(*rgb.get_pixel(0, 0), Rgb([255, 0, 0]));
+        assert_eq!(*rgb.get_pixel(1, 0), Rgb([51, 0, 204]));
+        assert_eq!(*rgb.get_pixel(2, 0), Rgb([0, 0, 255]));
+        assert!(gray.get_pixel(0, 0)[0] < gray.get_pixel(1, 0)[0]);
+        assert_eq!(*gray.get_pixel(2, 0), Luma([255]));
+    }
+
+    #[test]
+    fn should_render_flattened() {
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(3, 4, Rgba([0, 0, 0, 255]))))
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let hand = Hand::new(vec![
+            vec![HandTile::new(II_MAN, Normal)],
+            vec![HandTile::new(TON, Normal)],
+        ]);
+
+        let image: GrayImage = RasterRenderer::render_flattened(
+            &hand,
+            &tile_set,
+            RenderOptions::default(),
+            Rgb([255, 255, 255]),
+        )
+        .unwrap();
+
+        assert_eq!(image.dimensions(), (7, 4));
+        assert_eq!(*image.get_pixel(0, 0), Luma([0]));
+        assert_eq!(*image.get_pixel(3, 0), Luma([255]));
+    }
+}
@@ -1,30 +1,142 @@
 use std::collections::HashMap;
+#[cfg(feature = "regenerate-tile-sets")]
 use std::env;
 
+use image::RgbaImage;
 use lazy_static::lazy_static;
 
 use crate::raster_renderer::tile_set_util::*;
-use crate::raster_renderer::TwoPartTileSet;
+use crate::raster_renderer::{Scale, TileSetMetadata, TwoPartTileSet};
 use crate::tiles::*;
+use crate::Tile;
 
 macro_rules! load_tile_set {
     ($path:expr, $tile_set_name:literal) => {{
         let map = load_tile_map!($path, $tile_set_name);
         let front = load_tile_image!($path, $tile_set_name, "Front");
-        TwoPartTileSet::new(front, map).expect("could not create tile set")
+        create_tile_set(front, map, $tile_set_name)
     }};
 }
 
+// Loads a tile set with the tile back recolored by the build script, and the
+// rest of the tile images shared with the Yellow tile set.
+#[cfg(any(feature = "fluffy-stuff-blue", feature = "fluffy-stuff-green"))]
+macro_rules! load_recolored_tile_set {
+    ($tile_set_name:literal) => {{
+        let mut map = load_tile_map!(tile_set_path!(), "Yellow");
+        let back = load_tile_image!(
+            concat!(env!("OUT_DIR"), "/tilesets/FluffyStuff/"),
+            $tile_set_name,
+            "Back"
+        );
+        map.insert(ANY, back);
+        let front = load_tile_image!(tile_set_path!(), "Yellow", "Front");
+        create_tile_set(front, map, $tile_set_name)
+    }};
+}
+
+fn create_tile_set(
+    front: RgbaImage,
+    map: HashMap<Tile, RgbaImage>,
+    tile_set_name: &str,
+) -> TwoPartTileSet {
+    let metadata = TileSetMetadata::new(
+        format!("FluffyStuff ({})", tile_set_name),
+        "FluffyStuff",
+        "CC0 1.0 Universal",
+    )
+    .url("https://github.com/FluffyStuff/riichi-mahjong-tiles")
+    .recommended_scale(Scale::Factor(0.25));
+
+    TwoPartTileSet::new(front, map)
+        .expect("could not create tile set")
+        .with_metadata(metadata)
+}
+
+// Directory containing the rasterized tile images: either rendered from the
+// SVG files by the build script, or the pre-rasterized ones shipped with the
+// crate.
+#[cfg(feature = "regenerate-tile-sets")]
+macro_rules! tile_set_path {
+    () => {
+        concat!(env!("OUT_DIR"), "/tilesets/FluffyStuff/")
+    };
+}
+
+#[cfg(not(feature = "regenerate-tile-sets"))]
+macro_rules! tile_set_path {
+    () => {
+        "../../tilesets/FluffyStuff/"
+    };
+}
+
+#[cfg(feature = "fluffy-stuff-yellow")]
 lazy_static! {
     /// Tile set based on the FluffyStuff's tiles - Yellow version.
     pub static ref YELLOW_FLUFFY_STUFF_TILE_SET: TwoPartTileSet =
-        load_tile_set!(concat!(env!("OUT_DIR"), "/tilesets/FluffyStuff/"), "Yellow");
+        load_tile_set!(tile_set_path!(), "Yellow");
+}
 
+#[cfg(feature = "fluffy-stuff-red")]
+lazy_static! {
     /// Tile set based on the FluffyStuff's tiles - Red version.
     pub static ref RED_FLUFFY_STUFF_TILE_SET: TwoPartTileSet =
-        load_tile_set!(concat!(env!("OUT_DIR"), "/tilesets/FluffyStuff/"), "Red");
+        load_tile_set!(tile_set_path!(), "Red");
+}
 
+#[cfg(feature = "fluffy-stuff-black")]
+lazy_static! {
     /// Tile set based on the FluffyStuff's tiles - Black version.
     pub static ref BLACK_FLUFFY_STUFF_TILE_SET: TwoPartTileSet =
-        load_tile_set!(concat!(env!("OUT_DIR"), "/tilesets/FluffyStuff/"), "Black");
+        load_tile_set!(tile_set_path!(), "Black");
+}
+
+#[cfg(feature = "fluffy-stuff-blue")]
+lazy_static! {
+    /// Tile set based on the FluffyStuff's tiles - Blue version (the Yellow
+    /// version with the tile backs recolored).
+    pub static ref BLUE_FLUFFY_STUFF_TILE_SET: TwoPartTileSet = load_recolored_tile_set!("Blue");
+}
+
+#[cfg(feature = "fluffy-stuff-green")]
+lazy_static! {
+    /// Tile set based on the FluffyStuff's tiles - Green version (the Yellow
+    /// version with the tile backs recolored).
+    pub static ref GREEN_FLUFFY_STUFF_TILE_SET: TwoPartTileSet = load_recolored_tile_set!("Green");
+}
+
+#[cfg(all(test, feature = "regenerate-tile-sets"))]
+mod tests {
+    use std::collections::HashMap;
+    use std::env;
+
+    use crate::raster_renderer::tile_set_util::*;
+    use crate::tiles::*;
+
+    #[cfg(feature = "fluffy-stuff-yellow")]
+    #[test]
+    fn pre_rasterized_yellow_tile_set_should_be_up_to_date() {
+        assert!(
+            load_tile_map!(tile_set_path!(), "Yellow")
+                == load_tile_map!("../../tilesets/FluffyStuff/", "Yellow")
+        );
+    }
+
+    #[cfg(feature = "fluffy-stuff-red")]
+    #[test]
+    fn pre_rasterized_red_tile_set_should_be_up_to_date() {
+        assert!(
+            load_tile_map!(tile_set_path!(), "Red")
+                == load_tile_map!("../../tilesets/FluffyStuff/", "Red")
+        );
+    }
+
+    #[cfg(feature = "fluffy-stuff-black")]
+    #[test]
+    fn pre_rasterized_black_tile_set_should_be_up_to_date() {
+        assert!(
+            load_tile_map!(tile_set_path!(), "Black")
+                == load_tile_map!("../../tilesets/FluffyStuff/", "Black")
+        );
+    }
 }
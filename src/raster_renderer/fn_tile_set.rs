@@ -0,0 +1,115 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::raster_renderer::{TileImageResult, TileSet};
+use crate::HandTile;
+
+/// An implementation of [TileSet] that returns the tile images created by
+/// given closure, so that a tile set can be defined inline (e.g. in quick
+/// experiments and tests) without creating a struct and implementing the
+/// trait.
+///
+/// The closure is called every time a tile image is requested; the images
+/// are not cached. Face-down tiles are passed to the closure as the tile
+/// backs ([ANY](crate::tiles::ANY)).
+///
+/// # Examples
+/// ```
+/// use riichi_hand::image::{Rgba, RgbaImage};
+/// use riichi_hand::parser::HandParser;
+/// use riichi_hand::raster_renderer::{FnTileSet, RasterRenderer, RenderOptions};
+/// use riichi_hand::TilePlacement;
+///
+/// let tile_set = FnTileSet::new(30, 40, |hand_tile| {
+///     let (width, height) = if hand_tile.placement == TilePlacement::Normal {
+///         (30, 40)
+///     } else {
+///         (40, 30)
+///     };
+///     Ok(RgbaImage::from_pixel(width, height, Rgba([255, 0, 0, 255])))
+/// });
+/// let hand = HandParser::parse("123m_1*11p").unwrap();
+/// let image = RasterRenderer::render(&hand, &tile_set, RenderOptions::default()).unwrap();
+/// assert_eq!(image.height(), 40);
+/// ```
+pub struct FnTileSet<F: Fn(&HandTile) -> TileImageResult> {
+    tile_width: u32,
+    tile_height: u32,
+    tile_image: F,
+}
+
+impl<F: Fn(&HandTile) -> TileImageResult> FnTileSet<F> {
+    /// Creates a new [FnTileSet] instance with given tile dimensions, in
+    /// pixels, returning the tile images created by given closure.
+    ///
+    /// The closure should return images of the dimensions described in
+    /// [TileSet::tile_image].
+    pub fn new(tile_width: u32, tile_height: u32, tile_image: F) -> Self {
+        Self {
+            tile_width,
+            tile_height,
+            tile_image,
+        }
+    }
+}
+
+impl<F: Fn(&HandTile) -> TileImageResult> Debug for FnTileSet<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FnTileSet")
+            .field("tile_width", &self.tile_width)
+            .field("tile_height", &self.tile_height)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F: Fn(&HandTile) -> TileImageResult> TileSet for FnTileSet<F> {
+    #[inline]
+    fn tile_image(&self, hand_tile: &HandTile) -> TileImageResult {
+        (self.tile_image)(&hand_tile.displayed())
+    }
+
+    #[inline]
+    fn tile_width(&self) -> u32 {
+        self.tile_width
+    }
+
+    #[inline]
+    fn tile_height(&self) -> u32 {
+        self.tile_height
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use image::{Rgba, RgbaImage};
+
+    use crate::raster_renderer::{FnTileSet, TileImageRetrieveError, TileSet};
+    use crate::tiles::*;
+    use crate::HandTile;
+    use crate::TilePlacement::{FaceDown, Normal, Rotated};
+
+    #[test]
+    fn should_return_closure_images() {
+        let tile_set = FnTileSet::new(2, 3, |hand_tile| {
+            if hand_tile.placement == Normal {
+                let color = if hand_tile.tile == ANY { 0 } else { 255 };
+                Ok(RgbaImage::from_pixel(2, 3, Rgba([color, 0, 0, 255])))
+            } else {
+                Err(TileImageRetrieveError::TileNotSupported(
+                    *hand_tile,
+                    "not rotated".to_string(),
+                ))
+            }
+        });
+
+        assert_eq!((tile_set.tile_width(), tile_set.tile_height()), (2, 3));
+        let image = tile_set.tile_image(&HandTile::new(CHUN, Normal)).unwrap();
+        assert_eq!(*image.get_pixel(0, 0), Rgba([255, 0, 0, 255]));
+        assert!(tile_set.tile_image(&HandTile::new(CHUN, Rotated)).is_err());
+        assert_eq!(
+            format!("{:?}", tile_set),
+            "FnTileSet { tile_width: 2, tile_height: 3, .. }"
+        );
+        let back = tile_set.tile_image(&HandTile::new(CHUN, FaceDown)).unwrap();
+        assert_eq!(*back.get_pixel(0, 0), Rgba([0, 0, 0, 255]));
+    }
+}
@@ -0,0 +1,403 @@
+use std::collections::HashMap;
+
+use image::{ImageBuffer, Rgba, RgbaImage};
+
+use crate::raster_renderer::{TileImageRefResult, TileImageResult, TileSet, TwoPartTileSet};
+use crate::tiles::{ALL_BONUS_TILES, ALL_TILES, ANY, UNKNOWN};
+use crate::{BonusTile, HandTile, Suite, Tile, TilePlacement};
+
+const FRONT_COLOR: Rgba<u8> = Rgba([250, 248, 240, 255]);
+const BACK_COLOR: Rgba<u8> = Rgba([230, 140, 40, 255]);
+const BORDER_COLOR: Rgba<u8> = Rgba([170, 165, 150, 255]);
+const BLACK: Rgba<u8> = Rgba([40, 40, 40, 255]);
+const RED: Rgba<u8> = Rgba([210, 30, 30, 255]);
+const GREEN: Rgba<u8> = Rgba([30, 130, 60, 255]);
+const BLUE: Rgba<u8> = Rgba([30, 70, 170, 255]);
+
+/// Width and height of a glyph of the built-in bitmap font.
+const GLYPH_WIDTH: u32 = 5;
+const GLYPH_HEIGHT: u32 = 7;
+/// Number of samples per pixel (in each dimension) used to antialias the
+/// tile edges.
+const SUPERSAMPLING: u32 = 4;
+
+/// Returns the rows of given character's glyph of the built-in 5x7 bitmap
+/// font, where the most significant of the 5 bits is the leftmost pixel.
+fn glyph(character: char) -> [u8; GLYPH_HEIGHT as usize] {
+    match character {
+        '1' => [0x04, 0x0C, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        '2' => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1F],
+        '3' => [0x1F, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0E],
+        '4' => [0x02, 0x06, 0x0A, 0x12, 0x1F, 0x02, 0x02],
+        '5' => [0x1F, 0x10, 0x1E, 0x01, 0x01, 0x11, 0x0E],
+        '6' => [0x06, 0x08, 0x10, 0x1E, 0x11, 0x11, 0x0E],
+        '7' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08],
+        '8' => [0x0E, 0x11, 0x11, 0x0E, 0x11, 0x11, 0x0E],
+        '9' => [0x0E, 0x11, 0x11, 0x0F, 0x01, 0x02, 0x0C],
+        'm' => [0x00, 0x00, 0x1A, 0x15, 0x15, 0x11, 0x11],
+        'p' => [0x00, 0x00, 0x1E, 0x11, 0x1E, 0x10, 0x10],
+        's' => [0x00, 0x00, 0x0E, 0x10, 0x0E, 0x01, 0x1E],
+        'E' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x1F],
+        'S' => [0x0F, 0x10, 0x10, 0x0E, 0x01, 0x01, 0x1E],
+        'W' => [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0A],
+        'N' => [0x11, 0x11, 0x19, 0x15, 0x13, 0x11, 0x11],
+        'P' => [0x1E, 0x11, 0x11, 0x1E, 0x10, 0x10, 0x10],
+        'F' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x10],
+        'C' => [0x0E, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0E],
+        'f' => [0x06, 0x09, 0x08, 0x1C, 0x08, 0x08, 0x08],
+        'l' => [0x0C, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        'e' => [0x00, 0x00, 0x0E, 0x11, 0x1F, 0x10, 0x0E],
+        '?' => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x00, 0x04],
+        _ => [0; GLYPH_HEIGHT as usize],
+    }
+}
+
+/// Returns the characters drawn on given tile (the big one and the small
+/// one below it, if any) along with their color.
+fn tile_symbols(tile: Tile) -> (char, Option<char>, Rgba<u8>) {
+    const WINDS: [char; 4] = ['E', 'S', 'W', 'N'];
+    const DRAGONS: [(char, Rgba<u8>); 3] = [('P', BLUE), ('F', GREEN), ('C', RED)];
+
+    let (suite, color) = match tile.suite {
+        Suite::Manzu => ('m', BLACK),
+        Suite::Pinzu => ('p', BLUE),
+        Suite::Souzu => ('s', GREEN),
+        Suite::Honor => {
+            let index = usize::from(tile.value) - 1;
+            return match WINDS.get(index) {
+                Some(&wind) => (wind, None, BLACK),
+                None => (DRAGONS[index - 4].0, None, DRAGONS[index - 4].1),
+            };
+        }
+        Suite::Any if tile == UNKNOWN => return ('?', None, BLACK),
+        Suite::Any => return (' ', None, BLACK),
+    };
+
+    match tile.value.0 {
+        // red five
+        0 => ('5', Some(suite), RED),
+        value => (
+            char::from_digit(u32::from(value), 10).unwrap_or(' '),
+            Some(suite),
+            color,
+        ),
+    }
+}
+
+/// Returns the characters drawn on given bonus tile (the number and the
+/// abbreviation below it, `fl` for flowers and `se` for seasons) along with
+/// their color.
+fn bonus_tile_symbols(tile: BonusTile) -> (char, &'static str, Rgba<u8>) {
+    let number = char::from_digit(u32::from(tile.number()), 10).unwrap_or(' ');
+
+    if tile.is_flower() {
+        (number, "fl", RED)
+    } else {
+        (number, "se", BLUE)
+    }
+}
+
+/// Draws given character at given position, with each pixel of the glyph
+/// drawn as a square of given size.
+fn draw_glyph(image: &mut RgbaImage, character: char, x: f32, y: f32, size: f32, color: Rgba<u8>) {
+    for (row, bits) in glyph(character).iter().enumerate() {
+        for column in 0..GLYPH_WIDTH {
+            if bits & (1 << (GLYPH_WIDTH - 1 - column)) == 0 {
+                continue;
+            }
+
+            let left = (x + column as f32 * size).round() as u32;
+            let right = (x + (column + 1) as f32 * size).round() as u32;
+            let top = (y + row as f32 * size).round() as u32;
+            let bottom = (y + (row + 1) as f32 * size).round() as u32;
+            for pixel_y in top..bottom.min(image.height()) {
+                for pixel_x in left..right.min(image.width()) {
+                    image.put_pixel(pixel_x, pixel_y, color);
+                }
+            }
+        }
+    }
+}
+
+/// Returns whether given point is inside a rectangle with rounded corners.
+fn in_rounded_rect(x: f32, y: f32, width: f32, height: f32, inset: f32, radius: f32) -> bool {
+    let (left, top, right, bottom) = (inset, inset, width - inset, height - inset);
+    if x < left || x > right || y < top || y > bottom {
+        return false;
+    }
+
+    let radius = (radius - inset).max(0.0);
+    let dx = x - x.clamp(left + radius, right - radius);
+    let dy = y - y.clamp(top + radius, bottom - radius);
+    dx * dx + dy * dy <= radius * radius
+}
+
+/// Draws a tile-shaped rectangle with rounded corners, filled with given
+/// color and with a thin border.
+fn tile_shape(width: u32, height: u32, fill: Rgba<u8>) -> RgbaImage {
+    let (shape_width, shape_height) = (width as f32, height as f32);
+    let radius = shape_width / 8.0;
+    let border = (shape_width / 40.0).max(1.0);
+    let samples = (SUPERSAMPLING * SUPERSAMPLING) as f32;
+
+    ImageBuffer::from_fn(width, height, |x, y| {
+        let (mut outer, mut inner) = (0.0, 0.0);
+        for sample in 0..SUPERSAMPLING * SUPERSAMPLING {
+            let sample_x =
+                x as f32 + ((sample % SUPERSAMPLING) as f32 + 0.5) / SUPERSAMPLING as f32;
+            let sample_y =
+                y as f32 + ((sample / SUPERSAMPLING) as f32 + 0.5) / SUPERSAMPLING as f32;
+            if in_rounded_rect(sample_x, sample_y, shape_width, shape_height, 0.0, radius) {
+                outer += 1.0;
+                if in_rounded_rect(
+                    sample_x,
+                    sample_y,
+                    shape_width,
+                    shape_height,
+                    border,
+                    radius,
+                ) {
+                    inner += 1.0;
+                }
+            }
+        }
+        if outer == 0.0 {
+            return Rgba([0, 0, 0, 0]);
+        }
+
+        let mut pixel = fill;
+        for (channel, (&fill, &border)) in pixel.0[..3]
+            .iter_mut()
+            .zip(fill.0[..3].iter().zip(&BORDER_COLOR.0[..3]))
+        {
+            let color = (f32::from(fill) * inner + f32::from(border) * (outer - inner)) / outer;
+            *channel = color.round() as u8;
+        }
+        pixel[3] = (255.0 * outer / samples).round() as u8;
+        pixel
+    })
+}
+
+/// Draws given text horizontally centered, at given height, with one pixel
+/// of the glyphs drawn as a square of given size.
+fn draw_centered_text(image: &mut RgbaImage, text: &str, y: f32, size: f32, color: Rgba<u8>) {
+    let length = text.chars().count() as f32;
+    let text_width = (length * (GLYPH_WIDTH + 1) as f32 - 1.0) * size;
+    let x = (image.width() as f32 - text_width) / 2.0;
+
+    for (index, character) in text.chars().enumerate() {
+        let offset = index as f32 * (GLYPH_WIDTH + 1) as f32 * size;
+        draw_glyph(image, character, x + offset, y, size, color);
+    }
+}
+
+/// Draws a big symbol with a small text below it, as on the number tiles.
+fn draw_numbered(image: &mut RgbaImage, symbol: char, text: &str, color: Rgba<u8>) {
+    let (width, height) = (image.width() as f32, image.height() as f32);
+
+    draw_centered_text(
+        image,
+        &symbol.to_string(),
+        height * 0.12,
+        width / 12.0,
+        color,
+    );
+    draw_centered_text(image, text, height * 0.6, width / 18.0, color);
+}
+
+/// Draws the foreground of given bonus tile.
+fn bonus_tile_foreground(tile: BonusTile, width: u32, height: u32) -> RgbaImage {
+    let mut image = ImageBuffer::new(width, height);
+    let (number, text, color) = bonus_tile_symbols(tile);
+    draw_numbered(&mut image, number, text, color);
+
+    image
+}
+
+/// Draws the foreground of given tile.
+fn tile_foreground(tile: Tile, width: u32, height: u32) -> RgbaImage {
+    let mut image = ImageBuffer::new(width, height);
+    let (symbol, suite, color) = tile_symbols(tile);
+    let (width, height) = (width as f32, height as f32);
+
+    match suite {
+        Some(suite) => draw_numbered(&mut image, symbol, &suite.to_string(), color),
+        None => {
+            let size = width / 8.0;
+            let x = (width - GLYPH_WIDTH as f32 * size) / 2.0;
+            let y = (height - GLYPH_HEIGHT as f32 * size) / 2.0;
+            draw_glyph(&mut image, symbol, x, y, size, color);
+        }
+    }
+
+    image
+}
+
+#[derive(Debug)]
+/// An implementation of [TileSet] that draws the tiles programmatically,
+/// without any image assets: rounded tile fronts with the tile numbers and
+/// suites (`m`, `p`, `s`), or the winds (`E`, `S`, `W`, `N`) and the dragons
+/// (`P`, `F`, `C`) written with a built-in bitmap font. The red fives are
+/// written in red. The [UNKNOWN] tiles are drawn with a `?`, and the
+/// [BLANK](crate::tiles::BLANK) tiles are just the tile fronts.
+///
+/// The bonus tiles are supported as well: the flowers are written in red as
+/// their numbers followed by `fl`, and the seasons are written in blue as
+/// their numbers followed by `se`. This is the only bundled tile set that
+/// supports them, as the image-based ones do not include any bonus tile art.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::parser::HandParser;
+/// use riichi_hand::raster_renderer::{GeneratedTileSet, RasterRenderer, RenderOptions};
+///
+/// let tile_set = GeneratedTileSet::new(64);
+/// let hand = HandParser::parse("123m456p").unwrap();
+/// let image = RasterRenderer::render(&hand, &tile_set, RenderOptions::default()).unwrap();
+/// assert_eq!(image.height(), 64);
+/// ```
+pub struct GeneratedTileSet {
+    tile_set: TwoPartTileSet,
+}
+
+impl GeneratedTileSet {
+    /// Creates a new [GeneratedTileSet] instance with tiles of given height in
+    /// pixels. The tiles are 3:4 wide.
+    ///
+    /// # Panics
+    /// Panics if the tile height is less than 2 pixels.
+    pub fn new(tile_height: u32) -> Self {
+        assert!(tile_height >= 2, "tile height must be at least 2 pixels");
+        let tile_width = tile_height * 3 / 4;
+
+        let front = tile_shape(tile_width, tile_height, FRONT_COLOR);
+        let tile_map: HashMap<_, _> = ALL_TILES
+            .iter()
+            .chain(&[UNKNOWN])
+            .map(|&tile| {
+                let image = if tile == ANY {
+                    tile_shape(tile_width, tile_height, BACK_COLOR)
+                } else {
+                    tile_foreground(tile, tile_width, tile_height)
+                };
+                (tile, image)
+            })
+            .collect();
+        let bonus_tile_map = ALL_BONUS_TILES
+            .iter()
+            .map(|&tile| (tile, bonus_tile_foreground(tile, tile_width, tile_height)))
+            .collect();
+        let tile_set = TwoPartTileSet::new(front, tile_map)
+            .and_then(|tile_set| tile_set.with_bonus_tiles(bonus_tile_map))
+            .expect("generated tiles must have equal sizes");
+
+        Self { tile_set }
+    }
+}
+
+impl TileSet for GeneratedTileSet {
+    #[inline]
+    fn tile_image(&self, hand_tile: &HandTile) -> TileImageResult {
+        self.tile_set.tile_image(hand_tile)
+    }
+
+    #[inline]
+    fn tile_image_ref(&self, hand_tile: &HandTile) -> TileImageRefResult<'_> {
+        self.tile_set.tile_image_ref(hand_tile)
+    }
+
+    #[inline]
+    fn bonus_tile_image(&self, tile: BonusTile, placement: TilePlacement) -> TileImageResult {
+        self.tile_set.bonus_tile_image(tile, placement)
+    }
+
+    #[inline]
+    fn tile_width(&self) -> u32 {
+        self.tile_set.tile_width()
+    }
+
+    #[inline]
+    fn tile_height(&self) -> u32 {
+        self.tile_set.tile_height()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::raster_renderer::generated_tile_set::{
+        bonus_tile_symbols, tile_symbols, BLACK, BLUE, FRONT_COLOR, RED,
+    };
+    use crate::raster_renderer::{GeneratedTileSet, TileSet};
+    use crate::tiles::*;
+    use crate::TilePlacement::{FaceDown, Normal, Rotated};
+    use crate::{BonusTile, HandTile};
+
+    #[test]
+    fn should_choose_tile_symbols() {
+        assert_eq!(tile_symbols(SAN_MAN), ('3', Some('m'), BLACK));
+        assert_eq!(tile_symbols(AKADORA_PIN), ('5', Some('p'), RED));
+        assert_eq!(tile_symbols(PEI), ('N', None, BLACK));
+        assert_eq!(tile_symbols(CHUN), ('C', None, RED));
+        assert_eq!(tile_symbols(UNKNOWN), ('?', None, BLACK));
+        assert_eq!(bonus_tile_symbols(BonusTile::Orchid), ('2', "fl", RED));
+        assert_eq!(bonus_tile_symbols(BonusTile::Winter), ('4', "se", BLUE));
+    }
+
+    #[test]
+    fn should_generate_tiles() {
+        let tile_set = GeneratedTileSet::new(40);
+        assert_eq!((tile_set.tile_width(), tile_set.tile_height()), (30, 40));
+
+        let haku = tile_set.tile_image(&HandTile::new(HAKU, Normal)).unwrap();
+        let ton = tile_set.tile_image(&HandTile::new(TON, Normal)).unwrap();
+        let back = tile_set.tile_image(&HandTile::new(ANY, Normal)).unwrap();
+        let rotated = tile_set.tile_image(&HandTile::new(TON, Rotated)).unwrap();
+
+        assert_eq!(haku.dimensions(), (30, 40));
+        // rounded corners
+        assert_eq!(haku.get_pixel(0, 0)[3], 0);
+        assert_eq!(*haku.get_pixel(15, 3), FRONT_COLOR);
+        assert_ne!(haku, ton);
+        assert_ne!(back, ton);
+        assert_eq!(rotated.dimensions(), (40, 30));
+    }
+
+    #[test]
+    fn should_generate_blank_and_unknown_tiles() {
+        let tile_set = GeneratedTileSet::new(40);
+
+        let blank = tile_set.tile_image(&HandTile::new(BLANK, Normal)).unwrap();
+        let unknown = tile_set
+            .tile_image(&HandTile::new(UNKNOWN, Normal))
+            .unwrap();
+        let back = tile_set.tile_image(&HandTile::new(ANY, Normal)).unwrap();
+
+        assert_eq!(blank.dimensions(), (30, 40));
+        assert_eq!(*blank.get_pixel(15, 20), FRONT_COLOR);
+        assert_ne!(blank, back);
+        assert_ne!(unknown, blank);
+        assert_ne!(unknown, back);
+    }
+
+    #[test]
+    fn should_generate_bonus_tiles() {
+        let tile_set = GeneratedTileSet::new(40);
+
+        let plum = tile_set.bonus_tile_image(BonusTile::Plum, Normal).unwrap();
+        let spring = tile_set
+            .bonus_tile_image(BonusTile::Spring, Normal)
+            .unwrap();
+        let rotated = tile_set.bonus_tile_image(BonusTile::Plum, Rotated).unwrap();
+        let face_down = tile_set
+            .bonus_tile_image(BonusTile::Plum, FaceDown)
+            .unwrap();
+
+        assert_eq!(plum.dimensions(), (30, 40));
+        assert_ne!(plum, spring);
+        assert_eq!(rotated.dimensions(), (40, 30));
+        assert_eq!(
+            face_down,
+            tile_set.tile_image(&HandTile::new(ANY, Normal)).unwrap()
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use image::{Rgba, RgbaImage};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+/// A way of highlighting a single tile, used with
+/// [RasterRenderer::render_highlighted](crate::raster_renderer::RasterRenderer::render_highlighted).
+pub enum Highlight {
+    /// Blends the tile with given color. The alpha channel of the color
+    /// determines the strength of the tint (e.g. `Rgba([255, 0, 0, 128])`
+    /// makes the tile half-red).
+    Tint(Rgba<u8>),
+    /// Darkens the tile by halving its color channels.
+    Dim,
+    /// Draws an outline of given color and width (in tile set pixels) along the
+    /// inner edges of the tile.
+    Outline(Rgba<u8>, u32),
+}
+
+impl Highlight {
+    pub(super) fn apply(self, image: &mut RgbaImage) {
+        match self {
+            Self::Tint(color) => {
+                let alpha = u32::from(color[3]);
+                for pixel in image.pixels_mut() {
+                    for (channel, &tint) in pixel.0[..3].iter_mut().zip(&color.0[..3]) {
+                        *channel = ((u32::from(*channel) * (255 - alpha) + u32::from(tint) * alpha)
+                            / 255) as u8;
+                    }
+                }
+            }
+            Self::Dim => {
+                for pixel in image.pixels_mut() {
+                    for channel in &mut pixel.0[..3] {
+                        *channel /= 2;
+                    }
+                }
+            }
+            Self::Outline(color, width) => {
+                let (image_width, image_height) = image.dimensions();
+                for (x, y, pixel) in image.enumerate_pixels_mut() {
+                    if x < width
+                        || y < width
+                        || x + width >= image_width
+                        || y + width >= image_height
+                    {
+                        *pixel = color;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use image::{Rgba, RgbaImage};
+
+    use crate::raster_renderer::Highlight;
+
+    #[test]
+    fn should_tint_image() {
+        let mut image = RgbaImage::from_pixel(2, 2, Rgba([255, 255, 255, 200]));
+
+        Highlight::Tint(Rgba([255, 0, 0, 255])).apply(&mut image);
+
+        assert_eq!(*image.get_pixel(1, 1), Rgba([255, 0, 0, 200]));
+    }
+
+    #[test]
+    fn should_dim_image() {
+        let mut image = RgbaImage::from_pixel(2, 2, Rgba([255, 100, 0, 255]));
+
+        Highlight::Dim.apply(&mut image);
+
+        assert_eq!(*image.get_pixel(0, 0), Rgba([127, 50, 0, 255]));
+    }
+
+    #[test]
+    fn should_outline_image() {
+        let white = Rgba([255, 255, 255, 255]);
+        let red = Rgba([255, 0, 0, 255]);
+        let mut image = RgbaImage::from_pixel(5, 6, white);
+
+        Highlight::Outline(red, 1).apply(&mut image);
+
+        assert_eq!(*image.get_pixel(0, 0), red);
+        assert_eq!(*image.get_pixel(4, 3), red);
+        assert_eq!(*image.get_pixel(2, 5), red);
+        assert_eq!(*image.get_pixel(1, 1), white);
+        assert_eq!(*image.get_pixel(3, 4), white);
+    }
+}
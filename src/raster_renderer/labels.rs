@@ -0,0 +1,442 @@
+use ab_glyph::{point, Font, FontArc, Glyph, PxScale, ScaleFont};
+use image::{Pixel, Rgba};
+
+use crate::raster_renderer::renderer::stack_layouts;
+use crate::raster_renderer::{HandRenderResult, ImageType, RasterRenderer, RenderOptions, TileSet};
+use crate::renderer::{HandRenderer, LabelPosition, TilePosition};
+use crate::Hand;
+
+#[derive(Clone, Debug)]
+/// Options of the tile labels and captions rendered with
+/// [RasterRenderer::render_labeled] and [RasterRenderer::render_captioned].
+pub struct LabelOptions {
+    /// Font used to render the labels.
+    pub font: FontArc,
+    /// Height of the font, in tile set pixels.
+    pub size: f32,
+    /// Color of the labels.
+    pub color: Rgba<u8>,
+    /// Placement of the labels (or the caption) relative to the tiles.
+    pub position: LabelPosition,
+}
+
+impl LabelOptions {
+    #[inline]
+    /// Creates a new label options object instance. The labels are black and
+    /// placed below the tiles.
+    pub fn new(font: FontArc, size: f32) -> Self {
+        Self {
+            font,
+            size,
+            color: Rgba([0, 0, 0, 255]),
+            position: LabelPosition::default(),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the label options with given label color.
+    pub fn color(self, color: Rgba<u8>) -> Self {
+        Self { color, ..self }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the label options with given placement of the labels.
+    pub fn position(self, position: LabelPosition) -> Self {
+        Self { position, ..self }
+    }
+}
+
+impl<'a, T: TileSet> RasterRenderer<'a, T> {
+    /// Renders given [Hand] instance using [TileSet] and [RenderOptions],
+    /// with short text labels (e.g. "dora", or the number of accepted tiles)
+    /// centered above or below some of its tiles.
+    ///
+    /// The tiles are identified by their index in the hand, counting all the
+    /// tiles of all the groups in order. A strip for the labels, as high as
+    /// the font, is added to the image, even if no labels are given.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use riichi_hand::ab_glyph::FontArc;
+    /// use riichi_hand::parser::HandParser;
+    /// use riichi_hand::raster_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_TILE_SET;
+    /// use riichi_hand::raster_renderer::{LabelOptions, RasterRenderer, RenderOptions};
+    ///
+    /// let font = FontArc::try_from_vec(std::fs::read("DejaVuSans.ttf").unwrap()).unwrap();
+    /// let hand = HandParser::parse("123m456p789s11z_2z").unwrap();
+    /// let image = RasterRenderer::render_labeled(
+    ///     &hand,
+    ///     &[(11, "dora")],
+    ///     &LabelOptions::new(font, 60.0),
+    ///     &*YELLOW_FLUFFY_STUFF_TILE_SET,
+    ///     RenderOptions::default(),
+    /// )
+    /// .unwrap();
+    /// ```
+    pub fn render_labeled(
+        hand: &Hand,
+        labels: &[(usize, &str)],
+        label_options: &LabelOptions,
+        tile_set: &'a T,
+        options: RenderOptions,
+    ) -> HandRenderResult {
+        let renderer = Self::new(tile_set, options);
+        let layout_options = renderer.layout_options();
+        let font = label_options
+            .font
+            .as_scaled(PxScale::from(label_options.size));
+
+        let mut layout = renderer.layout(hand, layout_options);
+        let label_height = font.height().ceil() as u32;
+        let label_y = layout.reserve_label_space(label_height, label_options.position);
+        let tiles = layout.tiles.iter().map(|position| (*position, None));
+        let mut image = renderer.draw_tiles(layout.width, layout.height, tiles)?;
+
+        let padding = renderer.padding();
+        for (index, text) in labels {
+            if let Some(position) = layout.tiles.get(*index) {
+                let (tile_width, _) = position.size(layout_options);
+                let center_x = (padding + position.x) as f32 + tile_width as f32 / 2.0;
+                let baseline_y = (padding + label_y) as f32 + font.ascent();
+                draw_text(&mut image, text, label_options, center_x, baseline_y);
+            }
+        }
+
+        Ok(renderer.finish_image(image))
+    }
+
+    /// Renders given [Hand] instance using [TileSet] and [RenderOptions],
+    /// with a single line caption (e.g. the name of the hand, its score, or
+    /// the name of the player) centered above or below the tiles.
+    ///
+    /// If the caption is wider than the hand, the image is widened, and the
+    /// hand is centered horizontally.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use riichi_hand::ab_glyph::FontArc;
+    /// use riichi_hand::parser::HandParser;
+    /// use riichi_hand::raster_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_TILE_SET;
+    /// use riichi_hand::raster_renderer::{LabelOptions, RasterRenderer, RenderOptions};
+    ///
+    /// let font = FontArc::try_from_vec(std::fs::read("DejaVuSans.ttf").unwrap()).unwrap();
+    /// let hand = HandParser::parse("123m456p789s11z_2z").unwrap();
+    /// let image = RasterRenderer::render_captioned(
+    ///     &hand,
+    ///     "Riichi, Ippatsu, Tsumo - 3 han 40 fu - 5200",
+    ///     &LabelOptions::new(font, 60.0),
+    ///     &*YELLOW_FLUFFY_STUFF_TILE_SET,
+    ///     RenderOptions::default(),
+    /// )
+    /// .unwrap();
+    /// ```
+    pub fn render_captioned(
+        hand: &Hand,
+        caption: &str,
+        label_options: &LabelOptions,
+        tile_set: &'a T,
+        options: RenderOptions,
+    ) -> HandRenderResult {
+        let renderer = Self::new(tile_set, options);
+        let font = label_options
+            .font
+            .as_scaled(PxScale::from(label_options.size));
+
+        let mut layout = renderer.layout(hand, renderer.layout_options());
+        let caption_height = font.height().ceil() as u32;
+        let caption_y = layout.reserve_label_space(caption_height, label_options.position);
+        let (_, caption_width) = layout_text(caption, label_options);
+        let caption_width = caption_width.ceil() as u32;
+        let offset_x = caption_width.saturating_sub(layout.width) / 2;
+        let width = layout.width.max(caption_width);
+        let tiles = layout.tiles.iter().map(|position| {
+            let position = TilePosition {
+                x: position.x + offset_x,
+                ..*position
+            };
+            (position, None)
+        });
+        let mut image = renderer.draw_tiles(width, layout.height, tiles)?;
+
+        let padding = renderer.padding();
+        let center_x = padding as f32 + width as f32 / 2.0;
+        let baseline_y = (padding + caption_y) as f32 + font.ascent();
+        draw_text(&mut image, caption, label_options, center_x, baseline_y);
+
+        Ok(renderer.finish_image(image))
+    }
+
+    /// Renders given [Hand] instances using [TileSet] and [RenderOptions] into
+    /// a single image, with each hand in its own row, aligned to the left
+    /// edge, and a caption (e.g. "A" and "B", or the names of the waits)
+    /// above or below every row. The captions are aligned to the left edge as
+    /// well.
+    ///
+    /// The captions are matched with the hands by their order. A strip for
+    /// the caption, as high as the font, is added to every row, even if there
+    /// are fewer captions than hands.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use riichi_hand::ab_glyph::FontArc;
+    /// use riichi_hand::parser::HandParser;
+    /// use riichi_hand::raster_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_TILE_SET;
+    /// use riichi_hand::raster_renderer::{LabelOptions, RasterRenderer, RenderOptions};
+    ///
+    /// let font = FontArc::try_from_vec(std::fs::read("DejaVuSans.ttf").unwrap()).unwrap();
+    /// let hands = [
+    ///     HandParser::parse("123m456p789s1122z").unwrap(),
+    ///     HandParser::parse("123m456p789s1123z").unwrap(),
+    /// ];
+    /// let image = RasterRenderer::render_many_captioned(
+    ///     &hands,
+    ///     &["Shanpon", "Tanki"],
+    ///     &LabelOptions::new(font, 60.0),
+    ///     &*YELLOW_FLUFFY_STUFF_TILE_SET,
+    ///     RenderOptions::default(),
+    /// )
+    /// .unwrap();
+    /// ```
+    pub fn render_many_captioned(
+        hands: &[Hand],
+        captions: &[&str],
+        label_options: &LabelOptions,
+        tile_set: &'a T,
+        options: RenderOptions,
+    ) -> HandRenderResult {
+        let renderer = Self::new(tile_set, options);
+        let font = label_options
+            .font
+            .as_scaled(PxScale::from(label_options.size));
+        let caption_height = font.height().ceil() as u32;
+
+        let mut caption_ys = Vec::with_capacity(hands.len());
+        let mut caption_width: u32 = 0;
+        let layouts: Vec<_> = hands
+            .iter()
+            .enumerate()
+            .map(|(index, hand)| {
+                let mut layout = renderer.layout(hand, renderer.layout_options());
+                caption_ys.push(layout.reserve_label_space(caption_height, label_options.position));
+                if let Some(caption) = captions.get(index) {
+                    let (_, width) = layout_text(caption, label_options);
+                    caption_width = caption_width.max(width.ceil() as u32);
+                }
+                layout
+            })
+            .collect();
+        let (layout, offsets) = stack_layouts(layouts, renderer.group_gap());
+        let width = layout.width.max(caption_width);
+        let tiles = layout.tiles.iter().map(|position| (*position, None));
+        let mut image = renderer.draw_tiles(width, layout.height, tiles)?;
+
+        let padding = renderer.padding();
+        for ((caption, offset_y), caption_y) in captions.iter().zip(offsets).zip(caption_ys) {
+            let (_, text_width) = layout_text(caption, label_options);
+            let center_x = padding as f32 + text_width / 2.0;
+            let baseline_y = (padding + offset_y + caption_y) as f32 + font.ascent();
+            draw_text(&mut image, caption, label_options, center_x, baseline_y);
+        }
+
+        Ok(renderer.finish_image(image))
+    }
+}
+
+/// Lays out the glyphs of given text in a single line, starting at the origin.
+/// Returns the glyphs along with the total width of the text.
+pub(super) fn layout_text(text: &str, options: &LabelOptions) -> (Vec<Glyph>, f32) {
+    let font = options.font.as_scaled(PxScale::from(options.size));
+
+    let mut glyphs: Vec<Glyph> = Vec::new();
+    let mut caret = 0.0;
+    for c in text.chars() {
+        let glyph_id = font.glyph_id(c);
+        if let Some(previous) = glyphs.last() {
+            caret += font.kern(previous.id, glyph_id);
+        }
+        glyphs.push(glyph_id.with_scale_and_position(font.scale(), point(caret, 0.0)));
+        caret += font.h_advance(glyph_id);
+    }
+
+    (glyphs, caret)
+}
+
+/// Draws given text horizontally centered at `center_x`, with the baseline at
+/// `baseline_y`.
+pub(super) fn draw_text(
+    image: &mut ImageType,
+    text: &str,
+    options: &LabelOptions,
+    center_x: f32,
+    baseline_y: f32,
+) {
+    let font = options.font.as_scaled(PxScale::from(options.size));
+    let (glyphs, width) = layout_text(text, options);
+
+    let offset = point(center_x - width / 2.0, baseline_y);
+    for mut glyph in glyphs {
+        glyph.position += offset;
+        if let Some(outlined) = font.outline_glyph(glyph) {
+            let bounds = outlined.px_bounds();
+            outlined.draw(|x, y, coverage| {
+                let x = i64::from(x) + bounds.min.x as i64;
+                let y = i64::from(y) + bounds.min.y as i64;
+                if x < 0 || y < 0 || x >= i64::from(image.width()) || y >= i64::from(image.height())
+                {
+                    return;
+                }
+
+                let mut color = options.color;
+                color[3] = (f32::from(color[3]) * coverage).round() as u8;
+                image.get_pixel_mut(x as u32, y as u32).blend(&color);
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::ops::Range;
+
+    use ab_glyph::FontArc;
+    use image::{Rgba, RgbaImage};
+
+    use crate::raster_renderer::{LabelOptions, RasterRenderer, RenderOptions, SimpleTileSet};
+    use crate::renderer::LabelPosition;
+    use crate::tiles::*;
+    use crate::TilePlacement::Normal;
+    use crate::{Hand, HandTile};
+
+    const FONT_PATH: &str = "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf";
+
+    #[test]
+    fn should_render_labels() {
+        // the crate does not ship any fonts, so use a system one if available
+        let font = match std::fs::read(FONT_PATH) {
+            Ok(data) => FontArc::try_from_vec(data).unwrap(),
+            Err(_) => return,
+        };
+        let white = Rgba([255, 255, 255, 255]);
+        let red = Rgba([255, 0, 0, 255]);
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(30, 40, white)))
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let hand = Hand::new(vec![vec![
+            HandTile::new(II_MAN, Normal),
+            HandTile::new(RYAN_MAN, Normal),
+        ]]);
+        let options = LabelOptions::new(font, 20.0).color(red);
+
+        let below = RasterRenderer::render_labeled(
+            &hand,
+            &[(1, "88")],
+            &options,
+            &tile_set,
+            RenderOptions::default(),
+        )
+        .unwrap();
+        let above = RasterRenderer::render_labeled(
+            &hand,
+            &[(1, "88")],
+            &options.position(LabelPosition::Above),
+            &tile_set,
+            RenderOptions::default(),
+        )
+        .unwrap();
+
+        let label_height = below.height() - 40;
+        assert!(label_height > 0);
+        assert_eq!(below.dimensions(), above.dimensions());
+        assert_eq!(*below.get_pixel(0, 0), white);
+        assert_eq!(*above.get_pixel(0, label_height), white);
+        // the label strip is transparent, except for the labels
+        let has_label = |image: &RgbaImage, y_range: Range<u32>, x_range: Range<u32>| {
+            y_range
+                .into_iter()
+                .any(|y| x_range.clone().any(|x| image.get_pixel(x, y)[3] > 0))
+        };
+        assert!(has_label(&below, 40..below.height(), 30..60));
+        assert!(!has_label(&below, 40..below.height(), 0..30));
+        assert!(has_label(&above, 0..label_height, 30..60));
+    }
+
+    #[test]
+    fn should_render_caption() {
+        let font = match std::fs::read(FONT_PATH) {
+            Ok(data) => FontArc::try_from_vec(data).unwrap(),
+            Err(_) => return,
+        };
+        let white = Rgba([255, 255, 255, 255]);
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(3, 4, white)))
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let hand = Hand::new(vec![vec![HandTile::new(II_MAN, Normal)]]);
+
+        let image = RasterRenderer::render_captioned(
+            &hand,
+            "Chiitoitsu",
+            &LabelOptions::new(font, 10.0),
+            &tile_set,
+            RenderOptions::default(),
+        )
+        .unwrap();
+
+        // the caption is wider than the hand, so the hand is centered
+        let (width, height) = image.dimensions();
+        assert!(width > 3);
+        assert!(height > 4);
+        assert_eq!(image.get_pixel(0, 0)[3], 0);
+        assert_eq!(*image.get_pixel((width - 3) / 2, 0), white);
+        assert!((0..width).any(|x| image.get_pixel(x, height - 3)[3] > 0));
+    }
+
+    #[test]
+    fn should_render_many_captioned_hands() {
+        let font = match std::fs::read(FONT_PATH) {
+            Ok(data) => FontArc::try_from_vec(data).unwrap(),
+            Err(_) => return,
+        };
+        let white = Rgba([255, 255, 255, 255]);
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(30, 40, white)))
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let hands = [
+            Hand::new(vec![vec![HandTile::new(II_MAN, Normal); 3]]),
+            Hand::new(vec![vec![HandTile::new(II_MAN, Normal)]]),
+        ];
+
+        let image = RasterRenderer::render_many_captioned(
+            &hands,
+            &["A", "B"],
+            &LabelOptions::new(font, 20.0).position(LabelPosition::Above),
+            &tile_set,
+            RenderOptions::default(),
+        )
+        .unwrap();
+
+        let (width, height) = image.dimensions();
+        let caption_height = (height - 80 - 10) / 2;
+        assert_eq!(width, 90);
+        assert_eq!(*image.get_pixel(0, caption_height), white);
+        assert_eq!(*image.get_pixel(0, height - 1), white);
+        assert_eq!(image.get_pixel(31, height - 1)[3], 0);
+        // both captions are drawn at the left edge
+        let has_caption = |y_range: Range<u32>| {
+            y_range
+                .into_iter()
+                .any(|y| (0..30).any(|x| image.get_pixel(x, y)[3] > 0))
+        };
+        assert!(has_caption(0..caption_height));
+        assert!(has_caption(caption_height + 50..2 * caption_height + 50));
+        assert!(!(0..caption_height).any(|y| image.get_pixel(60, y)[3] > 0));
+    }
+}
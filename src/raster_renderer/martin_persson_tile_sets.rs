@@ -3,13 +3,24 @@ use std::collections::HashMap;
 use lazy_static::lazy_static;
 
 use crate::raster_renderer::tile_set_util::*;
-use crate::raster_renderer::SimpleTileSet;
+use crate::raster_renderer::{Scale, SimpleTileSet, TileSetMetadata, TintOptions, TintedTileSet};
 use crate::tiles::*;
 
 macro_rules! load_tile_set {
     ($path:literal, $tile_set_name:literal) => {{
         let map = load_tile_map!($path, $tile_set_name);
-        SimpleTileSet::new(map).expect("could not create tile set")
+        SimpleTileSet::new(map)
+            .expect("could not create tile set")
+            .with_metadata(
+                TileSetMetadata::new(
+                    "Martin Persson",
+                    "Martin Persson",
+                    "free for personal and commercial use, provided that a link to \
+                     the author's page is provided",
+                )
+                .url("http://www.martinpersson.org")
+                .recommended_scale(Scale::Factor(1.0)),
+            )
     }};
 }
 
@@ -17,4 +28,14 @@ lazy_static! {
     /// Tile set based on the Martin Persson's tiles
     pub static ref MARTIN_PERSSON_TILE_SET: SimpleTileSet =
         load_tile_set!("../../tilesets/", "MartinPersson");
+    /// Tile set based on the Martin Persson's tiles, which supports rotated
+    /// tiles (e.g. in open melds) by rotating the tile images
+    pub static ref ROTATING_MARTIN_PERSSON_TILE_SET: SimpleTileSet =
+        load_tile_set!("../../tilesets/", "MartinPersson").with_auto_rotation();
+    /// Tile set based on the Martin Persson's tiles, with dark tile bodies and
+    /// light symbols; suited to dark-themed backgrounds
+    pub static ref DARK_MARTIN_PERSSON_TILE_SET: TintedTileSet = TintedTileSet::new(
+        &*MARTIN_PERSSON_TILE_SET,
+        TintOptions::new().invert_front()
+    );
 }
@@ -0,0 +1,318 @@
+use ab_glyph::{Font, PxScale, ScaleFont};
+
+use crate::game::Wind;
+use crate::raster_renderer::labels::{draw_text, layout_text};
+use crate::raster_renderer::renderer::stack_layouts;
+use crate::raster_renderer::{
+    HandRenderResult, Highlight, LabelOptions, RasterRenderer, RenderOptions, TileSet,
+};
+use crate::renderer::{HandRenderer, LabelPosition, LayoutOptions, WinningTile};
+use crate::{Hand, HandTile, Tile, TilePlacement};
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+/// A "what would you discard?" (nanikiru) problem rendered with
+/// [RasterRenderer::render_nanikiru].
+pub struct NanikiruProblem {
+    /// The hand, usually 14 tiles, with the drawn tile last.
+    pub hand: Hand,
+    /// Wind of the round, if shown.
+    pub round_wind: Option<Wind>,
+    /// Seat wind of the player, if shown.
+    pub seat_wind: Option<Wind>,
+    /// Number of the turn, if shown.
+    pub turn: Option<u32>,
+    /// Revealed dora indicators. The dora wall is not shown if there are none.
+    pub dora_indicators: Vec<Tile>,
+    /// Index of the tile to discard, counting all the tiles of all the groups
+    /// in order, if known.
+    pub answer: Option<usize>,
+}
+
+impl NanikiruProblem {
+    #[inline]
+    #[must_use]
+    /// Creates a new problem with given hand, and no additional information.
+    pub fn new(hand: Hand) -> Self {
+        Self {
+            hand,
+            round_wind: None,
+            seat_wind: None,
+            turn: None,
+            dora_indicators: Vec::new(),
+            answer: None,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the problem with given round and seat winds.
+    pub fn winds(self, round_wind: Wind, seat_wind: Wind) -> Self {
+        Self {
+            round_wind: Some(round_wind),
+            seat_wind: Some(seat_wind),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the problem with given turn number.
+    pub fn turn(self, turn: u32) -> Self {
+        Self {
+            turn: Some(turn),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the problem with given revealed dora indicators.
+    pub fn dora_indicators(self, dora_indicators: Vec<Tile>) -> Self {
+        Self {
+            dora_indicators,
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the problem with given index of the tile to discard.
+    pub fn answer(self, answer: usize) -> Self {
+        Self {
+            answer: Some(answer),
+            ..self
+        }
+    }
+
+    /// Returns the caption of the problem, e.g. "Turn 7 - what would you
+    /// discard?".
+    fn caption(&self) -> String {
+        match self.turn {
+            Some(turn) => format!("Turn {} - what would you discard?", turn),
+            None => "What would you discard?".to_owned(),
+        }
+    }
+
+    /// Returns the round wind, the seat wind and the dora wall (whichever are
+    /// shown) as separate groups of a single hand, along with their names.
+    fn info(&self) -> (Hand, Vec<&'static str>) {
+        let wind_group = |wind: Wind| vec![HandTile::new(wind.tile(), TilePlacement::Normal)];
+
+        let mut groups = Vec::new();
+        let mut names = Vec::new();
+        if let Some(round_wind) = self.round_wind {
+            groups.push(wind_group(round_wind));
+            names.push("Round");
+        }
+        if let Some(seat_wind) = self.seat_wind {
+            groups.push(wind_group(seat_wind));
+            names.push("Seat");
+        }
+        if !self.dora_indicators.is_empty() {
+            let dora_wall = Hand::dora_wall(&self.dora_indicators, self.dora_indicators.len());
+            groups.extend(dora_wall.groups().iter().cloned());
+            names.push("Dora");
+        }
+
+        (Hand::new(groups), names)
+    }
+}
+
+impl<'a, T: TileSet> RasterRenderer<'a, T> {
+    /// Renders given [NanikiruProblem] using [TileSet] and [RenderOptions] in
+    /// a standardized puzzle layout: the round wind, the seat wind and the
+    /// dora wall (each with its name below), the hand below them with the
+    /// drawn tile separated, and the caption with the turn number above or
+    /// below everything.
+    ///
+    /// If `show_answer` is `true`, the tile to discard is outlined with the
+    /// label color; otherwise the answer is hidden.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use riichi_hand::ab_glyph::FontArc;
+    /// use riichi_hand::game::Wind;
+    /// use riichi_hand::parser::HandParser;
+    /// use riichi_hand::raster_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_TILE_SET;
+    /// use riichi_hand::raster_renderer::{
+    ///     LabelOptions, NanikiruProblem, RasterRenderer, RenderOptions,
+    /// };
+    /// use riichi_hand::tiles::II_MAN;
+    ///
+    /// let font = FontArc::try_from_vec(std::fs::read("DejaVuSans.ttf").unwrap()).unwrap();
+    /// let hand = HandParser::parse("2346m456p2789s11z_5s").unwrap();
+    /// let problem = NanikiruProblem::new(hand)
+    ///     .winds(Wind::East, Wind::South)
+    ///     .turn(7)
+    ///     .dora_indicators(vec![II_MAN])
+    ///     .answer(8);
+    /// let image = RasterRenderer::render_nanikiru(
+    ///     &problem,
+    ///     false,
+    ///     &LabelOptions::new(font, 60.0),
+    ///     &*YELLOW_FLUFFY_STUFF_TILE_SET,
+    ///     RenderOptions::default(),
+    /// )
+    /// .unwrap();
+    /// ```
+    pub fn render_nanikiru(
+        problem: &NanikiruProblem,
+        show_answer: bool,
+        label_options: &LabelOptions,
+        tile_set: &'a T,
+        options: RenderOptions,
+    ) -> HandRenderResult {
+        let renderer = Self::new(tile_set, options);
+        let layout_options = renderer.layout_options();
+        let gap = renderer.group_gap();
+        let font = label_options
+            .font
+            .as_scaled(PxScale::from(label_options.size));
+        let text_height = font.height().ceil() as u32;
+
+        let (info, names) = problem.info();
+        let info_options = LayoutOptions {
+            winning_tile: None,
+            max_width: None,
+            ..layout_options
+        };
+        let mut info_layout = renderer.layout(&info, info_options);
+        let mut names_y = info_layout.reserve_label_space(text_height, LabelPosition::Below);
+        let hand_options = LayoutOptions {
+            winning_tile: layout_options.winning_tile.or(Some(WinningTile::Last)),
+            ..layout_options
+        };
+        let hand_layout = renderer.layout(&problem.hand, hand_options);
+
+        let info_tile_count = info_layout.tiles.len();
+        let layouts = if names.is_empty() {
+            vec![hand_layout]
+        } else {
+            vec![info_layout, hand_layout]
+        };
+        let (mut layout, _) = stack_layouts(layouts, gap);
+        let caption_y = layout.reserve_label_space(gap + text_height, label_options.position);
+        let caption_y = match label_options.position {
+            LabelPosition::Above => {
+                names_y += gap + text_height;
+                caption_y
+            }
+            LabelPosition::Below => caption_y + gap,
+        };
+
+        let caption = problem.caption();
+        let (_, caption_width) = layout_text(&caption, label_options);
+        let width = layout.width.max(caption_width.ceil() as u32);
+        let outline_width = (layout_options.tile_width / 16).max(1);
+        let tiles = layout.tiles.iter().enumerate().map(|(index, position)| {
+            let highlight = match index.checked_sub(info_tile_count) {
+                Some(index) if show_answer && problem.answer == Some(index) => {
+                    Some(Highlight::Outline(label_options.color, outline_width))
+                }
+                _ => None,
+            };
+            (*position, highlight)
+        });
+        let mut image = renderer.draw_tiles(width, layout.height, tiles)?;
+
+        let padding = renderer.padding();
+        let info_tiles = &layout.tiles[..info_tile_count];
+        for (group, name) in names.iter().enumerate() {
+            let group_tiles = info_tiles.iter().filter(|position| position.group == group);
+            let start_x = group_tiles.clone().map(|position| position.x).min();
+            let end_x = group_tiles
+                .map(|position| position.x + position.size(layout_options).0)
+                .max();
+            if let (Some(start_x), Some(end_x)) = (start_x, end_x) {
+                let center_x = padding as f32 + (start_x + end_x) as f32 / 2.0;
+                let baseline_y = (padding + names_y) as f32 + font.ascent();
+                draw_text(&mut image, name, label_options, center_x, baseline_y);
+            }
+        }
+        let center_x = padding as f32 + width as f32 / 2.0;
+        let baseline_y = (padding + caption_y) as f32 + font.ascent();
+        draw_text(&mut image, &caption, label_options, center_x, baseline_y);
+
+        Ok(renderer.finish_image(image))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use ab_glyph::FontArc;
+    use image::{Rgba, RgbaImage};
+
+    use crate::game::Wind;
+    use crate::parser::HandParser;
+    use crate::raster_renderer::{
+        Gap, LabelOptions, NanikiruProblem, RasterRenderer, RenderOptions, SimpleTileSet,
+    };
+    use crate::tiles::*;
+
+    const FONT_PATH: &str = "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf";
+
+    fn problem() -> NanikiruProblem {
+        let hand = HandParser::parse("2346m456p2789s11z_5s").unwrap();
+
+        NanikiruProblem::new(hand)
+            .winds(Wind::East, Wind::South)
+            .turn(7)
+            .dora_indicators(vec![II_MAN])
+            .answer(0)
+    }
+
+    #[test]
+    fn should_build_problem_info() {
+        let (info, names) = problem().info();
+
+        assert_eq!(names, vec!["Round", "Seat", "Dora"]);
+        assert_eq!(info.groups().len(), 3);
+        assert_eq!(info.tiles().take(2).collect::<Vec<_>>(), vec![TON, NAN]);
+        assert_eq!(problem().caption(), "Turn 7 - what would you discard?");
+
+        let (info, names) = NanikiruProblem::new(problem().hand).info();
+        assert!(info.groups().is_empty());
+        assert!(names.is_empty());
+    }
+
+    #[test]
+    fn should_render_nanikiru_problem() {
+        let font = match std::fs::read(FONT_PATH) {
+            Ok(data) => FontArc::try_from_vec(data).unwrap(),
+            Err(_) => return,
+        };
+        let white = Rgba([255, 255, 255, 255]);
+        let red = Rgba([255, 0, 0, 255]);
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(32, 40, white)))
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let options = RenderOptions::new(Gap::Pixels(0), Gap::Pixels(10));
+        let label_options = LabelOptions::new(font, 20.0).color(red);
+        let render = |show_answer| {
+            RasterRenderer::render_nanikiru(
+                &problem(),
+                show_answer,
+                &label_options,
+                &tile_set,
+                options.clone(),
+            )
+            .unwrap()
+        };
+
+        let hidden = render(false);
+        let shown = render(true);
+
+        // winds and dora wall, their names, the hand, and the caption
+        let hand_y = 40 + 20 + 10;
+        assert_eq!(hidden.dimensions(), (14 * 32 + 10, hand_y + 40 + 10 + 20));
+        assert_eq!(*hidden.get_pixel(0, 0), white);
+        assert_eq!(*hidden.get_pixel(0, hand_y), white);
+        assert_eq!(*shown.get_pixel(0, hand_y), red);
+        assert_eq!(*shown.get_pixel(16, hand_y + 20), white);
+        assert!((40..60).any(|y| hidden.get_pixel(16, y)[3] > 0 || hidden.get_pixel(10, y)[3] > 0));
+    }
+}
@@ -0,0 +1,332 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use image::imageops::FilterType;
+use image::Rgba;
+
+use crate::meld::AnkanStyle;
+use crate::raster_renderer::{Gap, RenderOptions, Rotation, Scale, Shadow, Texture};
+use crate::renderer::{VerticalAlign, WinningTile};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+/// An error that occurs when validating [RenderOptions].
+pub enum RenderOptionsError {
+    /// A gap (or the padding), with given name, is expressed as a ratio that
+    /// is not a finite, non-negative number.
+    InvalidGap(&'static str, f32),
+    /// The scale factor is not a finite, positive number, or the target tile
+    /// height is zero.
+    InvalidScale(Scale),
+    /// The shadow blur radius is not a finite, non-negative number, or the
+    /// opacity is not between `0.0` and `1.0`.
+    InvalidShadow(Shadow),
+    /// The maximum width of a row of tiles is zero.
+    ZeroMaxWidth,
+}
+
+impl Error for RenderOptionsError {}
+
+impl Display for RenderOptionsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidGap(name, ratio) => write!(f, "invalid {} ratio: {}", name, ratio),
+            Self::InvalidScale(Scale::Factor(factor)) => {
+                write!(f, "invalid scale factor: {}", factor)
+            }
+            Self::InvalidScale(Scale::TileHeight(height)) => {
+                write!(f, "invalid target tile height: {}", height)
+            }
+            Self::InvalidShadow(shadow) => write!(
+                f,
+                "invalid shadow: blur radius {}, opacity {}",
+                shadow.blur_radius, shadow.opacity
+            ),
+            Self::ZeroMaxWidth => write!(f, "maximum width must not be zero"),
+        }
+    }
+}
+
+impl RenderOptions {
+    #[inline]
+    #[must_use]
+    /// Returns a builder of the render options that validates them when
+    /// building. The options not set explicitly have the same values as in
+    /// [RenderOptions::default].
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::raster_renderer::{Gap, RenderOptions, RenderOptionsError};
+    ///
+    /// let options = RenderOptions::builder()
+    ///     .tile_gap(Gap::Pixels(2))
+    ///     .scale(0.5)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(options.tile_gap, Gap::Pixels(2));
+    ///
+    /// let result = RenderOptions::builder().padding(Gap::Ratio(-1.0)).build();
+    /// assert_eq!(result.unwrap_err(), RenderOptionsError::InvalidGap("padding", -1.0));
+    /// ```
+    pub fn builder() -> RenderOptionsBuilder {
+        RenderOptionsBuilder::default()
+    }
+
+    /// Checks that the gaps and the padding are non-negative, the image is
+    /// not scaled to zero size, the shadow parameters are in range, and the
+    /// maximum width of a row is not zero.
+    pub fn validate(&self) -> Result<(), RenderOptionsError> {
+        let gaps = [
+            ("tile gap", self.tile_gap),
+            ("group gap", self.group_gap),
+            ("padding", self.padding),
+        ];
+        for (name, gap) in gaps {
+            if let Gap::Ratio(ratio) = gap {
+                if !ratio.is_finite() || ratio < 0.0 {
+                    return Err(RenderOptionsError::InvalidGap(name, ratio));
+                }
+            }
+        }
+
+        let valid_scale = match self.scale {
+            Scale::Factor(factor) => factor.is_finite() && factor > 0.0,
+            Scale::TileHeight(height) => height > 0,
+        };
+        if !valid_scale {
+            return Err(RenderOptionsError::InvalidScale(self.scale));
+        }
+
+        if let Some(shadow) = self.shadow {
+            let valid_blur = shadow.blur_radius.is_finite() && shadow.blur_radius >= 0.0;
+            if !valid_blur || !(0.0..=1.0).contains(&shadow.opacity) {
+                return Err(RenderOptionsError::InvalidShadow(shadow));
+            }
+        }
+
+        if self.max_width == Some(0) {
+            return Err(RenderOptionsError::ZeroMaxWidth);
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+/// Builder of [RenderOptions], created with [RenderOptions::builder].
+pub struct RenderOptionsBuilder {
+    options: RenderOptions,
+}
+
+impl RenderOptionsBuilder {
+    #[inline]
+    #[must_use]
+    /// Sets the gap between tiles.
+    pub fn tile_gap<G: Into<Gap>>(self, tile_gap: G) -> Self {
+        Self {
+            options: RenderOptions {
+                tile_gap: tile_gap.into(),
+                ..self.options
+            },
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the gap between groups.
+    pub fn group_gap<G: Into<Gap>>(self, group_gap: G) -> Self {
+        Self {
+            options: RenderOptions {
+                group_gap: group_gap.into(),
+                ..self.options
+            },
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the background color (see [RenderOptions::background]).
+    pub fn background(self, background: Rgba<u8>) -> Self {
+        Self {
+            options: self.options.background(background),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the texture drawn beneath the tiles (see [RenderOptions::texture]).
+    pub fn texture(self, texture: Texture) -> Self {
+        Self {
+            options: self.options.texture(texture),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the padding around the hand (see [RenderOptions::padding]).
+    pub fn padding(self, padding: Gap) -> Self {
+        Self {
+            options: self.options.padding(padding),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the scale factor of the image (see [RenderOptions::scale]).
+    pub fn scale(self, factor: f32) -> Self {
+        Self {
+            options: self.options.scale(factor),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the height of a single tile in the scaled image (see
+    /// [RenderOptions::target_tile_height]).
+    pub fn target_tile_height(self, tile_height: u32) -> Self {
+        Self {
+            options: self.options.target_tile_height(tile_height),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the filter used when scaling the image (see
+    /// [RenderOptions::filter]).
+    pub fn filter(self, filter: FilterType) -> Self {
+        Self {
+            options: self.options.filter(filter),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the vertical alignment of the groups (see
+    /// [RenderOptions::vertical_align]).
+    pub fn vertical_align(self, vertical_align: VerticalAlign) -> Self {
+        Self {
+            options: self.options.vertical_align(vertical_align),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the tile separated from the rest of its group (see
+    /// [RenderOptions::winning_tile]).
+    pub fn winning_tile(self, winning_tile: WinningTile) -> Self {
+        Self {
+            options: self.options.winning_tile(winning_tile),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the drop shadow rendered under each tile (see
+    /// [RenderOptions::shadow]).
+    pub fn shadow(self, shadow: Shadow) -> Self {
+        Self {
+            options: self.options.shadow(shadow),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the rotation of the image (see [RenderOptions::rotation]).
+    pub fn rotation(self, rotation: Rotation) -> Self {
+        Self {
+            options: self.options.rotation(rotation),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the maximum width of a row of tiles (see
+    /// [RenderOptions::max_width]).
+    pub fn max_width(self, max_width: u32) -> Self {
+        Self {
+            options: self.options.max_width(max_width),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets whether the groups of four identical tiles are displayed as
+    /// ankans (see [RenderOptions::face_down_closed_kans]).
+    pub fn face_down_closed_kans(self, face_down_closed_kans: bool) -> Self {
+        Self {
+            options: self.options.face_down_closed_kans(face_down_closed_kans),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Sets the convention the ankans are displayed with (see
+    /// [RenderOptions::ankan_style]).
+    pub fn ankan_style(self, ankan_style: AnkanStyle) -> Self {
+        Self {
+            options: self.options.ankan_style(ankan_style),
+        }
+    }
+
+    /// Validates the options (see [RenderOptions::validate]) and returns
+    /// them.
+    pub fn build(self) -> Result<RenderOptions, RenderOptionsError> {
+        self.options.validate()?;
+
+        Ok(self.options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::raster_renderer::{
+        Gap, RenderOptions, RenderOptionsBuilder, RenderOptionsError, Scale, Shadow, TileWidthRatio,
+    };
+
+    #[test]
+    fn should_build_valid_options() {
+        let options = RenderOptions::builder()
+            .tile_gap(TileWidthRatio(0.1))
+            .group_gap(Gap::Pixels(10))
+            .target_tile_height(64)
+            .shadow(Shadow::new(2, 2, 1.0, 0.5))
+            .max_width(500)
+            .build()
+            .unwrap();
+
+        assert_eq!(options.tile_gap, Gap::Ratio(0.1));
+        assert_eq!(options.group_gap, Gap::Pixels(10));
+        assert_eq!(options.scale, Scale::TileHeight(64));
+        assert_eq!(options.max_width, Some(500));
+        assert!(RenderOptions::builder().build().is_ok());
+    }
+
+    #[test]
+    fn should_reject_invalid_options() {
+        let error = |builder: RenderOptionsBuilder| builder.build().unwrap_err();
+
+        assert!(matches!(
+            error(RenderOptions::builder().tile_gap(Gap::Ratio(f32::NAN))),
+            RenderOptionsError::InvalidGap("tile gap", _)
+        ));
+        assert_eq!(
+            error(RenderOptions::builder().group_gap(TileWidthRatio(-0.5))),
+            RenderOptionsError::InvalidGap("group gap", -0.5)
+        );
+        assert_eq!(
+            error(RenderOptions::builder().scale(0.0)),
+            RenderOptionsError::InvalidScale(Scale::Factor(0.0))
+        );
+        assert_eq!(
+            error(RenderOptions::builder().target_tile_height(0)),
+            RenderOptionsError::InvalidScale(Scale::TileHeight(0))
+        );
+        assert_eq!(
+            error(RenderOptions::builder().shadow(Shadow::new(0, 0, 1.0, 2.0))),
+            RenderOptionsError::InvalidShadow(Shadow::new(0, 0, 1.0, 2.0))
+        );
+        assert_eq!(
+            error(RenderOptions::builder().max_width(0)),
+            RenderOptionsError::ZeroMaxWidth
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use image::ImageBuffer;
+use rayon::prelude::*;
+
+use crate::raster_renderer::{HandRenderResult, RasterRenderer, RenderOptions, TileSet};
+use crate::renderer::HandRenderer;
+use crate::Hand;
+
+impl<'a, T: TileSet + Sync> RasterRenderer<'a, T> {
+    /// Renders given [Hand] instance using [TileSet] and [RenderOptions],
+    /// just like [RasterRenderer::render], but retrieves (and composes) the
+    /// tile images in parallel, using the global `rayon` thread pool, before
+    /// drawing them onto the image. This pays off for the tile sets that
+    /// compose the tile images on the fly, and for the long hands.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::parser::HandParser;
+    /// use riichi_hand::raster_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_TILE_SET;
+    /// use riichi_hand::raster_renderer::{RasterRenderer, RenderOptions};
+    ///
+    /// let hand = HandParser::parse("123m456p789s1122z").unwrap();
+    /// let image = RasterRenderer::render_parallel(
+    ///     &hand,
+    ///     &*YELLOW_FLUFFY_STUFF_TILE_SET,
+    ///     RenderOptions::default(),
+    /// )
+    /// .unwrap();
+    /// ```
+    pub fn render_parallel(
+        hand: &Hand,
+        tile_set: &'a T,
+        options: RenderOptions,
+    ) -> HandRenderResult {
+        let renderer = Self::new(tile_set, options);
+        let layout = renderer.layout(hand, renderer.layout_options());
+
+        let tile_images = layout
+            .tiles
+            .par_iter()
+            .map(|position| renderer.tile_image(*position, None))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut image = ImageBuffer::new(0, 0);
+        renderer.draw_tile_images_into(layout.width, layout.height, &tile_images, &mut image);
+
+        Ok(renderer.finish_image(image))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use image::{Rgba, RgbaImage};
+
+    use crate::parser::HandParser;
+    use crate::raster_renderer::{
+        Gap, RasterRenderer, RenderOptions, Shadow, SimpleTileSet, TwoPartTileSet,
+    };
+    use crate::tiles::*;
+
+    #[test]
+    fn should_render_same_image_in_parallel() {
+        let map = ALL_TILES
+            .iter()
+            .enumerate()
+            .map(|(index, &tile)| {
+                let color = Rgba([(index * 7) as u8, 0, 0, 255]);
+                (tile, RgbaImage::from_pixel(3, 4, color))
+            })
+            .collect::<HashMap<_, _>>();
+        let tile_set =
+            TwoPartTileSet::new(RgbaImage::from_pixel(3, 4, Rgba([0, 0, 255, 255])), map).unwrap();
+        let hand = HandParser::parse("1*23m456p_7777s").unwrap();
+        let options = RenderOptions::default()
+            .padding(Gap::Pixels(2))
+            .shadow(Shadow::new(1, 1, 0.0, 0.5));
+
+        let expected = RasterRenderer::render(&hand, &tile_set, options.clone()).unwrap();
+        let actual = RasterRenderer::render_parallel(&hand, &tile_set, options).unwrap();
+
+        assert!(actual == expected, "actual and expected images differ");
+    }
+
+    #[test]
+    fn should_return_error_in_parallel() {
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::new(3, 4)))
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let hand = HandParser::parse("12*3m").unwrap();
+
+        let result = RasterRenderer::render_parallel(&hand, &tile_set, RenderOptions::default());
+
+        assert!(result.is_err());
+    }
+}
@@ -1,31 +1,557 @@
+use std::borrow::Cow;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 
-use image::{imageops, GenericImage, ImageBuffer, Rgba, RgbaImage};
+use image::imageops::FilterType;
+use image::{imageops, DynamicImage, ImageBuffer, Rgba, RgbaImage};
 
+use crate::meld::AnkanStyle;
+use crate::raster_renderer::highlight::Highlight;
+use crate::raster_renderer::texture::{self, Texture};
 use crate::raster_renderer::tile_set::{TileImageRetrieveError, TileSet};
-use crate::TilePlacement::{Normal, Rotated, RotatedAndShifted};
-use crate::{Hand, HandGroup, HandTile};
+use crate::renderer::{
+    HandRenderer, Layout, LayoutOptions, PlacedTile, Rect, TilePosition, VerticalAlign, WinningTile,
+};
+use crate::river::{Discard, River};
+use crate::{Hand, HandTile, Tile};
 
-#[derive(Copy, Clone, Default, Debug)]
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+/// A distance expressed as a fraction of tile width.
 pub struct TileWidthRatio(pub f32);
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// Size of a gap (between tiles or groups) or padding (around the hand).
+pub enum Gap {
+    /// Size expressed in pixels.
+    Pixels(u32),
+    /// Size expressed as a fraction of tile width.
+    Ratio(f32),
+}
+
+impl Gap {
+    fn pixels(self, tile_width: u32) -> u32 {
+        match self {
+            Self::Pixels(pixels) => pixels,
+            Self::Ratio(ratio) => (ratio * tile_width as f32) as u32,
+        }
+    }
+}
+
+impl Default for Gap {
+    fn default() -> Self {
+        Self::Pixels(0)
+    }
+}
+
+impl From<TileWidthRatio> for Gap {
+    fn from(ratio: TileWidthRatio) -> Self {
+        Self::Ratio(ratio.0)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// Scaling of the rendered image.
+pub enum Scale {
+    /// Scale the image by given factor (e.g. `0.5` halves both dimensions).
+    Factor(f32),
+    /// Scale the image so that a single (non-rotated) tile is given number of
+    /// pixels high.
+    TileHeight(u32),
+}
+
+impl Scale {
+    fn factor(self, tile_height: u32) -> f32 {
+        match self {
+            Self::Factor(factor) => factor,
+            Self::TileHeight(height) => height as f32 / tile_height as f32,
+        }
+    }
+}
+
+impl Default for Scale {
+    fn default() -> Self {
+        Self::Factor(1.0)
+    }
+}
+
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// Rotation of the rendered image, e.g. to place the hand on a side of a
+/// table.
+pub enum Rotation {
+    /// The image is not rotated (the hand of the player at the bottom of the
+    /// table).
+    #[default]
+    Upright,
+    /// The image is rotated clockwise by 90 degrees (the hand of the player on
+    /// the left side of the table).
+    Clockwise90,
+    /// The image is rotated by 180 degrees (the hand of the player at the top
+    /// of the table).
+    Clockwise180,
+    /// The image is rotated clockwise by 270 degrees (the hand of the player
+    /// on the right side of the table).
+    Clockwise270,
+}
+
+impl Rotation {
+    pub(super) fn apply(self, image: ImageType) -> ImageType {
+        match self {
+            Self::Upright => image,
+            Self::Clockwise90 => imageops::rotate90(&image),
+            Self::Clockwise180 => imageops::rotate180(&image),
+            Self::Clockwise270 => imageops::rotate270(&image),
+        }
+    }
+
+    /// Returns the rectangle of an image of given size, moved to where
+    /// [Rotation::apply] moves its pixels.
+    fn apply_to_rect(self, rect: Rect, width: u32, height: u32) -> Rect {
+        match self {
+            Self::Upright => rect,
+            Self::Clockwise90 => Rect::new(
+                height - rect.y - rect.height,
+                rect.x,
+                rect.height,
+                rect.width,
+            ),
+            Self::Clockwise180 => Rect::new(
+                width - rect.x - rect.width,
+                height - rect.y - rect.height,
+                rect.width,
+                rect.height,
+            ),
+            Self::Clockwise270 => {
+                Rect::new(rect.y, width - rect.x - rect.width, rect.height, rect.width)
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+/// Soft drop shadow rendered under each tile. All the dimensions are expressed
+/// in the tile set pixels, before scaling.
+pub struct Shadow {
+    /// Horizontal offset of the shadow relative to the tile.
+    pub offset_x: i32,
+    /// Vertical offset of the shadow relative to the tile.
+    pub offset_y: i32,
+    /// Standard deviation of the Gaussian blur applied to the shadow. `0.0`
+    /// means a hard shadow.
+    pub blur_radius: f32,
+    /// Opacity of the shadow, from `0.0` (invisible) to `1.0` (solid black).
+    pub opacity: f32,
+}
+
+impl Shadow {
+    #[inline]
+    #[must_use]
+    /// Creates a new shadow object instance.
+    pub const fn new(offset_x: i32, offset_y: i32, blur_radius: f32, opacity: f32) -> Self {
+        Self {
+            offset_x,
+            offset_y,
+            blur_radius,
+            opacity,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[non_exhaustive]
 /// Rendering options used with [RasterRenderer].
+///
+/// The options can be created with [RenderOptions::new] and modified with
+/// the builder methods, or created with [RenderOptions::builder], which also
+/// validates them.
+///
+/// With the `serde` feature enabled, the options can be (de)serialized, e.g.
+/// from a configuration file. The background color is given as an `[r, g, b,
+/// a]` array, the missing fields take their [default](RenderOptions::default)
+/// values, and the texture is skipped.
 pub struct RenderOptions {
-    /// Gap between tiles, expressed as a fraction of tile width.
-    pub tile_gap: TileWidthRatio,
-    /// Gap between groups, expressed as a fraction of tile width.
-    pub group_gap: TileWidthRatio,
+    /// Gap between tiles.
+    pub tile_gap: Gap,
+    /// Gap between groups.
+    pub group_gap: Gap,
+    /// Color of the image background, i.e. the area not covered by any tile.
+    #[cfg_attr(feature = "serde", serde(with = "rgba_serde"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "[u8; 4]"))]
+    pub background: Rgba<u8>,
+    /// Texture drawn on top of the background color, beneath the tiles, if
+    /// any.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub texture: Option<Texture>,
+    /// Padding added on each side of the rendered hand, filled with the
+    /// background color.
+    pub padding: Gap,
+    /// Scaling applied to the final image. All the other dimensions (including
+    /// [Gap::Pixels]) are expressed in the tile set pixels, before
+    /// scaling.
+    pub scale: Scale,
+    /// Filter used to resample the image when scaling it.
+    #[cfg_attr(feature = "serde", serde(with = "FilterTypeDef"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "FilterTypeDef"))]
+    pub filter: FilterType,
+    /// Vertical alignment of the groups of different heights.
+    pub vertical_align: VerticalAlign,
+    /// Tile separated from the rest of its group with a group gap, if any.
+    pub winning_tile: Option<WinningTile>,
+    /// Drop shadow rendered under each tile, if any. The shadow is clipped to
+    /// the image, so it might be necessary to add some padding.
+    pub shadow: Option<Shadow>,
+    /// Rotation applied to the final image, after scaling.
+    pub rotation: Rotation,
+    /// Maximum width of a row of tiles (excluding the padding), if any. The
+    /// groups that do not fit are wrapped onto the next rows.
+    pub max_width: Option<u32>,
+    /// Whether the groups of four identical, normally placed tiles are
+    /// displayed as ankans, with the first and last tiles face-down (see
+    /// [LayoutOptions::face_down_closed_kans]).
+    pub face_down_closed_kans: bool,
+    /// Convention the ankans are displayed with, if they should be changed
+    /// (see [LayoutOptions::ankan_style]).
+    pub ankan_style: Option<AnkanStyle>,
+}
+
+/// Fully transparent background color.
+const TRANSPARENT: Rgba<u8> = Rgba([0, 0, 0, 0]);
+
+/// (De)serialization of the colors as `[r, g, b, a]` arrays.
+#[cfg(feature = "serde")]
+mod rgba_serde {
+    use image::Rgba;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(
+        color: &Rgba<u8>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        color.0.serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Rgba<u8>, D::Error> {
+        <[u8; 4]>::deserialize(deserializer).map(Rgba)
+    }
+}
+
+/// Serializable mirror of the resampling filters of `image`.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(remote = "FilterType", rename_all = "snake_case")]
+enum FilterTypeDef {
+    Nearest,
+    Triangle,
+    CatmullRom,
+    Gaussian,
+    Lanczos3,
 }
 
 impl RenderOptions {
     #[inline]
-    /// Creates a new render options object instance.
-    pub fn new(tile_gap: TileWidthRatio, group_gap: TileWidthRatio) -> Self {
+    /// Creates a new render options object instance. The background of the
+    /// image is fully transparent and not textured, there is no padding, the
+    /// image is not scaled (and resampled using a Lanczos filter when it is),
+    /// the groups are aligned to the bottom edge, no tile is separated from
+    /// its group, the tiles have no shadows and are never wrapped, the
+    /// ankans are detected and displayed with the first and last tiles
+    /// face-down, and the image is not rotated.
+    ///
+    /// The gaps can be given either as [Gap] values, or as [TileWidthRatio].
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::raster_renderer::{Gap, RenderOptions, TileWidthRatio};
+    ///
+    /// let options = RenderOptions::new(Gap::Pixels(2), TileWidthRatio(0.5));
+    /// assert_eq!(options.tile_gap, Gap::Pixels(2));
+    /// assert_eq!(options.group_gap, Gap::Ratio(0.5));
+    /// ```
+    pub fn new<T: Into<Gap>, G: Into<Gap>>(tile_gap: T, group_gap: G) -> Self {
+        Self {
+            tile_gap: tile_gap.into(),
+            group_gap: group_gap.into(),
+            background: TRANSPARENT,
+            texture: None,
+            padding: Gap::default(),
+            scale: Scale::default(),
+            filter: FilterType::Lanczos3,
+            vertical_align: VerticalAlign::default(),
+            winning_tile: None,
+            shadow: None,
+            rotation: Rotation::default(),
+            max_width: None,
+            face_down_closed_kans: true,
+            ankan_style: None,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the render options with given background color (e.g.
+    /// `Rgba([255, 255, 255, 255])` for solid white, or `Rgba([0, 0, 0, 0])`
+    /// for fully transparent).
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::image::Rgba;
+    /// use riichi_hand::raster_renderer::RenderOptions;
+    ///
+    /// let options = RenderOptions::default().background(Rgba([255, 255, 255, 255]));
+    /// assert_eq!(options.background, Rgba([255, 255, 255, 255]));
+    /// ```
+    pub fn background(self, background: Rgba<u8>) -> Self {
+        Self { background, ..self }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the render options with given texture drawn beneath the tiles
+    /// (e.g. a table felt).
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::image::Rgba;
+    /// use riichi_hand::raster_renderer::{RenderOptions, Texture};
+    ///
+    /// let options = RenderOptions::default().texture(Texture::Felt(Rgba([30, 110, 60, 255])));
+    /// assert_eq!(options.texture, Some(Texture::Felt(Rgba([30, 110, 60, 255]))));
+    /// ```
+    pub fn texture(self, texture: Texture) -> Self {
+        Self {
+            texture: Some(texture),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the render options with given padding around the hand.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::raster_renderer::{Gap, RenderOptions};
+    ///
+    /// let options = RenderOptions::default().padding(Gap::Ratio(0.5));
+    /// assert_eq!(options.padding, Gap::Ratio(0.5));
+    /// ```
+    pub fn padding(self, padding: Gap) -> Self {
+        Self { padding, ..self }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the render options that scale the image by given factor. The
+    /// image is resampled using [RenderOptions::filter].
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::raster_renderer::{RenderOptions, Scale};
+    ///
+    /// let options = RenderOptions::default().scale(0.25);
+    /// assert_eq!(options.scale, Scale::Factor(0.25));
+    /// ```
+    pub fn scale(self, factor: f32) -> Self {
+        Self {
+            scale: Scale::Factor(factor),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the render options that scale the image so that a single
+    /// (non-rotated) tile is given number of pixels high. The image is
+    /// resampled using [RenderOptions::filter].
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::raster_renderer::{RenderOptions, Scale};
+    ///
+    /// let options = RenderOptions::default().target_tile_height(64);
+    /// assert_eq!(options.scale, Scale::TileHeight(64));
+    /// ```
+    pub fn target_tile_height(self, tile_height: u32) -> Self {
+        Self {
+            scale: Scale::TileHeight(tile_height),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the render options with given filter used to resample the
+    /// image when scaling it, trading speed for quality (e.g.
+    /// [FilterType::Nearest] is the fastest, and [FilterType::Lanczos3], the
+    /// default, gives the best quality).
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::image::imageops::FilterType;
+    /// use riichi_hand::raster_renderer::RenderOptions;
+    ///
+    /// let options = RenderOptions::default()
+    ///     .scale(0.25)
+    ///     .filter(FilterType::Triangle);
+    /// assert_eq!(options.filter, FilterType::Triangle);
+    /// ```
+    pub fn filter(self, filter: FilterType) -> Self {
+        Self { filter, ..self }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the render options with given vertical alignment of the groups
+    /// of different heights (e.g. because of the rotated tiles).
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::raster_renderer::RenderOptions;
+    /// use riichi_hand::renderer::VerticalAlign;
+    ///
+    /// let options = RenderOptions::default().vertical_align(VerticalAlign::Center);
+    /// assert_eq!(options.vertical_align, VerticalAlign::Center);
+    /// ```
+    pub fn vertical_align(self, vertical_align: VerticalAlign) -> Self {
+        Self {
+            vertical_align,
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the render options with given tile (usually the winning tile)
+    /// separated from the rest of its group with a group gap, so that the
+    /// hand does not need to be split into groups manually.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::raster_renderer::RenderOptions;
+    /// use riichi_hand::renderer::WinningTile;
+    ///
+    /// let options = RenderOptions::default().winning_tile(WinningTile::Last);
+    /// assert_eq!(options.winning_tile, Some(WinningTile::Last));
+    /// ```
+    pub fn winning_tile(self, winning_tile: WinningTile) -> Self {
+        Self {
+            winning_tile: Some(winning_tile),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the render options with given drop shadow rendered under each
+    /// tile.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::raster_renderer::{Gap, RenderOptions, Shadow};
+    ///
+    /// let options = RenderOptions::default()
+    ///     .shadow(Shadow::new(4, 4, 3.0, 0.5))
+    ///     .padding(Gap::Pixels(10));
+    /// assert_eq!(options.shadow, Some(Shadow::new(4, 4, 3.0, 0.5)));
+    /// ```
+    pub fn shadow(self, shadow: Shadow) -> Self {
+        Self {
+            shadow: Some(shadow),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the render options with given rotation of the final image, so
+    /// that the hand can be placed on any side of a table.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::raster_renderer::{RenderOptions, Rotation};
+    ///
+    /// let options = RenderOptions::default().rotation(Rotation::Clockwise90);
+    /// assert_eq!(options.rotation, Rotation::Clockwise90);
+    /// ```
+    pub fn rotation(self, rotation: Rotation) -> Self {
+        Self { rotation, ..self }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the render options with given maximum width (in the tile set
+    /// pixels, excluding the padding) of a row of tiles. The groups (or the
+    /// tiles of a group, if it is too wide on its own) that do not fit are
+    /// wrapped onto the next rows, which is useful for the long inputs, such
+    /// as all the tile kinds.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::raster_renderer::RenderOptions;
+    ///
+    /// let options = RenderOptions::default().max_width(1000);
+    /// assert_eq!(options.max_width, Some(1000));
+    /// ```
+    pub fn max_width(self, max_width: u32) -> Self {
         Self {
-            tile_gap,
-            group_gap,
+            max_width: Some(max_width),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the render options with the groups of four identical, normally
+    /// placed tiles displayed as ankans or not.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::raster_renderer::RenderOptions;
+    ///
+    /// let options = RenderOptions::default().face_down_closed_kans(false);
+    /// assert!(!options.face_down_closed_kans);
+    /// ```
+    pub fn face_down_closed_kans(self, face_down_closed_kans: bool) -> Self {
+        Self {
+            face_down_closed_kans,
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the render options with the ankans (e.g. created with
+    /// [Meld::to_group](crate::meld::Meld::to_group)) displayed with given
+    /// convention.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::meld::AnkanStyle;
+    /// use riichi_hand::raster_renderer::RenderOptions;
+    ///
+    /// let options = RenderOptions::default().ankan_style(AnkanStyle::FaceDownMiddle);
+    /// assert_eq!(options.ankan_style, Some(AnkanStyle::FaceDownMiddle));
+    /// ```
+    pub fn ankan_style(self, ankan_style: AnkanStyle) -> Self {
+        Self {
+            ankan_style: Some(ankan_style),
+            ..self
         }
     }
 }
@@ -47,126 +573,621 @@ pub struct RasterRenderer<'a, T: TileSet> {
 pub type ImageType = RgbaImage;
 /// Result of [RasterRenderer::render].
 pub type HandRenderResult = Result<ImageType, HandRenderError>;
+/// Tile image along with the coordinates of its top left corner.
+pub(super) type PlacedTileImage<'a> = (i64, i64, Cow<'a, ImageType>);
 
 impl<'a, T: TileSet> RasterRenderer<'a, T> {
     #[inline]
     /// Renders given [Hand] instance using [TileSet] and [RenderOptions].
     pub fn render(hand: &Hand, tile_set: &'a T, options: RenderOptions) -> HandRenderResult {
-        Self::new(tile_set, options).render_internal(hand)
+        Self::new(tile_set, options).render_hand(hand)
     }
 
     #[inline]
-    fn new(tile_set: &'a T, options: RenderOptions) -> Self {
-        Self { tile_set, options }
+    /// Renders given [Hand] instance using [TileSet] and [RenderOptions],
+    /// returning the image as a [DynamicImage], which is what most of the
+    /// `image` crate based pipelines take. It can be converted to other pixel
+    /// types (e.g. with [DynamicImage::into_rgb8] or
+    /// [DynamicImage::into_luma8]) and encoded with [DynamicImage::write_to].
+    ///
+    /// # Examples
+    /// ```
+    /// use std::io::Cursor;
+    ///
+    /// use riichi_hand::image::ImageFormat;
+    /// use riichi_hand::parser::HandParser;
+    /// use riichi_hand::raster_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_TILE_SET;
+    /// use riichi_hand::raster_renderer::{RasterRenderer, RenderOptions};
+    ///
+    /// let hand = HandParser::parse("123m456p").unwrap();
+    /// let image =
+    ///     RasterRenderer::render_dynamic(&hand, &*YELLOW_FLUFFY_STUFF_TILE_SET, RenderOptions::default())
+    ///         .unwrap();
+    /// let mut png = Vec::new();
+    /// image.write_to(&mut Cursor::new(&mut png), ImageFormat::Png).unwrap();
+    /// let grayscale = image.into_luma8();
+    /// ```
+    pub fn render_dynamic(
+        hand: &Hand,
+        tile_set: &'a T,
+        options: RenderOptions,
+    ) -> Result<DynamicImage, HandRenderError> {
+        Self::render(hand, tile_set, options).map(DynamicImage::ImageRgba8)
+    }
+
+    /// Renders given [Hand] instance using [TileSet] and [RenderOptions] into
+    /// given buffer, which is resized to fit the image. The allocation of the
+    /// buffer is reused if possible, which makes this method useful when
+    /// rendering many hands in a row.
+    ///
+    /// Note that scaling or rotating the image (see [RenderOptions::scale] and
+    /// [RenderOptions::rotation]) always allocates a new buffer.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::parser::HandParser;
+    /// use riichi_hand::raster_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_TILE_SET;
+    /// use riichi_hand::raster_renderer::{ImageType, RasterRenderer, RenderOptions};
+    ///
+    /// let mut buffer = ImageType::new(0, 0);
+    /// for hand in ["123m456p", "789s1122z"] {
+    ///     let hand = HandParser::parse(hand).unwrap();
+    ///     RasterRenderer::render_into(
+    ///         &hand,
+    ///         &*YELLOW_FLUFFY_STUFF_TILE_SET,
+    ///         RenderOptions::default(),
+    ///         &mut buffer,
+    ///     )
+    ///     .unwrap();
+    /// }
+    /// ```
+    pub fn render_into(
+        hand: &Hand,
+        tile_set: &'a T,
+        options: RenderOptions,
+        buffer: &mut ImageType,
+    ) -> Result<(), HandRenderError> {
+        Self::new(tile_set, options).render_hand_into(hand, buffer)
     }
 
-    fn render_internal(&self, hand: &Hand) -> HandRenderResult {
-        let (width, height) = self.calculate_image_size(hand);
-        let mut image = ImageBuffer::new(width, height);
+    /// Renders given [Hand] instances using [TileSet] and [RenderOptions] into
+    /// a single image, with each hand in its own row, aligned to the left
+    /// edge. The rows are separated by the group gap.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::parser::HandParser;
+    /// use riichi_hand::raster_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_TILE_SET;
+    /// use riichi_hand::raster_renderer::{RasterRenderer, RenderOptions};
+    ///
+    /// let hands = [
+    ///     HandParser::parse("123m456p789s1122z").unwrap(),
+    ///     HandParser::parse("123m456p789s1123z").unwrap(),
+    /// ];
+    /// let image =
+    ///     RasterRenderer::render_many(&hands, &*YELLOW_FLUFFY_STUFF_TILE_SET, RenderOptions::default())
+    ///         .unwrap();
+    /// ```
+    pub fn render_many(
+        hands: &[Hand],
+        tile_set: &'a T,
+        options: RenderOptions,
+    ) -> HandRenderResult {
+        let renderer = Self::new(tile_set, options);
+        let layouts = hands
+            .iter()
+            .map(|hand| renderer.layout(hand, renderer.layout_options()));
+        let (layout, _) = stack_layouts(layouts, renderer.group_gap());
 
-        self.render_hand(hand, &mut image)?;
+        renderer.render_layout(&layout)
+    }
 
-        Ok(image)
+    #[inline]
+    /// Renders given [Hand] instance using [TileSet] and [RenderOptions],
+    /// highlighting some of its tiles (e.g. the winning tile).
+    ///
+    /// The tiles are identified by their index in the hand, counting all the
+    /// tiles of all the groups in order. If a tile is given more than once,
+    /// the last highlight is used.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::image::Rgba;
+    /// use riichi_hand::parser::HandParser;
+    /// use riichi_hand::raster_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_TILE_SET;
+    /// use riichi_hand::raster_renderer::{Highlight, RasterRenderer, RenderOptions};
+    ///
+    /// let hand = HandParser::parse("123m456p789s11z_2z").unwrap();
+    /// let image = RasterRenderer::render_highlighted(
+    ///     &hand,
+    ///     &[(11, Highlight::Tint(Rgba([255, 0, 0, 96])))],
+    ///     &*YELLOW_FLUFFY_STUFF_TILE_SET,
+    ///     RenderOptions::default(),
+    /// )
+    /// .unwrap();
+    /// ```
+    pub fn render_highlighted(
+        hand: &Hand,
+        highlights: &[(usize, Highlight)],
+        tile_set: &'a T,
+        options: RenderOptions,
+    ) -> HandRenderResult {
+        let renderer = Self::new(tile_set, options);
+        let layout = renderer.layout(hand, renderer.layout_options());
+        let tiles = layout.tiles.iter().enumerate().map(|(index, position)| {
+            let highlight = highlights
+                .iter()
+                .rev()
+                .find(|(highlighted, _)| *highlighted == index)
+                .map(|(_, highlight)| *highlight);
+            (*position, highlight)
+        });
+
+        renderer.render_tiles(layout.width, layout.height, tiles)
+    }
+
+    #[inline]
+    /// Renders the dead wall strip with given dora indicators, of which the
+    /// first `revealed` are face-up, using [TileSet] and [RenderOptions]. See
+    /// [Hand::dora_wall] for the details.
+    pub fn render_dora_wall(
+        indicators: &[Tile],
+        revealed: usize,
+        tile_set: &'a T,
+        options: RenderOptions,
+    ) -> HandRenderResult {
+        Self::render(&Hand::dora_wall(indicators, revealed), tile_set, options)
     }
 
-    fn render_hand<I: GenericImage<Pixel = Rgba<u8>>>(
+    #[inline]
+    /// Renders given [River] using [TileSet] and [RenderOptions].
+    ///
+    /// The discards are laid out in rows (see [River::rows]), separated
+    /// vertically by the tile gap. Riichi declaration tiles are rendered
+    /// sideways. If `mark_called` is `true`, the tiles called by other players
+    /// are rendered darkened.
+    pub fn render_river(
+        river: &River,
+        tile_set: &'a T,
+        options: RenderOptions,
+        mark_called: bool,
+    ) -> HandRenderResult {
+        Self::new(tile_set, options).render_river_internal(river, mark_called)
+    }
+
+    #[inline]
+    /// Creates a new renderer instance that uses given [TileSet] and
+    /// [RenderOptions]. The instance implements [HandRenderer], and can be
+    /// kept alive to render any number of hands, which is useful along with
+    /// the tile sets that cache the tile images (such as [TwoPartTileSet]),
+    /// and [RasterRenderer::render_hand_into].
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::parser::HandParser;
+    /// use riichi_hand::raster_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_TILE_SET;
+    /// use riichi_hand::raster_renderer::{ImageType, RasterRenderer, RenderOptions};
+    /// use riichi_hand::renderer::HandRenderer;
+    ///
+    /// let renderer = RasterRenderer::new(&*YELLOW_FLUFFY_STUFF_TILE_SET, RenderOptions::default());
+    /// let image = renderer.render_hand(&HandParser::parse("123m").unwrap()).unwrap();
+    ///
+    /// let mut buffer = ImageType::new(0, 0);
+    /// renderer
+    ///     .render_hand_into(&HandParser::parse("456p").unwrap(), &mut buffer)
+    ///     .unwrap();
+    /// assert_eq!(image.dimensions(), buffer.dimensions());
+    /// ```
+    ///
+    /// [TwoPartTileSet]: crate::raster_renderer::TwoPartTileSet
+    pub fn new(tile_set: &'a T, options: RenderOptions) -> Self {
+        Self { tile_set, options }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the tile set used by this renderer.
+    pub fn tile_set(&self) -> &'a T {
+        self.tile_set
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the options used by this renderer.
+    pub fn options(&self) -> &RenderOptions {
+        &self.options
+    }
+
+    /// Renders given [Hand] instance into given buffer, which is resized to
+    /// fit the image, reusing its allocation if possible (see
+    /// [RasterRenderer::render_into]).
+    pub fn render_hand_into(
         &self,
         hand: &Hand,
-        image: &mut I,
+        buffer: &mut ImageType,
     ) -> Result<(), HandRenderError> {
-        let mut start_x = 0;
-        for group in hand.groups() {
-            let (width, height) = self.calculate_group_size(group);
-            let mut sub_image =
-                imageops::crop(image, start_x, image.height() - height, width, height);
-            self.render_group(group, &mut *sub_image)?;
-
-            start_x += width + self.group_gap();
+        let layout = self.layout(hand, self.layout_options());
+        let tiles = layout.tiles.iter().map(|position| (*position, None));
+
+        if self.scale_factor() == 1.0 && self.options.rotation == Rotation::Upright {
+            self.draw_tiles_into(layout.width, layout.height, tiles, buffer)
+        } else {
+            *buffer = self.render_tiles(layout.width, layout.height, tiles)?;
+            Ok(())
         }
+    }
 
-        Ok(())
+    /// Returns the rectangles occupied by the tiles of given [Hand] instance
+    /// in the image rendered by [RasterRenderer::render_hand], in the order
+    /// they appear in the hand, taking into account the padding, scale and
+    /// rotation of the image. This is useful for hit-testing, e.g. to find the
+    /// tile under the cursor.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::parser::HandParser;
+    /// use riichi_hand::raster_renderer::{
+    ///     GeneratedTileSet, Gap, RasterRenderer, RenderOptions,
+    /// };
+    /// use riichi_hand::renderer::{HandRenderer, Rect};
+    ///
+    /// let tile_set = GeneratedTileSet::new(40);
+    /// let options = RenderOptions::default().padding(Gap::Pixels(10)).scale(0.5);
+    /// let renderer = RasterRenderer::new(&tile_set, options);
+    /// let hand = HandParser::parse("12m").unwrap();
+    ///
+    /// let tiles = renderer.placed_tiles(&hand);
+    /// let image = renderer.render_hand(&hand).unwrap();
+    ///
+    /// assert_eq!(tiles[0].rect, Rect::new(5, 5, 15, 20));
+    /// assert!(tiles[1].rect.x + tiles[1].rect.width <= image.width());
+    /// ```
+    #[must_use]
+    pub fn placed_tiles(&self, hand: &Hand) -> Vec<PlacedTile> {
+        let layout = self.layout(hand, self.layout_options());
+        let padding = self.padding();
+        let factor = self.scale_factor();
+        let scale = |value: u32| {
+            if factor == 1.0 {
+                value
+            } else {
+                (value as f32 * factor).round() as u32
+            }
+        };
+        let width = scale(layout.width + 2 * padding);
+        let height = scale(layout.height + 2 * padding);
+
+        let mut tiles = layout.placed_tiles(hand, |tile| self.tile_set.tile_size(tile));
+        for tile in &mut tiles {
+            let (x, y) = (padding + tile.rect.x, padding + tile.rect.y);
+            let scaled = Rect::new(
+                scale(x),
+                scale(y),
+                scale(x + tile.rect.width) - scale(x),
+                scale(y + tile.rect.height) - scale(y),
+            );
+            tile.rect = self.options.rotation.apply_to_rect(scaled, width, height);
+        }
+
+        tiles
     }
 
-    fn render_group<I: GenericImage<Pixel = Rgba<u8>>>(
+    pub(super) fn render_river_internal(
         &self,
-        group: &HandGroup,
-        image: &mut I,
-    ) -> Result<(), HandRenderError> {
-        let mut start_x = 0;
-        let mut last_placement = Normal;
-        for tile in group {
-            let (width, height) = self.calculate_tile_size(tile);
-            if last_placement == Rotated && tile.placement == RotatedAndShifted {
-                start_x -= width + self.tile_gap();
-            }
+        river: &River,
+        mark_called: bool,
+    ) -> HandRenderResult {
+        let row_height = self.tile_set.tile_height();
+        let row_gap = self.tile_gap();
+
+        // the river has no winning tile to separate, and is already split into
+        // rows
+        let layout_options = LayoutOptions {
+            winning_tile: None,
+            max_width: None,
+            ..self.layout_options()
+        };
 
-            let mut sub_image =
-                imageops::crop(image, start_x, image.height() - height, width, height);
-            self.render_tile(tile, &mut *sub_image)?;
+        let mut width = 0;
+        let mut height = 0;
+        let mut tiles = Vec::new();
+        for row in river.rows() {
+            let hand = Hand::new(vec![row.iter().map(Discard::hand_tile).collect()]);
+            let layout = self.layout(&hand, layout_options);
+            let start_y = if height == 0 { 0 } else { height + row_gap };
+            let offset_y = start_y + row_height - layout.height;
 
-            last_placement = tile.placement;
-            start_x += width + self.tile_gap();
+            tiles.extend(layout.tiles.iter().zip(row).map(|(position, discard)| {
+                let position = TilePosition {
+                    y: offset_y + position.y,
+                    ..*position
+                };
+                let highlight = if mark_called && discard.called {
+                    Some(Highlight::Dim)
+                } else {
+                    None
+                };
+                (position, highlight)
+            }));
+
+            width = width.max(layout.width);
+            height = start_y + row_height;
         }
 
-        Ok(())
+        self.render_tiles(width, height, tiles)
     }
 
-    fn render_tile<I: GenericImage<Pixel = Rgba<u8>>>(
+    /// Renders the tiles at given positions (optionally highlighted) onto an
+    /// image of given size, then applies padding and scaling.
+    pub(super) fn render_tiles<I: IntoIterator<Item = (TilePosition, Option<Highlight>)>>(
         &self,
-        tile: &HandTile,
-        image: &mut I,
+        width: u32,
+        height: u32,
+        tiles: I,
+    ) -> HandRenderResult {
+        let image = self.draw_tiles(width, height, tiles)?;
+
+        Ok(self.finish_image(image))
+    }
+
+    /// Renders the tiles at given positions (optionally highlighted) onto an
+    /// image of given size, with padding, but without scaling.
+    pub(super) fn draw_tiles<I: IntoIterator<Item = (TilePosition, Option<Highlight>)>>(
+        &self,
+        width: u32,
+        height: u32,
+        tiles: I,
+    ) -> Result<ImageType, HandRenderError> {
+        let mut image = ImageBuffer::new(0, 0);
+        self.draw_tiles_into(width, height, tiles, &mut image)?;
+
+        Ok(image)
+    }
+
+    /// Renders the tiles at given positions (optionally highlighted) onto given
+    /// buffer, with padding, but without scaling. The buffer is resized to fit
+    /// the image, reusing its allocation if possible.
+    fn draw_tiles_into<I: IntoIterator<Item = (TilePosition, Option<Highlight>)>>(
+        &self,
+        width: u32,
+        height: u32,
+        tiles: I,
+        image: &mut ImageType,
     ) -> Result<(), HandRenderError> {
-        let tile_image = self.tile_set.tile_image(tile)?;
-        imageops::overlay(image, &tile_image, 0, 0);
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("draw_tiles", width, height).entered();
+        #[cfg(feature = "tracing")]
+        let start = std::time::Instant::now();
+
+        let tile_images = tiles
+            .into_iter()
+            .map(|(position, highlight)| self.tile_image(position, highlight))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.draw_tile_images_into(width, height, &tile_images, image);
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            tiles = tile_images.len(),
+            image_width = image.width(),
+            image_height = image.height(),
+            elapsed = ?start.elapsed(),
+            "drew tiles"
+        );
 
         Ok(())
     }
 
-    fn calculate_image_size(&self, hand: &Hand) -> (u32, u32) {
-        hand.groups()
-            .iter()
-            .map(|group| self.calculate_group_size(group))
-            .reduce(|(w1, h1), (w2, h2)| (w1 + w2 + self.group_gap(), h1.max(h2)))
-            .unwrap_or((0, 0))
+    /// Returns the image of the tile at given position (optionally
+    /// highlighted), along with the coordinates of its top left corner in the
+    /// padded image.
+    pub(super) fn tile_image(
+        &self,
+        position: TilePosition,
+        highlight: Option<Highlight>,
+    ) -> Result<PlacedTileImage<'a>, HandRenderError> {
+        let tile_set: &'a T = self.tile_set;
+        let mut tile_image = tile_set.tile_image_ref(&position.tile)?;
+        if let Some(highlight) = highlight {
+            highlight.apply(tile_image.to_mut());
+        }
+
+        let padding = self.padding();
+        let x = i64::from(padding + position.x);
+        let y = i64::from(padding + position.y);
+
+        Ok((x, y, tile_image))
     }
 
-    fn calculate_group_size(&self, group: &HandGroup) -> (u32, u32) {
-        group
-            .iter()
-            .map(|tile| (tile.placement, self.calculate_tile_size(tile)))
-            .reduce(|(placement_1, (w1, h1)), (placement_2, (w2, h2))| {
-                let width = if placement_1 == Rotated && placement_2 == RotatedAndShifted {
-                    w1
-                } else {
-                    w1 + w2 + self.tile_gap()
-                };
-                (placement_2, (width, h1.max(h2)))
-            })
-            .unwrap_or((Normal, (0, 0)))
-            .1
+    /// Draws given tile images onto given buffer, with padding, but without
+    /// scaling. The buffer is resized to fit the image, reusing its allocation
+    /// if possible.
+    pub(super) fn draw_tile_images_into(
+        &self,
+        width: u32,
+        height: u32,
+        tile_images: &[PlacedTileImage<'_>],
+        image: &mut ImageType,
+    ) {
+        let padding = self.padding();
+        let (image_width, image_height) = (width + 2 * padding, height + 2 * padding);
+        if image.dimensions() != (image_width, image_height) {
+            let mut data = std::mem::take(image).into_raw();
+            data.resize(4 * image_width as usize * image_height as usize, 0);
+            *image = ImageBuffer::from_raw(image_width, image_height, data)
+                .expect("buffer should have been resized to fit the image");
+        }
+        texture::fill_canvas(
+            image,
+            self.options.background,
+            self.options.texture.as_ref(),
+        );
+
+        if let Some(shadow) = self.options.shadow {
+            let shadows = Self::draw_shadows(image.width(), image.height(), tile_images, shadow);
+            imageops::overlay(image, &shadows, 0, 0);
+        }
+        for (x, y, tile_image) in tile_images {
+            imageops::overlay(image, &**tile_image, *x, *y);
+        }
     }
 
-    #[inline]
-    fn calculate_tile_size(&self, tile: &HandTile) -> (u32, u32) {
-        let width = self.tile_set.tile_width();
-        let height = self.tile_set.tile_height();
-
-        match tile.placement {
-            Normal => (width, height),
-            Rotated => (height, width),
-            RotatedAndShifted => (height, 2 * width),
+    /// Draws the shadows of given tile images onto a transparent image of
+    /// given size.
+    fn draw_shadows(
+        width: u32,
+        height: u32,
+        tile_images: &[PlacedTileImage<'_>],
+        shadow: Shadow,
+    ) -> ImageType {
+        let mut shadows = ImageBuffer::from_pixel(width, height, TRANSPARENT);
+        for (x, y, tile_image) in tile_images {
+            let mut tile_shadow = tile_image.clone().into_owned();
+            for pixel in tile_shadow.pixels_mut() {
+                let alpha = (f32::from(pixel[3]) * shadow.opacity).round() as u8;
+                *pixel = Rgba([0, 0, 0, alpha]);
+            }
+
+            imageops::overlay(
+                &mut shadows,
+                &tile_shadow,
+                x + i64::from(shadow.offset_x),
+                y + i64::from(shadow.offset_y),
+            );
+        }
+
+        // `imageops::blur` panics on empty images (e.g. of an empty hand)
+        if shadow.blur_radius > 0.0 && width > 0 && height > 0 {
+            imageops::blur(&shadows, shadow.blur_radius)
+        } else {
+            shadows
         }
     }
 
-    fn group_gap(&self) -> u32 {
-        (self.options.group_gap.0 * self.tile_set.tile_width() as f32) as u32
+    /// Lays out the tiles of given [Hand] instance using given options and
+    /// the tile sizes of the tile set.
+    pub(super) fn layout(&self, hand: &Hand, options: LayoutOptions) -> Layout {
+        Layout::with_tile_sizes(hand, options, |tile| self.tile_set.tile_size(tile))
+    }
+
+    pub(super) fn group_gap(&self) -> u32 {
+        self.options.group_gap.pixels(self.tile_set.tile_width())
     }
 
     fn tile_gap(&self) -> u32 {
-        (self.options.tile_gap.0 * self.tile_set.tile_width() as f32) as u32
+        self.options.tile_gap.pixels(self.tile_set.tile_width())
+    }
+
+    pub(super) fn padding(&self) -> u32 {
+        self.padding_for(self.options.padding)
+    }
+
+    pub(super) fn padding_for(&self, padding: Gap) -> u32 {
+        padding.pixels(self.tile_set.tile_width())
+    }
+
+    pub(super) fn finish_image(&self, image: ImageType) -> ImageType {
+        let image = self.scale_image_by(image, self.options.scale);
+
+        self.options.rotation.apply(image)
+    }
+
+    fn scale_factor(&self) -> f32 {
+        self.options.scale.factor(self.tile_set.tile_height())
+    }
+
+    pub(super) fn scale_image_by(&self, image: ImageType, scale: Scale) -> ImageType {
+        let factor = scale.factor(self.tile_set.tile_height());
+        if factor == 1.0 {
+            return image;
+        }
+
+        let width = (image.width() as f32 * factor).round() as u32;
+        let height = (image.height() as f32 * factor).round() as u32;
+        #[cfg(feature = "tracing")]
+        let start = std::time::Instant::now();
+        let scaled = imageops::resize(&image, width, height, self.options.filter);
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            from_width = image.width(),
+            from_height = image.height(),
+            width,
+            height,
+            filter = ?self.options.filter,
+            elapsed = ?start.elapsed(),
+            "scaled image"
+        );
+
+        scaled
     }
 }
 
+impl<'a, T: TileSet> HandRenderer for RasterRenderer<'a, T> {
+    type Output = ImageType;
+    type Error = HandRenderError;
+
+    fn layout_options(&self) -> LayoutOptions {
+        let options = LayoutOptions::new(
+            self.tile_set.tile_width(),
+            self.tile_set.tile_height(),
+            self.tile_gap(),
+            self.group_gap(),
+        )
+        .with_vertical_align(self.options.vertical_align);
+
+        LayoutOptions {
+            winning_tile: self.options.winning_tile,
+            max_width: self.options.max_width,
+            face_down_closed_kans: self.options.face_down_closed_kans,
+            ankan_style: self.options.ankan_style,
+            ..options
+        }
+    }
+
+    #[inline]
+    fn tile_size(&self, tile: &HandTile) -> (u32, u32) {
+        self.tile_set.tile_size(tile)
+    }
+
+    fn render_layout(&self, layout: &Layout) -> HandRenderResult {
+        let tiles = layout.tiles.iter().map(|position| (*position, None));
+        self.render_tiles(layout.width, layout.height, tiles)
+    }
+}
+
+/// Places given layouts one below another, separated by given gap, in a
+/// single layout. Returns the layout along with the Y coordinates of the top
+/// edges of all the given layouts.
+pub(super) fn stack_layouts<I: IntoIterator<Item = Layout>>(
+    layouts: I,
+    gap: u32,
+) -> (Layout, Vec<u32>) {
+    let mut stacked = Layout {
+        width: 0,
+        height: 0,
+        tiles: Vec::new(),
+    };
+    let mut offsets = Vec::new();
+    for layout in layouts {
+        let offset_y = if offsets.is_empty() {
+            0
+        } else {
+            stacked.height + gap
+        };
+        stacked
+            .tiles
+            .extend(layout.tiles.iter().map(|position| TilePosition {
+                y: offset_y + position.y,
+                ..*position
+            }));
+        stacked.width = stacked.width.max(layout.width);
+        stacked.height = offset_y + layout.height;
+        offsets.push(offset_y);
+    }
+
+    (stacked, offsets)
+}
+
 #[derive(Clone, Debug)]
 /// An error that occurs when calling [RasterRenderer::render].
 pub enum HandRenderError {
@@ -194,18 +1215,27 @@ impl From<TileImageRetrieveError> for HandRenderError {
 
 #[cfg(test)]
 mod tests {
-    use image::{ImageFormat, RgbaImage};
+    use std::collections::HashMap;
+    use std::sync::Arc;
 
-    #[cfg(feature = "fluffy-stuff-tile-sets")]
+    use image::imageops::FilterType;
+    use image::{ColorType, ImageFormat, Rgba, RgbaImage};
+
+    #[cfg(feature = "fluffy-stuff-yellow")]
     use crate::raster_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_TILE_SET;
     #[cfg(feature = "martin-persson-tile-sets")]
     use crate::raster_renderer::martin_persson_tile_sets::MARTIN_PERSSON_TILE_SET;
-    use crate::raster_renderer::renderer::{RasterRenderer, RenderOptions, TileWidthRatio};
+    use crate::raster_renderer::renderer::{
+        Gap, RasterRenderer, RenderOptions, Rotation, Shadow, TileWidthRatio,
+    };
+    use crate::raster_renderer::{Highlight, SimpleTileSet, Texture, TileSet, TwoPartTileSet};
+    use crate::renderer::{HandRenderer, Rect, VerticalAlign, WinningTile};
+    use crate::river::{Discard, River};
     use crate::tiles::*;
     use crate::TilePlacement::{Normal, Rotated, RotatedAndShifted};
     use crate::{Hand, HandTile};
 
-    #[cfg(feature = "fluffy-stuff-tile-sets")]
+    #[cfg(feature = "fluffy-stuff-yellow")]
     #[test]
     fn should_render_hand_with_fluffy_stuff_tile_set() {
         let buffer = RasterRenderer::render(
@@ -252,6 +1282,510 @@ mod tests {
         assert_eq!(error.to_string(), "could not retrieve tile image: tile rotated Ryan man not supported: this tile set does not support rotated tiles");
     }
 
+    #[test]
+    fn should_render_dynamic_image() {
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(3, 4, Rgba([255, 0, 0, 255]))))
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let hand = Hand::new(vec![vec![
+            HandTile::new(II_MAN, Normal),
+            HandTile::new(RYAN_MAN, Normal),
+        ]]);
+
+        let image =
+            RasterRenderer::render_dynamic(&hand, &tile_set, RenderOptions::default()).unwrap();
+
+        let expected = RasterRenderer::render(&hand, &tile_set, RenderOptions::default()).unwrap();
+        assert_eq!(image.color(), ColorType::Rgba8);
+        assert!(
+            image.to_rgba8() == expected,
+            "dynamic and rendered images differ"
+        );
+        assert_eq!(image.into_rgb8().get_pixel(5, 3).0, [255, 0, 0]);
+    }
+
+    #[test]
+    fn should_render_background() {
+        let red = Rgba([255, 0, 0, 255]);
+        let white = Rgba([255, 255, 255, 255]);
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(3, 4, red)))
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let hand = Hand::new(vec![
+            vec![HandTile::new(II_MAN, Normal)],
+            vec![HandTile::new(II_MAN, Normal)],
+        ]);
+
+        let transparent =
+            RasterRenderer::render(&hand, &tile_set, RenderOptions::default()).unwrap();
+        let opaque =
+            RasterRenderer::render(&hand, &tile_set, RenderOptions::default().background(white))
+                .unwrap();
+
+        assert_eq!(opaque.dimensions(), (7, 4));
+        assert_eq!(*transparent.get_pixel(3, 0), Rgba([0, 0, 0, 0]));
+        assert_eq!(*opaque.get_pixel(3, 0), white);
+        assert_eq!(*opaque.get_pixel(0, 0), red);
+        assert_eq!(*opaque.get_pixel(4, 3), red);
+    }
+
+    #[test]
+    fn should_render_padding() {
+        let red = Rgba([255, 0, 0, 255]);
+        let white = Rgba([255, 255, 255, 255]);
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(4, 5, red)))
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let hand = Hand::new(vec![vec![HandTile::new(II_MAN, Normal)]]);
+
+        let options = RenderOptions::default().background(white);
+        let pixels =
+            RasterRenderer::render(&hand, &tile_set, options.clone().padding(Gap::Pixels(3)))
+                .unwrap();
+        let ratio =
+            RasterRenderer::render(&hand, &tile_set, options.padding(Gap::Ratio(0.5))).unwrap();
+
+        assert_eq!(pixels.dimensions(), (10, 11));
+        assert_eq!(*pixels.get_pixel(2, 2), white);
+        assert_eq!(*pixels.get_pixel(3, 3), red);
+        assert_eq!(*pixels.get_pixel(6, 7), red);
+        assert_eq!(*pixels.get_pixel(7, 8), white);
+        assert_eq!(ratio.dimensions(), (8, 9));
+        assert_eq!(*ratio.get_pixel(2, 2), red);
+    }
+
+    #[test]
+    fn should_scale_image() {
+        let red = Rgba([255, 0, 0, 255]);
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(30, 40, red)))
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let hand = Hand::new(vec![vec![
+            HandTile::new(II_MAN, Normal),
+            HandTile::new(RYAN_MAN, Normal),
+        ]]);
+
+        let unscaled = RasterRenderer::render(&hand, &tile_set, RenderOptions::default()).unwrap();
+        let factor =
+            RasterRenderer::render(&hand, &tile_set, RenderOptions::default().scale(0.5)).unwrap();
+        let tile_height = RasterRenderer::render(
+            &hand,
+            &tile_set,
+            RenderOptions::default().target_tile_height(10),
+        )
+        .unwrap();
+
+        assert_eq!(unscaled.dimensions(), (60, 40));
+        assert_eq!(factor.dimensions(), (30, 20));
+        assert_eq!(*factor.get_pixel(15, 10), red);
+        assert_eq!(tile_height.dimensions(), (15, 10));
+    }
+
+    #[test]
+    fn should_align_groups_vertically() {
+        let red = Rgba([255, 0, 0, 255]);
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(4, 6, red)))
+            .collect::<HashMap<_, _>>();
+        let tile_set = TwoPartTileSet::new(RgbaImage::from_pixel(4, 6, red), map).unwrap();
+        let hand = Hand::new(vec![
+            vec![HandTile::new(II_MAN, Normal)],
+            vec![
+                HandTile::new(TON, Rotated),
+                HandTile::new(TON, RotatedAndShifted),
+            ],
+        ]);
+        let options = RenderOptions::default().vertical_align(VerticalAlign::Top);
+
+        let image = RasterRenderer::render(&hand, &tile_set, options).unwrap();
+
+        assert_eq!(image.dimensions(), (11, 8));
+        assert_eq!(*image.get_pixel(0, 0), red);
+        assert_eq!(image.get_pixel(0, 7)[3], 0);
+    }
+
+    #[test]
+    fn should_render_pixel_gaps() {
+        let red = Rgba([255, 0, 0, 255]);
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(3, 4, red)))
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let hand = Hand::new(vec![
+            vec![HandTile::new(II_MAN, Normal), HandTile::new(II_MAN, Normal)],
+            vec![HandTile::new(II_MAN, Normal)],
+        ]);
+
+        let image = RasterRenderer::render(
+            &hand,
+            &tile_set,
+            RenderOptions::new(Gap::Pixels(1), Gap::Pixels(5)),
+        )
+        .unwrap();
+
+        assert_eq!(image.dimensions(), (3 + 1 + 3 + 5 + 3, 4));
+        assert_eq!(image.get_pixel(3, 0)[3], 0);
+        assert_eq!(*image.get_pixel(4, 0), red);
+        assert_eq!(image.get_pixel(11, 0)[3], 0);
+        assert_eq!(*image.get_pixel(12, 0), red);
+    }
+
+    #[test]
+    fn should_render_dora_wall() {
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| {
+                (
+                    tile,
+                    RgbaImage::from_pixel(3, 4, Rgba([tile.value.0, 0, 0, 255])),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+
+        let image = RasterRenderer::render_dora_wall(
+            &[KYUU_MAN, PAA_PIN],
+            1,
+            &tile_set,
+            RenderOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(image.dimensions(), (21, 4));
+        assert_eq!(image.get_pixel(3, 0)[0], 0);
+        assert_eq!(image.get_pixel(6, 0)[0], 9);
+        assert_eq!(image.get_pixel(9, 0)[0], 0);
+    }
+
+    #[test]
+    fn should_render_river() {
+        let white = Rgba([255, 255, 255, 255]);
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(3, 4, white)))
+            .collect::<HashMap<_, _>>();
+        let tile_set = TwoPartTileSet::new(RgbaImage::from_pixel(3, 4, white), map).unwrap();
+        let mut discards = vec![Discard::new(TON); 8];
+        discards[1] = Discard::new(NAN).called();
+        discards[7] = Discard::new(SHAA).riichi();
+        let river = River::new(discards);
+
+        let unmarked =
+            RasterRenderer::render_river(&river, &tile_set, RenderOptions::default(), false)
+                .unwrap();
+        let marked = RasterRenderer::render_river(
+            &river,
+            &tile_set,
+            RenderOptions::new(Gap::Pixels(1), Gap::Pixels(0)),
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(unmarked.dimensions(), (18, 8));
+        assert_eq!(*unmarked.get_pixel(3, 0), white);
+        assert_eq!(unmarked.get_pixel(3, 4)[3], 0);
+        assert_eq!(*unmarked.get_pixel(3, 5), white);
+        assert_eq!(*unmarked.get_pixel(6, 7), white);
+        assert_eq!(unmarked.get_pixel(7, 7)[3], 0);
+
+        assert_eq!(marked.dimensions(), (23, 9));
+        assert_eq!(*marked.get_pixel(4, 0), Rgba([127, 127, 127, 255]));
+        assert_eq!(*marked.get_pixel(0, 0), white);
+        assert_eq!(marked.get_pixel(0, 4)[3], 0);
+        assert_eq!(*marked.get_pixel(0, 5), white);
+    }
+
+    #[test]
+    fn should_render_highlighted_tiles() {
+        let white = Rgba([255, 255, 255, 255]);
+        let red = Rgba([255, 0, 0, 255]);
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(3, 4, white)))
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let hand = Hand::new(vec![
+            vec![HandTile::new(II_MAN, Normal), HandTile::new(II_MAN, Normal)],
+            vec![HandTile::new(II_MAN, Normal)],
+        ]);
+
+        let image = RasterRenderer::render_highlighted(
+            &hand,
+            &[(0, Highlight::Dim), (2, Highlight::Tint(red))],
+            &tile_set,
+            RenderOptions::new(Gap::Pixels(0), Gap::Pixels(1)),
+        )
+        .unwrap();
+
+        assert_eq!(image.dimensions(), (10, 4));
+        assert_eq!(*image.get_pixel(0, 0), Rgba([127, 127, 127, 255]));
+        assert_eq!(*image.get_pixel(3, 0), white);
+        assert_eq!(*image.get_pixel(7, 0), red);
+    }
+
+    #[test]
+    fn should_render_shadow() {
+        let white = Rgba([255, 255, 255, 255]);
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(3, 4, white)))
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let hand = Hand::new(vec![vec![HandTile::new(II_MAN, Normal)]]);
+        let options = RenderOptions::default().padding(Gap::Pixels(4));
+
+        let hard = RasterRenderer::render(
+            &hand,
+            &tile_set,
+            options.clone().shadow(Shadow::new(2, 1, 0.0, 0.5)),
+        )
+        .unwrap();
+        let soft = RasterRenderer::render(
+            &hand,
+            &tile_set,
+            options.shadow(Shadow::new(2, 1, 1.0, 0.5)),
+        )
+        .unwrap();
+
+        assert_eq!(hard.dimensions(), (11, 12));
+        assert_eq!(*hard.get_pixel(4, 4), white);
+        assert_eq!(*hard.get_pixel(8, 8), Rgba([0, 0, 0, 128]));
+        assert_eq!(hard.get_pixel(8, 4)[3], 0);
+        assert_eq!(hard.get_pixel(9, 9)[3], 0);
+        assert_eq!(*soft.get_pixel(4, 4), white);
+        assert!(soft.get_pixel(9, 9)[3] > 0);
+        assert!(soft.get_pixel(8, 8)[3] < 128);
+    }
+
+    #[test]
+    fn should_render_texture_beneath_tiles() {
+        let red = Rgba([255, 0, 0, 255]);
+        let blue = Rgba([0, 0, 255, 255]);
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(3, 4, red)))
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let hand = Hand::new(vec![vec![HandTile::new(II_MAN, Normal)]]);
+        let texture = Texture::Image(Arc::new(RgbaImage::from_pixel(1, 1, blue)));
+
+        let image = RasterRenderer::render(
+            &hand,
+            &tile_set,
+            RenderOptions::default()
+                .padding(Gap::Pixels(1))
+                .texture(texture),
+        )
+        .unwrap();
+
+        assert_eq!(image.dimensions(), (5, 6));
+        assert_eq!(*image.get_pixel(0, 0), blue);
+        assert_eq!(*image.get_pixel(1, 1), red);
+        assert_eq!(*image.get_pixel(4, 5), blue);
+    }
+
+    #[test]
+    fn should_separate_winning_tile() {
+        let red = Rgba([255, 0, 0, 255]);
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(3, 4, red)))
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let hand = Hand::new(vec![vec![
+            HandTile::new(II_MAN, Normal),
+            HandTile::new(II_MAN, Normal),
+        ]]);
+
+        let image = RasterRenderer::render(
+            &hand,
+            &tile_set,
+            RenderOptions::new(Gap::Pixels(0), Gap::Pixels(2)).winning_tile(WinningTile::Last),
+        )
+        .unwrap();
+
+        assert_eq!(image.dimensions(), (8, 4));
+        assert_eq!(*image.get_pixel(2, 0), red);
+        assert_eq!(image.get_pixel(3, 0)[3], 0);
+        assert_eq!(image.get_pixel(4, 0)[3], 0);
+        assert_eq!(*image.get_pixel(5, 0), red);
+    }
+
+    #[test]
+    fn should_render_rotated() {
+        let red = Rgba([255, 0, 0, 255]);
+        let blue = Rgba([0, 0, 255, 255]);
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| {
+                let color = if tile == II_MAN { red } else { blue };
+                (tile, RgbaImage::from_pixel(3, 4, color))
+            })
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let hand = Hand::new(vec![vec![
+            HandTile::new(II_MAN, Normal),
+            HandTile::new(RYAN_MAN, Normal),
+        ]]);
+        let render = |rotation| {
+            let options = RenderOptions::default().rotation(rotation);
+            RasterRenderer::render(&hand, &tile_set, options).unwrap()
+        };
+
+        let upright = render(Rotation::Upright);
+        let clockwise_90 = render(Rotation::Clockwise90);
+        let clockwise_180 = render(Rotation::Clockwise180);
+        let clockwise_270 = render(Rotation::Clockwise270);
+
+        // the first (red) tile ends up on the left, top, right and bottom side
+        assert_eq!(upright.dimensions(), (6, 4));
+        assert_eq!(*upright.get_pixel(0, 0), red);
+        assert_eq!(clockwise_90.dimensions(), (4, 6));
+        assert_eq!(*clockwise_90.get_pixel(3, 0), red);
+        assert_eq!(*clockwise_90.get_pixel(3, 5), blue);
+        assert_eq!(clockwise_180.dimensions(), (6, 4));
+        assert_eq!(*clockwise_180.get_pixel(5, 0), red);
+        assert_eq!(*clockwise_180.get_pixel(0, 0), blue);
+        assert_eq!(clockwise_270.dimensions(), (4, 6));
+        assert_eq!(*clockwise_270.get_pixel(0, 5), red);
+        assert_eq!(*clockwise_270.get_pixel(0, 0), blue);
+    }
+
+    #[test]
+    fn should_render_wrapped() {
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(3, 4, Rgba([255, 0, 0, 255]))))
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let hand = Hand::new(vec![
+            vec![HandTile::new(II_MAN, Normal); 3],
+            vec![HandTile::new(TON, Normal); 3],
+        ]);
+        let options = RenderOptions::new(Gap::Pixels(1), Gap::Pixels(2)).padding(Gap::Pixels(1));
+
+        let image =
+            RasterRenderer::render(&hand, &tile_set, options.clone().max_width(12)).unwrap();
+        let unwrapped = RasterRenderer::render(&hand, &tile_set, options).unwrap();
+
+        assert_eq!(unwrapped.dimensions(), (26, 6));
+        assert_eq!(image.dimensions(), (13, 12));
+        assert_eq!(image.get_pixel(1, 5)[3], 0);
+        assert_eq!(image.get_pixel(1, 7)[3], 255);
+    }
+
+    #[test]
+    fn should_render_many_hands() {
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(3, 4, Rgba([255, 0, 0, 255]))))
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let hands = [
+            Hand::new(vec![vec![HandTile::new(II_MAN, Normal)]]),
+            Hand::new(vec![vec![HandTile::new(II_MAN, Normal); 3]]),
+        ];
+        let options = RenderOptions::new(Gap::Pixels(0), Gap::Pixels(2));
+
+        let image = RasterRenderer::render_many(&hands, &tile_set, options).unwrap();
+
+        assert_eq!(image.dimensions(), (9, 10));
+        assert_eq!(image.get_pixel(0, 0)[3], 255);
+        assert_eq!(image.get_pixel(3, 0)[3], 0);
+        assert_eq!(image.get_pixel(0, 4)[3], 0);
+        assert_eq!(image.get_pixel(8, 9)[3], 255);
+    }
+
+    #[test]
+    fn should_scale_with_given_filter() {
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| {
+                let image = RgbaImage::from_fn(4, 4, |x, _| {
+                    let value = if x % 2 == 0 { 0 } else { 255 };
+                    Rgba([value, value, value, 255])
+                });
+                (tile, image)
+            })
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let hand = Hand::new(vec![vec![HandTile::new(II_MAN, Normal)]]);
+        let render = |filter| {
+            let options = RenderOptions::default().scale(0.5).filter(filter);
+            RasterRenderer::render(&hand, &tile_set, options).unwrap()
+        };
+
+        let nearest = render(FilterType::Nearest);
+        let triangle = render(FilterType::Triangle);
+
+        assert_eq!(nearest.dimensions(), (2, 2));
+        assert_eq!(triangle.dimensions(), (2, 2));
+        // nearest neighbor picks single pixels, while the others blend them
+        assert!([0, 255].contains(&nearest.get_pixel(0, 0)[0]));
+        assert!(![0, 255].contains(&triangle.get_pixel(0, 0)[0]));
+    }
+
+    #[test]
+    fn should_reuse_renderer() {
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(3, 4, Rgba([255, 0, 0, 255]))))
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let options = RenderOptions::new(Gap::Pixels(0), Gap::Pixels(1)).scale(2.0);
+        let renderer = RasterRenderer::new(&tile_set, options.clone());
+        let hands = [
+            Hand::new(vec![vec![HandTile::new(II_MAN, Normal)]]),
+            Hand::new(vec![vec![HandTile::new(TON, Normal)], vec![]]),
+        ];
+
+        assert_eq!(renderer.options().scale, options.scale);
+        assert_eq!(renderer.tile_set().tile_width(), 3);
+        let mut buffer = RgbaImage::new(0, 0);
+        for hand in &hands {
+            let expected = RasterRenderer::render(hand, &tile_set, options.clone()).unwrap();
+            assert!(renderer.render_hand(hand).unwrap() == expected);
+            renderer.render_hand_into(hand, &mut buffer).unwrap();
+            assert!(buffer == expected, "actual and expected images differ");
+        }
+    }
+
+    #[test]
+    fn should_render_into_buffer() {
+        let red = Rgba([255, 0, 0, 255]);
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(3, 4, red)))
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let long_hand = Hand::new(vec![vec![HandTile::new(II_MAN, Normal); 3]]);
+        let short_hand = Hand::new(vec![vec![HandTile::new(II_MAN, Normal)], vec![]]);
+        let options = RenderOptions::new(Gap::Pixels(0), Gap::Pixels(1));
+
+        let mut buffer = RgbaImage::new(0, 0);
+        RasterRenderer::render_into(&long_hand, &tile_set, options.clone(), &mut buffer).unwrap();
+        let data = buffer.as_raw().as_ptr();
+        assert_eq!(buffer.dimensions(), (9, 4));
+        RasterRenderer::render_into(&short_hand, &tile_set, options.clone(), &mut buffer).unwrap();
+
+        let expected = RasterRenderer::render(&short_hand, &tile_set, options.clone()).unwrap();
+        assert!(buffer == expected, "actual and expected images differ");
+        assert_eq!(buffer.as_raw().as_ptr(), data);
+        RasterRenderer::render_into(&short_hand, &tile_set, options.scale(2.0), &mut buffer)
+            .unwrap();
+        assert_eq!(buffer.dimensions(), (8, 8));
+    }
+
     fn load_expected_image(expected_file: &[u8]) -> RgbaImage {
         image::load_from_memory_with_format(expected_file, ImageFormat::Png)
             .expect("could not load expected image")
@@ -299,4 +1833,212 @@ mod tests {
             vec![HandTile::new(UU_MAN, Normal)],
         ])
     }
+
+    #[test]
+    fn should_render_tiles_of_different_sizes() {
+        let red = Rgba([255, 0, 0, 255]);
+        let blue = Rgba([0, 0, 255, 255]);
+        let mut map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(3, 4, red)))
+            .collect::<HashMap<_, _>>();
+        map.insert(TON, RgbaImage::from_pixel(4, 5, blue));
+        let tile_set = SimpleTileSet::with_varying_sizes(map).unwrap();
+        let hand = Hand::new(vec![vec![
+            HandTile::new(II_MAN, Normal),
+            HandTile::new(TON, Normal),
+            HandTile::new(II_MAN, Normal),
+        ]]);
+
+        let image = RasterRenderer::render(
+            &hand,
+            &tile_set,
+            RenderOptions::new(Gap::Pixels(1), Gap::Pixels(0)),
+        )
+        .unwrap();
+
+        assert_eq!(image.dimensions(), (12, 5));
+        assert_eq!(image.get_pixel(0, 0)[3], 0);
+        assert_eq!(*image.get_pixel(0, 1), red);
+        assert_eq!(*image.get_pixel(4, 0), blue);
+        assert_eq!(*image.get_pixel(7, 4), blue);
+        assert_eq!(image.get_pixel(8, 4)[3], 0);
+        assert_eq!(*image.get_pixel(9, 1), red);
+    }
+
+    #[test]
+    fn should_return_rects_of_rendered_tiles() {
+        let red = Rgba([255, 0, 0, 255]);
+        let mut map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(3, 4, red)))
+            .collect::<HashMap<_, _>>();
+        map.insert(TON, RgbaImage::from_pixel(4, 5, red));
+        let tile_set = SimpleTileSet::with_varying_sizes(map).unwrap();
+        let hand = Hand::new(vec![
+            vec![HandTile::new(II_MAN, Normal), HandTile::new(TON, Normal)],
+            vec![HandTile::new(II_MAN, Normal)],
+        ]);
+
+        for rotation in [
+            Rotation::Upright,
+            Rotation::Clockwise90,
+            Rotation::Clockwise180,
+            Rotation::Clockwise270,
+        ] {
+            let options = RenderOptions::new(Gap::Pixels(1), Gap::Pixels(2))
+                .background(Rgba([255, 255, 255, 255]))
+                .padding(Gap::Pixels(1))
+                .scale(2.0)
+                .filter(FilterType::Nearest)
+                .rotation(rotation);
+            let renderer = RasterRenderer::new(&tile_set, options);
+            let tiles = renderer.placed_tiles(&hand);
+            let image = renderer.render_hand(&hand).unwrap();
+
+            if rotation == Rotation::Upright {
+                let rects: Vec<_> = tiles.iter().map(|tile| tile.rect).collect();
+                assert_eq!(
+                    rects,
+                    vec![
+                        Rect::new(2, 4, 6, 8),
+                        Rect::new(10, 2, 8, 10),
+                        Rect::new(22, 4, 6, 8)
+                    ]
+                );
+            }
+            for (x, y, pixel) in image.enumerate_pixels() {
+                let inside = tiles.iter().any(|tile| tile.rect.contains(x, y));
+                assert_eq!(
+                    inside,
+                    *pixel == red,
+                    "{:?}: pixel ({}, {})",
+                    rotation,
+                    x,
+                    y
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn should_trace_parsing_and_rendering() {
+        use std::sync::Mutex;
+
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        use crate::parser::HandParser;
+        use crate::raster_renderer::{GeneratedTileSet, Scale};
+
+        /// Collects the names of the spans and the messages of the events.
+        #[derive(Default)]
+        struct Collector(Mutex<Vec<String>>);
+
+        struct MessageVisitor<'a>(&'a mut Vec<String>);
+
+        impl Visit for MessageVisitor<'_> {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                if field.name() == "message" {
+                    self.0.push(format!("{:?}", value));
+                }
+            }
+        }
+
+        impl Subscriber for &'static Collector {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let mut messages = self.0.lock().unwrap();
+                messages.push(format!("span {}", span.metadata().name()));
+                Id::from_u64(messages.len() as u64)
+            }
+
+            fn record(&self, _: &Id, _: &Record<'_>) {}
+
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+            fn event(&self, event: &Event<'_>) {
+                event.record(&mut MessageVisitor(&mut self.0.lock().unwrap()));
+            }
+
+            fn enter(&self, _: &Id) {}
+
+            fn exit(&self, _: &Id) {}
+        }
+
+        let collector: &'static Collector = Box::leak(Box::default());
+        let tile_set = GeneratedTileSet::new(8);
+        tracing::subscriber::with_default(collector, || {
+            let hand = HandParser::parse("11m").unwrap();
+            let options = RenderOptions {
+                scale: Scale::Factor(0.5),
+                ..RenderOptions::default()
+            };
+            RasterRenderer::render(&hand, &tile_set, options).unwrap();
+        });
+
+        let messages = collector.0.lock().unwrap();
+        assert_eq!(
+            *messages,
+            vec![
+                "span parse",
+                "span draw_tiles",
+                "composed tile cache miss",
+                "composed tile cache hit",
+                "drew tiles",
+                "scaled image",
+            ]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_deserialize_render_options() {
+        let options: RenderOptions = serde_json::from_str(
+            r#"{
+                "tile_gap": {"pixels": 2},
+                "background": [255, 255, 255, 255],
+                "scale": {"tile_height": 64},
+                "filter": "nearest",
+                "rotation": "clockwise90",
+                "winning_tile": "last"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(options.tile_gap, Gap::Pixels(2));
+        assert_eq!(options.group_gap, RenderOptions::default().group_gap);
+        assert_eq!(options.background, Rgba([255, 255, 255, 255]));
+        assert_eq!(options.scale, crate::raster_renderer::Scale::TileHeight(64));
+        assert_eq!(options.filter, FilterType::Nearest);
+        assert_eq!(options.rotation, Rotation::Clockwise90);
+        assert_eq!(options.winning_tile, Some(WinningTile::Last));
+        assert!(options.face_down_closed_kans);
+
+        let json = serde_json::to_value(&options).unwrap();
+        assert_eq!(json["background"], serde_json::json!([255, 255, 255, 255]));
+        assert!(json.get("texture").is_none());
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn should_generate_json_schema() {
+        let schema = serde_json::to_value(schemars::schema_for!(RenderOptions)).unwrap();
+        let properties = schema["properties"].as_object().unwrap();
+
+        assert!(properties.contains_key("tile_gap"));
+        assert!(properties.contains_key("shadow"));
+        assert!(!properties.contains_key("texture"));
+        assert_eq!(properties["background"]["type"], "array");
+        assert_eq!(properties["filter"]["default"], "lanczos3");
+        assert_eq!(
+            schema["definitions"]["FilterType"]["enum"][0],
+            serde_json::json!("nearest")
+        );
+    }
 }
@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+use std::sync::{PoisonError, RwLock};
+
+use image::imageops::{self, FilterType};
+use image::RgbaImage;
+
+use crate::raster_renderer::{Scale, TileImageResult, TileSet, TileSetMetadata};
+use crate::TilePlacement::Normal;
+use crate::{BonusTile, HandTile, Tile, TilePlacement};
+
+#[derive(Debug)]
+/// An implementation of [TileSet] that resizes the tiles of another tile set
+/// to given height, so that small images can be rendered without downscaling
+/// the whole, large image with
+/// [RenderOptions::scale](crate::raster_renderer::RenderOptions::scale).
+///
+/// The tiles are resized when they are requested for the first time, and
+/// then cached. The bonus tiles are resized every time they are requested.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::parser::HandParser;
+/// use riichi_hand::raster_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_TILE_SET;
+/// use riichi_hand::raster_renderer::{RasterRenderer, RenderOptions, ScaledTileSet};
+///
+/// let tile_set = ScaledTileSet::new(&*YELLOW_FLUFFY_STUFF_TILE_SET, 64);
+/// let hand = HandParser::parse("123m456p").unwrap();
+/// let image = RasterRenderer::render(&hand, &tile_set, RenderOptions::default()).unwrap();
+/// assert_eq!(image.height(), 64);
+/// ```
+pub struct ScaledTileSet<T: TileSet> {
+    inner: T,
+    tile_width: u32,
+    tile_height: u32,
+    filter: FilterType,
+    cache: RwLock<HashMap<(Tile, bool), RgbaImage>>,
+}
+
+impl<T: TileSet> ScaledTileSet<T> {
+    /// Creates a new [ScaledTileSet] instance resizing the tiles of given tile
+    /// set so that a single (non-rotated) tile is given number of pixels high.
+    /// The tiles are resampled using a Lanczos filter.
+    pub fn new(inner: T, tile_height: u32) -> Self {
+        let factor = f64::from(tile_height) / f64::from(inner.tile_height().max(1));
+        let tile_width = (f64::from(inner.tile_width()) * factor).round() as u32;
+
+        Self {
+            inner,
+            tile_width,
+            tile_height,
+            filter: FilterType::Lanczos3,
+            cache: RwLock::new(HashMap::new()),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the tile set with given filter used to resample the tiles.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::image::imageops::FilterType;
+    /// use riichi_hand::raster_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_TILE_SET;
+    /// use riichi_hand::raster_renderer::ScaledTileSet;
+    ///
+    /// let tile_set =
+    ///     ScaledTileSet::new(&*YELLOW_FLUFFY_STUFF_TILE_SET, 64).filter(FilterType::Triangle);
+    /// ```
+    pub fn filter(self, filter: FilterType) -> Self {
+        Self { filter, ..self }
+    }
+
+    /// Returns the tile set whose tiles are resized.
+    #[inline]
+    #[must_use]
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns the resized image of given (displayed) tile, resizing it and
+    /// storing it in the cache if needed.
+    fn cached_image(&self, hand_tile: &HandTile) -> TileImageResult {
+        // rotated and shifted tiles look the same as the rotated ones
+        let key = (hand_tile.tile, hand_tile.placement != Normal);
+        let cache = self.cache.read().unwrap_or_else(PoisonError::into_inner);
+        if let Some(image) = cache.get(&key) {
+            return Ok(image.clone());
+        }
+        drop(cache);
+
+        let image = self.inner.tile_image_ref(hand_tile)?;
+        let image = self.resize(&image).unwrap_or_else(|| image.into_owned());
+        self.cache
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key, image.clone());
+
+        Ok(image)
+    }
+
+    /// Returns given tile image resized to the tile set size, or [None] if it
+    /// already has the desired size.
+    fn resize(&self, image: &RgbaImage) -> Option<RgbaImage> {
+        let (width, height) = self.scaled_size(image.dimensions());
+
+        if image.dimensions() == (width, height) {
+            None
+        } else {
+            Some(imageops::resize(image, width, height, self.filter))
+        }
+    }
+
+    /// Returns given dimensions of an image of the inner tile set, scaled to
+    /// the tile set size.
+    fn scaled_size(&self, (width, height): (u32, u32)) -> (u32, u32) {
+        let factor = f64::from(self.tile_height) / f64::from(self.inner.tile_height().max(1));
+
+        (
+            (f64::from(width) * factor).round() as u32,
+            (f64::from(height) * factor).round() as u32,
+        )
+    }
+}
+
+impl<T: TileSet> TileSet for ScaledTileSet<T> {
+    #[inline]
+    fn tile_image(&self, hand_tile: &HandTile) -> TileImageResult {
+        self.cached_image(&hand_tile.displayed())
+    }
+
+    fn bonus_tile_image(&self, tile: BonusTile, placement: TilePlacement) -> TileImageResult {
+        let image = self.inner.bonus_tile_image(tile, placement)?;
+
+        Ok(self.resize(&image).unwrap_or(image))
+    }
+
+    #[inline]
+    fn tile_size(&self, hand_tile: &HandTile) -> (u32, u32) {
+        self.scaled_size(self.inner.tile_size(hand_tile))
+    }
+
+    fn metadata(&self) -> Option<TileSetMetadata> {
+        let mut metadata = self.inner.metadata()?;
+        // the recommended scale factor is relative to the inner tile set size
+        if let Some(Scale::Factor(factor)) = metadata.recommended_scale {
+            let ratio = self.inner.tile_height() as f32 / self.tile_height.max(1) as f32;
+            metadata.recommended_scale = Some(Scale::Factor(factor * ratio));
+        }
+
+        Some(metadata)
+    }
+
+    #[inline]
+    fn tile_width(&self) -> u32 {
+        self.tile_width
+    }
+
+    #[inline]
+    fn tile_height(&self) -> u32 {
+        self.tile_height
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use image::{Rgba, RgbaImage};
+
+    use crate::raster_renderer::{
+        Scale, ScaledTileSet, SimpleTileSet, TileSet, TileSetMetadata, TwoPartTileSet,
+    };
+    use crate::tiles::*;
+    use crate::HandTile;
+    use crate::TilePlacement::{Normal, Rotated};
+
+    #[test]
+    fn should_scale_tiles() {
+        let red = Rgba([255, 0, 0, 255]);
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(30, 40, red)))
+            .collect::<HashMap<_, _>>();
+        let front = RgbaImage::from_pixel(30, 40, red);
+        let tile_set = ScaledTileSet::new(TwoPartTileSet::new(front, map).unwrap(), 8);
+
+        assert_eq!((tile_set.tile_width(), tile_set.tile_height()), (6, 8));
+        let image = tile_set.tile_image(&HandTile::new(II_MAN, Normal)).unwrap();
+        assert_eq!(image.dimensions(), (6, 8));
+        assert_eq!(*image.get_pixel(3, 4), red);
+        assert_eq!(
+            tile_set.tile_image(&HandTile::new(II_MAN, Normal)).unwrap(),
+            image
+        );
+        let rotated = tile_set.tile_image(&HandTile::new(TON, Rotated)).unwrap();
+        assert_eq!(rotated.dimensions(), (8, 6));
+    }
+
+    #[test]
+    fn should_adjust_recommended_scale() {
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::new(30, 40)))
+            .collect::<HashMap<_, _>>();
+        let metadata = TileSetMetadata::new("Tiles", "Author", "License");
+        let tile_set = SimpleTileSet::new(map)
+            .unwrap()
+            .with_metadata(metadata.clone().recommended_scale(Scale::Factor(0.5)));
+
+        let scaled = ScaledTileSet::new(&tile_set, 10);
+        assert_eq!(
+            scaled.metadata(),
+            Some(metadata.recommended_scale(Scale::Factor(2.0)))
+        );
+    }
+
+    #[test]
+    fn should_return_inner_tile_set_errors() {
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::new(30, 40)))
+            .collect::<HashMap<_, _>>();
+        let tile_set = ScaledTileSet::new(SimpleTileSet::new(map).unwrap(), 8);
+
+        assert!(tile_set.tile_image(&HandTile::new(TON, Rotated)).is_err());
+    }
+}
@@ -0,0 +1,265 @@
+use ab_glyph::{Font, PxScale, ScaleFont};
+
+use crate::points::ScoreBreakdown;
+use crate::raster_renderer::labels::{draw_text, layout_text};
+use crate::raster_renderer::renderer::stack_layouts;
+use crate::raster_renderer::{
+    HandRenderResult, LabelOptions, RasterRenderer, RenderOptions, TileSet,
+};
+use crate::renderer::{HandRenderer, LabelPosition, LayoutOptions};
+use crate::{Hand, Tile};
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+/// Everything shown on a score card rendered with
+/// [RasterRenderer::render_score_card]: the winning hand, the dora
+/// indicators, and the score.
+pub struct ScoreCard {
+    /// The winning hand.
+    pub hand: Hand,
+    /// Revealed dora indicators.
+    pub dora_indicators: Vec<Tile>,
+    /// Uradora indicators. The uradora wall is not shown if there are none.
+    pub ura_dora_indicators: Vec<Tile>,
+    /// Yaku, dora and points of the hand.
+    pub breakdown: ScoreBreakdown,
+}
+
+impl ScoreCard {
+    #[inline]
+    #[must_use]
+    /// Creates a new score card with no dora indicators.
+    pub fn new(hand: Hand, breakdown: ScoreBreakdown) -> Self {
+        Self {
+            hand,
+            dora_indicators: Vec::new(),
+            ura_dora_indicators: Vec::new(),
+            breakdown,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the score card with given revealed dora indicators.
+    pub fn dora_indicators(self, dora_indicators: Vec<Tile>) -> Self {
+        Self {
+            dora_indicators,
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the score card with given uradora indicators.
+    pub fn ura_dora_indicators(self, ura_dora_indicators: Vec<Tile>) -> Self {
+        Self {
+            ura_dora_indicators,
+            ..self
+        }
+    }
+
+    /// Returns the dead wall strips with the dora and uradora indicators, as
+    /// separate groups of a single hand.
+    fn dora_walls(&self) -> Hand {
+        let mut walls = vec![self.dora_indicators.as_slice()];
+        if !self.ura_dora_indicators.is_empty() {
+            walls.push(self.ura_dora_indicators.as_slice());
+        }
+
+        Hand::new(
+            walls
+                .into_iter()
+                .flat_map(|indicators| {
+                    Hand::dora_wall(indicators, indicators.len())
+                        .groups()
+                        .clone()
+                })
+                .collect(),
+        )
+    }
+}
+
+impl<'a, T: TileSet> RasterRenderer<'a, T> {
+    /// Renders given [ScoreCard] using [TileSet] and [RenderOptions]: the
+    /// hand, the dora (and uradora) walls below it, and the score breakdown
+    /// (the yaku with their han values, the dora, and the payment summary),
+    /// one item per line, above or below the tiles.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use riichi_hand::ab_glyph::FontArc;
+    /// use riichi_hand::parser::HandParser;
+    /// use riichi_hand::points::{
+    ///     DoraCount, Fu, Han, Honbas, PointsCalculationMode, Ruleset, ScoreBreakdown, YakuEntry,
+    /// };
+    /// use riichi_hand::raster_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_TILE_SET;
+    /// use riichi_hand::raster_renderer::{LabelOptions, RasterRenderer, RenderOptions, ScoreCard};
+    /// use riichi_hand::tiles::II_MAN;
+    ///
+    /// let font = FontArc::try_from_vec(std::fs::read("DejaVuSans.ttf").unwrap()).unwrap();
+    /// let hand = HandParser::parse("234m456p789s11z33z_3z").unwrap();
+    /// let breakdown = ScoreBreakdown::new(
+    ///     PointsCalculationMode::Default,
+    ///     &Ruleset::default(),
+    ///     vec![YakuEntry::new("Riichi", Han::new(1))],
+    ///     DoraCount::new(1, 0, 0),
+    ///     Fu::new(40),
+    ///     Honbas::ZERO,
+    /// )
+    /// .unwrap();
+    /// let card = ScoreCard::new(hand, breakdown).dora_indicators(vec![II_MAN]);
+    /// let image = RasterRenderer::render_score_card(
+    ///     &card,
+    ///     &LabelOptions::new(font, 60.0),
+    ///     &*YELLOW_FLUFFY_STUFF_TILE_SET,
+    ///     RenderOptions::default(),
+    /// )
+    /// .unwrap();
+    /// ```
+    pub fn render_score_card(
+        card: &ScoreCard,
+        label_options: &LabelOptions,
+        tile_set: &'a T,
+        options: RenderOptions,
+    ) -> HandRenderResult {
+        let renderer = Self::new(tile_set, options);
+        let layout_options = renderer.layout_options();
+        let gap = renderer.group_gap();
+        let font = label_options
+            .font
+            .as_scaled(PxScale::from(label_options.size));
+
+        // the dora walls have no winning tile to separate
+        let dora_wall_options = LayoutOptions {
+            winning_tile: None,
+            ..layout_options
+        };
+        let layouts = vec![
+            renderer.layout(&card.hand, layout_options),
+            renderer.layout(&card.dora_walls(), dora_wall_options),
+        ];
+        let (mut layout, _) = stack_layouts(layouts, gap);
+
+        let text = card.breakdown.to_string();
+        let lines: Vec<_> = text.lines().collect();
+        let line_height = (font.height() + font.line_gap()).ceil() as u32;
+        let text_height = lines.len() as u32 * line_height;
+        let block_y = layout.reserve_label_space(gap + text_height, label_options.position);
+        let text_y = match label_options.position {
+            LabelPosition::Above => block_y,
+            LabelPosition::Below => block_y + gap,
+        };
+        let text_width = lines
+            .iter()
+            .map(|line| layout_text(line, label_options).1.ceil() as u32)
+            .max()
+            .unwrap_or(0);
+
+        let width = layout.width.max(text_width);
+        let tiles = layout.tiles.iter().map(|position| (*position, None));
+        let mut image = renderer.draw_tiles(width, layout.height, tiles)?;
+
+        let padding = renderer.padding();
+        for (index, line) in lines.iter().enumerate() {
+            let (_, line_width) = layout_text(line, label_options);
+            let center_x = padding as f32 + line_width / 2.0;
+            let line_y = padding + text_y + index as u32 * line_height;
+            draw_text(
+                &mut image,
+                line,
+                label_options,
+                center_x,
+                line_y as f32 + font.ascent(),
+            );
+        }
+
+        Ok(renderer.finish_image(image))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use ab_glyph::FontArc;
+    use image::{Rgba, RgbaImage};
+
+    use crate::parser::HandParser;
+    use crate::points::{
+        DoraCount, Fu, Han, Honbas, PointsCalculationMode, Ruleset, ScoreBreakdown, YakuEntry,
+    };
+    use crate::raster_renderer::{
+        Gap, LabelOptions, RasterRenderer, RenderOptions, ScoreCard, SimpleTileSet,
+    };
+    use crate::tiles::*;
+
+    const FONT_PATH: &str = "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf";
+
+    fn score_card() -> ScoreCard {
+        let hand = HandParser::parse("234m456p789s11z33z_3z").unwrap();
+        let breakdown = ScoreBreakdown::new(
+            PointsCalculationMode::Default,
+            &Ruleset::default(),
+            vec![
+                YakuEntry::new("Riichi", Han::new(1)),
+                YakuEntry::new("Tsumo", Han::new(1)),
+            ],
+            DoraCount::new(1, 0, 0),
+            Fu::new(40),
+            Honbas::ZERO,
+        )
+        .unwrap();
+
+        ScoreCard::new(hand, breakdown).dora_indicators(vec![II_MAN])
+    }
+
+    #[test]
+    fn should_build_dora_walls() {
+        let card = score_card();
+        assert_eq!(card.dora_walls().groups().len(), 1);
+        assert_eq!(
+            card.dora_walls()
+                .hand_tiles()
+                .map(|tile| tile.displayed().tile)
+                .collect::<Vec<_>>(),
+            vec![ANY, ANY, II_MAN, ANY, ANY, ANY, ANY]
+        );
+
+        let card = card.ura_dora_indicators(vec![TON]);
+        assert_eq!(card.dora_walls().groups().len(), 2);
+        assert_eq!(card.dora_walls().tiles().nth(9), Some(TON));
+    }
+
+    #[test]
+    fn should_render_score_card() {
+        let font = match std::fs::read(FONT_PATH) {
+            Ok(data) => FontArc::try_from_vec(data).unwrap(),
+            Err(_) => return,
+        };
+        let white = Rgba([255, 255, 255, 255]);
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(30, 40, white)))
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let options = RenderOptions::new(Gap::Pixels(0), Gap::Pixels(10));
+
+        let image = RasterRenderer::render_score_card(
+            &score_card(),
+            &LabelOptions::new(font, 20.0),
+            &tile_set,
+            options,
+        )
+        .unwrap();
+
+        // hand, dora wall, and the text (four lines) below them
+        let (width, height) = image.dimensions();
+        assert_eq!(width, 14 * 30 + 10);
+        assert!(height >= 40 + 10 + 40 + 10 + 4 * 20);
+        assert_eq!(*image.get_pixel(0, 0), white);
+        assert_eq!(*image.get_pixel(0, 50), white);
+        assert_eq!(image.get_pixel(7 * 30, 50)[3], 0);
+        let has_text = |y: u32| (0..width).any(|x| image.get_pixel(x, y)[3] > 0);
+        assert!(!has_text(95));
+        assert!((100..height).any(has_text));
+    }
+}
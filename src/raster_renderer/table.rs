@@ -0,0 +1,203 @@
+use image::{imageops, ImageBuffer, Rgba, RgbaImage};
+
+use crate::raster_renderer::texture;
+use crate::raster_renderer::{
+    Gap, HandRenderResult, ImageType, RasterRenderer, RenderOptions, Rotation, Scale, TileSet,
+};
+use crate::renderer::{HandRenderer, LayoutOptions};
+use crate::table::{Table, PLAYER_COUNT};
+
+const TRANSPARENT: Rgba<u8> = Rgba([0, 0, 0, 0]);
+
+#[derive(Debug)]
+/// Renders a whole [Table] (the hands and rivers of all the players, and the
+/// dora indicators) to a single, square raster image.
+///
+/// Every player's river is placed in front of their hand, and both are rotated
+/// so that they face the center of the table, where the dora indicators and an
+/// optional info block (e.g. round wind and scores, rendered by the caller)
+/// are placed. The gaps between the table elements are equal to the group gap.
+pub struct TableRenderer<'a, T: TileSet> {
+    renderer: RasterRenderer<'a, T>,
+    options: RenderOptions,
+}
+
+impl<'a, T: TileSet> TableRenderer<'a, T> {
+    #[inline]
+    /// Renders given [Table] using [TileSet] and [RenderOptions]. If `info` is
+    /// given, it is placed in the center of the table, below the dora
+    /// indicators.
+    ///
+    /// The padding, background, texture and scaling from the options are
+    /// applied to the whole table.
+    pub fn render(
+        table: &Table,
+        info: Option<&RgbaImage>,
+        tile_set: &'a T,
+        options: RenderOptions,
+    ) -> HandRenderResult {
+        Self::new(tile_set, options).render_internal(table, info)
+    }
+
+    #[inline]
+    fn new(tile_set: &'a T, options: RenderOptions) -> Self {
+        let part_options = RenderOptions {
+            background: TRANSPARENT,
+            texture: None,
+            padding: Gap::Pixels(0),
+            scale: Scale::Factor(1.0),
+            rotation: Rotation::Upright,
+            ..options.clone()
+        };
+
+        Self {
+            renderer: RasterRenderer::new(tile_set, part_options),
+            options,
+        }
+    }
+
+    fn render_internal(&self, table: &Table, info: Option<&RgbaImage>) -> HandRenderResult {
+        let gap = self.renderer.group_gap();
+
+        let mut seats = Vec::with_capacity(PLAYER_COUNT);
+        for (hand, river) in table.hands.iter().zip(&table.rivers) {
+            let hand = self.renderer.render_hand(hand)?;
+            let river = self.renderer.render_river_internal(river, true)?;
+            seats.push(stack_vertically(&[&river, &hand], gap));
+        }
+        let dora_wall_options = LayoutOptions {
+            winning_tile: None,
+            max_width: None,
+            ..self.renderer.layout_options()
+        };
+        let dora_wall = self
+            .renderer
+            .render_layout(&self.renderer.layout(&table.dora_wall(), dora_wall_options))?;
+        let center = match info {
+            Some(info) => stack_vertically(&[&dora_wall, info], gap),
+            None => dora_wall,
+        };
+
+        let max_seat_width = seats.iter().map(|seat| seat.width()).max().unwrap_or(0);
+        let max_seat_height = seats.iter().map(|seat| seat.height()).max().unwrap_or(0);
+        let center_size = center.width().max(center.height()) + 2 * gap;
+        let size = max_seat_width.max(center_size) + 2 * (max_seat_height + gap);
+
+        let mut image = ImageBuffer::from_pixel(size, size, TRANSPARENT);
+        let rotated_seats = [
+            seats[0].clone(),
+            imageops::rotate270(&seats[1]),
+            imageops::rotate180(&seats[2]),
+            imageops::rotate90(&seats[3]),
+        ];
+        let positions = [
+            ((size - seats[0].width()) / 2, size - seats[0].height()),
+            (size - seats[1].height(), (size - seats[1].width()) / 2),
+            ((size - seats[2].width()) / 2, 0),
+            (0, (size - seats[3].width()) / 2),
+        ];
+        for (seat, (x, y)) in rotated_seats.iter().zip(positions.iter()) {
+            imageops::overlay(&mut image, seat, i64::from(*x), i64::from(*y));
+        }
+        imageops::overlay(
+            &mut image,
+            &center,
+            i64::from((size - center.width()) / 2),
+            i64::from((size - center.height()) / 2),
+        );
+
+        Ok(self.finish(image))
+    }
+
+    /// Applies the padding, background, texture, scaling and rotation to the
+    /// composed table.
+    fn finish(&self, table: ImageType) -> ImageType {
+        let padding = self.renderer.padding_for(self.options.padding);
+        let mut image = texture::canvas(
+            table.width() + 2 * padding,
+            table.height() + 2 * padding,
+            self.options.background,
+            self.options.texture.as_ref(),
+        );
+        imageops::overlay(&mut image, &table, i64::from(padding), i64::from(padding));
+
+        let image = self.renderer.scale_image_by(image, self.options.scale);
+
+        self.options.rotation.apply(image)
+    }
+}
+
+/// Stacks the images vertically, centering them horizontally. Empty images
+/// are skipped.
+fn stack_vertically(images: &[&RgbaImage], gap: u32) -> RgbaImage {
+    let images: Vec<_> = images
+        .iter()
+        .filter(|image| image.height() > 0)
+        .copied()
+        .collect();
+    let width = images.iter().map(|image| image.width()).max().unwrap_or(0);
+    let height = images.iter().map(|image| image.height()).sum::<u32>()
+        + gap * (images.len() as u32).saturating_sub(1);
+
+    let mut result = ImageBuffer::from_pixel(width, height, TRANSPARENT);
+    let mut y = 0;
+    for image in images {
+        let x = (width - image.width()) / 2;
+        imageops::overlay(&mut result, image, i64::from(x), i64::from(y));
+        y += image.height() + gap;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use image::{Rgba, RgbaImage};
+
+    use crate::parser::HandParser;
+    use crate::raster_renderer::{Gap, RenderOptions, TableRenderer, TwoPartTileSet};
+    use crate::river::{Discard, River};
+    use crate::table::Table;
+    use crate::tiles::*;
+
+    #[test]
+    fn should_render_table() {
+        let white = Rgba([255, 255, 255, 255]);
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::from_pixel(3, 4, white)))
+            .collect::<HashMap<_, _>>();
+        let tile_set = TwoPartTileSet::new(RgbaImage::from_pixel(3, 4, white), map).unwrap();
+
+        let mut table = Table::default();
+        table.hands[0] = HandParser::parse("11123456789999m").unwrap();
+        table.rivers[1] = River::new(vec![Discard::new(TON); 3]);
+        let info = RgbaImage::from_pixel(5, 5, Rgba([255, 0, 0, 255]));
+
+        let image = TableRenderer::render(
+            &table,
+            Some(&info),
+            &tile_set,
+            RenderOptions::new(Gap::Pixels(0), Gap::Pixels(1)).padding(Gap::Pixels(2)),
+        )
+        .unwrap();
+
+        // the widest seat (14 tiles), and the tallest seat on both sides, with
+        // the gaps and padding
+        let size = 42 + 2 * (4 + 1) + 2 * 2;
+        assert_eq!(image.dimensions(), (size, size));
+        // bottom hand
+        assert_eq!(*image.get_pixel(size / 2, size - 3), white);
+        // right river, rotated
+        assert_eq!(*image.get_pixel(size - 3, size / 2), white);
+        assert_eq!(image.get_pixel(size - 7, size / 2)[3], 0);
+        // info block in the center, below the dora wall
+        assert_eq!(
+            *image.get_pixel(size / 2, size / 2 + 3),
+            Rgba([255, 0, 0, 255])
+        );
+        assert_eq!(image.get_pixel(2, 2)[3], 0);
+    }
+}
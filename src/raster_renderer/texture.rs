@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use image::{imageops, ImageBuffer, Rgba, RgbaImage};
+
+/// Maximum relative change of the felt color brightness.
+const FELT_GRAIN: f32 = 0.08;
+
+#[derive(Clone, Debug, PartialEq)]
+/// Texture drawn beneath the tiles, on top of the background color.
+pub enum Texture {
+    /// Given image, repeated to cover the whole background.
+    Image(Arc<RgbaImage>),
+    /// Procedurally generated table felt of given base color (e.g.
+    /// `Rgba([30, 110, 60, 255])` for a typical green felt).
+    Felt(Rgba<u8>),
+}
+
+impl Texture {
+    /// Draws the texture onto the whole image.
+    fn fill(&self, image: &mut RgbaImage) {
+        match self {
+            Self::Image(texture) => {
+                let (texture_width, texture_height) = texture.dimensions();
+                if texture_width == 0 || texture_height == 0 {
+                    return;
+                }
+
+                for y in (0..image.height()).step_by(texture_height as usize) {
+                    for x in (0..image.width()).step_by(texture_width as usize) {
+                        imageops::overlay(image, &**texture, i64::from(x), i64::from(y));
+                    }
+                }
+            }
+            Self::Felt(color) => {
+                let felt = ImageBuffer::from_fn(image.width(), image.height(), |x, y| {
+                    let grain = (noise(x, y) & 0xFF) as f32 / 255.0 - 0.5;
+                    let brightness = 1.0 + 2.0 * grain * FELT_GRAIN;
+                    let mut pixel = *color;
+                    for channel in &mut pixel.0[..3] {
+                        *channel = (f32::from(*channel) * brightness).round().min(255.0) as u8;
+                    }
+                    pixel
+                });
+                imageops::overlay(image, &felt, 0, 0);
+            }
+        }
+    }
+}
+
+/// Creates an image of given size filled with the background color and the
+/// texture, if any.
+pub(super) fn canvas(
+    width: u32,
+    height: u32,
+    background: Rgba<u8>,
+    texture: Option<&Texture>,
+) -> RgbaImage {
+    let mut image = ImageBuffer::new(width, height);
+    fill_canvas(&mut image, background, texture);
+
+    image
+}
+
+/// Fills the whole image with the background color and the texture, if any.
+pub(super) fn fill_canvas(image: &mut RgbaImage, background: Rgba<u8>, texture: Option<&Texture>) {
+    for pixel in image.pixels_mut() {
+        *pixel = background;
+    }
+    if let Some(texture) = texture {
+        texture.fill(image);
+    }
+}
+
+/// Deterministic pseudo-random value for given coordinates, so that the same
+/// image is always rendered the same way.
+fn noise(x: u32, y: u32) -> u32 {
+    let mut hash = x.wrapping_mul(0x9E37_79B1) ^ y.wrapping_mul(0x85EB_CA77);
+    hash ^= hash >> 15;
+    hash = hash.wrapping_mul(0xC2B2_AE3D);
+    hash ^= hash >> 13;
+
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use image::{Rgba, RgbaImage};
+
+    use crate::raster_renderer::texture::canvas;
+    use crate::raster_renderer::Texture;
+
+    #[test]
+    fn should_repeat_image_texture() {
+        let mut texture = RgbaImage::from_pixel(2, 2, Rgba([255, 0, 0, 255]));
+        texture.put_pixel(0, 0, Rgba([0, 0, 255, 255]));
+        let texture = Texture::Image(Arc::new(texture));
+
+        let image = canvas(5, 3, Rgba([0, 0, 0, 0]), Some(&texture));
+
+        assert_eq!(*image.get_pixel(0, 0), Rgba([0, 0, 255, 255]));
+        assert_eq!(*image.get_pixel(2, 2), Rgba([0, 0, 255, 255]));
+        assert_eq!(*image.get_pixel(4, 0), Rgba([0, 0, 255, 255]));
+        assert_eq!(*image.get_pixel(3, 1), Rgba([255, 0, 0, 255]));
+    }
+
+    #[test]
+    fn should_generate_felt_texture() {
+        let color = Rgba([30, 110, 60, 255]);
+        let texture = Texture::Felt(color);
+
+        let image = canvas(16, 16, Rgba([0, 0, 0, 0]), Some(&texture));
+
+        assert_eq!(image, canvas(16, 16, Rgba([0, 0, 0, 0]), Some(&texture)));
+        assert!(image.pixels().any(|pixel| *pixel != color));
+        for pixel in image.pixels() {
+            assert_eq!(pixel[3], 255);
+            assert!((i32::from(pixel[1]) - 110).abs() <= 10);
+        }
+    }
+}
@@ -1,15 +1,20 @@
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
+use std::sync::{PoisonError, RwLock};
 
 use image::{ImageBuffer, RgbaImage};
 
-use crate::tiles::{ALL_TILES, ANY};
-use crate::TilePlacement::Normal;
-use crate::{HandTile, Tile};
+use crate::raster_renderer::TileSetMetadata;
+use crate::tiles::{ALL_TILES, ANY, BLANK};
+use crate::TilePlacement::{FaceDown, Normal, Rotated};
+use crate::{BonusTile, HandTile, Tile, TilePlacement};
 
 /// Result of [TileSet::tile_image].
 pub type TileImageResult = Result<RgbaImage, TileImageRetrieveError>;
+/// Result of [TileSet::tile_image_ref].
+pub type TileImageRefResult<'a> = Result<Cow<'a, RgbaImage>, TileImageRetrieveError>;
 
 /// Set of tile images that can be used to render a hand using
 /// [RasterRenderer](super::RasterRenderer).
@@ -17,16 +22,71 @@ pub trait TileSet {
     /// Returns an image of given tile, taking into account the tile placement
     /// (rotation).
     ///
-    /// The returned image should always have dimensions `W x H` for tiles that
-    /// are not rotated, and `H x W` for the rotated versions (where W and H
-    /// are the return values of `tile_width()` and `tile_height()`,
-    /// respectively)
+    /// The returned image should have dimensions `W x H` for tiles that are
+    /// not rotated, and `H x W` for the rotated versions (where W and H are
+    /// the return values of `tile_width()` and `tile_height()`,
+    /// respectively), unless the tile set reports different dimensions for
+    /// some tiles with [TileSet::tile_size].
     fn tile_image(&self, hand_tile: &HandTile) -> TileImageResult;
 
-    /// Returns tile width, in pixels. Must be the same for all images.
+    /// Returns an image of given tile, just like [TileSet::tile_image], but
+    /// borrows it from the tile set instead of copying, if possible. This is
+    /// what [RasterRenderer](super::RasterRenderer) uses to render the hands.
+    ///
+    /// The default implementation returns the image of
+    /// [TileSet::tile_image]. Tile sets that store the ready images should
+    /// override it.
+    fn tile_image_ref(&self, hand_tile: &HandTile) -> TileImageRefResult<'_> {
+        self.tile_image(hand_tile).map(Cow::Owned)
+    }
+
+    /// Returns an image of given bonus tile (a flower or a season), taking
+    /// into account the tile placement, with the same dimensions as the
+    /// images returned by [TileSet::tile_image].
+    ///
+    /// Bonus tiles are not used in Riichi, so they are optional. The default
+    /// implementation returns
+    /// [BonusTileNotSupported](TileImageRetrieveError::BonusTileNotSupported).
+    ///
+    /// Of the bundled tile sets, only
+    /// [GeneratedTileSet](super::GeneratedTileSet) supports the bonus tiles;
+    /// the image-based ones (FluffyStuff and Martin Persson) do not include
+    /// any bonus tile art.
+    fn bonus_tile_image(&self, tile: BonusTile, placement: TilePlacement) -> TileImageResult {
+        let _ = placement;
+        Err(TileImageRetrieveError::BonusTileNotSupported(tile))
+    }
+
+    /// Returns the dimensions of the image of given tile, taking into account
+    /// the tile placement, which the renderer uses to lay out the tiles.
+    ///
+    /// The default implementation assumes that all the tiles have the same
+    /// dimensions (`W x H` for the tiles that are not rotated, and `H x W`
+    /// for the rotated ones). Tile sets whose images have slightly different
+    /// sizes should override it.
+    fn tile_size(&self, hand_tile: &HandTile) -> (u32, u32) {
+        match hand_tile.displayed().placement {
+            Normal | FaceDown => (self.tile_width(), self.tile_height()),
+            _ => (self.tile_height(), self.tile_width()),
+        }
+    }
+
+    /// Returns the attribution information of the tile set (its name, author,
+    /// license, etc.), if any.
+    ///
+    /// The default implementation returns [None].
+    fn metadata(&self) -> Option<TileSetMetadata> {
+        None
+    }
+
+    /// Returns tile width, in pixels. Unless [TileSet::tile_size] is
+    /// overridden, it must be the same for all images. Otherwise, it is the
+    /// typical tile width, used to compute the gaps between the tiles.
     fn tile_width(&self) -> u32;
 
-    /// Returns tile height, in pixels. Must be the same for all images.
+    /// Returns tile height, in pixels. Unless [TileSet::tile_size] is
+    /// overridden, it must be the same for all images. Otherwise, it is the
+    /// typical tile height, used to scale the image to given tile height.
     fn tile_height(&self) -> u32;
 }
 
@@ -35,6 +95,22 @@ impl<T: TileSet + ?Sized> TileSet for &T {
         T::tile_image(self, hand_tile)
     }
 
+    fn tile_image_ref(&self, hand_tile: &HandTile) -> TileImageRefResult<'_> {
+        T::tile_image_ref(self, hand_tile)
+    }
+
+    fn bonus_tile_image(&self, tile: BonusTile, placement: TilePlacement) -> TileImageResult {
+        T::bonus_tile_image(self, tile, placement)
+    }
+
+    fn tile_size(&self, hand_tile: &HandTile) -> (u32, u32) {
+        T::tile_size(self, hand_tile)
+    }
+
+    fn metadata(&self) -> Option<TileSetMetadata> {
+        T::metadata(self)
+    }
+
     fn tile_width(&self) -> u32 {
         T::tile_width(self)
     }
@@ -49,6 +125,22 @@ impl<T: TileSet + ?Sized> TileSet for Box<T> {
         T::tile_image(self, hand_tile)
     }
 
+    fn tile_image_ref(&self, hand_tile: &HandTile) -> TileImageRefResult<'_> {
+        T::tile_image_ref(self, hand_tile)
+    }
+
+    fn bonus_tile_image(&self, tile: BonusTile, placement: TilePlacement) -> TileImageResult {
+        T::bonus_tile_image(self, tile, placement)
+    }
+
+    fn tile_size(&self, hand_tile: &HandTile) -> (u32, u32) {
+        T::tile_size(self, hand_tile)
+    }
+
+    fn metadata(&self) -> Option<TileSetMetadata> {
+        T::metadata(self)
+    }
+
     fn tile_width(&self) -> u32 {
         T::tile_width(self)
     }
@@ -63,6 +155,8 @@ impl<T: TileSet + ?Sized> TileSet for Box<T> {
 pub enum TileImageRetrieveError {
     /// This specific hand tile is not supported.
     TileNotSupported(HandTile, String),
+    /// This bonus tile is not supported by the tile set.
+    BonusTileNotSupported(BonusTile),
 }
 
 impl Error for TileImageRetrieveError {}
@@ -73,10 +167,21 @@ impl Display for TileImageRetrieveError {
             Self::TileNotSupported(tile, message) => {
                 write!(f, "tile {} not supported: {}", tile, message)
             }
+            Self::BonusTileNotSupported(tile) => write!(f, "bonus tile {} not supported", tile),
         }
     }
 }
 
+/// Returns the error returned for the tiles that do not need to be supported
+/// by the tile sets, such as [UNKNOWN](crate::tiles::UNKNOWN), if there is no
+/// image of given tile.
+fn missing_tile_error(hand_tile: &HandTile) -> TileImageRetrieveError {
+    TileImageRetrieveError::TileNotSupported(
+        *hand_tile,
+        "this tile set has no image of this tile".to_string(),
+    )
+}
+
 #[derive(Copy, Clone, Debug)]
 /// An error that occurs when creating a [TwoPartTileSet] or a [SimpleTileSet].
 pub enum TileSetCreationError {
@@ -106,12 +211,23 @@ impl Display for TileSetCreationError {
 #[derive(Debug)]
 /// An implementation of [TileSet] that expects a hash map of tile images.
 ///
-/// This implementation does not support rotated tiles. This just returns the
-/// tiles as is, returning an error if an unsupported tile is requested.
+/// By default, this implementation does not support rotated tiles. This just
+/// returns the tiles as is, returning an error if an unsupported tile is
+/// requested. The rotated tiles can be enabled with
+/// [with_auto_rotation](SimpleTileSet::with_auto_rotation).
+///
+/// The tile images can have different dimensions if the tile set is created
+/// with [with_varying_sizes](SimpleTileSet::with_varying_sizes).
+///
+/// The images of [BLANK] and [UNKNOWN](crate::tiles::UNKNOWN) tiles are
+/// optional; if they are missing, these tiles are not supported.
 pub struct SimpleTileSet {
     tile_map: HashMap<Tile, RgbaImage>,
     tile_width: u32,
     tile_height: u32,
+    auto_rotate: bool,
+    rotated_cache: RwLock<HashMap<Tile, RgbaImage>>,
+    metadata: Option<TileSetMetadata>,
 }
 
 impl SimpleTileSet {
@@ -119,23 +235,139 @@ impl SimpleTileSet {
     pub fn new(tile_map: HashMap<Tile, RgbaImage>) -> Result<Self, TileSetCreationError> {
         Self::validate_tile_map(&tile_map)?;
 
+        Ok(Self::from_tile_map(tile_map))
+    }
+
+    /// Creates a new [SimpleTileSet] instance using given map of tile images,
+    /// which do not need to have the same dimensions (as is common with the
+    /// scraped or legacy tile art). The tiles are laid out using their own
+    /// dimensions, while the dimensions of the tile back ([ANY]) are used as
+    /// the tile width and height of the tile set.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use riichi_hand::image::RgbaImage;
+    /// use riichi_hand::raster_renderer::{SimpleTileSet, TileSet};
+    /// use riichi_hand::tiles::{ALL_TILES, CHUN};
+    /// use riichi_hand::HandTile;
+    /// use riichi_hand::TilePlacement::Normal;
+    ///
+    /// let mut map: HashMap<_, _> = ALL_TILES
+    ///     .iter()
+    ///     .map(|&tile| (tile, RgbaImage::new(30, 40)))
+    ///     .collect();
+    /// map.insert(CHUN, RgbaImage::new(31, 41));
+    /// let tile_set = SimpleTileSet::with_varying_sizes(map).unwrap();
+    ///
+    /// assert_eq!(tile_set.tile_size(&HandTile::new(CHUN, Normal)), (31, 41));
+    /// ```
+    pub fn with_varying_sizes(
+        tile_map: HashMap<Tile, RgbaImage>,
+    ) -> Result<Self, TileSetCreationError> {
+        Self::validate_tiles_present(&tile_map)?;
+
+        Ok(Self::from_tile_map(tile_map))
+    }
+
+    fn from_tile_map(tile_map: HashMap<Tile, RgbaImage>) -> Self {
         let tile_width = tile_map[&ANY].width();
         let tile_height = tile_map[&ANY].height();
 
-        Ok(Self {
+        Self {
             tile_map,
             tile_width,
             tile_height,
-        })
+            auto_rotate: false,
+            rotated_cache: RwLock::new(HashMap::new()),
+            metadata: None,
+        }
     }
 
-    fn validate_tile_map(tile_map: &HashMap<Tile, RgbaImage>) -> Result<(), TileSetCreationError> {
+    #[inline]
+    #[must_use]
+    /// Returns the tile set with given attribution information, returned by
+    /// [TileSet::metadata].
+    pub fn with_metadata(self, metadata: TileSetMetadata) -> Self {
+        Self {
+            metadata: Some(metadata),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the tile set that supports rotated tiles by rotating the tile
+    /// images clockwise. The rotated images are cached, so that each tile is
+    /// rotated only once.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use riichi_hand::image::RgbaImage;
+    /// use riichi_hand::raster_renderer::{SimpleTileSet, TileSet};
+    /// use riichi_hand::tiles::{ALL_TILES, CHUN};
+    /// use riichi_hand::HandTile;
+    /// use riichi_hand::TilePlacement::Rotated;
+    ///
+    /// let map: HashMap<_, _> = ALL_TILES
+    ///     .iter()
+    ///     .map(|&tile| (tile, RgbaImage::new(30, 40)))
+    ///     .collect();
+    /// let tile_set = SimpleTileSet::new(map).unwrap().with_auto_rotation();
+    ///
+    /// let image = tile_set.tile_image(&HandTile::new(CHUN, Rotated)).unwrap();
+    /// assert_eq!(image.dimensions(), (40, 30));
+    /// ```
+    pub fn with_auto_rotation(self) -> Self {
+        Self {
+            auto_rotate: true,
+            ..self
+        }
+    }
+
+    /// Returns given image of given tile rotated clockwise, rotating it and
+    /// storing it in the cache if needed.
+    fn rotated_image(&self, tile: Tile, image: &RgbaImage) -> RgbaImage {
+        let cache = self
+            .rotated_cache
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(image) = cache.get(&tile) {
+            #[cfg(feature = "tracing")]
+            tracing::trace!(%tile, "rotated tile cache hit");
+            return image.clone();
+        }
+        drop(cache);
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(%tile, "rotated tile cache miss");
+        let rotated = image::imageops::rotate90(image);
+        self.rotated_cache
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(tile, rotated.clone());
+
+        rotated
+    }
+
+    fn validate_tiles_present(
+        tile_map: &HashMap<Tile, RgbaImage>,
+    ) -> Result<(), TileSetCreationError> {
         for tile in ALL_TILES {
             if !tile_map.contains_key(&tile) {
                 return Err(TileSetCreationError::TileMissing(tile));
             }
         }
 
+        Ok(())
+    }
+
+    fn validate_tile_map(tile_map: &HashMap<Tile, RgbaImage>) -> Result<(), TileSetCreationError> {
+        Self::validate_tiles_present(tile_map)?;
+
         let tile_width = tile_map[&ANY].width();
         let tile_height = tile_map[&ANY].height();
         let same_dimensions = tile_map
@@ -152,8 +384,20 @@ impl SimpleTileSet {
 impl TileSet for SimpleTileSet {
     #[inline]
     fn tile_image(&self, hand_tile: &HandTile) -> TileImageResult {
+        self.tile_image_ref(hand_tile).map(Cow::into_owned)
+    }
+
+    #[inline]
+    fn tile_image_ref(&self, hand_tile: &HandTile) -> TileImageRefResult<'_> {
+        let hand_tile = &hand_tile.displayed();
+        let image = self
+            .tile_map
+            .get(&hand_tile.tile)
+            .ok_or_else(|| missing_tile_error(hand_tile))?;
         if hand_tile.placement == Normal {
-            Ok(self.tile_map[&hand_tile.tile].clone())
+            Ok(Cow::Borrowed(image))
+        } else if self.auto_rotate {
+            Ok(Cow::Owned(self.rotated_image(hand_tile.tile, image)))
         } else {
             Err(TileImageRetrieveError::TileNotSupported(
                 *hand_tile,
@@ -162,6 +406,27 @@ impl TileSet for SimpleTileSet {
         }
     }
 
+    fn tile_size(&self, hand_tile: &HandTile) -> (u32, u32) {
+        let hand_tile = hand_tile.displayed();
+        let (width, height) = self
+            .tile_map
+            .get(&hand_tile.tile)
+            .map_or((self.tile_width, self.tile_height), |image| {
+                image.dimensions()
+            });
+
+        if hand_tile.placement == Normal {
+            (width, height)
+        } else {
+            (height, width)
+        }
+    }
+
+    #[inline]
+    fn metadata(&self) -> Option<TileSetMetadata> {
+        self.metadata.clone()
+    }
+
     #[inline]
     fn tile_width(&self) -> u32 {
         self.tile_width
@@ -181,11 +446,25 @@ impl TileSet for SimpleTileSet {
 /// fly. Also, it assumes "realistic" light for the rendered tiles (i.e. for
 /// rotated tiles, it mirrors the background, so it always seems like the light
 /// is coming from once source).
+///
+/// The combined images are cached, so that rendering the same tiles again is
+/// cheap. The cache can be filled upfront with
+/// [precompose_all](TwoPartTileSet::precompose_all).
+///
+/// The foregrounds of the bonus tiles can be added with
+/// [with_bonus_tiles](TwoPartTileSet::with_bonus_tiles).
+///
+/// The foregrounds of [BLANK] and [UNKNOWN](crate::tiles::UNKNOWN) tiles are
+/// optional. Without a foreground, blank tiles are drawn as just the tile
+/// fronts, and unknown tiles are not supported.
 pub struct TwoPartTileSet {
     front: RgbaImage,
     tile_map: HashMap<Tile, RgbaImage>,
+    bonus_tile_map: HashMap<BonusTile, RgbaImage>,
     tile_width: u32,
     tile_height: u32,
+    cache: RwLock<HashMap<(Tile, bool), RgbaImage>>,
+    metadata: Option<TileSetMetadata>,
 }
 
 impl TwoPartTileSet {
@@ -203,8 +482,60 @@ impl TwoPartTileSet {
         Ok(Self {
             front,
             tile_map,
+            bonus_tile_map: HashMap::new(),
             tile_width,
             tile_height,
+            cache: RwLock::new(HashMap::new()),
+            metadata: None,
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the tile set with given attribution information, returned by
+    /// [TileSet::metadata].
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use riichi_hand::image::RgbaImage;
+    /// use riichi_hand::raster_renderer::{TileSet, TileSetMetadata, TwoPartTileSet};
+    /// use riichi_hand::tiles::ALL_TILES;
+    ///
+    /// let map: HashMap<_, _> = ALL_TILES
+    ///     .iter()
+    ///     .map(|&tile| (tile, RgbaImage::new(30, 40)))
+    ///     .collect();
+    /// let tile_set = TwoPartTileSet::new(RgbaImage::new(30, 40), map)
+    ///     .unwrap()
+    ///     .with_metadata(TileSetMetadata::new("My tiles", "Me", "CC0 1.0 Universal"));
+    ///
+    /// assert_eq!(tile_set.metadata().unwrap().name, "My tiles");
+    /// ```
+    pub fn with_metadata(self, metadata: TileSetMetadata) -> Self {
+        Self {
+            metadata: Some(metadata),
+            ..self
+        }
+    }
+
+    /// Returns the tile set with given map of bonus tile foregrounds. The
+    /// map does not need to contain all the bonus tiles.
+    pub fn with_bonus_tiles(
+        self,
+        bonus_tile_map: HashMap<BonusTile, RgbaImage>,
+    ) -> Result<Self, TileSetCreationError> {
+        let same_dimensions = bonus_tile_map
+            .values()
+            .all(|image| image.width() == self.tile_width && image.height() == self.tile_height);
+        if !same_dimensions {
+            return Err(TileSetCreationError::ImagesDoNotHaveEqualDimensions);
+        }
+
+        Ok(Self {
+            bonus_tile_map,
+            ..self
         })
     }
 
@@ -231,12 +562,75 @@ impl TwoPartTileSet {
         Ok(())
     }
 
-    fn hand_tile_foreground(&self, hand_tile: &HandTile) -> RgbaImage {
-        if hand_tile.tile == ANY {
-            return ImageBuffer::new(0, 0);
+    /// Combines the images of all the tiles in all the placements upfront, so
+    /// that no tile image needs to be combined when rendering.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::raster_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_TILE_SET;
+    ///
+    /// YELLOW_FLUFFY_STUFF_TILE_SET.precompose_all();
+    /// ```
+    pub fn precompose_all(&self) {
+        for tile in ALL_TILES {
+            for placement in [Normal, Rotated] {
+                let hand_tile = HandTile::new(tile, placement);
+                self.cached_image(&hand_tile);
+            }
         }
+    }
+
+    /// Returns the combined image of given (displayed) tile, combining it and
+    /// storing it in the cache if needed.
+    fn cached_image(&self, hand_tile: &HandTile) -> RgbaImage {
+        // rotated and shifted tiles look the same as the rotated ones
+        let key = (hand_tile.tile, hand_tile.placement != Normal);
+        let cache = self.cache.read().unwrap_or_else(PoisonError::into_inner);
+        if let Some(image) = cache.get(&key) {
+            #[cfg(feature = "tracing")]
+            tracing::trace!(tile = %hand_tile, "composed tile cache hit");
+            return image.clone();
+        }
+        drop(cache);
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(tile = %hand_tile, "composed tile cache miss");
+        let mut image = self.hand_tile_background(hand_tile);
+        let foreground = self.hand_tile_foreground(hand_tile);
+        image::imageops::overlay(&mut image, &foreground, 0, 0);
+        self.cache
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key, image.clone());
 
-        let buffer = &self.tile_map[&hand_tile.tile];
+        image
+    }
+
+    /// Returns the combined image of given bonus tile.
+    fn bonus_image(&self, tile: BonusTile, placement: TilePlacement) -> TileImageResult {
+        if placement == FaceDown {
+            return Ok(self.cached_image(&HandTile::new(ANY, Normal)));
+        }
+        let foreground = self
+            .bonus_tile_map
+            .get(&tile)
+            .ok_or(TileImageRetrieveError::BonusTileNotSupported(tile))?;
+
+        let mut image = Self::placed_background(&self.front, placement);
+        if placement == Normal {
+            image::imageops::overlay(&mut image, foreground, 0, 0);
+        } else {
+            image::imageops::overlay(&mut image, &image::imageops::rotate90(foreground), 0, 0);
+        }
+
+        Ok(image)
+    }
+
+    fn hand_tile_foreground(&self, hand_tile: &HandTile) -> RgbaImage {
+        let buffer = match self.tile_map.get(&hand_tile.tile) {
+            Some(buffer) if hand_tile.tile != ANY => buffer,
+            _ => return ImageBuffer::new(0, 0),
+        };
 
         if hand_tile.placement == Normal {
             buffer.clone()
@@ -252,7 +646,11 @@ impl TwoPartTileSet {
             &self.front
         };
 
-        if hand_tile.placement == Normal {
+        Self::placed_background(background, hand_tile.placement)
+    }
+
+    fn placed_background(background: &RgbaImage, placement: TilePlacement) -> RgbaImage {
+        if placement == Normal {
             background.clone()
         } else {
             let mut new_background = image::imageops::rotate90(background);
@@ -265,11 +663,23 @@ impl TwoPartTileSet {
 impl TileSet for TwoPartTileSet {
     #[inline]
     fn tile_image(&self, hand_tile: &HandTile) -> TileImageResult {
-        let mut background = self.hand_tile_background(hand_tile);
-        let foreground = self.hand_tile_foreground(hand_tile);
-        image::imageops::overlay(&mut background, &foreground, 0, 0);
+        let hand_tile = hand_tile.displayed();
+        // blank tiles are just tile fronts, unless the foreground is given
+        if hand_tile.tile != BLANK && !self.tile_map.contains_key(&hand_tile.tile) {
+            return Err(missing_tile_error(&hand_tile));
+        }
 
-        Ok(background)
+        Ok(self.cached_image(&hand_tile))
+    }
+
+    #[inline]
+    fn bonus_tile_image(&self, tile: BonusTile, placement: TilePlacement) -> TileImageResult {
+        self.bonus_image(tile, placement)
+    }
+
+    #[inline]
+    fn metadata(&self) -> Option<TileSetMetadata> {
+        self.metadata.clone()
     }
 
     #[inline]
@@ -285,23 +695,26 @@ impl TileSet for TwoPartTileSet {
 
 #[cfg(test)]
 mod tests {
+    use std::borrow::Cow;
     use std::collections::HashMap;
 
-    use image::ImageBuffer;
+    use image::{ImageBuffer, Rgba, RgbaImage};
 
-    use crate::raster_renderer::{TileSet, TileSetCreationError, TwoPartTileSet};
-    use crate::tiles::{ALL_TILES, ANY, II_PIN};
-    use crate::HandTile;
-    use crate::TilePlacement::Normal;
+    use crate::raster_renderer::{
+        SimpleTileSet, TileImageRetrieveError, TileSet, TileSetCreationError, TwoPartTileSet,
+    };
+    use crate::tiles::{ALL_TILES, ANY, BLANK, II_PIN, UNKNOWN};
+    use crate::TilePlacement::{Normal, Rotated, RotatedAndShifted};
+    use crate::{BonusTile, HandTile};
 
     #[test]
     fn should_return_tile_missing_error() {
         let result = TwoPartTileSet::new(ImageBuffer::new(16, 16), HashMap::new());
         assert!(result.is_err());
-        match result.err().unwrap() {
-            TileSetCreationError::TileMissing(_) => assert!(true),
-            _ => assert!(false),
-        }
+        assert!(matches!(
+            result.err().unwrap(),
+            TileSetCreationError::TileMissing(_)
+        ));
     }
 
     #[test]
@@ -333,6 +746,140 @@ mod tests {
         assert!(TileSet::tile_image(&result, &HandTile::new(II_PIN, Normal)).is_ok());
     }
 
+    #[test]
+    fn should_borrow_simple_tile_set_images() {
+        let mut map = HashMap::new();
+        for tile in ALL_TILES {
+            map.insert(tile, RgbaImage::new(2, 3));
+        }
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let boxed: Box<dyn TileSet> = Box::new(&tile_set);
+
+        let image = boxed
+            .tile_image_ref(&HandTile::new(II_PIN, Normal))
+            .unwrap();
+        assert!(matches!(image, Cow::Borrowed(_)));
+        assert_eq!(
+            *image,
+            tile_set.tile_image(&HandTile::new(II_PIN, Normal)).unwrap()
+        );
+        assert!(boxed
+            .tile_image_ref(&HandTile::new(II_PIN, Rotated))
+            .is_err());
+    }
+
+    #[test]
+    fn should_rotate_simple_tile_set_tiles_when_enabled() {
+        let mut map = HashMap::new();
+        for tile in ALL_TILES {
+            map.insert(tile, RgbaImage::new(2, 3));
+        }
+        let mut image = RgbaImage::new(2, 3);
+        image.put_pixel(0, 0, Rgba([255, 0, 0, 255]));
+        map.insert(II_PIN, image);
+        let strict_tile_set = SimpleTileSet::new(map.clone()).unwrap();
+        let tile_set = SimpleTileSet::new(map).unwrap().with_auto_rotation();
+
+        assert!(strict_tile_set
+            .tile_image(&HandTile::new(II_PIN, Rotated))
+            .is_err());
+        let rotated = tile_set
+            .tile_image(&HandTile::new(II_PIN, RotatedAndShifted))
+            .unwrap();
+        assert_eq!(rotated.dimensions(), (3, 2));
+        // rotated clockwise
+        assert_eq!(*rotated.get_pixel(2, 0), Rgba([255, 0, 0, 255]));
+        assert_eq!(
+            tile_set
+                .tile_image(&HandTile::new(II_PIN, Rotated))
+                .unwrap(),
+            rotated
+        );
+        assert_eq!(tile_set.rotated_cache.read().unwrap().len(), 1);
+        assert_eq!(tile_set.tile_size(&HandTile::new(II_PIN, Rotated)), (3, 2));
+    }
+
+    #[test]
+    fn should_cache_composed_tiles() {
+        let mut map = HashMap::new();
+        for tile in ALL_TILES {
+            map.insert(tile, RgbaImage::new(2, 3));
+        }
+        map.insert(II_PIN, RgbaImage::from_pixel(2, 3, Rgba([255, 0, 0, 255])));
+        let tile_set =
+            TwoPartTileSet::new(RgbaImage::from_pixel(2, 3, Rgba([0, 0, 255, 255])), map).unwrap();
+
+        let rotated = tile_set
+            .tile_image(&HandTile::new(II_PIN, RotatedAndShifted))
+            .unwrap();
+        assert_eq!(tile_set.cache.read().unwrap().len(), 1);
+        assert_eq!(
+            tile_set
+                .tile_image(&HandTile::new(II_PIN, Rotated))
+                .unwrap(),
+            rotated
+        );
+        assert_eq!(rotated.dimensions(), (3, 2));
+        assert_eq!(*rotated.get_pixel(0, 0), Rgba([255, 0, 0, 255]));
+
+        tile_set.precompose_all();
+        assert_eq!(tile_set.cache.read().unwrap().len(), 2 * ALL_TILES.len());
+        assert_eq!(
+            *tile_set
+                .tile_image(&HandTile::new(ANY, Normal))
+                .unwrap()
+                .get_pixel(0, 0),
+            Rgba([0, 0, 0, 0])
+        );
+    }
+
+    #[test]
+    fn should_support_optional_blank_and_unknown_tiles() {
+        let mut map = HashMap::new();
+        for tile in ALL_TILES {
+            map.insert(tile, RgbaImage::new(2, 3));
+        }
+        let simple_tile_set = SimpleTileSet::new(map.clone()).unwrap();
+        let front = RgbaImage::from_pixel(2, 3, Rgba([0, 0, 255, 255]));
+        let two_part_tile_set = TwoPartTileSet::new(front.clone(), map.clone()).unwrap();
+
+        assert!(simple_tile_set
+            .tile_image(&HandTile::new(BLANK, Normal))
+            .is_err());
+        assert!(simple_tile_set
+            .tile_image(&HandTile::new(UNKNOWN, Normal))
+            .is_err());
+        assert_eq!(
+            two_part_tile_set
+                .tile_image(&HandTile::new(BLANK, Normal))
+                .unwrap(),
+            front
+        );
+        assert!(two_part_tile_set
+            .tile_image(&HandTile::new(UNKNOWN, Normal))
+            .is_err());
+
+        map.insert(UNKNOWN, RgbaImage::from_pixel(2, 3, Rgba([255, 0, 0, 255])));
+        let simple_tile_set = SimpleTileSet::new(map.clone()).unwrap();
+        let two_part_tile_set = TwoPartTileSet::new(front, map).unwrap();
+
+        let unknown = HandTile::new(UNKNOWN, Normal);
+        assert_eq!(
+            *simple_tile_set
+                .tile_image(&unknown)
+                .unwrap()
+                .get_pixel(0, 0),
+            Rgba([255, 0, 0, 255])
+        );
+        assert_eq!(
+            *two_part_tile_set
+                .tile_image(&unknown)
+                .unwrap()
+                .get_pixel(0, 0),
+            Rgba([255, 0, 0, 255])
+        );
+    }
+
     #[test]
     fn should_return_image_dimensions_error() {
         let buffer1 = ImageBuffer::new(16, 16);
@@ -345,10 +892,10 @@ mod tests {
 
         let result = TwoPartTileSet::new(buffer1, map);
         assert!(result.is_err());
-        match result.err().unwrap() {
-            TileSetCreationError::ImagesDoNotHaveEqualDimensions => assert!(true),
-            _ => assert!(false),
-        }
+        assert!(matches!(
+            result.err().unwrap(),
+            TileSetCreationError::ImagesDoNotHaveEqualDimensions
+        ));
 
         let buffer1 = ImageBuffer::new(16, 16);
         let buffer2 = ImageBuffer::new(32, 32);
@@ -359,9 +906,42 @@ mod tests {
 
         let result = TwoPartTileSet::new(buffer2, map);
         assert!(result.is_err());
-        match result.err().unwrap() {
-            TileSetCreationError::ImagesDoNotHaveEqualDimensions => assert!(true),
-            _ => assert!(false),
-        }
+        assert!(matches!(
+            result.err().unwrap(),
+            TileSetCreationError::ImagesDoNotHaveEqualDimensions
+        ));
+    }
+
+    #[test]
+    fn should_compose_bonus_tiles() {
+        let red = Rgba([255, 0, 0, 255]);
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, RgbaImage::new(2, 3)))
+            .collect::<HashMap<_, _>>();
+        let bonus_map = HashMap::from([(BonusTile::Plum, RgbaImage::from_pixel(2, 3, red))]);
+        let tile_set = TwoPartTileSet::new(RgbaImage::new(2, 3), map.clone()).unwrap();
+        assert!(matches!(
+            tile_set.bonus_tile_image(BonusTile::Plum, Normal),
+            Err(TileImageRetrieveError::BonusTileNotSupported(
+                BonusTile::Plum
+            ))
+        ));
+
+        let tile_set = tile_set.with_bonus_tiles(bonus_map).unwrap();
+
+        let plum = tile_set.bonus_tile_image(BonusTile::Plum, Normal).unwrap();
+        assert_eq!(*plum.get_pixel(1, 2), red);
+        let rotated = tile_set.bonus_tile_image(BonusTile::Plum, Rotated).unwrap();
+        assert_eq!(rotated.dimensions(), (3, 2));
+        assert!(tile_set
+            .bonus_tile_image(BonusTile::Winter, Normal)
+            .is_err());
+        assert!(matches!(
+            SimpleTileSet::new(map)
+                .unwrap()
+                .bonus_tile_image(BonusTile::Plum, Normal),
+            Err(TileImageRetrieveError::BonusTileNotSupported(_))
+        ));
     }
 }
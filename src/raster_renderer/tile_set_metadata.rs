@@ -0,0 +1,93 @@
+use std::borrow::Cow;
+
+use crate::raster_renderer::Scale;
+
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+/// Attribution information of a tile set, returned by
+/// [TileSet::metadata](crate::raster_renderer::TileSet::metadata), so that
+/// the applications can credit the authors of the tile images without
+/// hard-coding it.
+pub struct TileSetMetadata {
+    /// Human-readable name of the tile set.
+    pub name: Cow<'static, str>,
+    /// Author of the tile images.
+    pub author: Cow<'static, str>,
+    /// Link to the author's page or the original tile images, if any. Some
+    /// licenses require it to be provided along with the credits.
+    pub url: Option<Cow<'static, str>>,
+    /// License (or the terms of use) of the tile images.
+    pub license: Cow<'static, str>,
+    /// Scale the tile set looks best at, if any, to be used as
+    /// [RenderOptions::scale](crate::raster_renderer::RenderOptions::scale).
+    pub recommended_scale: Option<Scale>,
+}
+
+impl TileSetMetadata {
+    #[inline]
+    #[must_use]
+    /// Creates a new tile set metadata object instance with given tile set
+    /// name, author, and license, without a link and a recommended scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::raster_renderer::TileSetMetadata;
+    ///
+    /// let metadata = TileSetMetadata::new("My tiles", "Me", "CC0 1.0 Universal");
+    /// assert_eq!(metadata.author, "Me");
+    /// assert_eq!(metadata.url, None);
+    /// ```
+    pub fn new<N, A, L>(name: N, author: A, license: L) -> Self
+    where
+        N: Into<Cow<'static, str>>,
+        A: Into<Cow<'static, str>>,
+        L: Into<Cow<'static, str>>,
+    {
+        Self {
+            name: name.into(),
+            author: author.into(),
+            url: None,
+            license: license.into(),
+            recommended_scale: None,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the tile set metadata with given link to the author's page or
+    /// the original tile images.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::raster_renderer::TileSetMetadata;
+    ///
+    /// let metadata = TileSetMetadata::new("My tiles", "Me", "CC0 1.0 Universal")
+    ///     .url("https://example.com/");
+    /// assert_eq!(metadata.url.as_deref(), Some("https://example.com/"));
+    /// ```
+    pub fn url<U: Into<Cow<'static, str>>>(self, url: U) -> Self {
+        Self {
+            url: Some(url.into()),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the tile set metadata with given recommended scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::raster_renderer::{Scale, TileSetMetadata};
+    ///
+    /// let metadata = TileSetMetadata::new("My tiles", "Me", "CC0 1.0 Universal")
+    ///     .recommended_scale(Scale::TileHeight(64));
+    /// assert_eq!(metadata.recommended_scale, Some(Scale::TileHeight(64)));
+    /// ```
+    pub fn recommended_scale(self, recommended_scale: Scale) -> Self {
+        Self {
+            recommended_scale: Some(recommended_scale),
+            ..self
+        }
+    }
+}
@@ -0,0 +1,324 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use image::{Pixel, Rgba, RgbaImage};
+
+use crate::raster_renderer::{TileImageRefResult, TileImageResult, TileSet, TileSetMetadata};
+use crate::tiles::{ALL_BONUS_TILES, ALL_TILES, ANY, BLANK, UNKNOWN};
+use crate::TilePlacement::{FaceDown, Normal, Rotated};
+use crate::{BonusTile, HandTile, Tile, TilePlacement};
+
+/// Minimum value of every channel of a tile front pixel that is tinted.
+const FRONT_MIN_LIGHTNESS: u8 = 160;
+/// Maximum difference between the channels of a tile front pixel that is
+/// tinted.
+const FRONT_MAX_SATURATION: u8 = 40;
+
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash)]
+/// Colors used to recolor the tiles with [TintedTileSet].
+pub struct TintOptions {
+    /// Color of the tile backs, if they are recolored.
+    pub back: Option<Rgba<u8>>,
+    /// Color of the tile fronts, if they are recolored. Only the light,
+    /// unsaturated pixels (i.e. the tile body) are recolored, so the tile
+    /// faces keep their colors.
+    pub front: Option<Rgba<u8>>,
+    /// Whether the brightness of the unsaturated pixels of the tile fronts
+    /// is inverted, i.e. light tile bodies with dark symbols become dark
+    /// tile bodies with light symbols. This is done before recoloring the
+    /// fronts.
+    pub invert_front: bool,
+}
+
+impl TintOptions {
+    #[inline]
+    #[must_use]
+    /// Creates a new tint options object instance that does not recolor
+    /// anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the tint options that recolor the tile backs with given color.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::image::Rgba;
+    /// use riichi_hand::raster_renderer::TintOptions;
+    ///
+    /// let options = TintOptions::new().back(Rgba([40, 90, 200, 255]));
+    /// assert_eq!(options.back, Some(Rgba([40, 90, 200, 255])));
+    /// ```
+    pub fn back(self, back: Rgba<u8>) -> Self {
+        Self {
+            back: Some(back),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the tint options that recolor the tile fronts with given color
+    /// (e.g. a dark gray for dark tiles).
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::image::Rgba;
+    /// use riichi_hand::raster_renderer::TintOptions;
+    ///
+    /// let options = TintOptions::new().front(Rgba([60, 60, 60, 255]));
+    /// assert_eq!(options.front, Some(Rgba([60, 60, 60, 255])));
+    /// ```
+    pub fn front(self, front: Rgba<u8>) -> Self {
+        Self {
+            front: Some(front),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the tint options that invert the brightness of the
+    /// unsaturated pixels of the tile fronts, producing dark tiles with light
+    /// symbols.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::raster_renderer::TintOptions;
+    ///
+    /// let options = TintOptions::new().invert_front();
+    /// assert!(options.invert_front);
+    /// ```
+    pub fn invert_front(self) -> Self {
+        Self {
+            invert_front: true,
+            ..self
+        }
+    }
+}
+
+/// Replaces the color of given pixel with given color, keeping its
+/// brightness and alpha.
+fn colorize(pixel: &mut Rgba<u8>, color: Rgba<u8>) {
+    let luma = u32::from(pixel.to_luma()[0]);
+    for (channel, &tint) in pixel.0[..3].iter_mut().zip(&color.0[..3]) {
+        *channel = (u32::from(tint) * luma / 255) as u8;
+    }
+}
+
+/// Returns the lowest and the highest channel value of given pixel.
+fn channel_range(pixel: &Rgba<u8>) -> (u8, u8) {
+    let channels = &pixel.0[..3];
+    let min = channels.iter().min().copied().unwrap_or(0);
+    let max = channels.iter().max().copied().unwrap_or(0);
+
+    (min, max)
+}
+
+/// Returns whether given pixel is a shade of gray (e.g. a black symbol or
+/// a white tile body), rather than a colored symbol.
+fn is_unsaturated(pixel: &Rgba<u8>) -> bool {
+    let (min, max) = channel_range(pixel);
+
+    max - min <= FRONT_MAX_SATURATION
+}
+
+/// Returns whether given pixel is a part of the tile body, rather than the
+/// tile face.
+fn is_front_body(pixel: &Rgba<u8>) -> bool {
+    is_unsaturated(pixel) && channel_range(pixel).0 >= FRONT_MIN_LIGHTNESS
+}
+
+/// Inverts the brightness of given shade of gray.
+fn invert(pixel: &mut Rgba<u8>) {
+    for channel in &mut pixel.0[..3] {
+        *channel = 255 - *channel;
+    }
+}
+
+#[derive(Debug)]
+/// An implementation of [TileSet] that recolors the tiles of another tile
+/// set, e.g. to get tiles with blue backs, or dark tiles, without modifying
+/// the tile images.
+///
+/// All the tiles are recolored when the tile set is created. The tiles that
+/// the inner tile set does not support (e.g. the rotated tiles of a
+/// [SimpleTileSet](crate::raster_renderer::SimpleTileSet)) return the same
+/// errors as the inner tile set.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::image::Rgba;
+/// use riichi_hand::raster_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_TILE_SET;
+/// use riichi_hand::raster_renderer::{TintOptions, TintedTileSet};
+///
+/// let tile_set = TintedTileSet::new(
+///     &*YELLOW_FLUFFY_STUFF_TILE_SET,
+///     TintOptions::new().back(Rgba([40, 90, 200, 255])),
+/// );
+/// ```
+pub struct TintedTileSet {
+    tile_map: HashMap<(Tile, bool), TileImageResult>,
+    bonus_tile_map: HashMap<(BonusTile, bool), TileImageResult>,
+    tile_width: u32,
+    tile_height: u32,
+    metadata: Option<TileSetMetadata>,
+}
+
+impl TintedTileSet {
+    /// Creates a new [TintedTileSet] instance recoloring the tiles of given
+    /// tile set with given [TintOptions].
+    pub fn new<T: TileSet>(inner: T, options: TintOptions) -> Self {
+        let tiles = ALL_TILES.iter().chain(&[BLANK, UNKNOWN]).copied();
+        let mut tile_map = HashMap::with_capacity(2 * (ALL_TILES.len() + 2));
+        for tile in tiles {
+            for placement in [Normal, Rotated] {
+                let mut image = inner.tile_image(&HandTile::new(tile, placement));
+                if let Ok(image) = &mut image {
+                    Self::tint(image, tile == ANY, options);
+                }
+                tile_map.insert((tile, placement == Rotated), image);
+            }
+        }
+        let mut bonus_tile_map = HashMap::with_capacity(2 * ALL_BONUS_TILES.len());
+        for tile in ALL_BONUS_TILES {
+            for placement in [Normal, Rotated] {
+                let mut image = inner.bonus_tile_image(tile, placement);
+                if let Ok(image) = &mut image {
+                    Self::tint(image, false, options);
+                }
+                bonus_tile_map.insert((tile, placement == Rotated), image);
+            }
+        }
+
+        Self {
+            tile_map,
+            bonus_tile_map,
+            tile_width: inner.tile_width(),
+            tile_height: inner.tile_height(),
+            metadata: inner.metadata(),
+        }
+    }
+
+    /// Recolors given image of given tile with given [TintOptions].
+    fn tint(image: &mut RgbaImage, is_back: bool, options: TintOptions) {
+        if is_back {
+            if let Some(back) = options.back {
+                image.pixels_mut().for_each(|pixel| colorize(pixel, back));
+            }
+            return;
+        }
+
+        if options.invert_front {
+            image
+                .pixels_mut()
+                .filter(|pixel| is_unsaturated(pixel))
+                .for_each(invert);
+        }
+        if let Some(front) = options.front {
+            image
+                .pixels_mut()
+                .filter(|pixel| is_front_body(pixel))
+                .for_each(|pixel| colorize(pixel, front));
+        }
+    }
+}
+
+impl TileSet for TintedTileSet {
+    #[inline]
+    fn tile_image(&self, hand_tile: &HandTile) -> TileImageResult {
+        self.tile_image_ref(hand_tile).map(Cow::into_owned)
+    }
+
+    fn tile_image_ref(&self, hand_tile: &HandTile) -> TileImageRefResult<'_> {
+        let hand_tile = hand_tile.displayed();
+        // rotated and shifted tiles look the same as the rotated ones
+        let key = (hand_tile.tile, hand_tile.placement != Normal);
+
+        match &self.tile_map[&key] {
+            Ok(image) => Ok(Cow::Borrowed(image)),
+            Err(e) => Err(e.clone()),
+        }
+    }
+
+    fn bonus_tile_image(&self, tile: BonusTile, placement: TilePlacement) -> TileImageResult {
+        if placement == FaceDown {
+            return self.tile_image(&HandTile::new(ANY, Normal));
+        }
+
+        self.bonus_tile_map[&(tile, placement != Normal)].clone()
+    }
+
+    fn tile_size(&self, hand_tile: &HandTile) -> (u32, u32) {
+        let hand_tile = hand_tile.displayed();
+        let key = (hand_tile.tile, hand_tile.placement != Normal);
+
+        match &self.tile_map[&key] {
+            Ok(image) => image.dimensions(),
+            Err(_) if hand_tile.placement == Normal => (self.tile_width, self.tile_height),
+            Err(_) => (self.tile_height, self.tile_width),
+        }
+    }
+
+    #[inline]
+    fn metadata(&self) -> Option<TileSetMetadata> {
+        self.metadata.clone()
+    }
+
+    #[inline]
+    fn tile_width(&self) -> u32 {
+        self.tile_width
+    }
+
+    #[inline]
+    fn tile_height(&self) -> u32 {
+        self.tile_height
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use image::{Rgba, RgbaImage};
+
+    use crate::raster_renderer::{SimpleTileSet, TileSet, TintOptions, TintedTileSet};
+    use crate::tiles::*;
+    use crate::HandTile;
+    use crate::TilePlacement::{FaceDown, Normal, Rotated};
+
+    #[test]
+    fn should_tint_tiles() {
+        let white = Rgba([255, 255, 255, 255]);
+        let red = Rgba([220, 20, 20, 255]);
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| {
+                let mut image = RgbaImage::from_pixel(2, 1, white);
+                image.put_pixel(1, 0, red);
+                (tile, image)
+            })
+            .collect::<HashMap<_, _>>();
+        let tile_set = SimpleTileSet::new(map).unwrap();
+        let blue = Rgba([0, 0, 200, 255]);
+        let dark = Rgba([50, 50, 50, 255]);
+
+        let tinted = TintedTileSet::new(&tile_set, TintOptions::new().back(blue).front(dark));
+
+        let back = tinted.tile_image(&HandTile::new(II_MAN, FaceDown)).unwrap();
+        assert_eq!(*back.get_pixel(0, 0), blue);
+        assert!(back.get_pixel(1, 0)[0] == 0 && back.get_pixel(1, 0)[2] > 0);
+        let front = tinted.tile_image(&HandTile::new(II_MAN, Normal)).unwrap();
+        assert_eq!(*front.get_pixel(0, 0), dark);
+        assert_eq!(*front.get_pixel(1, 0), red);
+        assert_eq!((tinted.tile_width(), tinted.tile_height()), (2, 1));
+        assert!(tinted.tile_image(&HandTile::new(II_MAN, Rotated)).is_err());
+
+        let inverted = TintedTileSet::new(&tile_set, TintOptions::new().invert_front());
+        let front = inverted.tile_image(&HandTile::new(II_MAN, Normal)).unwrap();
+        assert_eq!(*front.get_pixel(0, 0), Rgba([0, 0, 0, 255]));
+        assert_eq!(*front.get_pixel(1, 0), red);
+    }
+}
@@ -0,0 +1,384 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, PoisonError, RwLock};
+
+use image::RgbaImage;
+use tiny_skia::{Pixmap, Transform};
+use usvg::fontdb;
+
+use crate::raster_renderer::{TileImageResult, TileSet};
+use crate::svg_renderer::SvgTileSet;
+use crate::tiles::{ALL_TILES, ANY, BLANK, UNKNOWN};
+use crate::TilePlacement::{Normal, Rotated};
+use crate::{HandTile, Tile};
+
+#[derive(Debug)]
+/// An error that occurs when creating a [VectorTileSet].
+pub enum VectorTileSetCreationError {
+    /// The tile front (background) image could not be parsed.
+    InvalidFront(usvg::Error),
+    /// Given tile's image could not be parsed.
+    InvalidTile(Tile, usvg::Error),
+    /// The requested tile dimensions are zero.
+    InvalidTileSize(u32, u32),
+}
+
+impl Error for VectorTileSetCreationError {}
+
+impl Display for VectorTileSetCreationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidFront(e) => write!(f, "could not parse the tile front image: {}", e),
+            Self::InvalidTile(tile, e) => {
+                write!(f, "could not parse the image of tile {}: {}", tile, e)
+            }
+            Self::InvalidTileSize(width, height) => {
+                write!(f, "invalid tile size: {}x{}", width, height)
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+/// Parsed SVG documents of a [SvgTileSet], shared by the [VectorTileSet]
+/// instances of different sizes.
+struct VectorTiles {
+    front: usvg::Tree,
+    tile_map: HashMap<Tile, usvg::Tree>,
+    tile_width: u32,
+    tile_height: u32,
+    foreground_margin: f32,
+}
+
+impl VectorTiles {
+    fn parse(svg_tile_set: &SvgTileSet) -> Result<Self, VectorTileSetCreationError> {
+        let options = usvg::Options::default();
+        let font_db = fontdb::Database::new();
+        let parse = |svg: &str| usvg::Tree::from_str(svg, &options, &font_db);
+
+        let front =
+            parse(svg_tile_set.front()).map_err(VectorTileSetCreationError::InvalidFront)?;
+        let tile_map = ALL_TILES
+            .iter()
+            .chain(&[BLANK, UNKNOWN])
+            .map(|&tile| {
+                parse(svg_tile_set.tile(&tile))
+                    .map(|tree| (tile, tree))
+                    .map_err(|e| VectorTileSetCreationError::InvalidTile(tile, e))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            front,
+            tile_map,
+            tile_width: svg_tile_set.tile_width(),
+            tile_height: svg_tile_set.tile_height(),
+            foreground_margin: svg_tile_set.foreground_margin(),
+        })
+    }
+}
+
+/// Returns the transform that draws given SVG document onto a tile of given
+/// size, shrinking it by given fraction of the dimensions on each side.
+fn fit_transform(tree: &usvg::Tree, width: u32, height: u32, margin: f32) -> Transform {
+    let size = tree.size();
+    let scale = 1.0 - 2.0 * margin;
+
+    Transform::from_scale(
+        width as f32 / size.width() * scale,
+        height as f32 / size.height() * scale,
+    )
+    .post_translate(width as f32 * margin, height as f32 * margin)
+}
+
+#[derive(Debug)]
+/// An implementation of [TileSet] that rasterizes the images of a
+/// [SvgTileSet] at exactly the requested tile size.
+///
+/// Unlike downscaling the pre-rendered tile images with
+/// [RenderOptions::scale](crate::raster_renderer::RenderOptions::scale), this
+/// produces crisp images even for small tiles. The SVG documents are parsed
+/// once, and each tile is rasterized on demand (and cached), so tile sets of
+/// other sizes can be created cheaply with
+/// [with_tile_height](VectorTileSet::with_tile_height).
+///
+/// Similarly to [TwoPartTileSet](crate::raster_renderer::TwoPartTileSet),
+/// the tile fronts of rotated tiles are mirrored, so it always seems like the
+/// light is coming from one source.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::parser::HandParser;
+/// use riichi_hand::raster_renderer::{RasterRenderer, RenderOptions, VectorTileSet};
+/// use riichi_hand::svg_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_SVG_TILE_SET;
+///
+/// let tile_set = VectorTileSet::new(&YELLOW_FLUFFY_STUFF_SVG_TILE_SET, 48).unwrap();
+/// let hand = HandParser::parse("123m456p").unwrap();
+/// let image = RasterRenderer::render(&hand, &tile_set, RenderOptions::default()).unwrap();
+/// assert_eq!(image.height(), 48);
+/// ```
+pub struct VectorTileSet {
+    tiles: Arc<VectorTiles>,
+    tile_width: u32,
+    tile_height: u32,
+    cache: RwLock<HashMap<(Tile, bool), RgbaImage>>,
+}
+
+impl VectorTileSet {
+    /// Creates a new [VectorTileSet] instance from given SVG tile set, with
+    /// tiles of given height in pixels. The tile width keeps the aspect ratio
+    /// of the SVG tiles.
+    pub fn new(
+        svg_tile_set: &SvgTileSet,
+        tile_height: u32,
+    ) -> Result<Self, VectorTileSetCreationError> {
+        let tiles = Arc::new(VectorTiles::parse(svg_tile_set)?);
+
+        Self::with_tiles(tiles, tile_height)
+    }
+
+    /// Returns a tile set of the same SVG images rasterized with given tile
+    /// height in pixels, without parsing the SVG documents again.
+    pub fn with_tile_height(&self, tile_height: u32) -> Result<Self, VectorTileSetCreationError> {
+        Self::with_tiles(Arc::clone(&self.tiles), tile_height)
+    }
+
+    fn with_tiles(
+        tiles: Arc<VectorTiles>,
+        tile_height: u32,
+    ) -> Result<Self, VectorTileSetCreationError> {
+        let tile_width = (u64::from(tile_height) * u64::from(tiles.tile_width)
+            / u64::from(tiles.tile_height.max(1))) as u32;
+        if tile_width == 0 || tile_height == 0 {
+            return Err(VectorTileSetCreationError::InvalidTileSize(
+                tile_width,
+                tile_height,
+            ));
+        }
+
+        Ok(Self {
+            tiles,
+            tile_width,
+            tile_height,
+            cache: RwLock::new(HashMap::new()),
+        })
+    }
+
+    /// Rasterizes all the tiles in all the placements upfront, so that no
+    /// tile needs to be rasterized when rendering.
+    pub fn precompose_all(&self) {
+        for tile in ALL_TILES {
+            for placement in [Normal, Rotated] {
+                self.cached_image(&HandTile::new(tile, placement));
+            }
+        }
+    }
+
+    /// Returns the image of given (displayed) tile, rasterizing it and storing
+    /// it in the cache if needed.
+    fn cached_image(&self, hand_tile: &HandTile) -> RgbaImage {
+        // rotated and shifted tiles look the same as the rotated ones
+        let key = (hand_tile.tile, hand_tile.placement != Normal);
+        let cache = self.cache.read().unwrap_or_else(PoisonError::into_inner);
+        if let Some(image) = cache.get(&key) {
+            return image.clone();
+        }
+        drop(cache);
+
+        let image = self.rasterize(key.0, key.1);
+        self.cache
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key, image.clone());
+
+        image
+    }
+
+    /// Rasterizes the front and the foreground (or just the back, for
+    /// [ANY]) of given tile.
+    fn rasterize(&self, tile: Tile, rotated: bool) -> RgbaImage {
+        let (width, height) = (self.tile_width, self.tile_height);
+        let (background_orientation, foreground_orientation, (image_width, image_height)) =
+            if rotated {
+                (
+                    // mirrored rotation, i.e. transposition
+                    Transform::from_row(0.0, 1.0, 1.0, 0.0, 0.0, 0.0),
+                    // clockwise rotation
+                    Transform::from_row(0.0, 1.0, -1.0, 0.0, height as f32, 0.0),
+                    (height, width),
+                )
+            } else {
+                (
+                    Transform::identity(),
+                    Transform::identity(),
+                    (width, height),
+                )
+            };
+
+        let mut pixmap =
+            Pixmap::new(image_width, image_height).expect("tile size must not be zero");
+        let back = &self.tiles.tile_map[&ANY];
+        let (background, foreground) = if tile == ANY {
+            (back, None)
+        } else {
+            (&self.tiles.front, Some(&self.tiles.tile_map[&tile]))
+        };
+        let transform =
+            fit_transform(background, width, height, 0.0).post_concat(background_orientation);
+        resvg::render(background, transform, &mut pixmap.as_mut());
+        if let Some(foreground) = foreground {
+            let margin = self.tiles.foreground_margin;
+            let transform = fit_transform(foreground, width, height, margin)
+                .post_concat(foreground_orientation);
+            resvg::render(foreground, transform, &mut pixmap.as_mut());
+        }
+
+        // tiny-skia stores premultiplied colors
+        let data = pixmap
+            .pixels()
+            .iter()
+            .flat_map(|pixel| {
+                let color = pixel.demultiply();
+                [color.red(), color.green(), color.blue(), color.alpha()]
+            })
+            .collect();
+        RgbaImage::from_raw(image_width, image_height, data)
+            .expect("pixmap size must match the image size")
+    }
+}
+
+impl TileSet for VectorTileSet {
+    #[inline]
+    fn tile_image(&self, hand_tile: &HandTile) -> TileImageResult {
+        Ok(self.cached_image(&hand_tile.displayed()))
+    }
+
+    #[inline]
+    fn tile_width(&self) -> u32 {
+        self.tile_width
+    }
+
+    #[inline]
+    fn tile_height(&self) -> u32 {
+        self.tile_height
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::collections::HashMap;
+
+    use crate::raster_renderer::{TileSet, VectorTileSet, VectorTileSetCreationError};
+    use crate::svg_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_SVG_TILE_SET;
+    use crate::svg_renderer::SvgTileSet;
+    use crate::tiles::*;
+    use crate::HandTile;
+    use crate::TilePlacement::{Normal, Rotated};
+
+    const FRONT_SVG: &str = r##"<svg xmlns="http://www.w3.org/2000/svg" width="30" height="40">
+        <rect width="30" height="40" fill="#ff0000"/>
+    </svg>"##;
+    const TILE_SVG: &str = r##"<svg xmlns="http://www.w3.org/2000/svg" width="30" height="40">
+        <rect width="30" height="40" fill="#0000ff"/>
+    </svg>"##;
+    const CORNER_SVG: &str = r##"<svg xmlns="http://www.w3.org/2000/svg" width="30" height="40">
+        <rect width="15" height="20" fill="#0000ff"/>
+    </svg>"##;
+
+    #[test]
+    fn should_rasterize_at_given_size() {
+        let tile_set = VectorTileSet::new(&YELLOW_FLUFFY_STUFF_SVG_TILE_SET, 40).unwrap();
+        assert_eq!((tile_set.tile_width(), tile_set.tile_height()), (30, 40));
+
+        let image = tile_set.tile_image(&HandTile::new(II_MAN, Normal)).unwrap();
+        assert_eq!(image.dimensions(), (30, 40));
+        assert_eq!(image.get_pixel(15, 20)[3], 255);
+
+        let rotated = tile_set
+            .tile_image(&HandTile::new(II_MAN, Rotated))
+            .unwrap();
+        assert_eq!(rotated.dimensions(), (40, 30));
+
+        let larger = tile_set.with_tile_height(80).unwrap();
+        assert_eq!((larger.tile_width(), larger.tile_height()), (60, 80));
+    }
+
+    #[test]
+    fn should_rasterize_foreground_with_margin() {
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, Cow::Borrowed(TILE_SVG)))
+            .collect::<HashMap<_, _>>();
+        let svg_tile_set = SvgTileSet::new(Cow::Borrowed(FRONT_SVG), map, 30, 40)
+            .unwrap()
+            .with_foreground_margin(0.25);
+
+        let tile_set = VectorTileSet::new(&svg_tile_set, 80).unwrap();
+        let image = tile_set.tile_image(&HandTile::new(II_MAN, Normal)).unwrap();
+
+        assert_eq!(image.dimensions(), (60, 80));
+        assert_eq!(image.get_pixel(30, 40).0, [0, 0, 255, 255]);
+        assert_eq!(image.get_pixel(10, 10).0, [255, 0, 0, 255]);
+        assert_eq!(image.get_pixel(50, 70).0, [255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn should_rasterize_default_blank_and_unknown_tiles() {
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, Cow::Borrowed(TILE_SVG)))
+            .collect::<HashMap<_, _>>();
+        let svg_tile_set = SvgTileSet::new(Cow::Borrowed(FRONT_SVG), map, 30, 40).unwrap();
+        let tile_set = VectorTileSet::new(&svg_tile_set, 40).unwrap();
+
+        let blank = tile_set.tile_image(&HandTile::new(BLANK, Normal)).unwrap();
+        let unknown = tile_set
+            .tile_image(&HandTile::new(UNKNOWN, Normal))
+            .unwrap();
+
+        assert!(blank.pixels().all(|pixel| pixel.0 == [255, 0, 0, 255]));
+        assert_eq!(unknown.get_pixel(5, 5).0, [255, 0, 0, 255]);
+        assert_eq!(unknown.get_pixel(15, 29).0, [0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn should_return_invalid_tile_size_error() {
+        let result = VectorTileSet::new(&YELLOW_FLUFFY_STUFF_SVG_TILE_SET, 0);
+
+        assert!(matches!(
+            result,
+            Err(VectorTileSetCreationError::InvalidTileSize(0, 0))
+        ));
+    }
+
+    #[test]
+    fn should_rasterize_rotated_tiles() {
+        let map = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, Cow::Borrowed(CORNER_SVG)))
+            .collect::<HashMap<_, _>>();
+        let svg_tile_set = SvgTileSet::new(Cow::Borrowed(FRONT_SVG), map, 30, 40).unwrap();
+        let tile_set = VectorTileSet::new(&svg_tile_set, 40).unwrap();
+        tile_set.precompose_all();
+        let blue = [0, 0, 255, 255];
+        let red = [255, 0, 0, 255];
+
+        let normal = tile_set.tile_image(&HandTile::new(II_MAN, Normal)).unwrap();
+        let rotated = tile_set
+            .tile_image(&HandTile::new(II_MAN, Rotated))
+            .unwrap();
+        let rotated_back = tile_set.tile_image(&HandTile::new(ANY, Rotated)).unwrap();
+
+        assert_eq!(normal.get_pixel(5, 5).0, blue);
+        assert_eq!(normal.get_pixel(25, 35).0, red);
+        // the foreground is rotated clockwise
+        assert_eq!(rotated.dimensions(), (40, 30));
+        assert_eq!(rotated.get_pixel(35, 5).0, blue);
+        assert_eq!(rotated.get_pixel(5, 5).0, red);
+        // the back is mirrored
+        assert_eq!(rotated_back.get_pixel(5, 5).0, blue);
+        assert_eq!(rotated_back.get_pixel(35, 5)[3], 0);
+    }
+}
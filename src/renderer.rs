@@ -0,0 +1,72 @@
+pub use layout::{
+    LabelPosition, Layout, LayoutOptions, PlacedTile, Rect, TilePosition, VerticalAlign,
+    WinningTile,
+};
+
+use crate::{Hand, HandTile};
+
+mod layout;
+
+/// A rendering backend that converts a [Layout] of a hand into some output
+/// format (e.g. a raster image, or an SVG document).
+///
+/// The layout logic (tile positions, gaps, rotated and shifted tiles) is shared
+/// between all the backends, so a custom backend only needs to draw the tiles
+/// at the positions it is given.
+///
+/// # Examples
+/// ```
+/// use std::convert::Infallible;
+///
+/// use riichi_hand::parser::HandParser;
+/// use riichi_hand::renderer::{HandRenderer, Layout, LayoutOptions};
+///
+/// /// Lists the positions of the tiles.
+/// struct PositionRenderer;
+///
+/// impl HandRenderer for PositionRenderer {
+///     type Output = Vec<(u32, u32)>;
+///     type Error = Infallible;
+///
+///     fn layout_options(&self) -> LayoutOptions {
+///         LayoutOptions::new(3, 4, 0, 1)
+///     }
+///
+///     fn render_layout(&self, layout: &Layout) -> Result<Self::Output, Self::Error> {
+///         Ok(layout.tiles.iter().map(|tile| (tile.x, tile.y)).collect())
+///     }
+/// }
+///
+/// let hand = HandParser::parse("12m_3*3z").unwrap();
+/// assert_eq!(
+///     PositionRenderer.render_hand(&hand).unwrap(),
+///     vec![(0, 0), (3, 0), (7, 1), (11, 0)]
+/// );
+/// ```
+pub trait HandRenderer {
+    /// Type of the rendered hand.
+    type Output;
+    /// Type of the error that can occur during rendering.
+    type Error;
+
+    /// Returns the options used to lay out the tiles of a hand.
+    fn layout_options(&self) -> LayoutOptions;
+
+    /// Renders an already laid out hand.
+    fn render_layout(&self, layout: &Layout) -> Result<Self::Output, Self::Error>;
+
+    /// Returns the size of the image of given tile, taking into account its
+    /// placement. The default implementation assumes that all the tiles have
+    /// the same size (see [LayoutOptions::tile_size]).
+    fn tile_size(&self, tile: &HandTile) -> (u32, u32) {
+        self.layout_options().tile_size(tile)
+    }
+
+    /// Lays out given [Hand] instance and renders it.
+    fn render_hand(&self, hand: &Hand) -> Result<Self::Output, Self::Error> {
+        let layout =
+            Layout::with_tile_sizes(hand, self.layout_options(), |tile| self.tile_size(tile));
+
+        self.render_layout(&layout)
+    }
+}
@@ -0,0 +1,929 @@
+use alloc::borrow::Cow;
+
+use crate::meld::AnkanStyle;
+use crate::TilePlacement::{FaceDown, Normal, Rotated, RotatedAndShifted};
+use crate::{Hand, HandGroup, HandTile};
+
+/// Position of a single tile in a [Layout].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct TilePosition {
+    /// The tile, along with its placement. Face-down tiles are given as they
+    /// are displayed (see [HandTile::displayed]).
+    pub tile: HandTile,
+    /// Index of the hand group the tile belongs to.
+    pub group: usize,
+    /// X coordinate of the top left corner of the tile.
+    pub x: u32,
+    /// Y coordinate of the top left corner of the tile.
+    pub y: u32,
+}
+
+impl TilePosition {
+    /// Returns the size of the tile image (`W x H` for the non-rotated tiles,
+    /// and `H x W` for the rotated ones, where W and H are the tile width and
+    /// height).
+    #[inline]
+    #[must_use]
+    pub fn size(&self, options: LayoutOptions) -> (u32, u32) {
+        options.tile_size(&self.tile)
+    }
+}
+
+/// Axis-aligned rectangle, in the units of the rendering backend (e.g.
+/// pixels).
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash)]
+pub struct Rect {
+    /// X coordinate of the top left corner.
+    pub x: u32,
+    /// Y coordinate of the top left corner.
+    pub y: u32,
+    /// Width of the rectangle.
+    pub width: u32,
+    /// Height of the rectangle.
+    pub height: u32,
+}
+
+impl Rect {
+    /// Creates a new rectangle instance.
+    #[inline]
+    #[must_use]
+    pub const fn new(x: u32, y: u32, width: u32, height: u32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Returns whether given point lies inside the rectangle (including its
+    /// top and left edges, but not the bottom and right ones).
+    #[inline]
+    #[must_use]
+    pub const fn contains(&self, x: u32, y: u32) -> bool {
+        x >= self.x && y >= self.y && x - self.x < self.width && y - self.y < self.height
+    }
+}
+
+/// A tile of a hand along with the rectangle it occupies once laid out (see
+/// [Layout::compute]).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PlacedTile {
+    /// Rectangle occupied by the tile image.
+    pub rect: Rect,
+    /// The tile as stored in the hand. Unlike [TilePosition::tile], face-down
+    /// tiles are not replaced by the tile backs.
+    pub hand_tile: HandTile,
+    /// Index of the hand group the tile belongs to.
+    pub group_index: usize,
+}
+
+/// Positions of all the tiles of a hand, along with the total dimensions.
+///
+/// The tiles are laid out from left to right, with the groups aligned
+/// according to [LayoutOptions::vertical_align] (to the bottom edge by
+/// default). The tiles inside a group are always aligned to the bottom edge.
+/// Rotated tiles occupy `H x W` units (where W and H are the tile width and
+/// height), and a rotated and shifted tile following a rotated tile is stacked
+/// on top of it.
+///
+/// If [LayoutOptions::max_width] is set, the groups that do not fit in a row
+/// are wrapped onto the next rows (each as high as the whole hand, separated
+/// by the group gap). A group that is wider than the maximum width on its own
+/// is split between the tiles.
+///
+/// The tiles do not need to have the same size: see
+/// [Layout::with_tile_sizes].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Layout {
+    /// Total width of the laid out hand.
+    pub width: u32,
+    /// Total height of the laid out hand.
+    pub height: u32,
+    /// Positions of all the tiles, in the order they appear in the hand.
+    pub tiles: Vec<TilePosition>,
+}
+
+/// Vertical alignment of the groups of different heights (e.g. because of the
+/// rotated tiles) in a [Layout].
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum VerticalAlign {
+    /// Align the groups to the top edge.
+    Top,
+    /// Center the groups vertically.
+    Center,
+    /// Align the groups to the bottom edge.
+    #[default]
+    Bottom,
+}
+
+/// Tile separated from the rest of the hand with a group gap, following the
+/// common presentation of a winning hand (13 tiles, and the winning tile).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum WinningTile {
+    /// The last tile of the hand.
+    Last,
+    /// The tile with given index in the hand, counting all the tiles of all the
+    /// groups in order.
+    Index(usize),
+}
+
+/// Placement of the tile labels (see [Layout::reserve_label_space]).
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash)]
+pub enum LabelPosition {
+    /// Place the labels above the tiles.
+    Above,
+    /// Place the labels below the tiles.
+    #[default]
+    Below,
+}
+
+/// Dimensions used to lay out a hand, in the units of the rendering backend
+/// (e.g. pixels).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct LayoutOptions {
+    /// Width of a single (non-rotated) tile.
+    pub tile_width: u32,
+    /// Height of a single (non-rotated) tile.
+    pub tile_height: u32,
+    /// Gap between tiles in a group.
+    pub tile_gap: u32,
+    /// Gap between groups.
+    pub group_gap: u32,
+    /// Vertical alignment of the groups.
+    pub vertical_align: VerticalAlign,
+    /// Tile separated from the rest of its group, if any.
+    pub winning_tile: Option<WinningTile>,
+    /// Maximum width of a row of tiles, if any.
+    pub max_width: Option<u32>,
+    /// Whether the groups of four identical, normally placed tiles are
+    /// considered ankans ("closed kans"), and displayed with the first and
+    /// last tiles face-down (see [Hand::with_face_down_closed_kans]).
+    pub face_down_closed_kans: bool,
+    /// Convention the ankans (groups of four tiles of the same kind, two or
+    /// none of which are face-down) are displayed with, if they should be
+    /// changed (see [AnkanStyle::restyle]). The unmarked ankans are restyled
+    /// even if [LayoutOptions::face_down_closed_kans] is disabled.
+    pub ankan_style: Option<AnkanStyle>,
+}
+
+impl LayoutOptions {
+    /// Creates a new layout options object instance. The groups are aligned
+    /// to the bottom edge, no tile is separated from its group, the tiles
+    /// are never wrapped, and the ankans are detected and displayed with the
+    /// first and last tiles face-down.
+    #[inline]
+    #[must_use]
+    pub const fn new(tile_width: u32, tile_height: u32, tile_gap: u32, group_gap: u32) -> Self {
+        Self {
+            tile_width,
+            tile_height,
+            tile_gap,
+            group_gap,
+            vertical_align: VerticalAlign::Bottom,
+            winning_tile: None,
+            max_width: None,
+            face_down_closed_kans: true,
+            ankan_style: None,
+        }
+    }
+
+    /// Returns the layout options with given vertical alignment of the groups.
+    #[inline]
+    #[must_use]
+    pub const fn with_vertical_align(self, vertical_align: VerticalAlign) -> Self {
+        Self {
+            vertical_align,
+            ..self
+        }
+    }
+
+    /// Returns the layout options with given tile separated from the rest of
+    /// its group.
+    #[inline]
+    #[must_use]
+    pub const fn with_winning_tile(self, winning_tile: WinningTile) -> Self {
+        Self {
+            winning_tile: Some(winning_tile),
+            ..self
+        }
+    }
+
+    /// Returns the layout options with given maximum width of a row of tiles.
+    #[inline]
+    #[must_use]
+    pub const fn with_max_width(self, max_width: u32) -> Self {
+        Self {
+            max_width: Some(max_width),
+            ..self
+        }
+    }
+
+    /// Returns the layout options with the groups of four identical, normally
+    /// placed tiles displayed as ankans or not.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::parser::HandParser;
+    /// use riichi_hand::renderer::{Layout, LayoutOptions};
+    /// use riichi_hand::tiles::{ANY, TON};
+    ///
+    /// let hand = HandParser::parse("1111z").unwrap();
+    /// let options = LayoutOptions::new(3, 4, 0, 0);
+    /// let tiles = |options| {
+    ///     Layout::new(&hand, options)
+    ///         .tiles
+    ///         .iter()
+    ///         .map(|position| position.tile.tile)
+    ///         .collect::<Vec<_>>()
+    /// };
+    ///
+    /// assert_eq!(tiles(options), vec![ANY, TON, TON, ANY]);
+    /// assert_eq!(tiles(options.with_face_down_closed_kans(false)), vec![TON; 4]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_face_down_closed_kans(self, face_down_closed_kans: bool) -> Self {
+        Self {
+            face_down_closed_kans,
+            ..self
+        }
+    }
+
+    /// Returns the layout options with the ankans displayed with given
+    /// convention.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::meld::{AnkanStyle, Meld};
+    /// use riichi_hand::renderer::{Layout, LayoutOptions};
+    /// use riichi_hand::tiles::{ANY, TON};
+    /// use riichi_hand::Hand;
+    ///
+    /// let hand = Hand::new(vec![Meld::ankan(vec![TON; 4]).to_group()]);
+    /// let options = LayoutOptions::new(3, 4, 0, 0).with_ankan_style(AnkanStyle::FaceDownMiddle);
+    ///
+    /// let tiles: Vec<_> = Layout::new(&hand, options)
+    ///     .tiles
+    ///     .iter()
+    ///     .map(|position| position.tile.tile)
+    ///     .collect();
+    /// assert_eq!(tiles, vec![TON, ANY, ANY, TON]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_ankan_style(self, ankan_style: AnkanStyle) -> Self {
+        Self {
+            ankan_style: Some(ankan_style),
+            ..self
+        }
+    }
+
+    /// Returns the size of the image of given tile, assuming that all the
+    /// tiles have the same size (`W x H` for the non-rotated tiles, and
+    /// `H x W` for the rotated ones, where W and H are the tile width and
+    /// height).
+    #[inline]
+    #[must_use]
+    pub fn tile_size(&self, tile: &HandTile) -> (u32, u32) {
+        match tile.placement {
+            Normal | FaceDown => (self.tile_width, self.tile_height),
+            Rotated | RotatedAndShifted => (self.tile_height, self.tile_width),
+        }
+    }
+}
+
+impl Layout {
+    /// Lays out the tiles of given [Hand] instance.
+    #[must_use]
+    pub fn new(hand: &Hand, options: LayoutOptions) -> Self {
+        Self::with_tile_sizes(hand, options, |tile| options.tile_size(tile))
+    }
+
+    /// Lays out the tiles of given [Hand] instance, with the size of each tile
+    /// image (taking into account its placement) given by `tile_size`, so that
+    /// the tiles do not need to have the same size. The tiles inside a group
+    /// are aligned to the bottom edge, and the rotated and shifted tiles are
+    /// stacked on top of the tiles they follow, as usual.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::parser::HandParser;
+    /// use riichi_hand::renderer::{Layout, LayoutOptions};
+    /// use riichi_hand::tiles::TON;
+    ///
+    /// let hand = HandParser::parse("1m1z2m").unwrap();
+    /// let options = LayoutOptions::new(3, 4, 1, 2);
+    /// // the East tile is wider and taller than the others
+    /// let layout = Layout::with_tile_sizes(&hand, options, |tile| {
+    ///     if tile.tile == TON {
+    ///         (5, 6)
+    ///     } else {
+    ///         options.tile_size(tile)
+    ///     }
+    /// });
+    ///
+    /// assert_eq!((layout.width, layout.height), (13, 6));
+    /// let positions: Vec<_> = layout.tiles.iter().map(|tile| (tile.x, tile.y)).collect();
+    /// assert_eq!(positions, vec![(0, 2), (4, 0), (10, 2)]);
+    /// ```
+    #[must_use]
+    pub fn with_tile_sizes<F: Fn(&HandTile) -> (u32, u32)>(
+        hand: &Hand,
+        options: LayoutOptions,
+        tile_size: F,
+    ) -> Self {
+        let groups: Vec<_> = hand
+            .groups()
+            .iter()
+            .map(|group| {
+                let mut group = Cow::Borrowed(group);
+                if options.face_down_closed_kans && Hand::is_closed_kan(&group) {
+                    let group = group.to_mut();
+                    group[0].placement = FaceDown;
+                    group[3].placement = FaceDown;
+                }
+                if let Some(restyled) = options
+                    .ankan_style
+                    .and_then(|ankan_style| ankan_style.restyle(&group))
+                {
+                    group = Cow::Owned(restyled);
+                }
+
+                Self::layout_group(&group, options, &tile_size)
+            })
+            .collect();
+
+        let height = groups.iter().map(|group| group.height).max().unwrap_or(0);
+        let mut tiles = Vec::new();
+        let mut start_x = 0;
+        for (index, group) in groups.iter().enumerate() {
+            let start_y = match options.vertical_align {
+                VerticalAlign::Top => 0,
+                VerticalAlign::Center => (height - group.height) / 2,
+                VerticalAlign::Bottom => height - group.height,
+            };
+            tiles.extend(group.tiles.iter().map(|position| TilePosition {
+                group: index,
+                x: start_x + position.x,
+                y: start_y + position.y,
+                ..*position
+            }));
+
+            start_x += group.width + options.group_gap;
+        }
+        let width = start_x.saturating_sub(options.group_gap);
+
+        let mut layout = Self {
+            width,
+            height,
+            tiles,
+        };
+        if let Some(winning_tile) = options.winning_tile {
+            layout.separate_winning_tile(winning_tile, options.group_gap);
+        }
+        if let Some(max_width) = options.max_width {
+            layout.wrap(max_width, options, &tile_size);
+        }
+
+        layout
+    }
+
+    /// Lays out the tiles of given [Hand] instance and returns the rectangles
+    /// they occupy, in the order they appear in the hand. This is useful for
+    /// hit-testing, e.g. to find the tile under the cursor.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::parser::HandParser;
+    /// use riichi_hand::renderer::{Layout, LayoutOptions, Rect};
+    /// use riichi_hand::tiles::TON;
+    ///
+    /// let hand = HandParser::parse("1m_1*z").unwrap();
+    /// let tiles = Layout::compute(&hand, &LayoutOptions::new(3, 4, 1, 2));
+    ///
+    /// assert_eq!(tiles[0].rect, Rect::new(0, 0, 3, 4));
+    /// assert_eq!(tiles[1].rect, Rect::new(5, 1, 4, 3));
+    ///
+    /// let hovered = tiles.iter().find(|tile| tile.rect.contains(6, 2)).unwrap();
+    /// assert_eq!((hovered.hand_tile.tile, hovered.group_index), (TON, 1));
+    /// ```
+    #[must_use]
+    pub fn compute(hand: &Hand, options: &LayoutOptions) -> Vec<PlacedTile> {
+        Self::compute_with_tile_sizes(hand, options, |tile| options.tile_size(tile))
+    }
+
+    /// Lays out the tiles of given [Hand] instance, with the size of each tile
+    /// image given by `tile_size` (see [Layout::with_tile_sizes]), and returns
+    /// the rectangles they occupy, in the order they appear in the hand.
+    #[must_use]
+    pub fn compute_with_tile_sizes<F: Fn(&HandTile) -> (u32, u32)>(
+        hand: &Hand,
+        options: &LayoutOptions,
+        tile_size: F,
+    ) -> Vec<PlacedTile> {
+        Self::with_tile_sizes(hand, *options, &tile_size).placed_tiles(hand, tile_size)
+    }
+
+    /// Returns the rectangles occupied by the tiles of this layout, which must
+    /// have been created for given [Hand] instance with given tile sizes.
+    pub(crate) fn placed_tiles<F: Fn(&HandTile) -> (u32, u32)>(
+        &self,
+        hand: &Hand,
+        tile_size: F,
+    ) -> Vec<PlacedTile> {
+        self.tiles
+            .iter()
+            .zip(hand.groups().iter().flatten())
+            .map(|(position, hand_tile)| {
+                let (width, height) = tile_size(&position.tile);
+                PlacedTile {
+                    rect: Rect::new(position.x, position.y, width, height),
+                    hand_tile: *hand_tile,
+                    group_index: position.group,
+                }
+            })
+            .collect()
+    }
+
+    /// Reserves a strip of given height for the tile labels, above or below
+    /// all the tiles, moving the tiles if needed. Returns the Y coordinate of
+    /// the top edge of the strip.
+    pub fn reserve_label_space(&mut self, height: u32, position: LabelPosition) -> u32 {
+        let label_y = match position {
+            LabelPosition::Above => {
+                for tile in &mut self.tiles {
+                    tile.y += height;
+                }
+                0
+            }
+            LabelPosition::Below => self.height,
+        };
+        self.height += height;
+
+        label_y
+    }
+
+    /// Moves the winning tile (and all the tiles after it) to the right, so
+    /// that it is separated from the other tiles of its group by given gap.
+    fn separate_winning_tile(&mut self, winning_tile: WinningTile, gap: u32) {
+        let index = match winning_tile {
+            WinningTile::Last => self.tiles.len().saturating_sub(1),
+            WinningTile::Index(index) => index,
+        };
+        let group = match self.tiles.get(index) {
+            Some(position) => position.group,
+            None => return,
+        };
+        let shares_group = |other: usize| {
+            self.tiles
+                .get(other)
+                .map_or(false, |position| position.group == group)
+        };
+        let gap_before = if index > 0 && shares_group(index - 1) {
+            gap
+        } else {
+            0
+        };
+        let gap_after = if shares_group(index + 1) { gap } else { 0 };
+
+        for (tile_index, position) in self.tiles.iter_mut().enumerate().skip(index) {
+            position.x += gap_before;
+            if tile_index > index {
+                position.x += gap_after;
+            }
+        }
+        self.width += gap_before + gap_after;
+    }
+
+    /// Moves the tiles that do not fit in given width onto the next rows.
+    /// The gaps between the tiles placed in the same row are preserved.
+    fn wrap<F: Fn(&HandTile) -> (u32, u32)>(
+        &mut self,
+        max_width: u32,
+        options: LayoutOptions,
+        tile_size: &F,
+    ) {
+        if self.width <= max_width {
+            return;
+        }
+
+        let row_height = self.height;
+        let end_x = |position: &TilePosition| position.x + tile_size(&position.tile).0;
+        let mut row = 0;
+        let mut row_start_x = 0;
+        let mut width = 0;
+        let mut previous: Option<TilePosition> = None;
+        for index in 0..self.tiles.len() {
+            let position = self.tiles[index];
+            let wrap = match previous {
+                Some(previous) if previous.group != position.group => {
+                    let group_end_x = self.tiles[index..]
+                        .iter()
+                        .take_while(|other| other.group == position.group)
+                        .map(end_x)
+                        .max()
+                        .unwrap_or(0);
+                    group_end_x - row_start_x > max_width
+                }
+                // never separate a rotated and shifted tile from the tile it
+                // is stacked on
+                Some(previous) => {
+                    end_x(&position) - row_start_x > max_width && position.x >= end_x(&previous)
+                }
+                None => false,
+            };
+            if wrap {
+                row += 1;
+                row_start_x = position.x;
+            }
+            previous = Some(position);
+
+            let tile = &mut self.tiles[index];
+            tile.x -= row_start_x;
+            tile.y += row * (row_height + options.group_gap);
+            width = width.max(end_x(tile));
+        }
+
+        self.width = width;
+        self.height = (row + 1) * row_height + row * options.group_gap;
+    }
+
+    fn layout_group<F: Fn(&HandTile) -> (u32, u32)>(
+        group: &HandGroup,
+        options: LayoutOptions,
+        tile_size: &F,
+    ) -> Self {
+        let slots: Vec<_> = group
+            .iter()
+            .map(|tile| {
+                let tile = tile.displayed();
+                (tile, Self::slot_size(&tile, tile_size(&tile)))
+            })
+            .collect();
+        let height = slots.iter().map(|(_, (_, h))| *h).max().unwrap_or(0);
+
+        let mut tiles = Vec::with_capacity(slots.len());
+        let mut start_x = 0;
+        let mut last_placement = Normal;
+        for (tile, (width, slot_height)) in slots {
+            if last_placement == Rotated && tile.placement == RotatedAndShifted {
+                start_x -= width + options.tile_gap;
+            }
+
+            tiles.push(TilePosition {
+                tile,
+                group: 0,
+                x: start_x,
+                y: height - slot_height,
+            });
+
+            last_placement = tile.placement;
+            start_x += width + options.tile_gap;
+        }
+        let width = start_x.saturating_sub(options.tile_gap);
+
+        Self {
+            width,
+            height,
+            tiles,
+        }
+    }
+
+    /// Returns the size of the area reserved for the tile with an image of
+    /// given size. The tile image is placed in the top left corner of that
+    /// area.
+    fn slot_size(tile: &HandTile, (width, height): (u32, u32)) -> (u32, u32) {
+        match tile.placement {
+            Normal | FaceDown | Rotated => (width, height),
+            RotatedAndShifted => (width, 2 * height),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::meld::{AnkanStyle, Meld};
+    use crate::parser::HandParser;
+    use crate::renderer::{
+        LabelPosition, Layout, LayoutOptions, Rect, TilePosition, VerticalAlign, WinningTile,
+    };
+    use crate::tiles::*;
+    use crate::TilePlacement::{FaceDown, Normal, Rotated, RotatedAndShifted};
+    use crate::{Hand, HandTile};
+
+    const OPTIONS: LayoutOptions = LayoutOptions::new(3, 4, 1, 2);
+
+    #[test]
+    fn should_layout_hand() {
+        let hand = Hand::new(vec![
+            vec![
+                HandTile::new(II_MAN, Normal),
+                HandTile::new(RYAN_MAN, Rotated),
+            ],
+            vec![],
+            vec![
+                HandTile::new(TON, Rotated),
+                HandTile::new(TON, RotatedAndShifted),
+                HandTile::new(TON, Normal),
+            ],
+        ]);
+        let layout = Layout::new(&hand, OPTIONS);
+
+        assert_eq!(layout.width, 20);
+        assert_eq!(layout.height, 6);
+        let positions: Vec<_> = layout
+            .tiles
+            .iter()
+            .map(|TilePosition { x, y, .. }| (*x, *y))
+            .collect();
+        assert_eq!(positions, vec![(0, 2), (4, 3), (12, 3), (12, 0), (17, 2)]);
+        let groups: Vec<_> = layout.tiles.iter().map(|position| position.group).collect();
+        assert_eq!(groups, vec![0, 0, 2, 2, 2]);
+    }
+
+    #[test]
+    fn should_layout_face_down_tiles_as_backs() {
+        let hand = Hand::new(vec![vec![
+            HandTile::new(TON, FaceDown),
+            HandTile::new(TON, Normal),
+        ]]);
+        let layout = Layout::new(&hand, OPTIONS);
+
+        assert_eq!(layout.width, 7);
+        assert_eq!(layout.tiles[0].tile, HandTile::new(ANY, Normal));
+        assert_eq!(layout.tiles[1].tile, HandTile::new(TON, Normal));
+    }
+
+    #[test]
+    fn should_align_groups_vertically() {
+        let hand = Hand::new(vec![
+            vec![HandTile::new(II_MAN, Normal)],
+            vec![
+                HandTile::new(TON, Rotated),
+                HandTile::new(TON, RotatedAndShifted),
+            ],
+        ]);
+        let first_tile_y = |vertical_align| {
+            let layout = Layout::new(&hand, OPTIONS.with_vertical_align(vertical_align));
+            assert_eq!(layout.height, 6);
+            layout.tiles[0].y
+        };
+
+        assert_eq!(first_tile_y(VerticalAlign::Top), 0);
+        assert_eq!(first_tile_y(VerticalAlign::Center), 1);
+        assert_eq!(first_tile_y(VerticalAlign::Bottom), 2);
+    }
+
+    #[test]
+    fn should_separate_winning_tile() {
+        let hand = Hand::new(vec![
+            vec![
+                HandTile::new(II_MAN, Normal),
+                HandTile::new(RYAN_MAN, Normal),
+                HandTile::new(SAN_MAN, Normal),
+            ],
+            vec![HandTile::new(TON, Normal)],
+        ]);
+        let layout = |winning_tile| {
+            let layout = Layout::new(&hand, OPTIONS.with_winning_tile(winning_tile));
+            let positions: Vec<_> = layout.tiles.iter().map(|position| position.x).collect();
+            (layout.width, positions)
+        };
+
+        assert_eq!(layout(WinningTile::Index(2)), (18, vec![0, 4, 10, 15]));
+        assert_eq!(layout(WinningTile::Index(1)), (20, vec![0, 6, 12, 17]));
+        assert_eq!(layout(WinningTile::Index(0)), (18, vec![0, 6, 10, 15]));
+        // the last tile is already in a separate group
+        assert_eq!(layout(WinningTile::Last), (16, vec![0, 4, 8, 13]));
+        assert_eq!(layout(WinningTile::Index(10)), (16, vec![0, 4, 8, 13]));
+    }
+
+    #[test]
+    fn should_wrap_groups() {
+        let hand = Hand::new(vec![
+            vec![
+                HandTile::new(II_MAN, Normal),
+                HandTile::new(RYAN_MAN, Normal),
+                HandTile::new(SAN_MAN, Normal),
+            ],
+            vec![HandTile::new(TON, Normal)],
+            vec![
+                HandTile::new(II_PIN, Normal),
+                HandTile::new(RYAN_PIN, Normal),
+                HandTile::new(SAN_PIN, Normal),
+                HandTile::new(SUU_PIN, Normal),
+            ],
+        ]);
+
+        let layout = Layout::new(&hand, OPTIONS.with_max_width(12));
+
+        assert_eq!(layout.width, 11);
+        assert_eq!(layout.height, 22);
+        let positions: Vec<_> = layout
+            .tiles
+            .iter()
+            .map(|position| (position.x, position.y))
+            .collect();
+        assert_eq!(
+            positions,
+            vec![
+                (0, 0),
+                (4, 0),
+                (8, 0),
+                (0, 6),
+                (0, 12),
+                (4, 12),
+                (8, 12),
+                (0, 18)
+            ]
+        );
+        assert_eq!(
+            Layout::new(&hand, OPTIONS.with_max_width(33)),
+            Layout::new(&hand, OPTIONS)
+        );
+    }
+
+    #[test]
+    fn should_not_wrap_stacked_tiles() {
+        let hand = Hand::new(vec![vec![
+            HandTile::new(II_MAN, Rotated),
+            HandTile::new(RYAN_MAN, RotatedAndShifted),
+            HandTile::new(SAN_MAN, Normal),
+        ]]);
+
+        let layout = Layout::new(&hand, OPTIONS.with_max_width(3));
+
+        assert_eq!(layout.width, 4);
+        assert_eq!(layout.height, 14);
+        let positions: Vec<_> = layout.tiles.iter().map(|position| position.x).collect();
+        assert_eq!(positions, vec![0, 0, 0]);
+        assert_eq!(layout.tiles[2].y, 10);
+    }
+
+    #[test]
+    fn should_reserve_label_space() {
+        let hand = Hand::new(vec![vec![
+            HandTile::new(II_MAN, Normal),
+            HandTile::new(RYAN_MAN, Rotated),
+        ]]);
+        let mut above = Layout::new(&hand, OPTIONS);
+        let mut below = above.clone();
+
+        assert_eq!(above.reserve_label_space(2, LabelPosition::Above), 0);
+        assert_eq!(below.reserve_label_space(2, LabelPosition::Below), 4);
+        assert_eq!(above.height, 6);
+        assert_eq!(below.height, 6);
+        assert_eq!((above.tiles[0].y, above.tiles[1].y), (2, 3));
+        assert_eq!((below.tiles[0].y, below.tiles[1].y), (0, 1));
+        assert_eq!(above.tiles[1].size(OPTIONS), (4, 3));
+    }
+
+    #[test]
+    fn should_layout_empty_hand() {
+        let layout = Layout::new(&Hand::new(vec![]), OPTIONS);
+
+        assert_eq!(layout.width, 0);
+        assert_eq!(layout.height, 0);
+        assert!(layout.tiles.is_empty());
+    }
+
+    #[test]
+    fn should_compute_tile_rects() {
+        let hand = Hand::new(vec![
+            vec![
+                HandTile::new(II_MAN, FaceDown),
+                HandTile::new(RYAN_MAN, Rotated),
+            ],
+            vec![
+                HandTile::new(TON, Rotated),
+                HandTile::new(TON, RotatedAndShifted),
+            ],
+        ]);
+
+        let tiles = Layout::compute(&hand, &OPTIONS);
+
+        let rects: Vec<_> = tiles.iter().map(|tile| tile.rect).collect();
+        assert_eq!(
+            rects,
+            vec![
+                Rect::new(0, 2, 3, 4),
+                Rect::new(4, 3, 4, 3),
+                Rect::new(10, 3, 4, 3),
+                Rect::new(10, 0, 4, 3),
+            ]
+        );
+        assert_eq!(tiles[0].hand_tile, HandTile::new(II_MAN, FaceDown));
+        let groups: Vec<_> = tiles.iter().map(|tile| tile.group_index).collect();
+        assert_eq!(groups, vec![0, 0, 1, 1]);
+
+        let hovered: Vec<_> = [(0, 0), (0, 2), (13, 2), (13, 3), (9, 4)]
+            .iter()
+            .map(|&(x, y)| tiles.iter().position(|tile| tile.rect.contains(x, y)))
+            .collect();
+        assert_eq!(hovered, vec![None, Some(0), Some(3), Some(2), None]);
+    }
+
+    #[test]
+    fn should_detect_closed_kans() {
+        let hand = HandParser::parse("1111m_0555p_1*111s").unwrap();
+        let placements = |options| {
+            Layout::new(&hand, options)
+                .tiles
+                .iter()
+                .map(|position| (position.tile.tile, position.tile.placement))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            placements(OPTIONS),
+            vec![
+                (ANY, Normal),
+                (II_MAN, Normal),
+                (II_MAN, Normal),
+                (ANY, Normal),
+                (ANY, Normal),
+                (UU_PIN, Normal),
+                (UU_PIN, Normal),
+                (ANY, Normal),
+                (II_SOU, Rotated),
+                (II_SOU, Normal),
+                (II_SOU, Normal),
+                (II_SOU, Normal),
+            ]
+        );
+        assert_eq!(
+            placements(OPTIONS.with_face_down_closed_kans(false)),
+            vec![
+                (II_MAN, Normal),
+                (II_MAN, Normal),
+                (II_MAN, Normal),
+                (II_MAN, Normal),
+                (AKADORA_PIN, Normal),
+                (UU_PIN, Normal),
+                (UU_PIN, Normal),
+                (UU_PIN, Normal),
+                (II_SOU, Rotated),
+                (II_SOU, Normal),
+                (II_SOU, Normal),
+                (II_SOU, Normal),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_restyle_ankans() {
+        let hand = Hand::new(vec![
+            Meld::ankan(vec![HAKU; 4]).to_group(),
+            Meld::ankan(vec![HATSU; 4]).to_group_with_ankan_style(AnkanStyle::FaceDownMiddle),
+        ]);
+        let tiles = |options| {
+            Layout::new(&hand, options)
+                .tiles
+                .iter()
+                .map(|position| position.tile.tile)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            tiles(OPTIONS),
+            vec![ANY, HAKU, HAKU, ANY, HATSU, ANY, ANY, HATSU]
+        );
+        assert_eq!(
+            tiles(OPTIONS.with_ankan_style(AnkanStyle::FaceDownMiddle)),
+            vec![HAKU, ANY, ANY, HAKU, HATSU, ANY, ANY, HATSU]
+        );
+        assert_eq!(
+            tiles(OPTIONS.with_ankan_style(AnkanStyle::FaceDownEnds)),
+            vec![ANY, HAKU, HAKU, ANY, ANY, HATSU, HATSU, ANY]
+        );
+    }
+
+    #[test]
+    fn should_restyle_unmarked_ankans() {
+        let hand = HandParser::parse("1111m_5505p").unwrap();
+        let tiles = |options| {
+            Layout::new(&hand, options)
+                .tiles
+                .iter()
+                .map(|position| position.tile.tile)
+                .collect::<Vec<_>>()
+        };
+        let options = OPTIONS.with_ankan_style(AnkanStyle::FaceDownMiddle);
+
+        assert_eq!(
+            tiles(options),
+            vec![II_MAN, ANY, ANY, II_MAN, UU_PIN, ANY, ANY, UU_PIN]
+        );
+        assert_eq!(
+            tiles(options.with_face_down_closed_kans(false)),
+            vec![II_MAN, ANY, ANY, II_MAN, UU_PIN, ANY, ANY, UU_PIN]
+        );
+    }
+}
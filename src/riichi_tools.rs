@@ -0,0 +1,259 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use riichi_tools_rs::riichi::hand::Hand as RtHand;
+use riichi_tools_rs::riichi::tile::{Tile as RtTile, TileColor, TileType};
+
+use crate::TilePlacement::Normal;
+use crate::{Hand, HandTile, InvalidTileError, Suite, Tile, TileValue};
+
+#[derive(Debug, Copy, Clone)]
+/// An error that occurs when converting tiles and hands between this crate
+/// and `riichi-tools-rs`.
+pub enum RiichiToolsConversionError {
+    /// The tile has no `riichi-tools-rs` counterpart (e.g. [ANY](crate::tiles::ANY)).
+    UnsupportedTile(Tile),
+    /// The `riichi-tools-rs` tile does not describe a valid tile (e.g. a wind
+    /// with the number 0).
+    InvalidTile(InvalidTileError),
+    /// The hand contains called or rotated tiles. Only closed hands can be
+    /// converted.
+    OpenHand,
+}
+
+impl Display for RiichiToolsConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedTile(tile) => {
+                write!(f, "tile not supported by riichi-tools-rs: {}", tile)
+            }
+            Self::InvalidTile(error) => write!(f, "{}", error),
+            Self::OpenHand => write!(f, "only closed hands can be converted"),
+        }
+    }
+}
+
+impl Error for RiichiToolsConversionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidTile(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<InvalidTileError> for RiichiToolsConversionError {
+    fn from(error: InvalidTileError) -> Self {
+        Self::InvalidTile(error)
+    }
+}
+
+/// Converts a tile to a `riichi-tools-rs` tile. Red fives are converted to
+/// fives with the `is_red` flag set.
+///
+/// # Examples
+/// ```
+/// use std::convert::TryFrom;
+/// use riichi_hand::tiles::{AKADORA_SOU, ANY, CHUN};
+///
+/// let tile = riichi_tools_rs::riichi::tile::Tile::try_from(AKADORA_SOU).unwrap();
+/// assert_eq!(tile.to_string(), "0s");
+/// assert!(tile.is_red);
+/// let tile = riichi_tools_rs::riichi::tile::Tile::try_from(CHUN).unwrap();
+/// assert_eq!(tile.to_string(), "7z");
+/// assert!(riichi_tools_rs::riichi::tile::Tile::try_from(ANY).is_err());
+/// ```
+impl TryFrom<Tile> for RtTile {
+    type Error = RiichiToolsConversionError;
+
+    fn try_from(tile: Tile) -> Result<Self, Self::Error> {
+        let TileValue(value) = tile.value;
+        let color = match tile.suite {
+            Suite::Manzu => TileColor::Manzu,
+            Suite::Pinzu => TileColor::Pinzu,
+            Suite::Souzu => TileColor::Souzu,
+            Suite::Honor if value <= 4 => return Ok(RtTile::new(TileType::Wind(value))),
+            Suite::Honor => return Ok(RtTile::new(TileType::Dragon(value))),
+            Suite::Any => return Err(RiichiToolsConversionError::UnsupportedTile(tile)),
+        };
+
+        let number = if value == 0 { 5 } else { value };
+        let mut converted = RtTile::new(TileType::Number(number, color));
+        converted.is_red = value == 0;
+        Ok(converted)
+    }
+}
+
+/// Converts a `riichi-tools-rs` tile to a tile. Fives with the `is_red` flag
+/// set are converted to red fives; other flags are ignored.
+///
+/// # Examples
+/// ```
+/// use std::convert::TryFrom;
+/// use riichi_hand::tiles::{AKADORA_PIN, HATSU};
+/// use riichi_hand::Tile;
+/// use riichi_tools_rs::riichi::tile::Tile as RtTile;
+///
+/// let tile = RtTile::from_text("0p").unwrap();
+/// assert_eq!(Tile::try_from(tile).unwrap(), AKADORA_PIN);
+/// let tile = RtTile::from_text("6z").unwrap();
+/// assert_eq!(Tile::try_from(tile).unwrap(), HATSU);
+/// ```
+impl TryFrom<RtTile> for Tile {
+    type Error = RiichiToolsConversionError;
+
+    fn try_from(tile: RtTile) -> Result<Self, Self::Error> {
+        let (suite, value) = match tile.tile_type {
+            TileType::Number(5, color) if tile.is_red => (suite_from_color(color), 0),
+            TileType::Number(number, color) => (suite_from_color(color), number),
+            TileType::Wind(number) | TileType::Dragon(number) => (Suite::Honor, number),
+        };
+
+        Ok(Tile::new(suite, TileValue(value))?)
+    }
+}
+
+fn suite_from_color(color: TileColor) -> Suite {
+    match color {
+        TileColor::Manzu => Suite::Manzu,
+        TileColor::Pinzu => Suite::Pinzu,
+        TileColor::Souzu => Suite::Souzu,
+    }
+}
+
+/// Converts a closed hand to a `riichi-tools-rs` hand, ignoring the groups.
+///
+/// Returns an error if any of the tiles is not placed normally (e.g. is a part
+/// of a call) or has no `riichi-tools-rs` counterpart.
+///
+/// # Examples
+/// ```
+/// use std::convert::TryFrom;
+/// use riichi_hand::parser::HandParser;
+/// use riichi_tools_rs::riichi::hand::Hand as RtHand;
+///
+/// let hand = HandParser::parse("123m406p789s_EEE_NN").unwrap();
+/// let converted = RtHand::try_from(&hand).unwrap();
+/// assert_eq!(converted.get_tiles().len(), 14);
+/// assert!(RtHand::try_from(&HandParser::parse("123m_1*23p").unwrap()).is_err());
+/// ```
+impl TryFrom<&Hand> for RtHand {
+    type Error = RiichiToolsConversionError;
+
+    fn try_from(hand: &Hand) -> Result<Self, Self::Error> {
+        let tiles = hand
+            .hand_tiles()
+            .map(|hand_tile| {
+                if hand_tile.placement != Normal {
+                    return Err(RiichiToolsConversionError::OpenHand);
+                }
+                RtTile::try_from(hand_tile.tile).map(Some)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(RtHand::new(tiles))
+    }
+}
+
+/// Converts a closed `riichi-tools-rs` hand to a hand consisting of a single
+/// group of the tiles, in the `riichi-tools-rs` order.
+///
+/// Returns an error if the hand contains open tiles.
+///
+/// # Examples
+/// ```
+/// use std::convert::TryFrom;
+/// use riichi_hand::parser::HandParser;
+/// use riichi_hand::Hand;
+/// use riichi_tools_rs::riichi::hand::Hand as RtHand;
+///
+/// let hand = RtHand::from_text("123m406p789s1122z", false).unwrap();
+/// assert_eq!(
+///     Hand::try_from(&hand).unwrap(),
+///     HandParser::parse("123m406p789sEESS").unwrap()
+/// );
+/// ```
+impl TryFrom<&RtHand> for Hand {
+    type Error = RiichiToolsConversionError;
+
+    fn try_from(hand: &RtHand) -> Result<Self, Self::Error> {
+        let group = hand
+            .get_tiles()
+            .iter()
+            .flatten()
+            .map(|&tile| {
+                if tile.is_open {
+                    return Err(RiichiToolsConversionError::OpenHand);
+                }
+                Ok(HandTile::new(Tile::try_from(tile)?, Normal))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Hand::new(vec![group]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use riichi_tools_rs::riichi::hand::Hand as RtHand;
+    use riichi_tools_rs::riichi::tile::{Tile as RtTile, TileType};
+
+    use crate::parser::HandParser;
+    use crate::riichi_tools::RiichiToolsConversionError;
+    use crate::tiles::{ALL_TILES, ANY};
+    use crate::{Hand, Tile};
+
+    #[test]
+    fn should_round_trip_all_tiles() {
+        for &tile in ALL_TILES.iter().filter(|&&tile| tile != ANY) {
+            let converted = RtTile::try_from(tile).unwrap();
+            assert_eq!(Tile::try_from(converted).unwrap(), tile);
+            assert_eq!(
+                RtTile::from_text(&converted.to_string()).unwrap(),
+                converted
+            );
+        }
+    }
+
+    #[test]
+    fn should_reject_invalid_tiles() {
+        assert!(matches!(
+            RtTile::try_from(ANY),
+            Err(RiichiToolsConversionError::UnsupportedTile(ANY))
+        ));
+        assert!(matches!(
+            Tile::try_from(RtTile::new(TileType::Wind(0))),
+            Err(RiichiToolsConversionError::InvalidTile(_))
+        ));
+    }
+
+    #[test]
+    fn should_convert_closed_hands() {
+        let hand = HandParser::parse("5550m123p789s4s").unwrap();
+        let converted = RtHand::try_from(&hand).unwrap();
+        assert_eq!(converted.to_string(), "5550m123p4789s");
+
+        assert_eq!(
+            Hand::try_from(&converted).unwrap(),
+            HandParser::parse("5550m123p4789s").unwrap()
+        );
+    }
+
+    #[test]
+    fn should_reject_open_hands() {
+        let hand = HandParser::parse("123m_5*55p").unwrap();
+        assert!(matches!(
+            RtHand::try_from(&hand),
+            Err(RiichiToolsConversionError::OpenHand)
+        ));
+
+        let hand = RtHand::from_text("123m456p789s11z(p5z1)", false).unwrap();
+        assert!(matches!(
+            Hand::try_from(&hand),
+            Err(RiichiToolsConversionError::OpenHand)
+        ));
+    }
+}
@@ -0,0 +1,269 @@
+use alloc::vec::{self, Vec};
+use core::iter::FromIterator;
+use core::slice;
+
+use crate::TilePlacement::{Normal, Rotated};
+use crate::{HandTile, Tile};
+
+/// Number of discards in a full river row.
+pub const RIVER_ROW_LENGTH: usize = 6;
+/// Number of river rows. The last row is not limited in length, since the
+/// discards continue on it once the other rows are full.
+pub const RIVER_ROWS: usize = 3;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+/// A single discarded tile.
+pub struct Discard {
+    /// The discarded tile.
+    pub tile: Tile,
+    /// Whether the tile was discarded to declare riichi (and hence is placed
+    /// sideways).
+    pub riichi_declaration: bool,
+    /// Whether the tile was called by another player (for a chii, pon, kan or
+    /// ron).
+    pub called: bool,
+    /// Whether the discarded tile was the one just drawn (tsumogiri), rather
+    /// than a tile from the hand (tedashi).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tsumogiri: bool,
+}
+
+impl Discard {
+    #[inline]
+    #[must_use]
+    /// Creates a new discard of given tile.
+    pub const fn new(tile: Tile) -> Self {
+        Self {
+            tile,
+            riichi_declaration: false,
+            called: false,
+            tsumogiri: false,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the discard marked as a riichi declaration.
+    pub const fn riichi(self) -> Self {
+        Self {
+            riichi_declaration: true,
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the discard marked as called by another player.
+    pub const fn called(self) -> Self {
+        Self {
+            called: true,
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the discard marked as the tile that was just drawn
+    /// (tsumogiri).
+    pub const fn tsumogiri(self) -> Self {
+        Self {
+            tsumogiri: true,
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the discard as a hand tile, rotated if it is a riichi
+    /// declaration.
+    pub fn hand_tile(&self) -> HandTile {
+        let placement = if self.riichi_declaration {
+            Rotated
+        } else {
+            Normal
+        };
+
+        HandTile::new(self.tile, placement)
+    }
+}
+
+#[derive(Clone, Default, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+/// Discard pile (river) of a single player, in the order of discarding.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::river::{Discard, River};
+/// use riichi_hand::tiles::*;
+///
+/// let mut river = River::new(vec![Discard::new(PEI), Discard::new(II_MAN).tsumogiri()]);
+/// river.push(Discard::new(CHUN).riichi());
+///
+/// assert_eq!(river.len(), 3);
+/// assert_eq!(river.rows().len(), 1);
+/// assert_eq!(river.iter().filter(|discard| discard.tsumogiri).count(), 1);
+/// ```
+pub struct River {
+    discards: Vec<Discard>,
+}
+
+impl River {
+    #[inline]
+    #[must_use]
+    /// Creates a new river containing given discards.
+    pub fn new(discards: Vec<Discard>) -> Self {
+        Self { discards }
+    }
+
+    #[inline]
+    /// Adds a discard at the end of the river.
+    pub fn push(&mut self, discard: Discard) {
+        self.discards.push(discard);
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns all the discards in the river.
+    pub fn discards(&self) -> &[Discard] {
+        &self.discards
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the number of discards in the river.
+    pub fn len(&self) -> usize {
+        self.discards.len()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns whether the river contains no discards.
+    pub fn is_empty(&self) -> bool {
+        self.discards.is_empty()
+    }
+
+    #[inline]
+    /// Returns an iterator over the discards, in the order of discarding.
+    pub fn iter(&self) -> slice::Iter<'_, Discard> {
+        self.discards.iter()
+    }
+
+    /// Returns the discards split into rows, as they are laid out on the
+    /// table: [RIVER_ROW_LENGTH] discards per row, except for the last of the
+    /// [RIVER_ROWS] rows, which holds all the remaining discards. Empty rows
+    /// are omitted.
+    #[must_use]
+    pub fn rows(&self) -> Vec<&[Discard]> {
+        let mut rows = Vec::with_capacity(RIVER_ROWS);
+        let mut rest = &self.discards[..];
+        while !rest.is_empty() {
+            let length = if rows.len() == RIVER_ROWS - 1 {
+                rest.len()
+            } else {
+                rest.len().min(RIVER_ROW_LENGTH)
+            };
+            let (row, remaining) = rest.split_at(length);
+            rows.push(row);
+            rest = remaining;
+        }
+
+        rows
+    }
+}
+
+impl<'a> IntoIterator for &'a River {
+    type Item = &'a Discard;
+    type IntoIter = slice::Iter<'a, Discard>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for River {
+    type Item = Discard;
+    type IntoIter = vec::IntoIter<Discard>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.discards.into_iter()
+    }
+}
+
+impl FromIterator<Discard> for River {
+    fn from_iter<T: IntoIterator<Item = Discard>>(iter: T) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl Extend<Discard> for River {
+    fn extend<T: IntoIterator<Item = Discard>>(&mut self, iter: T) {
+        self.discards.extend(iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::river::{Discard, River};
+    use crate::tiles::*;
+    use crate::TilePlacement::{Normal, Rotated};
+
+    #[test]
+    fn should_split_river_into_rows() {
+        let river = River::new(vec![Discard::new(TON); 20]);
+        let row_lengths: Vec<_> = river.rows().iter().map(|row| row.len()).collect();
+
+        assert_eq!(row_lengths, vec![6, 6, 8]);
+        assert!(River::default().rows().is_empty());
+    }
+
+    #[test]
+    fn should_rotate_riichi_declaration() {
+        let discard = Discard::new(HAKU).riichi().called();
+
+        assert!(discard.called);
+        assert_eq!(discard.hand_tile().placement, Rotated);
+        assert_eq!(Discard::new(HAKU).hand_tile().placement, Normal);
+    }
+
+    #[test]
+    fn should_iterate_over_discards() {
+        let mut river: River = [TON, NAN].iter().map(|&tile| Discard::new(tile)).collect();
+        river.extend(vec![Discard::new(HAKU).tsumogiri().riichi()]);
+
+        assert_eq!(river.len(), 3);
+        assert!(!river.is_empty());
+        assert!(River::default().is_empty());
+        let tiles: Vec<_> = (&river).into_iter().map(|discard| discard.tile).collect();
+        assert_eq!(tiles, vec![TON, NAN, HAKU]);
+        assert_eq!(
+            river
+                .into_iter()
+                .filter(|discard| discard.tsumogiri)
+                .count(),
+            1
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_serialize_river() {
+        let river = River::new(vec![
+            Discard::new(II_PIN).tsumogiri(),
+            Discard::new(CHUN).riichi().called(),
+        ]);
+
+        let json = serde_json::to_string(&river).unwrap();
+        assert_eq!(serde_json::from_str::<River>(&json).unwrap(), river);
+        let bytes = postcard::to_allocvec(&river).unwrap();
+        assert_eq!(postcard::from_bytes::<River>(&bytes).unwrap(), river);
+
+        let json = r#"{"discards":[{"tile":{"suite":"pinzu","value":1},"riichi_declaration":false,"called":false}]}"#;
+        assert_eq!(
+            serde_json::from_str::<River>(json).unwrap(),
+            River::new(vec![Discard::new(II_PIN)])
+        );
+    }
+}
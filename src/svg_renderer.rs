@@ -0,0 +1,8 @@
+pub use renderer::{SvgRenderOptions, SvgRenderer};
+pub use tile_set::{SvgTileSet, SvgTileSetCreationError};
+
+/// Ready-to-use SVG tile sets based on FluffyStuff's tile images.
+pub mod fluffy_stuff_tile_sets;
+
+mod renderer;
+mod tile_set;
@@ -0,0 +1,95 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+
+use crate::svg_renderer::SvgTileSet;
+use crate::tiles::*;
+
+const TILE_WIDTH: u32 = 300;
+const TILE_HEIGHT: u32 = 400;
+const FOREGROUND_TILE_MARGIN: f32 = 0.05;
+
+macro_rules! load_svg_tile_map {
+    ($tile_set_name:literal) => {{
+        let mut map = HashMap::with_capacity(ALL_TILES.len());
+        load_svg_tile!(map, AKADORA_MAN, $tile_set_name, "Man5-Dora");
+        load_svg_tile!(map, II_MAN, $tile_set_name, "Man1");
+        load_svg_tile!(map, RYAN_MAN, $tile_set_name, "Man2");
+        load_svg_tile!(map, SAN_MAN, $tile_set_name, "Man3");
+        load_svg_tile!(map, SUU_MAN, $tile_set_name, "Man4");
+        load_svg_tile!(map, UU_MAN, $tile_set_name, "Man5");
+        load_svg_tile!(map, ROU_MAN, $tile_set_name, "Man6");
+        load_svg_tile!(map, CHII_MAN, $tile_set_name, "Man7");
+        load_svg_tile!(map, PAA_MAN, $tile_set_name, "Man8");
+        load_svg_tile!(map, KYUU_MAN, $tile_set_name, "Man9");
+        load_svg_tile!(map, AKADORA_PIN, $tile_set_name, "Pin5-Dora");
+        load_svg_tile!(map, II_PIN, $tile_set_name, "Pin1");
+        load_svg_tile!(map, RYAN_PIN, $tile_set_name, "Pin2");
+        load_svg_tile!(map, SAN_PIN, $tile_set_name, "Pin3");
+        load_svg_tile!(map, SUU_PIN, $tile_set_name, "Pin4");
+        load_svg_tile!(map, UU_PIN, $tile_set_name, "Pin5");
+        load_svg_tile!(map, ROU_PIN, $tile_set_name, "Pin6");
+        load_svg_tile!(map, CHII_PIN, $tile_set_name, "Pin7");
+        load_svg_tile!(map, PAA_PIN, $tile_set_name, "Pin8");
+        load_svg_tile!(map, KYUU_PIN, $tile_set_name, "Pin9");
+        load_svg_tile!(map, AKADORA_SOU, $tile_set_name, "Sou5-Dora");
+        load_svg_tile!(map, II_SOU, $tile_set_name, "Sou1");
+        load_svg_tile!(map, RYAN_SOU, $tile_set_name, "Sou2");
+        load_svg_tile!(map, SAN_SOU, $tile_set_name, "Sou3");
+        load_svg_tile!(map, SUU_SOU, $tile_set_name, "Sou4");
+        load_svg_tile!(map, UU_SOU, $tile_set_name, "Sou5");
+        load_svg_tile!(map, ROU_SOU, $tile_set_name, "Sou6");
+        load_svg_tile!(map, CHII_SOU, $tile_set_name, "Sou7");
+        load_svg_tile!(map, PAA_SOU, $tile_set_name, "Sou8");
+        load_svg_tile!(map, KYUU_SOU, $tile_set_name, "Sou9");
+        load_svg_tile!(map, TON, $tile_set_name, "Ton");
+        load_svg_tile!(map, NAN, $tile_set_name, "Nan");
+        load_svg_tile!(map, SHAA, $tile_set_name, "Shaa");
+        load_svg_tile!(map, PEI, $tile_set_name, "Pei");
+        load_svg_tile!(map, HAKU, $tile_set_name, "Haku");
+        load_svg_tile!(map, HATSU, $tile_set_name, "Hatsu");
+        load_svg_tile!(map, CHUN, $tile_set_name, "Chun");
+        load_svg_tile!(map, ANY, $tile_set_name, "Back");
+        map
+    }};
+}
+
+macro_rules! load_svg_tile {
+    ($map:expr, $tile:expr, $tile_set_name:literal, $tile_name:literal) => {
+        $map.insert($tile, load_svg_image!($tile_set_name, $tile_name));
+    };
+}
+
+macro_rules! load_svg_image {
+    ($tile_set_name:literal, $tile_name:literal) => {
+        Cow::Borrowed(include_str!(concat!(
+            "../../tilesets/FluffyStuff/",
+            $tile_set_name,
+            "/",
+            $tile_name,
+            ".svg"
+        )))
+    };
+}
+
+macro_rules! load_svg_tile_set {
+    ($tile_set_name:literal) => {{
+        let map = load_svg_tile_map!($tile_set_name);
+        let front = load_svg_image!($tile_set_name, "Front");
+        SvgTileSet::new(front, map, TILE_WIDTH, TILE_HEIGHT)
+            .expect("could not create tile set")
+            .with_foreground_margin(FOREGROUND_TILE_MARGIN)
+    }};
+}
+
+lazy_static! {
+    /// SVG tile set based on the FluffyStuff's tiles - Yellow version.
+    pub static ref YELLOW_FLUFFY_STUFF_SVG_TILE_SET: SvgTileSet = load_svg_tile_set!("Yellow");
+
+    /// SVG tile set based on the FluffyStuff's tiles - Red version.
+    pub static ref RED_FLUFFY_STUFF_SVG_TILE_SET: SvgTileSet = load_svg_tile_set!("Red");
+
+    /// SVG tile set based on the FluffyStuff's tiles - Black version.
+    pub static ref BLACK_FLUFFY_STUFF_SVG_TILE_SET: SvgTileSet = load_svg_tile_set!("Black");
+}
@@ -0,0 +1,294 @@
+use std::convert::Infallible;
+use std::fmt::Write;
+
+use crate::renderer::{HandRenderer, Layout, LayoutOptions, TilePosition};
+use crate::svg_renderer::SvgTileSet;
+use crate::tiles::ANY;
+use crate::TilePlacement::Normal;
+use crate::{Hand, Tile};
+
+#[derive(Copy, Clone, Debug)]
+/// Rendering options used with [SvgRenderer].
+pub struct SvgRenderOptions {
+    /// Gap between tiles, expressed as a fraction of tile width.
+    pub tile_gap: f32,
+    /// Gap between groups, expressed as a fraction of tile width.
+    pub group_gap: f32,
+}
+
+impl SvgRenderOptions {
+    #[inline]
+    /// Creates a new render options object instance.
+    pub fn new(tile_gap: f32, group_gap: f32) -> Self {
+        Self {
+            tile_gap,
+            group_gap,
+        }
+    }
+}
+
+impl Default for SvgRenderOptions {
+    fn default() -> Self {
+        Self::new(0.0, 1.0 / 3.0)
+    }
+}
+
+#[derive(Debug)]
+/// Renders a [Hand] instance to an SVG document.
+///
+/// The output is a standalone SVG document that embeds all the tile images
+/// used in the hand, so it does not depend on any external files.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::parser::HandParser;
+/// use riichi_hand::svg_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_SVG_TILE_SET;
+/// use riichi_hand::svg_renderer::{SvgRenderOptions, SvgRenderer};
+///
+/// let hand = HandParser::parse("123m_7*77z").unwrap();
+/// let svg = SvgRenderer::render(&hand, &*YELLOW_FLUFFY_STUFF_SVG_TILE_SET, SvgRenderOptions::default());
+/// assert!(svg.starts_with("<svg"));
+/// ```
+pub struct SvgRenderer<'a> {
+    tile_set: &'a SvgTileSet,
+    options: SvgRenderOptions,
+}
+
+const FRONT_ID: &str = "front";
+
+impl<'a> SvgRenderer<'a> {
+    #[inline]
+    /// Renders given [Hand] instance using [SvgTileSet] and
+    /// [SvgRenderOptions].
+    #[must_use]
+    pub fn render(hand: &Hand, tile_set: &'a SvgTileSet, options: SvgRenderOptions) -> String {
+        let renderer = Self::new(tile_set, options);
+        renderer.render_svg(&Layout::new(hand, renderer.layout_options()))
+    }
+
+    #[inline]
+    /// Creates a new renderer instance that uses given [SvgTileSet] and
+    /// [SvgRenderOptions]. The instance implements [HandRenderer].
+    #[must_use]
+    pub fn new(tile_set: &'a SvgTileSet, options: SvgRenderOptions) -> Self {
+        Self { tile_set, options }
+    }
+
+    fn render_svg(&self, layout: &Layout) -> String {
+        let mut used_tiles: Vec<Tile> = Vec::new();
+        for position in &layout.tiles {
+            if !used_tiles.contains(&position.tile.tile) {
+                used_tiles.push(position.tile.tile);
+            }
+        }
+
+        let mut svg = String::new();
+        write!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" \
+             xmlns:xlink=\"http://www.w3.org/1999/xlink\" \
+             width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {0} {1}\">",
+            layout.width, layout.height
+        )
+        .expect("writing to a string cannot fail");
+
+        svg.push_str("<defs>");
+        if used_tiles.iter().any(|&tile| tile != ANY) {
+            Self::write_definition(&mut svg, FRONT_ID, self.tile_set.front());
+        }
+        for (index, tile) in used_tiles.iter().enumerate() {
+            Self::write_definition(&mut svg, &Self::tile_id(index), self.tile_set.tile(tile));
+        }
+        svg.push_str("</defs>");
+
+        for position in &layout.tiles {
+            let index = used_tiles
+                .iter()
+                .position(|&tile| tile == position.tile.tile)
+                .expect("tile should be in the list of used tiles");
+            self.write_tile(&mut svg, position, &Self::tile_id(index));
+        }
+
+        svg.push_str("</svg>");
+        svg
+    }
+
+    fn write_definition(svg: &mut String, id: &str, image: &str) {
+        let start = image.find("<svg").unwrap_or(0);
+        let image = prefix_ids(&image[start..], id);
+
+        write!(svg, "<g id=\"{}\">{}</g>", id, image.trim_end())
+            .expect("writing to a string cannot fail");
+    }
+
+    fn write_tile(&self, svg: &mut String, position: &TilePosition, tile_id: &str) {
+        let (background_transform, foreground_transform) = self.tile_transforms(position);
+
+        if position.tile.tile == ANY {
+            Self::write_use(svg, tile_id, &background_transform);
+        } else {
+            let margin = self.tile_set.foreground_margin();
+            let foreground_transform = format!(
+                "{} translate({} {}) scale({})",
+                foreground_transform,
+                self.tile_set.tile_width() as f32 * margin,
+                self.tile_set.tile_height() as f32 * margin,
+                1.0 - 2.0 * margin
+            );
+
+            Self::write_use(svg, FRONT_ID, &background_transform);
+            Self::write_use(svg, tile_id, &foreground_transform);
+        }
+    }
+
+    fn write_use(svg: &mut String, id: &str, transform: &str) {
+        write!(
+            svg,
+            "<use xlink:href=\"#{}\" transform=\"{}\"/>",
+            id, transform
+        )
+        .expect("writing to a string cannot fail");
+    }
+
+    /// Returns the transforms of the tile background and foreground. Similarly
+    /// to [TwoPartTileSet](crate::raster_renderer::TwoPartTileSet), rotated
+    /// tiles have their foreground rotated clockwise, and their background
+    /// additionally mirrored, so that the light seems to come from one source.
+    fn tile_transforms(&self, position: &TilePosition) -> (String, String) {
+        let x = position.x;
+        let y = position.y;
+
+        if position.tile.placement == Normal {
+            let transform = format!("translate({} {})", x, y);
+            (transform.clone(), transform)
+        } else {
+            let background = format!("matrix(0 1 1 0 {} {})", x, y);
+            let foreground = format!("matrix(0 1 -1 0 {} {})", x + self.tile_set.tile_height(), y);
+            (background, foreground)
+        }
+    }
+
+    fn tile_id(index: usize) -> String {
+        format!("tile-{}", index)
+    }
+}
+
+impl<'a> HandRenderer for SvgRenderer<'a> {
+    type Output = String;
+    type Error = Infallible;
+
+    fn layout_options(&self) -> LayoutOptions {
+        let tile_width = self.tile_set.tile_width();
+
+        LayoutOptions::new(
+            tile_width,
+            self.tile_set.tile_height(),
+            (self.options.tile_gap * tile_width as f32) as u32,
+            (self.options.group_gap * tile_width as f32) as u32,
+        )
+    }
+
+    fn render_layout(&self, layout: &Layout) -> Result<Self::Output, Self::Error> {
+        Ok(self.render_svg(layout))
+    }
+}
+
+/// Prefixes all the element IDs (and references to them) in given SVG
+/// document, so that multiple documents can be embedded in a single file
+/// without conflicts.
+fn prefix_ids(svg: &str, prefix: &str) -> String {
+    let mut result = String::with_capacity(svg.len());
+    let mut last_end = 0;
+    for (index, _) in svg.match_indices("id=\"") {
+        let is_attribute_start = svg[..index]
+            .chars()
+            .next_back()
+            .map_or(false, char::is_whitespace);
+        if is_attribute_start {
+            let end = index + "id=\"".len();
+            result.push_str(&svg[last_end..end]);
+            result.push_str(prefix);
+            result.push('-');
+            last_end = end;
+        }
+    }
+    result.push_str(&svg[last_end..]);
+
+    result
+        .replace("url(#", &format!("url(#{}-", prefix))
+        .replace("href=\"#", &format!("href=\"#{}-", prefix))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::HandParser;
+    use crate::renderer::HandRenderer;
+    use crate::svg_renderer::fluffy_stuff_tile_sets::YELLOW_FLUFFY_STUFF_SVG_TILE_SET;
+    use crate::svg_renderer::renderer::prefix_ids;
+    use crate::svg_renderer::{SvgRenderOptions, SvgRenderer};
+
+    #[test]
+    fn should_render_hand() {
+        let hand = HandParser::parse("123m_7*77z_????").unwrap();
+        let svg = SvgRenderer::render(
+            &hand,
+            &YELLOW_FLUFFY_STUFF_SVG_TILE_SET,
+            SvgRenderOptions::default(),
+        );
+
+        assert!(svg.contains("width=\"3300\" height=\"400\""));
+        assert_eq!(svg.matches("<use ").count(), 2 * 3 + 2 * 3 + 4);
+
+        let tree = usvg::Tree::from_str(
+            &svg,
+            &usvg::Options::default(),
+            &usvg::fontdb::Database::new(),
+        )
+        .unwrap();
+        assert_eq!(tree.size().width(), 3300.0);
+        assert_eq!(tree.size().height(), 400.0);
+    }
+
+    #[test]
+    fn should_render_hand_using_hand_renderer_trait() {
+        let hand = HandParser::parse("123m_7*77z").unwrap();
+        let renderer = SvgRenderer::new(
+            &YELLOW_FLUFFY_STUFF_SVG_TILE_SET,
+            SvgRenderOptions::default(),
+        );
+
+        assert_eq!(
+            renderer.render_hand(&hand).unwrap(),
+            SvgRenderer::render(
+                &hand,
+                &YELLOW_FLUFFY_STUFF_SVG_TILE_SET,
+                SvgRenderOptions::default()
+            )
+        );
+    }
+
+    #[test]
+    fn should_render_empty_hand() {
+        let hand = HandParser::parse("").unwrap();
+        let svg = SvgRenderer::render(
+            &hand,
+            &YELLOW_FLUFFY_STUFF_SVG_TILE_SET,
+            SvgRenderOptions::default(),
+        );
+
+        assert!(svg.contains("width=\"0\" height=\"0\""));
+        assert!(!svg.contains("<use "));
+    }
+
+    #[test]
+    fn should_prefix_ids() {
+        let svg = "<svg id=\"a\" inkscape:pageid=\"b\">\
+                   <rect\n   id=\"c\" fill=\"url(#d)\"/><use xlink:href=\"#e\"/></svg>";
+
+        assert_eq!(
+            prefix_ids(svg, "p"),
+            "<svg id=\"p-a\" inkscape:pageid=\"b\">\
+             <rect\n   id=\"p-c\" fill=\"url(#p-d)\"/><use xlink:href=\"#p-e\"/></svg>"
+        );
+    }
+}
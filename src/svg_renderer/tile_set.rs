@@ -0,0 +1,189 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::tiles::{ALL_TILES, BLANK, UNKNOWN};
+use crate::Tile;
+
+#[derive(Copy, Clone, Debug)]
+/// An error that occurs when creating a [SvgTileSet].
+pub enum SvgTileSetCreationError {
+    /// There is a tile missing in the tile map.
+    TileMissing(Tile),
+    /// A tile image is not a valid SVG document (i.e. it does not contain an
+    /// `<svg>` element).
+    InvalidSvg(Tile),
+}
+
+impl Error for SvgTileSetCreationError {}
+
+impl Display for SvgTileSetCreationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SvgTileSetCreationError::TileMissing(tile) => {
+                write!(f, "tile foreground missing: {}", tile)
+            }
+            SvgTileSetCreationError::InvalidSvg(tile) => {
+                write!(f, "tile image is not a valid SVG document: {}", tile)
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+/// Set of SVG tile images that can be used to render a hand using
+/// [SvgRenderer](super::SvgRenderer).
+///
+/// Similarly to [TwoPartTileSet](crate::raster_renderer::TwoPartTileSet), the
+/// tile set consists of a single tile front (background) image and a map of
+/// tile foregrounds. [ANY](crate::tiles::ANY) tile is treated as a tile back
+/// and is rendered without the front image.
+///
+/// The foregrounds of [BLANK] and [UNKNOWN] tiles are optional; if they are
+/// missing, blank tiles are rendered as just the tile fronts, and unknown
+/// tiles are rendered with a plain `?` symbol.
+///
+/// All the images are expected to be SVG documents of the same dimensions.
+pub struct SvgTileSet {
+    front: Cow<'static, str>,
+    tile_map: HashMap<Tile, Cow<'static, str>>,
+    tile_width: u32,
+    tile_height: u32,
+    foreground_margin: f32,
+}
+
+impl SvgTileSet {
+    /// Creates a new [SvgTileSet] instance using given front image, a map of
+    /// tile foregrounds, and the dimensions of a single tile.
+    pub fn new(
+        front: Cow<'static, str>,
+        mut tile_map: HashMap<Tile, Cow<'static, str>>,
+        tile_width: u32,
+        tile_height: u32,
+    ) -> Result<Self, SvgTileSetCreationError> {
+        tile_map.entry(BLANK).or_insert_with(|| {
+            Cow::Owned(format!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\"/>",
+                tile_width, tile_height
+            ))
+        });
+        tile_map.entry(UNKNOWN).or_insert_with(|| {
+            Cow::Owned(format!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+                 viewBox=\"0 0 30 40\"><path d=\"M10 14a5 5 0 1 1 7 4.6c-1.3.6-2 1.6-2 3V24\" \
+                 fill=\"none\" stroke=\"#000\" stroke-width=\"3\" stroke-linecap=\"round\"/>\
+                 <circle cx=\"15\" cy=\"29.5\" r=\"1.8\"/></svg>",
+                tile_width, tile_height
+            ))
+        });
+        Self::validate_tile_map(&tile_map)?;
+
+        Ok(Self {
+            front,
+            tile_map,
+            tile_width,
+            tile_height,
+            foreground_margin: 0.0,
+        })
+    }
+
+    /// Returns a tile set that shrinks the tile foregrounds by given fraction
+    /// of the tile dimensions on each side.
+    #[must_use]
+    pub fn with_foreground_margin(self, foreground_margin: f32) -> Self {
+        Self {
+            foreground_margin,
+            ..self
+        }
+    }
+
+    fn validate_tile_map(
+        tile_map: &HashMap<Tile, Cow<'static, str>>,
+    ) -> Result<(), SvgTileSetCreationError> {
+        for &tile in ALL_TILES.iter().chain(&[BLANK, UNKNOWN]) {
+            match tile_map.get(&tile) {
+                None => return Err(SvgTileSetCreationError::TileMissing(tile)),
+                Some(svg) if !svg.contains("<svg") => {
+                    return Err(SvgTileSetCreationError::InvalidSvg(tile))
+                }
+                Some(_) => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the SVG document of the tile front (background).
+    #[inline]
+    #[must_use]
+    pub fn front(&self) -> &str {
+        &self.front
+    }
+
+    /// Returns the SVG document of given tile's foreground (or the tile back
+    /// for [ANY](crate::tiles::ANY)).
+    #[inline]
+    #[must_use]
+    pub fn tile(&self, tile: &Tile) -> &str {
+        &self.tile_map[tile]
+    }
+
+    /// Returns tile width, in SVG user units.
+    #[inline]
+    #[must_use]
+    pub fn tile_width(&self) -> u32 {
+        self.tile_width
+    }
+
+    /// Returns tile height, in SVG user units.
+    #[inline]
+    #[must_use]
+    pub fn tile_height(&self) -> u32 {
+        self.tile_height
+    }
+
+    /// Returns the margin of the tile foregrounds, as a fraction of the tile
+    /// dimensions.
+    #[inline]
+    #[must_use]
+    pub fn foreground_margin(&self) -> f32 {
+        self.foreground_margin
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::collections::HashMap;
+
+    use crate::svg_renderer::{SvgTileSet, SvgTileSetCreationError};
+    use crate::tiles::{ALL_TILES, CHUN};
+
+    const SVG: &str = "<svg width=\"3\" height=\"4\"></svg>";
+
+    #[test]
+    fn should_return_tile_missing_error() {
+        let result = SvgTileSet::new(Cow::Borrowed(SVG), HashMap::new(), 3, 4);
+
+        assert!(matches!(
+            result.unwrap_err(),
+            SvgTileSetCreationError::TileMissing(_)
+        ));
+    }
+
+    #[test]
+    fn should_return_invalid_svg_error() {
+        let mut map: HashMap<_, _> = ALL_TILES
+            .iter()
+            .map(|&tile| (tile, Cow::Borrowed(SVG)))
+            .collect();
+        map.insert(CHUN, Cow::Borrowed("not an svg"));
+        let result = SvgTileSet::new(Cow::Borrowed(SVG), map, 3, 4);
+
+        assert!(matches!(
+            result.unwrap_err(),
+            SvgTileSetCreationError::InvalidSvg(CHUN)
+        ));
+    }
+}
@@ -0,0 +1,65 @@
+use alloc::vec::Vec;
+
+use crate::river::River;
+use crate::{Hand, Tile};
+
+/// Number of the players at the table.
+pub const PLAYER_COUNT: usize = 4;
+
+#[derive(Clone, Default, Debug, Eq, PartialEq, Hash)]
+/// Snapshot of the whole table: the hands and rivers of all the players, and
+/// the dora indicators.
+///
+/// The players are ordered counter-clockwise, starting with the player at the
+/// bottom of the table (i.e. the bottom player, the player on the right, the
+/// player at the top, and the player on the left), which is the order of
+/// turns.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::parser::HandParser;
+/// use riichi_hand::table::Table;
+/// use riichi_hand::tiles::II_MAN;
+///
+/// let mut table = Table::default();
+/// table.hands[0] = HandParser::parse("123m456p789s1122z").unwrap();
+/// table.dora_indicators = vec![II_MAN];
+/// table.revealed_dora = 1;
+/// ```
+pub struct Table {
+    /// Hands of the players.
+    pub hands: [Hand; PLAYER_COUNT],
+    /// Discard piles of the players.
+    pub rivers: [River; PLAYER_COUNT],
+    /// Dora indicators, including the ones that are not revealed yet.
+    pub dora_indicators: Vec<Tile>,
+    /// Number of the revealed dora indicators.
+    pub revealed_dora: usize,
+}
+
+impl Table {
+    #[inline]
+    #[must_use]
+    /// Creates a new table snapshot.
+    pub fn new(
+        hands: [Hand; PLAYER_COUNT],
+        rivers: [River; PLAYER_COUNT],
+        dora_indicators: Vec<Tile>,
+        revealed_dora: usize,
+    ) -> Self {
+        Self {
+            hands,
+            rivers,
+            dora_indicators,
+            revealed_dora,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the dead wall strip with the dora indicators (see
+    /// [Hand::dora_wall]).
+    pub fn dora_wall(&self) -> Hand {
+        Hand::dora_wall(&self.dora_indicators, self.revealed_dora)
+    }
+}
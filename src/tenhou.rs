@@ -0,0 +1,1177 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::game::Wind;
+use crate::meld::{Meld, MeldKind, RelativeSeat};
+use crate::points::{Fu, Han, Honbas, WinMethod};
+use crate::TilePlacement::Normal;
+use crate::{Hand, HandGroup, HandTile, Suite, Tile, TileValue};
+
+/// Number of the tile ids used by Tenhou (4 copies of 34 distinct tiles).
+pub const TILE_ID_COUNT: u32 = 136;
+/// Tenhou ids of the tiles that are the red fives (when playing with red
+/// fives): the first copies of 5m, 5p and 5s.
+const RED_FIVE_IDS: [u32; 3] = [16, 52, 88];
+/// Flag of the `GO` tag `type` attribute denoting a game without red fives.
+const NO_RED_FIVES_FLAG: u32 = 0x02;
+/// Number of han a single yakuman is scored as.
+const YAKUMAN_HAN: i32 = 13;
+/// Tenhou stores the scores in hundreds of points.
+const SCORE_UNIT: i32 = 100;
+/// Address of Tenhou's hand viewer and analyzer, taking the hand as the `q`
+/// query parameter.
+const HAND_VIEWER_URL: &str = "https://tenhou.net/2/?q=";
+
+/// Converts given Tenhou tile id (0 to 135) to a tile, or returns `None` if
+/// the id is out of range.
+///
+/// The ids are assigned in the order of manzu, pinzu, souzu, and the honors
+/// (winds, then dragons), with 4 consecutive ids for each tile. When playing
+/// with red fives, the first copy of each five is the red one.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::tenhou::tile_from_id;
+/// use riichi_hand::tiles::{AKADORA_PIN, CHUN, II_MAN, UU_PIN};
+///
+/// assert_eq!(tile_from_id(0, true), Some(II_MAN));
+/// assert_eq!(tile_from_id(52, true), Some(AKADORA_PIN));
+/// assert_eq!(tile_from_id(52, false), Some(UU_PIN));
+/// assert_eq!(tile_from_id(135, true), Some(CHUN));
+/// assert_eq!(tile_from_id(136, true), None);
+/// ```
+#[must_use]
+pub fn tile_from_id(id: u32, red_fives: bool) -> Option<Tile> {
+    if id >= TILE_ID_COUNT {
+        return None;
+    }
+    if red_fives && RED_FIVE_IDS.contains(&id) {
+        let suite = [Suite::Manzu, Suite::Pinzu, Suite::Souzu][(id / 36) as usize];
+        return Some(Tile {
+            suite,
+            value: TileValue(0),
+        });
+    }
+
+    let kind = id / 4;
+    let (suite, value) = match kind / 9 {
+        0 => (Suite::Manzu, kind % 9 + 1),
+        1 => (Suite::Pinzu, kind % 9 + 1),
+        2 => (Suite::Souzu, kind % 9 + 1),
+        _ => (Suite::Honor, kind - 27 + 1),
+    };
+
+    Some(Tile {
+        suite,
+        value: TileValue(value as u8),
+    })
+}
+
+impl Hand {
+    /// Converts the tiles of the hand (ignoring groups and tile placements) to
+    /// Tenhou tile ids (see [tile_from_id]), or returns `None` if the hand
+    /// contains a tile that is not a real tile (e.g.
+    /// [ANY](crate::tiles::ANY)) or more copies of a tile than there are in
+    /// the game.
+    ///
+    /// The copies of each tile are assigned in order, starting from the
+    /// lowest id. Red fives always get the id of the red copy, and the
+    /// regular fives use it only when all the other copies are taken.
+    ///
+    /// This method is only available with the `tenhou` feature enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// use riichi_hand::parser::HandParser;
+    ///
+    /// let hand = HandParser::parse("1105m_7z").unwrap();
+    /// assert_eq!(hand.to_tenhou_ids().unwrap(), vec![0, 1, 16, 17, 132]);
+    /// assert_eq!(HandParser::parse("11111m").unwrap().to_tenhou_ids(), None);
+    /// ```
+    #[must_use]
+    pub fn to_tenhou_ids(&self) -> Option<Vec<u32>> {
+        let mut used = [false; TILE_ID_COUNT as usize];
+        self.tiles()
+            .map(|tile| {
+                let id = free_tile_id(tile, &used)?;
+                used[id as usize] = true;
+                Some(id)
+            })
+            .collect()
+    }
+}
+
+/// Returns the lowest unused Tenhou id of given tile, taking the red fives
+/// into account.
+fn free_tile_id(tile: Tile, used: &[bool]) -> Option<u32> {
+    let TileValue(value) = tile.value;
+    let kind = match tile.suite {
+        Suite::Manzu | Suite::Pinzu | Suite::Souzu if value == 0 => {
+            let id = RED_FIVE_IDS[suite_index(tile.suite)];
+            return Some(id).filter(|&id| !used[id as usize]);
+        }
+        Suite::Manzu | Suite::Pinzu | Suite::Souzu => {
+            suite_index(tile.suite) as u32 * 9 + u32::from(value) - 1
+        }
+        Suite::Honor => 27 + u32::from(value) - 1,
+        Suite::Any => return None,
+    };
+
+    let first_id = kind * 4;
+    let mut ids: Vec<u32> = (first_id..first_id + 4).collect();
+    if RED_FIVE_IDS.contains(&first_id) {
+        ids.rotate_left(1);
+    }
+
+    ids.into_iter().find(|&id| !used[id as usize])
+}
+
+fn suite_index(suite: Suite) -> usize {
+    match suite {
+        Suite::Manzu => 0,
+        Suite::Pinzu => 1,
+        _ => 2,
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+/// An error that occurs when parsing a Tenhou game log.
+pub enum TenhouParseError {
+    /// The log is not a well-formed mjlog document (e.g. a tag is not
+    /// closed). Contains the byte offset of the malformed tag.
+    InvalidXml(usize),
+    /// A required attribute of a tag is missing.
+    MissingAttribute {
+        /// Name of the tag.
+        tag: String,
+        /// Name of the missing attribute.
+        attribute: &'static str,
+    },
+    /// An attribute of a tag has an invalid value (e.g. a tile id out of
+    /// range).
+    InvalidAttribute {
+        /// Name of the tag.
+        tag: String,
+        /// Name of the attribute.
+        attribute: &'static str,
+        /// Value of the attribute.
+        value: String,
+    },
+}
+
+impl Error for TenhouParseError {}
+
+impl Display for TenhouParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidXml(position) => write!(f, "malformed tag at position {}", position),
+            Self::MissingAttribute { tag, attribute } => {
+                write!(f, "tag {} is missing attribute {}", tag, attribute)
+            }
+            Self::InvalidAttribute {
+                tag,
+                attribute,
+                value,
+            } => write!(
+                f,
+                "invalid value of attribute {} of tag {}: {}",
+                attribute, tag, value
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+/// A call made by a player: a meld, or a nukidora.
+pub struct Call {
+    /// The meld, or `None` if the call is a nukidora (a north tile set aside
+    /// as a dora in three-player games), which Tenhou records as a call.
+    pub meld: Option<Meld>,
+    /// Tiles of the call, placed as they are displayed on the table (see
+    /// [Meld::to_group]). A nukidora is a single tile.
+    pub group: HandGroup,
+    /// The player the tile was called from, if any.
+    pub from_player: Option<usize>,
+}
+
+impl Call {
+    /// Returns whether the call is a nukidora rather than a meld.
+    #[inline]
+    #[must_use]
+    pub fn is_nukidora(&self) -> bool {
+        self.meld.is_none()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+/// Start of a round (hand), with the starting hands of the players.
+pub struct RoundStart {
+    /// Round wind.
+    pub round_wind: Wind,
+    /// Number of the hand in the round, from 1 to 4.
+    pub hand_number: u8,
+    /// Number of honbas (repeat counters).
+    pub honbas: Honbas,
+    /// Number of riichi sticks left on the table from the previous hands.
+    pub riichi_sticks: u32,
+    /// The first dora indicator.
+    pub dora_indicator: Tile,
+    /// The dealer.
+    pub dealer: usize,
+    /// Scores of the players at the start of the round.
+    pub scores: [i32; 4],
+    /// Starting hands of the players (empty for the missing player in
+    /// three-player games), in Tenhou's order.
+    pub hands: [Vec<Tile>; 4],
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+/// A win (agari) ending the round.
+pub struct Agari {
+    /// The player who won.
+    pub winner: usize,
+    /// The player who discarded the winning tile, or the winner for a win by
+    /// tsumo.
+    pub from_player: usize,
+    /// The way the hand was won.
+    pub win_method: WinMethod,
+    /// Hand of the winner: the closed tiles without the winning tile, the
+    /// winning tile, and the calls, in the order they were made, as separate
+    /// groups.
+    pub hand: Hand,
+    /// The winning tile.
+    pub win_tile: Tile,
+    /// Tenhou ids of the yaku, along with the number of han of each.
+    pub yaku: Vec<(u32, Han)>,
+    /// Tenhou ids of the yakuman, if any.
+    pub yakuman: Vec<u32>,
+    /// Total number of han (13 per yakuman for yakuman hands).
+    pub han: Han,
+    /// Number of fu.
+    pub fu: Fu,
+    /// Number of points the hand is worth, without the honbas and riichi
+    /// sticks.
+    pub points: i32,
+    /// Dora indicators.
+    pub dora_indicators: Vec<Tile>,
+    /// Ura dora indicators, if revealed.
+    pub ura_dora_indicators: Vec<Tile>,
+    /// Changes of the scores of the players, including the honbas and
+    /// riichi sticks.
+    pub score_changes: [i32; 4],
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+/// A draw (ryuukyoku) ending the round.
+pub struct Ryuukyoku {
+    /// Tenhou name of the abortive draw (e.g. `yao9` for kyuushu kyuuhai, or
+    /// `nm` for nagashi mangan), or `None` for an exhaustive draw.
+    pub kind: Option<String>,
+    /// Whether each of the players revealed a tenpai hand.
+    pub tenpai: [bool; 4],
+    /// Changes of the scores of the players (e.g. the noten penalties).
+    pub score_changes: [i32; 4],
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+/// A game event read from a Tenhou game log.
+///
+/// The players are identified by their indices in the log (0 to 3), which
+/// is the seating order, starting with the first dealer.
+pub enum TenhouEvent {
+    /// Names of the players.
+    Players([String; 4]),
+    /// Start of a round.
+    RoundStart(RoundStart),
+    /// A tile drawn by a player.
+    Draw {
+        /// The player.
+        player: usize,
+        /// The drawn tile.
+        tile: Tile,
+    },
+    /// A tile discarded by a player.
+    Discard {
+        /// The player.
+        player: usize,
+        /// The discarded tile.
+        tile: Tile,
+        /// Whether the discarded tile is the tile the player has just drawn
+        /// (tsumogiri).
+        tsumogiri: bool,
+    },
+    /// A call made by a player.
+    Call {
+        /// The player.
+        player: usize,
+        /// The call.
+        call: Call,
+    },
+    /// Riichi declared by a player.
+    Riichi {
+        /// The player.
+        player: usize,
+        /// Whether the riichi is accepted (i.e. the declaration tile was not
+        /// called for a ron and the riichi stick was placed), rather than
+        /// just declared.
+        accepted: bool,
+    },
+    /// A new dora indicator revealed.
+    Dora(Tile),
+    /// A win ending the round.
+    Agari(Agari),
+    /// A draw ending the round.
+    Ryuukyoku(Ryuukyoku),
+}
+
+/// A single tag of an mjlog document: its name and its attributes.
+struct Tag<'a> {
+    name: &'a str,
+    attributes: Vec<(&'a str, String)>,
+}
+
+impl Tag<'_> {
+    fn get(&self, attribute: &'static str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(name, _)| *name == attribute)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn required(&self, attribute: &'static str) -> Result<&str, TenhouParseError> {
+        self.get(attribute)
+            .ok_or_else(|| TenhouParseError::MissingAttribute {
+                tag: self.name.to_owned(),
+                attribute,
+            })
+    }
+
+    fn invalid(&self, attribute: &'static str) -> TenhouParseError {
+        TenhouParseError::InvalidAttribute {
+            tag: self.name.to_owned(),
+            attribute,
+            value: self.get(attribute).unwrap_or_default().to_owned(),
+        }
+    }
+
+    /// Returns given attribute parsed as a comma-separated list of numbers
+    /// (empty if the attribute is missing).
+    fn numbers<T: std::str::FromStr>(
+        &self,
+        attribute: &'static str,
+    ) -> Result<Vec<T>, TenhouParseError> {
+        match self.get(attribute) {
+            None | Some("") => Ok(Vec::new()),
+            Some(value) => value
+                .split(',')
+                .map(|number| number.trim().parse().map_err(|_| self.invalid(attribute)))
+                .collect(),
+        }
+    }
+
+    fn number<T: std::str::FromStr>(&self, attribute: &'static str) -> Result<T, TenhouParseError> {
+        self.required(attribute)?
+            .trim()
+            .parse()
+            .map_err(|_| self.invalid(attribute))
+    }
+
+    fn player(&self, attribute: &'static str) -> Result<usize, TenhouParseError> {
+        let player = self.number(attribute)?;
+        if player < 4 {
+            Ok(player)
+        } else {
+            Err(self.invalid(attribute))
+        }
+    }
+
+    /// Returns the score changes stored in given attribute as pairs of the
+    /// scores and the changes, in hundreds of points.
+    fn score_changes(&self, attribute: &'static str) -> Result<[i32; 4], TenhouParseError> {
+        let numbers: Vec<i32> = self.numbers(attribute)?;
+        let mut changes = [0; 4];
+        for (change, pair) in changes.iter_mut().zip(numbers.chunks(2)) {
+            *change = pair.get(1).ok_or_else(|| self.invalid(attribute))? * SCORE_UNIT;
+        }
+
+        Ok(changes)
+    }
+}
+
+/// Decodes the XML entities used in the attribute values.
+fn unescape(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Decodes a percent-encoded (UTF-8) string, as used for the player names.
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let hex = value
+            .get(index + 1..index + 3)
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[index], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                index += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Splits an mjlog document into its (opening or self-closing) tags,
+/// skipping the closing tags, comments and processing instructions.
+///
+/// The mjlog documents are flat lists of tags with attributes, so no other
+/// XML features are supported.
+fn tags(xml: &str) -> Result<Vec<Tag<'_>>, TenhouParseError> {
+    let mut tags = Vec::new();
+    let mut rest = xml;
+    while let Some(start) = rest.find('<') {
+        let position = xml.len() - rest.len() + start;
+        let tag = &rest[start + 1..];
+        let end = tag
+            .find('>')
+            .ok_or(TenhouParseError::InvalidXml(position))?;
+        rest = &tag[end + 1..];
+        let tag = tag[..end].trim_end_matches('/');
+        if tag.starts_with(['/', '?', '!']) {
+            continue;
+        }
+
+        let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+        let name = &tag[..name_end];
+        let mut attributes = Vec::new();
+        let mut attribute_rest = tag[name_end..].trim_start();
+        while !attribute_rest.is_empty() {
+            let (attribute, value) = attribute_rest
+                .split_once("=\"")
+                .ok_or(TenhouParseError::InvalidXml(position))?;
+            let (value, remaining) = value
+                .split_once('"')
+                .ok_or(TenhouParseError::InvalidXml(position))?;
+            attributes.push((attribute.trim(), unescape(value)));
+            attribute_rest = remaining.trim_start();
+        }
+        tags.push(Tag { name, attributes });
+    }
+
+    Ok(tags)
+}
+
+/// Returns the player `offset` seats after given player.
+fn relative_player(player: usize, offset: u32) -> usize {
+    (player + offset as usize) % 4
+}
+
+/// Parser state of a single game log.
+struct Parser {
+    red_fives: bool,
+    last_draws: [Option<u32>; 4],
+}
+
+impl Parser {
+    fn tile(
+        &self,
+        tag: &Tag<'_>,
+        attribute: &'static str,
+        id: u32,
+    ) -> Result<Tile, TenhouParseError> {
+        tile_from_id(id, self.red_fives).ok_or_else(|| tag.invalid(attribute))
+    }
+
+    fn tiles(&self, tag: &Tag<'_>, attribute: &'static str) -> Result<Vec<Tile>, TenhouParseError> {
+        tag.numbers(attribute)?
+            .into_iter()
+            .map(|id| self.tile(tag, attribute, id))
+            .collect()
+    }
+
+    fn parse_tag(&mut self, tag: &Tag<'_>) -> Result<Option<TenhouEvent>, TenhouParseError> {
+        let mut chars = tag.name.chars();
+        let first = chars.next().unwrap_or_default();
+        if !chars.as_str().is_empty() && chars.as_str().bytes().all(|c| c.is_ascii_digit()) {
+            if let Some(event) = self.parse_draw_or_discard(tag, first)? {
+                return Ok(Some(event));
+            }
+        }
+
+        let event = match tag.name {
+            "GO" => {
+                let game_type: u32 = tag.number("type")?;
+                self.red_fives = game_type & NO_RED_FIVES_FLAG == 0;
+                None
+            }
+            "UN" if tag.get("n0").is_some() => {
+                let name = |attribute| percent_decode(tag.get(attribute).unwrap_or_default());
+                Some(TenhouEvent::Players([
+                    name("n0"),
+                    name("n1"),
+                    name("n2"),
+                    name("n3"),
+                ]))
+            }
+            "INIT" => Some(TenhouEvent::RoundStart(self.parse_init(tag)?)),
+            "N" => {
+                let player = tag.player("who")?;
+                let meld = tag.number("m")?;
+                let call = self.decode_call(tag, "m", player, meld)?;
+                Some(TenhouEvent::Call { player, call })
+            }
+            "REACH" => Some(TenhouEvent::Riichi {
+                player: tag.player("who")?,
+                accepted: tag.number::<u32>("step")? == 2,
+            }),
+            "DORA" => {
+                let id = tag.number("hai")?;
+                Some(TenhouEvent::Dora(self.tile(tag, "hai", id)?))
+            }
+            "AGARI" => Some(TenhouEvent::Agari(self.parse_agari(tag)?)),
+            "RYUUKYOKU" => {
+                let mut tenpai = [false; 4];
+                for (player, attribute) in ["hai0", "hai1", "hai2", "hai3"].iter().enumerate() {
+                    tenpai[player] = tag.get(attribute).is_some();
+                }
+                Some(TenhouEvent::Ryuukyoku(Ryuukyoku {
+                    kind: tag.get("type").map(str::to_owned),
+                    tenpai,
+                    score_changes: tag.score_changes("sc")?,
+                }))
+            }
+            _ => None,
+        };
+
+        Ok(event)
+    }
+
+    /// Parses the draw (`T`, `U`, `V`, `W`) and discard (`D`, `E`, `F`, `G`)
+    /// tags, which consist of the letter denoting the player and the tile id.
+    fn parse_draw_or_discard(
+        &mut self,
+        tag: &Tag<'_>,
+        letter: char,
+    ) -> Result<Option<TenhouEvent>, TenhouParseError> {
+        let id = tag.name[1..].parse().map_err(|_| tag.invalid("id"))?;
+        let tile = self.tile(tag, "id", id)?;
+        if let Some(player) = "TUVW".find(letter) {
+            self.last_draws[player] = Some(id);
+            return Ok(Some(TenhouEvent::Draw { player, tile }));
+        }
+        if let Some(player) = "DEFG".find(letter) {
+            let tsumogiri = self.last_draws[player].take() == Some(id);
+            return Ok(Some(TenhouEvent::Discard {
+                player,
+                tile,
+                tsumogiri,
+            }));
+        }
+
+        Ok(None)
+    }
+
+    fn parse_init(&mut self, tag: &Tag<'_>) -> Result<RoundStart, TenhouParseError> {
+        self.last_draws = [None; 4];
+        let seed: Vec<u32> = tag.numbers("seed")?;
+        if seed.len() != 6 {
+            return Err(tag.invalid("seed"));
+        }
+        let scores: Vec<i32> = tag.numbers("ten")?;
+        let mut round_scores = [0; 4];
+        for (score, value) in round_scores.iter_mut().zip(scores) {
+            *score = value * SCORE_UNIT;
+        }
+
+        Ok(RoundStart {
+            round_wind: Wind::ALL[(seed[0] / 4 % 4) as usize],
+            hand_number: (seed[0] % 4 + 1) as u8,
+            honbas: Honbas::new(seed[1] as i32),
+            riichi_sticks: seed[2],
+            dora_indicator: self.tile(tag, "seed", seed[5])?,
+            dealer: tag.player("oya")?,
+            scores: round_scores,
+            hands: [
+                self.tiles(tag, "hai0")?,
+                self.tiles(tag, "hai1")?,
+                self.tiles(tag, "hai2")?,
+                self.tiles(tag, "hai3")?,
+            ],
+        })
+    }
+
+    fn parse_agari(&self, tag: &Tag<'_>) -> Result<Agari, TenhouParseError> {
+        let winner = tag.player("who")?;
+        let from_player = tag.player("fromWho")?;
+        let win_id: u32 = tag.number("machi")?;
+        let win_tile = self.tile(tag, "machi", win_id)?;
+
+        let mut closed_ids: Vec<u32> = tag.numbers("hai")?;
+        let win_index = closed_ids
+            .iter()
+            .position(|&id| id == win_id)
+            .ok_or_else(|| tag.invalid("machi"))?;
+        closed_ids.remove(win_index);
+        let closed = closed_ids
+            .into_iter()
+            .map(|id| Ok(HandTile::new(self.tile(tag, "hai", id)?, Normal)))
+            .collect::<Result<HandGroup, TenhouParseError>>()?;
+        let mut groups = vec![closed, vec![HandTile::new(win_tile, Normal)]];
+        for meld in tag.numbers("m")? {
+            groups.push(self.decode_call(tag, "m", winner, meld)?.group);
+        }
+
+        let yaku_numbers: Vec<u32> = tag.numbers("yaku")?;
+        let yaku: Vec<_> = yaku_numbers
+            .chunks(2)
+            .map(|pair| match pair {
+                [id, han] => Ok((*id, Han::new(*han as i32))),
+                _ => Err(tag.invalid("yaku")),
+            })
+            .collect::<Result<_, _>>()?;
+        let yakuman: Vec<u32> = tag.numbers("yakuman")?;
+        let han = if yakuman.is_empty() {
+            yaku.iter().map(|(_, han)| han.get()).sum()
+        } else {
+            YAKUMAN_HAN * yakuman.len() as i32
+        };
+        let ten: Vec<i32> = tag.numbers("ten")?;
+        if ten.len() < 2 {
+            return Err(tag.invalid("ten"));
+        }
+
+        Ok(Agari {
+            winner,
+            from_player,
+            win_method: if winner == from_player {
+                WinMethod::Tsumo
+            } else {
+                WinMethod::Ron
+            },
+            hand: Hand::new(groups),
+            win_tile,
+            yaku,
+            yakuman,
+            han: Han::new(han),
+            fu: Fu::new(ten[0]),
+            points: ten[1],
+            dora_indicators: self.tiles(tag, "doraHai")?,
+            ura_dora_indicators: self.tiles(tag, "doraHaiUra")?,
+            score_changes: tag.score_changes("sc")?,
+        })
+    }
+
+    /// Decodes a call of given player stored in Tenhou's bit field format.
+    fn decode_call(
+        &self,
+        tag: &Tag<'_>,
+        attribute: &'static str,
+        player: usize,
+        meld: u32,
+    ) -> Result<Call, TenhouParseError> {
+        let from = meld & 0x3;
+        let from_player = relative_player(player, from);
+        let from_seat = RelativeSeat::between(player, from_player);
+        let tile = |id: u32| self.tile(tag, attribute, id);
+        let tiles = |ids: &[u32]| {
+            ids.iter()
+                .map(|&id| tile(id))
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        if meld & 0x4 != 0 {
+            // chii: the lowest tile and the called tile index, and the copy
+            // of each tile
+            let encoded = meld >> 10;
+            let called = (encoded % 3) as usize;
+            let base = encoded / 3;
+            let base_kind = base / 7 * 9 + base % 7;
+            let ids: Vec<u32> = (0..3)
+                .map(|index| (base_kind + index) * 4 + ((meld >> (3 + 2 * index)) & 0x3))
+                .collect();
+            let called_id = ids[called];
+            let others: Vec<u32> = ids.into_iter().filter(|&id| id != called_id).collect();
+            let meld = Meld::new(
+                MeldKind::Chii,
+                tiles(&others)?,
+                Some(tile(called_id)?),
+                Some(RelativeSeat::Left),
+            );
+
+            return Ok(Call {
+                group: meld.to_group(),
+                meld: Some(meld),
+                from_player: Some(from_player),
+            });
+        }
+
+        if meld & 0x18 != 0 {
+            // pon or shouminkan: the tile and the called tile index, and the
+            // copy that is not used (pon) or that is added (shouminkan)
+            let encoded = meld >> 9;
+            let called = (encoded % 3) as usize;
+            let kind = encoded / 3;
+            let other = (meld >> 5) & 0x3;
+            let ids: Vec<u32> = (0..4)
+                .filter(|&copy| copy != other)
+                .map(|copy| kind * 4 + copy)
+                .collect();
+            let called_id = ids[called];
+            let mut others: Vec<u32> = ids.into_iter().filter(|&id| id != called_id).collect();
+            let meld_kind = if meld & 0x10 != 0 {
+                others.push(kind * 4 + other);
+                MeldKind::Shouminkan
+            } else {
+                MeldKind::Pon
+            };
+            let meld = Meld::new(
+                meld_kind,
+                tiles(&others)?,
+                Some(tile(called_id)?),
+                from_seat,
+            );
+
+            return Ok(Call {
+                group: meld.to_group(),
+                meld: Some(meld),
+                from_player: Some(from_player),
+            });
+        }
+
+        let id = meld >> 8;
+        if meld & 0x20 != 0 {
+            return Ok(Call {
+                meld: None,
+                group: vec![HandTile::new(tile(id)?, Normal)],
+                from_player: None,
+            });
+        }
+
+        // kan: the called (or any, for an ankan) tile
+        let kind = id / 4;
+        let ids: Vec<u32> = (0..4).map(|copy| kind * 4 + copy).collect();
+        if from == 0 {
+            let meld = Meld::ankan(tiles(&ids)?);
+            return Ok(Call {
+                group: meld.to_group(),
+                meld: Some(meld),
+                from_player: None,
+            });
+        }
+
+        let others: Vec<u32> = ids.into_iter().filter(|&other| other != id).collect();
+        let meld = Meld::new(
+            MeldKind::Daiminkan,
+            tiles(&others)?,
+            Some(tile(id)?),
+            from_seat,
+        );
+
+        Ok(Call {
+            group: meld.to_group(),
+            meld: Some(meld),
+            from_player: Some(from_player),
+        })
+    }
+}
+
+/// Parses a Tenhou game log in the mjlog (XML) format into a list of game
+/// events.
+///
+/// Tags that do not describe game events (e.g. the lobby information or
+/// the player disconnections) are skipped.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::tenhou::{parse_mjlog, TenhouEvent};
+/// use riichi_hand::tiles::HAKU;
+///
+/// let log = r#"<mjloggm ver="2.3">
+///     <GO type="169" lobby="0"/>
+///     <INIT seed="0,0,0,2,4,130" ten="250,250,250,250" oya="0"
+///         hai0="0,4,8,12,16,20,24,28,32,36,40,44,48"
+///         hai1="1,5,9,13,17,21,25,29,33,37,41,45,49"
+///         hai2="2,6,10,14,18,22,26,30,34,38,42,46,50"
+///         hai3="3,7,11,15,19,23,27,31,35,39,43,47,51"/>
+///     <T124/><D124/>
+/// </mjloggm>"#;
+///
+/// let events = parse_mjlog(log).unwrap();
+/// assert!(matches!(events[0], TenhouEvent::RoundStart(_)));
+/// assert_eq!(
+///     events[2],
+///     TenhouEvent::Discard {
+///         player: 0,
+///         tile: HAKU,
+///         tsumogiri: true,
+///     }
+/// );
+/// ```
+pub fn parse_mjlog(xml: &str) -> Result<Vec<TenhouEvent>, TenhouParseError> {
+    let mut parser = Parser {
+        red_fives: true,
+        last_draws: [None; 4],
+    };
+    let mut events = Vec::new();
+    for tag in tags(xml)? {
+        if let Some(event) = parser.parse_tag(&tag)? {
+            events.push(event);
+        }
+    }
+
+    Ok(events)
+}
+
+/// Returns a link to Tenhou's hand viewer (`tenhou.net/2`) showing given
+/// closed hand, or `None` if the hand contains called, rotated or face-down
+/// tiles, or tiles that are not real tiles (e.g. [ANY](crate::tiles::ANY)).
+///
+/// The hand is written in the `mpsz` notation (with red fives as `0`), with
+/// the winning tile, if any, at the end, where Tenhou expects the drawn tile.
+/// The viewer does not support dora indicators.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::parser::HandParser;
+/// use riichi_hand::tenhou::hand_url;
+/// use riichi_hand::tiles::CHUN;
+///
+/// let hand = HandParser::parse("123m406p789s11zrr").unwrap();
+/// assert_eq!(
+///     hand_url(&hand, Some(CHUN)).unwrap(),
+///     "https://tenhou.net/2/?q=123m406p789s11777z"
+/// );
+/// assert_eq!(hand_url(&HandParser::parse("123m_1*23p").unwrap(), None), None);
+/// ```
+#[must_use]
+pub fn hand_url(hand: &Hand, win_tile: Option<Tile>) -> Option<String> {
+    if hand.hand_tiles().any(|tile| tile.placement != Normal) {
+        return None;
+    }
+
+    let mut url = String::from(HAND_VIEWER_URL);
+    let mut tiles = hand.tiles().chain(win_tile).peekable();
+    while let Some(tile) = tiles.next() {
+        let suite = match tile.suite {
+            Suite::Manzu => 'm',
+            Suite::Pinzu => 'p',
+            Suite::Souzu => 's',
+            Suite::Honor => 'z',
+            Suite::Any => return None,
+        };
+        url.push_str(&tile.value.to_string());
+        if tiles.peek().map(|next| next.suite) != Some(tile.suite) {
+            url.push(suite);
+        }
+    }
+
+    Some(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::Wind;
+    use crate::meld::MeldKind;
+    use crate::parser::HandParser;
+    use crate::points::{Fu, Han, Honbas, WinMethod};
+    use crate::tenhou::{hand_url, parse_mjlog, tile_from_id, TenhouEvent, TenhouParseError};
+    use crate::tiles::*;
+    use crate::TilePlacement::{FaceDown, Normal, Rotated, RotatedAndShifted};
+    use crate::{HandTile, Tile};
+
+    /// Returns the tiles and placements of the call encoded in given `N` tag
+    /// attribute.
+    fn call(player: usize, meld: u32) -> (Option<MeldKind>, Vec<(Tile, char)>, Option<usize>) {
+        let log = format!(r#"<N who="{}" m="{}"/>"#, player, meld);
+        let events = parse_mjlog(&log).unwrap();
+        match &events[0] {
+            TenhouEvent::Call { call, .. } => {
+                let tiles = call
+                    .group
+                    .iter()
+                    .map(|hand_tile| {
+                        let placement = match hand_tile.placement {
+                            Normal => '-',
+                            Rotated => 'r',
+                            RotatedAndShifted => 's',
+                            FaceDown => 'f',
+                        };
+                        (hand_tile.tile, placement)
+                    })
+                    .collect();
+                (
+                    call.meld.as_ref().map(|meld| meld.kind),
+                    tiles,
+                    call.from_player,
+                )
+            }
+            event => panic!("unexpected event: {:?}", event),
+        }
+    }
+
+    #[test]
+    fn should_decode_calls() {
+        // chii of 3m (second copy) with 4m and 5m (red), called tile 3m
+        let chii = ((2 * 3) << 10) | (1 << 3) | 0x4 | 3;
+        assert_eq!(
+            call(1, chii),
+            (
+                Some(MeldKind::Chii),
+                vec![(SAN_MAN, 'r'), (SUU_MAN, '-'), (AKADORA_MAN, '-')],
+                Some(0)
+            )
+        );
+
+        // pon of chun from across, called the second of the used copies
+        let pon = ((33 * 3 + 1) << 9) | (3 << 5) | 0x8 | 2;
+        assert_eq!(
+            call(0, pon),
+            (
+                Some(MeldKind::Pon),
+                vec![(CHUN, '-'), (CHUN, 'r'), (CHUN, '-')],
+                Some(2)
+            )
+        );
+
+        // shouminkan of ton called from the left
+        let shouminkan = ((27 * 3) << 9) | 0x10 | 3;
+        assert_eq!(
+            call(2, shouminkan),
+            (
+                Some(MeldKind::Shouminkan),
+                vec![(TON, 'r'), (TON, 's'), (TON, '-'), (TON, '-')],
+                Some(1)
+            )
+        );
+
+        // daiminkan of 9s called from the right
+        let daiminkan = ((26 * 4 + 2) << 8) | 1;
+        assert_eq!(
+            call(3, daiminkan),
+            (
+                Some(MeldKind::Daiminkan),
+                vec![
+                    (KYUU_SOU, '-'),
+                    (KYUU_SOU, '-'),
+                    (KYUU_SOU, '-'),
+                    (KYUU_SOU, 'r')
+                ],
+                Some(0)
+            )
+        );
+
+        // ankan of 5p, including the red five
+        let ankan = (13 * 4) << 8;
+        assert_eq!(
+            call(0, ankan),
+            (
+                Some(MeldKind::Ankan),
+                vec![
+                    (AKADORA_PIN, 'f'),
+                    (UU_PIN, '-'),
+                    (UU_PIN, '-'),
+                    (UU_PIN, 'f')
+                ],
+                None
+            )
+        );
+
+        // nukidora (the second copy of pei)
+        let nukidora = ((30 * 4 + 1) << 8) | 0x20;
+        assert_eq!(call(1, nukidora), (None, vec![(PEI, '-')], None));
+    }
+
+    #[test]
+    fn should_parse_game_log() {
+        let log = r#"<mjloggm ver="2.3">
+            <SHUFFLE seed="mt19937ar-sha512-n288-base64,..." ref=""/>
+            <GO type="169" lobby="0"/>
+            <UN n0="%E3%81%82" n1="B" n2="C" n3="D" dan="0,0,0,0"/>
+            <TAIKYOKU oya="0"/>
+            <INIT seed="5,1,2,3,4,0" ten="240,250,260,250" oya="1"
+                hai0="1,2,3" hai1="4,5,6" hai2="" hai3=""/>
+            <T10/><D10/>
+            <U20/><E4/>
+            <N who="0" m="51306"/>
+            <REACH who="1" step="1"/><E30/><REACH who="1" step="2" ten="250,240,260,250"/>
+            <DORA hai="108"/>
+            <AGARI ba="1,1" hai="0,1,2,36,40,44,72,76,80,108,109" m="51306" machi="109"
+                ten="40,3900,0" yaku="18,1,54,2" doraHai="0" doraHaiUra="4"
+                who="0" fromWho="1" sc="240,49,250,-42,260,0,250,0"/>
+            <RYUUKYOKU type="yao9" ba="0,0" sc="250,0,250,0,250,0,250,0" hai2="1,2"/>
+        </mjloggm>"#;
+
+        let events = parse_mjlog(log).unwrap();
+
+        assert_eq!(events.len(), 13);
+        match &events[0] {
+            TenhouEvent::Players(names) => assert_eq!(names[0], "あ"),
+            event => panic!("unexpected event: {:?}", event),
+        }
+        match &events[1] {
+            TenhouEvent::RoundStart(round) => {
+                assert_eq!(round.round_wind, Wind::South);
+                assert_eq!(round.hand_number, 2);
+                assert_eq!(round.honbas, Honbas::new(1));
+                assert_eq!(round.riichi_sticks, 2);
+                assert_eq!(round.dora_indicator, II_MAN);
+                assert_eq!(round.dealer, 1);
+                assert_eq!(round.scores, [24000, 25000, 26000, 25000]);
+                assert_eq!(round.hands[0], vec![II_MAN, II_MAN, II_MAN]);
+                assert!(round.hands[2].is_empty());
+            }
+            event => panic!("unexpected event: {:?}", event),
+        }
+        assert_eq!(
+            events[3],
+            TenhouEvent::Discard {
+                player: 0,
+                tile: SAN_MAN,
+                tsumogiri: true
+            }
+        );
+        assert_eq!(
+            events[5],
+            TenhouEvent::Discard {
+                player: 1,
+                tile: RYAN_MAN,
+                tsumogiri: false
+            }
+        );
+        assert!(matches!(
+            &events[6],
+            TenhouEvent::Call { player: 0, call }
+                if call.meld.as_ref().map(|meld| meld.kind) == Some(MeldKind::Pon)
+        ));
+        assert_eq!(
+            events[7],
+            TenhouEvent::Riichi {
+                player: 1,
+                accepted: false
+            }
+        );
+        assert_eq!(events[10], TenhouEvent::Dora(TON));
+        match &events[11] {
+            TenhouEvent::Agari(agari) => {
+                assert_eq!((agari.winner, agari.from_player), (0, 1));
+                assert_eq!(agari.win_method, WinMethod::Ron);
+                assert_eq!(agari.win_tile, TON);
+                assert_eq!(
+                    (agari.han, agari.fu, agari.points),
+                    (Han::new(3), Fu::new(40), 3900)
+                );
+                assert_eq!(agari.yaku, vec![(18, Han::new(1)), (54, Han::new(2))]);
+                assert_eq!(agari.dora_indicators, vec![II_MAN]);
+                assert_eq!(agari.ura_dora_indicators, vec![RYAN_MAN]);
+                assert_eq!(agari.score_changes, [4900, -4200, 0, 0]);
+                let groups = agari.hand.groups();
+                assert_eq!(groups.len(), 3);
+                assert_eq!(groups[0].len(), 10);
+                assert_eq!(groups[1], vec![HandTile::new(TON, Normal)]);
+                assert_eq!(groups[2].len(), 3);
+            }
+            event => panic!("unexpected event: {:?}", event),
+        }
+        match events.last().unwrap() {
+            TenhouEvent::Ryuukyoku(ryuukyoku) => {
+                assert_eq!(ryuukyoku.kind.as_deref(), Some("yao9"));
+                assert_eq!(ryuukyoku.tenpai, [false, false, true, false]);
+            }
+            event => panic!("unexpected event: {:?}", event),
+        }
+    }
+
+    #[test]
+    fn should_respect_red_fives_flag() {
+        let events = parse_mjlog(r#"<GO type="171"/><T16/>"#).unwrap();
+
+        assert_eq!(
+            events[0],
+            TenhouEvent::Draw {
+                player: 0,
+                tile: UU_MAN
+            }
+        );
+    }
+
+    #[test]
+    fn should_return_parse_errors() {
+        assert_eq!(
+            parse_mjlog("<INIT seed=\"0\""),
+            Err(TenhouParseError::InvalidXml(0))
+        );
+        assert_eq!(
+            parse_mjlog("<REACH step=\"1\"/>"),
+            Err(TenhouParseError::MissingAttribute {
+                tag: "REACH".to_owned(),
+                attribute: "who"
+            })
+        );
+        assert_eq!(
+            parse_mjlog("<DORA hai=\"136\"/>"),
+            Err(TenhouParseError::InvalidAttribute {
+                tag: "DORA".to_owned(),
+                attribute: "hai",
+                value: "136".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn should_convert_hands_to_tile_ids() {
+        let hand = HandParser::parse("0555m_5505s_EEEE").unwrap();
+        let ids = hand.to_tenhou_ids().unwrap();
+        assert_eq!(
+            ids,
+            vec![16, 17, 18, 19, 89, 90, 88, 91, 108, 109, 110, 111]
+        );
+        let tiles: Vec<_> = ids
+            .iter()
+            .map(|&id| tile_from_id(id, true).unwrap())
+            .collect();
+        assert_eq!(tiles, hand.tiles().collect::<Vec<_>>());
+
+        assert_eq!(
+            HandParser::parse("5555p").unwrap().to_tenhou_ids().unwrap(),
+            vec![53, 54, 55, 52]
+        );
+        assert_eq!(HandParser::parse("00s").unwrap().to_tenhou_ids(), None);
+        assert_eq!(HandParser::parse("1m?").unwrap().to_tenhou_ids(), None);
+    }
+
+    #[test]
+    fn should_return_hand_urls() {
+        let hand = HandParser::parse("1119m_5550p_EE").unwrap();
+        assert_eq!(
+            hand_url(&hand, None).unwrap(),
+            "https://tenhou.net/2/?q=1119m5550p11z"
+        );
+        assert_eq!(
+            hand_url(&hand, Some(KYUU_MAN)).unwrap(),
+            "https://tenhou.net/2/?q=1119m5550p11z9m"
+        );
+
+        let hand = HandParser::parse("123m?").unwrap();
+        assert_eq!(hand_url(&hand, None), None);
+        let hand = HandParser::parse("123m")
+            .unwrap()
+            .with_face_down_closed_kans();
+        assert_eq!(hand_url(&hand, Some(ANY)), None);
+        let hand = HandParser::parse("1111m")
+            .unwrap()
+            .with_face_down_closed_kans();
+        assert_eq!(hand_url(&hand, None), None);
+    }
+}
@@ -0,0 +1,220 @@
+use std::convert::Infallible;
+
+use crate::renderer::{HandRenderer, Layout, LayoutOptions};
+use crate::TilePlacement::{FaceDown, Normal, Rotated, RotatedAndShifted};
+use crate::{Hand, Suite, Tile};
+
+const HONOR_LETTERS: [&str; 7] = ["E", "S", "W", "N", "w", "g", "r"];
+const HONOR_GLYPHS: [char; 7] = [
+    '\u{1F000}',
+    '\u{1F001}',
+    '\u{1F002}',
+    '\u{1F003}',
+    '\u{1F006}',
+    '\u{1F005}',
+    '\u{1F004}',
+];
+const MANZU_GLYPH_START: u32 = 0x1F007;
+const SOUZU_GLYPH_START: u32 = 0x1F010;
+const PINZU_GLYPH_START: u32 = 0x1F019;
+const ANY_LETTERS: [&str; 3] = ["?", "-", "??"];
+/// Glyphs of the tile back, the blank tile and the unknown tile. There are no
+/// Mahjong Tiles glyphs for the latter two.
+const ANY_GLYPHS: [char; 3] = ['\u{1F02B}', '\u{25AF}', '?'];
+
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash)]
+/// Style of the tiles in the text produced by [TextRenderer].
+pub enum TextStyle {
+    /// Plain ASCII notation, with the number tiles written as value and suite
+    /// (e.g. `1m`, `0p` for a red five), the honors as the letters used by
+    /// [HandParser](crate::parser::HandParser) (`E`, `S`, `W`, `N`, `w`, `g`,
+    /// `r`), tile backs as `?`, blank tiles as `-` and unknown tiles as `??`.
+    /// The tiles are separated by spaces and the groups by ` | `.
+    #[default]
+    Ascii,
+    /// Glyphs from the Unicode Mahjong Tiles block (e.g. `🀇`). Red fives are
+    /// rendered as regular fives, since there is no separate glyph for them.
+    /// The tiles are not separated and the groups are separated by spaces.
+    Unicode,
+}
+
+#[derive(Copy, Clone, Debug)]
+/// Renders a [Hand] instance to a single line of text.
+///
+/// Rotated tiles are enclosed in square brackets, and rotated and shifted
+/// tiles (i.e. the tiles added to a kan) in double square brackets.
+///
+/// # Examples
+/// ```
+/// use riichi_hand::parser::HandParser;
+/// use riichi_hand::text_renderer::{TextRenderer, TextStyle};
+///
+/// let hand = HandParser::parse("123m_E*EE").unwrap();
+///
+/// assert_eq!(TextRenderer::render(&hand, TextStyle::Ascii), "1m 2m 3m | [E] E E");
+/// assert_eq!(TextRenderer::render(&hand, TextStyle::Unicode), "🀇🀈🀉 [🀀]🀀🀀");
+/// ```
+pub struct TextRenderer {
+    style: TextStyle,
+}
+
+impl TextRenderer {
+    #[inline]
+    /// Renders given [Hand] instance using given [TextStyle].
+    #[must_use]
+    pub fn render(hand: &Hand, style: TextStyle) -> String {
+        let renderer = Self::new(style);
+        renderer.render_text(&Layout::new(hand, renderer.layout_options()))
+    }
+
+    #[inline]
+    /// Creates a new renderer instance that uses given [TextStyle]. The
+    /// instance implements [HandRenderer].
+    #[must_use]
+    pub const fn new(style: TextStyle) -> Self {
+        Self { style }
+    }
+
+    fn render_text(&self, layout: &Layout) -> String {
+        let (tile_separator, group_separator) = match self.style {
+            TextStyle::Ascii => (" ", " | "),
+            TextStyle::Unicode => ("", " "),
+        };
+
+        let mut text = String::new();
+        let mut last_group = None;
+        for position in &layout.tiles {
+            match last_group {
+                Some(group) if group != position.group => text.push_str(group_separator),
+                Some(_) => text.push_str(tile_separator),
+                None => {}
+            }
+            last_group = Some(position.group);
+
+            let name = self.tile_text(&position.tile.tile);
+            match position.tile.placement {
+                Normal | FaceDown => text.push_str(&name),
+                Rotated => text.push_str(&format!("[{}]", name)),
+                RotatedAndShifted => text.push_str(&format!("[[{}]]", name)),
+            }
+        }
+
+        text
+    }
+
+    fn tile_text(&self, tile: &Tile) -> String {
+        match self.style {
+            TextStyle::Ascii => Self::tile_ascii(tile),
+            TextStyle::Unicode => Self::tile_glyph(tile).to_string(),
+        }
+    }
+
+    fn tile_ascii(tile: &Tile) -> String {
+        let letters = match tile.suite {
+            Suite::Manzu => return format!("{}m", tile.value),
+            Suite::Pinzu => return format!("{}p", tile.value),
+            Suite::Souzu => return format!("{}s", tile.value),
+            Suite::Honor => Self::honor_index(tile).and_then(|index| HONOR_LETTERS.get(index)),
+            Suite::Any => ANY_LETTERS.get(usize::from(tile.value)),
+        };
+
+        // invalid tiles (e.g. created without `Tile::new`) are rendered as
+        // unknown ones
+        letters.unwrap_or(&ANY_LETTERS[2]).to_string()
+    }
+
+    fn tile_glyph(tile: &Tile) -> char {
+        let start = match tile.suite {
+            Suite::Manzu => MANZU_GLYPH_START,
+            Suite::Pinzu => PINZU_GLYPH_START,
+            Suite::Souzu => SOUZU_GLYPH_START,
+            Suite::Honor => {
+                return Self::honor_index(tile)
+                    .and_then(|index| HONOR_GLYPHS.get(index))
+                    .map_or(ANY_GLYPHS[2], |&glyph| glyph)
+            }
+            Suite::Any => {
+                return *ANY_GLYPHS
+                    .get(usize::from(tile.value))
+                    .unwrap_or(&ANY_GLYPHS[2])
+            }
+        };
+        // red fives use the glyph of the regular five
+        let value = match tile.value.0 {
+            0 => 5,
+            value @ 1..=9 => value,
+            _ => return ANY_GLYPHS[2],
+        };
+
+        char::from_u32(start + u32::from(value) - 1).unwrap_or(ANY_GLYPHS[2])
+    }
+
+    fn honor_index(tile: &Tile) -> Option<usize> {
+        usize::from(tile.value).checked_sub(1)
+    }
+}
+
+impl HandRenderer for TextRenderer {
+    type Output = String;
+    type Error = Infallible;
+
+    fn layout_options(&self) -> LayoutOptions {
+        LayoutOptions::new(1, 1, 0, 0)
+    }
+
+    fn render_layout(&self, layout: &Layout) -> Result<Self::Output, Self::Error> {
+        Ok(self.render_text(layout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::HandParser;
+    use crate::renderer::HandRenderer;
+    use crate::text_renderer::{TextRenderer, TextStyle};
+    use crate::{Hand, HandTile, Suite, Tile, TilePlacement, TileValue};
+
+    #[test]
+    fn should_render_ascii_text() {
+        let hand = HandParser::parse("1230m_wgr?_S*S**SS").unwrap();
+
+        assert_eq!(
+            TextRenderer::render(&hand, TextStyle::Ascii),
+            "1m 2m 3m 0m | w g r ? | [S] [[S]] S S"
+        );
+    }
+
+    #[test]
+    fn should_render_unicode_text() {
+        let hand = HandParser::parse("19p09s_NW*W?").unwrap();
+
+        assert_eq!(
+            TextRenderer::new(TextStyle::Unicode)
+                .render_hand(&hand)
+                .unwrap(),
+            "🀙🀡🀔🀘 🀃[🀂]🀂🀫"
+        );
+    }
+
+    #[test]
+    fn should_render_invalid_tiles_as_unknown() {
+        let invalid = HandTile::new(
+            Tile {
+                suite: Suite::Honor,
+                value: TileValue(9),
+            },
+            TilePlacement::Normal,
+        );
+        let hand = Hand::new(vec![vec![invalid]]);
+
+        assert_eq!(TextRenderer::render(&hand, TextStyle::Ascii), "??");
+        assert_eq!(TextRenderer::render(&hand, TextStyle::Unicode), "?");
+    }
+
+    #[test]
+    fn should_render_empty_hand() {
+        let hand = HandParser::parse("").unwrap();
+
+        assert_eq!(TextRenderer::render(&hand, TextStyle::Ascii), "");
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Suite, Tile, TileValue};
+use crate::{BonusTile, Suite, Tile, TileValue};
 
 // Manzu
 /// Akadora man (red 5man) tile.
@@ -194,11 +194,25 @@ pub const CHUN: Tile = Tile {
 };
 
 // Any
-/// Any tile.
+/// Any tile, rendered as the back of a tile.
 pub const ANY: Tile = Tile {
     suite: Suite::Any,
     value: TileValue(0),
 };
+/// Blank tile, rendered as the front of a tile without any symbol.
+///
+/// Unlike [ALL_TILES], the tile sets are not required to support it.
+pub const BLANK: Tile = Tile {
+    suite: Suite::Any,
+    value: TileValue(1),
+};
+/// Unknown tile, rendered as the front of a tile with a question mark.
+///
+/// Unlike [ALL_TILES], the tile sets are not required to support it.
+pub const UNKNOWN: Tile = Tile {
+    suite: Suite::Any,
+    value: TileValue(2),
+};
 
 // All
 /// A list containing all valid tiles.
@@ -242,3 +256,15 @@ pub const ALL_TILES: [Tile; 38] = [
     CHUN,
     ANY,
 ];
+
+/// A list containing all the bonus tiles (flowers, then seasons).
+pub const ALL_BONUS_TILES: [BonusTile; 8] = [
+    BonusTile::Plum,
+    BonusTile::Orchid,
+    BonusTile::Chrysanthemum,
+    BonusTile::Bamboo,
+    BonusTile::Spring,
+    BonusTile::Summer,
+    BonusTile::Autumn,
+    BonusTile::Winter,
+];